@@ -0,0 +1,173 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_common::interface::account::Account;
+use nautilus_model::{
+    enums::AccountType,
+    events::account::state::AccountState,
+    identifiers::account_id::AccountId,
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+    },
+};
+
+use crate::account::{cash::CashAccount, margin::MarginAccount};
+
+#[derive(Debug)]
+pub enum AccountAny {
+    Cash(CashAccount),
+    Margin(MarginAccount),
+}
+
+impl AccountAny {
+    /// Creates a new account of the type given by the account state `event`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the account type is not `CASH` or `MARGIN`.
+    pub fn from_state(event: AccountState, calculate_account_state: bool) -> anyhow::Result<Self> {
+        match event.account_type {
+            AccountType::Cash => Ok(Self::Cash(CashAccount::new(
+                event,
+                calculate_account_state,
+            )?)),
+            AccountType::Margin => Ok(Self::Margin(MarginAccount::new(
+                event,
+                calculate_account_state,
+            )?)),
+            AccountType::Betting => anyhow::bail!("Betting accounts are not yet supported"),
+        }
+    }
+
+    #[must_use]
+    pub fn as_account(&self) -> &dyn Account {
+        match self {
+            Self::Cash(account) => account,
+            Self::Margin(account) => account,
+        }
+    }
+
+    #[must_use]
+    pub fn id(&self) -> AccountId {
+        self.as_account().id()
+    }
+
+    #[must_use]
+    pub fn account_type(&self) -> AccountType {
+        self.as_account().account_type()
+    }
+
+    #[must_use]
+    pub fn is_margin_account(&self) -> bool {
+        matches!(self, Self::Margin(_))
+    }
+
+    #[must_use]
+    pub fn balance(&self, currency: &Currency) -> Option<AccountBalance> {
+        self.as_account().balances().get(currency).copied()
+    }
+
+    #[must_use]
+    pub fn margins(&self) -> Vec<MarginBalance> {
+        match self {
+            Self::Cash(_) => Vec::new(),
+            Self::Margin(account) => account.margins.values().copied().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn last_event(&self) -> Option<AccountState> {
+        self.as_account().last_event()
+    }
+
+    #[must_use]
+    pub fn event_count(&self) -> usize {
+        self.as_account().event_count()
+    }
+
+    pub fn apply(&mut self, event: AccountState) {
+        match self {
+            Self::Cash(account) => account.apply(event),
+            Self::Margin(account) => account.apply(event),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::AccountType,
+        events::account::{
+            state::AccountState,
+            stubs::{cash_account_state, margin_account_state},
+        },
+        types::{balance::AccountBalance, currency::Currency, money::Money},
+    };
+    use rstest::rstest;
+
+    use crate::account::any::AccountAny;
+
+    #[rstest]
+    fn test_from_state_cash(cash_account_state: AccountState) {
+        let account = AccountAny::from_state(cash_account_state.clone(), true).unwrap();
+
+        assert!(matches!(account, AccountAny::Cash(_)));
+        assert_eq!(account.id(), cash_account_state.account_id);
+        assert_eq!(account.account_type(), AccountType::Cash);
+        assert!(!account.is_margin_account());
+        assert!(account.margins().is_empty());
+        assert_eq!(account.last_event(), Some(cash_account_state));
+    }
+
+    #[rstest]
+    fn test_from_state_margin(margin_account_state: AccountState) {
+        let account = AccountAny::from_state(margin_account_state, true).unwrap();
+
+        assert!(matches!(account, AccountAny::Margin(_)));
+        assert!(account.is_margin_account());
+    }
+
+    #[rstest]
+    fn test_from_state_betting_returns_error(mut cash_account_state: AccountState) {
+        cash_account_state.account_type = AccountType::Betting;
+
+        assert!(AccountAny::from_state(cash_account_state, true).is_err());
+    }
+
+    #[rstest]
+    fn test_apply_updates_balances(cash_account_state: AccountState) {
+        let mut account = AccountAny::from_state(cash_account_state.clone(), true).unwrap();
+        let mut event = cash_account_state;
+        event.balances = vec![AccountBalance::new(
+            Money::from("1000000 USD"),
+            Money::from("0 USD"),
+            Money::from("1000000 USD"),
+        )
+        .unwrap()];
+
+        account.apply(event);
+
+        let balance = account.balance(&Currency::USD()).unwrap();
+        assert_eq!(account.event_count(), 2);
+        assert_eq!(balance.total, Money::from("1000000 USD"));
+        assert_eq!(balance.free, Money::from("1000000 USD"));
+        assert!(account.balance(&Currency::AUD()).is_none());
+    }
+}
@@ -15,6 +15,7 @@
 
 //! Provides account types and accounting functionality.
 
+pub mod any;
 pub mod base;
 pub mod cash;
 pub mod margin;
@@ -15,12 +15,40 @@
 
 //! The core `BacktestEngine` for backtesting on historical data.
 
-use std::ops::{Deref, DerefMut};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    iter::Peekable,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
 
-use nautilus_common::{clock::TestClock, ffi::clock::TestClock_API, timer::TimeEventHandler};
+use indexmap::IndexMap;
+use log::{error, info, warn};
+use nautilus_common::{
+    cache::Cache, clock::TestClock, ffi::clock::TestClock_API, msgbus::MessageBus,
+    timer::TimeEventHandler,
+};
 use nautilus_core::{
     ffi::{cvec::CVec, parsing::u8_as_bool},
     nanos::UnixNanos,
+    time::{get_atomic_clock_realtime, AtomicTime},
+    uuid::UUID4,
+};
+use nautilus_model::{
+    data::Data,
+    enums::{AccountType, BookType, OmsType},
+    identifiers::{instrument_id::InstrumentId, trader_id::TraderId, venue::Venue},
+    instruments::any::InstrumentAny,
+    polymorphism::GetTsInit,
+    types::{currency::Currency, money::Money},
+};
+use nautilus_persistence::backend::session::DataBackendSession;
+
+use crate::{
+    exchange::{data_instrument_id, SimulatedExchange},
+    matching_engine::OrderMatchingEngineConfig,
+    models::{LatencyModel, ProbabilisticFillModel},
 };
 
 /// Provides a means of accumulating and draining time event handlers.
@@ -60,9 +88,464 @@ impl Default for TimeEventAccumulator {
     }
 }
 
+/// Represents the results of a single complete backtest run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BacktestResult {
+    /// The trader ID for the run.
+    pub trader_id: TraderId,
+    /// The instance ID of the engine.
+    pub instance_id: UUID4,
+    /// The unique ID of the run.
+    pub run_id: Option<UUID4>,
+    /// UNIX timestamp (nanoseconds) when the run started (wall clock).
+    pub run_started: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) when the run finished (wall clock).
+    pub run_finished: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) of the start of the backtest period.
+    pub backtest_start: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) of the end of the backtest period.
+    pub backtest_end: Option<UnixNanos>,
+    /// The elapsed backtest time (seconds).
+    pub elapsed_time: f64,
+    /// The total data iterations for the run.
+    pub iterations: usize,
+    /// The total time events processed during the run.
+    pub total_events: usize,
+    /// The total orders held in the cache.
+    pub total_orders: usize,
+    /// The total positions held in the cache.
+    pub total_positions: usize,
+}
+
+/// Provides a backtest engine which runs simulated venues over a time-ordered data stream.
+///
+/// Each venue is a `SimulatedExchange` holding an `OrderMatchingEngine` per instrument. Data is
+/// routed to the venue of its instrument, with time events from the engines `TestClock` processed
+/// in between. Time events are published on the message bus to the topic `events.time.{name}`,
+/// and dispatched to the handlers registered on the clock.
+pub struct BacktestEngine {
+    /// The trader ID for the engine.
+    pub trader_id: TraderId,
+    /// The instance ID for the engine.
+    pub instance_id: UUID4,
+    clock: &'static AtomicTime,
+    test_clock: TestClock,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    venues: IndexMap<Venue, Rc<RefCell<SimulatedExchange>>>,
+    run_id: Option<UUID4>,
+    iteration: usize,
+    total_events: usize,
+    run_started: Option<UnixNanos>,
+    run_finished: Option<UnixNanos>,
+    backtest_start: Option<UnixNanos>,
+    backtest_end: Option<UnixNanos>,
+}
+
+impl BacktestEngine {
+    /// Creates a new [`BacktestEngine`] instance.
+    ///
+    /// The `clock` is set to backtest time during a run and is shared with all venues.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message bus cannot be created.
+    pub fn new(trader_id: TraderId, clock: &'static AtomicTime) -> anyhow::Result<Self> {
+        let instance_id = UUID4::new();
+        let msgbus = MessageBus::new(trader_id, instance_id, None, None)?;
+        Ok(Self {
+            trader_id,
+            instance_id,
+            clock,
+            test_clock: TestClock::new(),
+            msgbus: Rc::new(RefCell::new(msgbus)),
+            cache: Rc::new(RefCell::new(Cache::default())),
+            venues: IndexMap::new(),
+            run_id: None,
+            iteration: 0,
+            total_events: 0,
+            run_started: None,
+            run_finished: None,
+            backtest_start: None,
+            backtest_end: None,
+        })
+    }
+
+    /// Returns the message bus shared by the engine and its venues.
+    #[must_use]
+    pub fn msgbus(&self) -> Rc<RefCell<MessageBus>> {
+        self.msgbus.clone()
+    }
+
+    /// Returns the cache shared by the engine and its venues.
+    #[must_use]
+    pub fn cache(&self) -> Rc<RefCell<Cache>> {
+        self.cache.clone()
+    }
+
+    /// Returns a mutable reference to the engines test clock, for setting time alerts and timers.
+    pub fn clock_mut(&mut self) -> &mut TestClock {
+        &mut self.test_clock
+    }
+
+    /// Returns the current backtest iteration count.
+    #[must_use]
+    pub fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// Returns the venues added to the engine.
+    #[must_use]
+    pub fn venues(&self) -> Vec<Venue> {
+        self.venues.keys().copied().collect()
+    }
+
+    /// Returns the simulated exchange for the given `venue` (if found).
+    #[must_use]
+    pub fn get_venue(&self, venue: &Venue) -> Option<Rc<RefCell<SimulatedExchange>>> {
+        self.venues.get(venue).cloned()
+    }
+
+    /// Adds a simulated exchange with the given parameters to the engine.
+    ///
+    /// The `default_leverage` defaults to 1 (unleveraged), and only applies to `MARGIN` accounts.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `venue` has already been added to the engine.
+    /// - If the account configuration is invalid for the exchange.
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_venue(
+        &mut self,
+        venue: Venue,
+        oms_type: OmsType,
+        account_type: AccountType,
+        book_type: BookType,
+        starting_balances: Vec<Money>,
+        base_currency: Option<Currency>,
+        default_leverage: Option<f64>,
+        leverages: Option<HashMap<InstrumentId, f64>>,
+        fill_model: Option<ProbabilisticFillModel>,
+        latency_model: Option<LatencyModel>,
+        frozen_account: bool,
+        config: Option<OrderMatchingEngineConfig>,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            !self.venues.contains_key(&venue),
+            "Venue {venue} already added to engine"
+        );
+
+        let exchange = SimulatedExchange::new(
+            venue,
+            oms_type,
+            account_type,
+            starting_balances,
+            base_currency,
+            default_leverage.unwrap_or(1.0),
+            leverages.unwrap_or_default(),
+            book_type,
+            self.clock,
+            self.msgbus.clone(),
+            self.cache.clone(),
+            fill_model.unwrap_or_default(),
+            latency_model,
+            frozen_account,
+            config.unwrap_or_default(),
+        )?;
+        self.venues.insert(venue, Rc::new(RefCell::new(exchange)));
+
+        info!("Added venue {venue}");
+        Ok(())
+    }
+
+    /// Adds the given `instrument` to the cache and to the simulated exchange for its venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the venue for the instrument has not been added to the engine.
+    /// - If the instrument cannot be added to the cache or the exchange.
+    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        let instrument_id = instrument.id();
+        let Some(exchange) = self.venues.get(&instrument_id.venue) else {
+            anyhow::bail!(
+                "Cannot add instrument {instrument_id}: venue {} not added to engine",
+                instrument_id.venue
+            );
+        };
+
+        self.cache.borrow_mut().add_instrument(instrument.clone())?;
+        exchange.borrow_mut().add_instrument(instrument)?;
+
+        info!("Added instrument {instrument_id}");
+        Ok(())
+    }
+
+    /// Runs the backtest over the k-merged data stream from the given `session`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `start` is not before `end`.
+    pub fn run_session(
+        &mut self,
+        session: &mut DataBackendSession,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()> {
+        self.run(session.get_query_result(), start, end)
+    }
+
+    /// Runs the backtest over the given `data`, which must be ordered by `ts_init`.
+    ///
+    /// Data prior to `start` is skipped, and the run stops at the first data after `end`.
+    /// The engine may be run again for a following period, continuing from its current state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `start` is not before `end`.
+    pub fn run<I>(
+        &mut self,
+        data: I,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<()>
+    where
+        I: IntoIterator<Item = Data>,
+    {
+        if let (Some(start), Some(end)) = (start, end) {
+            anyhow::ensure!(start < end, "`start` {start} was >= `end` {end}");
+        }
+
+        let mut data = data
+            .into_iter()
+            .skip_while(|data| start.is_some_and(|start| data.ts_init() < start))
+            .peekable();
+
+        let Some(start_ns) = start.or_else(|| data.peek().map(GetTsInit::ts_init)) else {
+            warn!("No data to run");
+            return Ok(());
+        };
+
+        self.set_time(start_ns);
+
+        if self.iteration == 0 {
+            self.run_id = Some(UUID4::new());
+            self.run_started = Some(get_atomic_clock_realtime().get_time_ns());
+            self.backtest_start = Some(start_ns);
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().initialize_account();
+            }
+        }
+
+        let last_ns = self.run_loop(&mut data, end);
+
+        // Process remaining messages
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().process(self.clock.get_time_ns());
+        }
+
+        self.run_finished = Some(get_atomic_clock_realtime().get_time_ns());
+        self.backtest_end = Some(end.unwrap_or(last_ns));
+
+        info!("Backtest run completed after {} iterations", self.iteration);
+        Ok(())
+    }
+
+    /// Returns the result summary for the backtest run.
+    #[must_use]
+    pub fn get_result(&self) -> BacktestResult {
+        let elapsed_time = match (self.backtest_start, self.backtest_end) {
+            (Some(start), Some(end)) => end.as_u64().saturating_sub(start.as_u64()) as f64 / 1e9,
+            _ => 0.0,
+        };
+        let cache = self.cache.borrow();
+
+        BacktestResult {
+            trader_id: self.trader_id,
+            instance_id: self.instance_id,
+            run_id: self.run_id,
+            run_started: self.run_started,
+            run_finished: self.run_finished,
+            backtest_start: self.backtest_start,
+            backtest_end: self.backtest_end,
+            elapsed_time,
+            iterations: self.iteration,
+            total_events: self.total_events,
+            total_orders: cache.orders_total_count(None, None, None, None),
+            total_positions: cache.positions_total_count(None, None, None, None) as usize,
+        }
+    }
+
+    /// Reset the engine and all venues, ready for a new run.
+    pub fn reset(&mut self) {
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().reset();
+        }
+        self.cache.borrow_mut().reset();
+        self.test_clock = TestClock::new();
+        self.run_id = None;
+        self.iteration = 0;
+        self.total_events = 0;
+        self.run_started = None;
+        self.run_finished = None;
+        self.backtest_start = None;
+        self.backtest_end = None;
+
+        info!("Reset engine");
+    }
+
+    fn run_loop<I>(&mut self, data: &mut Peekable<I>, end: Option<UnixNanos>) -> UnixNanos
+    where
+        I: Iterator<Item = Data>,
+    {
+        let mut last_ns: Option<UnixNanos> = None;
+        let mut now_events: Vec<TimeEventHandler> = Vec::new();
+
+        while let Some(next) =
+            data.next_if(|data| !matches!(end, Some(end) if data.ts_init() > end))
+        {
+            let ts_init = next.ts_init();
+            if last_ns < Some(ts_init) {
+                // Advance clocks to the next data time
+                now_events = self.advance_time(ts_init);
+            }
+
+            self.process_data(&next);
+
+            // Process all exchange messages
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().process(ts_init);
+            }
+
+            last_ns = Some(ts_init);
+            if !matches!(data.peek(), Some(data) if data.ts_init() <= ts_init) {
+                // Finally process the time events for the current time
+                for handler in now_events.drain(..) {
+                    self.process_time_event(&handler);
+                }
+            }
+
+            self.iteration += 1;
+        }
+
+        last_ns.unwrap_or_else(|| self.clock.get_time_ns())
+    }
+
+    /// Advances time to `ts_now`, processing all time events prior to `ts_now` in order and
+    /// returning the handlers for `ts_now` (to be processed after the data for that time).
+    fn advance_time(&mut self, ts_now: UnixNanos) -> Vec<TimeEventHandler> {
+        let events = self.test_clock.advance_time(ts_now, false);
+        let mut handlers = self.test_clock.match_handlers(events);
+        let now_handlers =
+            handlers.split_off(handlers.partition_point(|h| h.event.ts_event < ts_now));
+
+        for handler in handlers {
+            let ts_event = handler.event.ts_event;
+            self.set_time(ts_event);
+            self.process_time_event(&handler);
+
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().process(ts_event);
+            }
+        }
+
+        self.set_time(ts_now);
+        now_handlers
+    }
+
+    fn process_time_event(&mut self, handler: &TimeEventHandler) {
+        let event = &handler.event;
+        let topic = format!("events.time.{}", event.name);
+        self.msgbus.borrow().publish(&topic, event);
+        call_time_event_handler(handler);
+        self.total_events += 1;
+    }
+
+    fn process_data(&mut self, data: &Data) {
+        let instrument_id = data_instrument_id(data);
+
+        let result = match data {
+            Data::Quote(quote) => self.cache.borrow_mut().add_quote(*quote),
+            Data::Trade(trade) => self.cache.borrow_mut().add_trade(*trade),
+            Data::Bar(bar) => self.cache.borrow_mut().add_bar(*bar),
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            error!("Error caching data for {instrument_id}: {e}");
+        }
+
+        let msgbus = self.msgbus.borrow();
+        let (venue, symbol) = (instrument_id.venue, instrument_id.symbol);
+        match data {
+            Data::Delta(delta) => {
+                msgbus.publish(&format!("data.book.deltas.{venue}.{symbol}"), delta)
+            }
+            Data::Deltas(deltas) => {
+                msgbus.publish(&format!("data.book.deltas.{venue}.{symbol}"), &**deltas);
+            }
+            Data::Depth10(depth) => {
+                msgbus.publish(&format!("data.book.depth.{venue}.{symbol}"), depth)
+            }
+            Data::Quote(quote) => msgbus.publish(&format!("data.quotes.{venue}.{symbol}"), quote),
+            Data::Trade(trade) => msgbus.publish(&format!("data.trades.{venue}.{symbol}"), trade),
+            Data::Bar(bar) => msgbus.publish(&format!("data.bars.{}", bar.bar_type), bar),
+        }
+        drop(msgbus);
+
+        match self.venues.get(&instrument_id.venue) {
+            Some(exchange) => exchange.borrow_mut().process_data(data),
+            None => error!("No venue found for {instrument_id}"),
+        }
+    }
+
+    fn set_time(&mut self, ts: UnixNanos) {
+        self.clock.set_time(ts);
+        self.test_clock.set_time(ts);
+    }
+}
+
+/// Calls the Python callable of the given `handler` with its time event.
+#[cfg(feature = "python")]
+fn call_time_event_handler(handler: &TimeEventHandler) {
+    use pyo3::{IntoPy, PyObject, Python};
+
+    Python::with_gil(|py| {
+        // SAFETY: `callback_ptr` is borrowed from a handler registered on the clock,
+        // which holds a reference for as long as the handler is registered
+        let callback = unsafe { PyObject::from_borrowed_ptr(py, handler.callback_ptr.cast()) };
+        if callback.is_none(py) {
+            return;
+        }
+
+        if let Err(e) = callback.call1(py, (handler.event.clone().into_py(py),)) {
+            error!(
+                "Error on time event handler for {}: {e}",
+                handler.event.name
+            );
+        }
+    });
+}
+
+/// Skips the Python callable of the given `handler`, which cannot be called without the
+/// `python` feature (Rust handlers receive the time event from its message bus topic).
+#[cfg(not(feature = "python"))]
+fn call_time_event_handler(handler: &TimeEventHandler) {
+    if !handler.callback_ptr.is_null() {
+        warn!(
+            "Skipping time event handler for {}: no Rust callback (`python` feature not enabled)",
+            handler.event.name
+        );
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+
 #[repr(C)]
 pub struct TimeEventAccumulatorAPI(Box<TimeEventAccumulator>);
 
@@ -112,14 +595,294 @@ pub extern "C" fn time_event_accumulator_drain(accumulator: &mut TimeEventAccumu
 mod tests {
     use std::ffi::c_char;
 
-    use nautilus_common::timer::TimeEvent;
+    use nautilus_common::{
+        clock::Clock,
+        handlers::{AnyMessageHandler, EventHandler},
+        timer::TimeEvent,
+    };
     use nautilus_core::uuid::UUID4;
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        instruments::stubs::{default_fx_ccy, equity_aapl},
+        types::{price::Price, quantity::Quantity},
+    };
     use pyo3::{types::PyList, Py, Python};
     use rstest::*;
     use ustr::Ustr;
 
     use super::*;
 
+    fn get_backtest_engine() -> BacktestEngine {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let mut engine = BacktestEngine::new(TraderId::from("TRADER-001"), clock).unwrap();
+        for venue in ["SIM", "XNAS"] {
+            engine
+                .add_venue(
+                    Venue::from(venue),
+                    OmsType::Netting,
+                    AccountType::Cash,
+                    BookType::L1_MBP,
+                    vec![Money::from("1000000 USD")],
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    false,
+                    None,
+                )
+                .unwrap();
+        }
+        engine
+            .add_instrument(InstrumentAny::CurrencyPair(default_fx_ccy(
+                "EUR/USD".into(),
+                None,
+            )))
+            .unwrap();
+        engine
+            .add_instrument(InstrumentAny::Equity(equity_aapl()))
+            .unwrap();
+        engine
+    }
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str, ts: u64) -> Data {
+        let bid = Price::from(bid);
+        let ask = Price::from(ask);
+        Data::Quote(
+            QuoteTick::new(
+                InstrumentId::from(instrument_id),
+                bid,
+                ask,
+                Quantity::from("100"),
+                Quantity::from("100"),
+                ts.into(),
+                ts.into(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn record_topic(engine: &BacktestEngine, topic: &str) -> Rc<RefCell<Vec<String>>> {
+        let records = Rc::new(RefCell::new(Vec::new()));
+        let records_clone = records.clone();
+        let handler = AnyMessageHandler::new(Ustr::from(topic), move |message| {
+            let record = if let Some(event) = message.downcast_ref::<TimeEvent>() {
+                format!("{}@{}", event.name, event.ts_event)
+            } else if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+                format!("{}@{}", quote.instrument_id, quote.ts_init)
+            } else {
+                panic!("unexpected message")
+            };
+            records_clone.borrow_mut().push(record);
+        });
+        engine.msgbus().borrow_mut().subscribe_any(topic, handler);
+        records
+    }
+
+    #[rstest]
+    fn test_add_venue_when_already_added_returns_error() {
+        let mut engine = get_backtest_engine();
+        let result = engine.add_venue(
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountType::Cash,
+            BookType::L1_MBP,
+            vec![Money::from("1000000 USD")],
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_add_instrument_when_venue_not_added_returns_error() {
+        let mut engine = get_backtest_engine();
+        let instrument = default_fx_ccy("EUR/USD".into(), Some(Venue::from("OTHER")));
+
+        let result = engine.add_instrument(InstrumentAny::CurrencyPair(instrument));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_run_routes_data_to_multiple_venues() {
+        let mut engine = get_backtest_engine();
+        let data = vec![
+            quote("EUR/USD.SIM", "1.00000", "1.00010", 1_000),
+            quote("AAPL.XNAS", "100.00", "100.10", 2_000),
+            quote("EUR/USD.SIM", "1.00010", "1.00020", 3_000),
+            quote("AAPL.XNAS", "100.10", "100.20", 3_000),
+        ];
+
+        engine.run(data, None, None).unwrap();
+
+        let sim = engine.get_venue(&Venue::from("SIM")).unwrap();
+        let sim = sim.borrow();
+        assert!(sim.get_account().is_some());
+        let eurusd = sim
+            .get_matching_engine(&InstrumentId::from("EUR/USD.SIM"))
+            .unwrap();
+        assert_eq!(eurusd.best_bid_price(), Some(Price::from("1.00010")));
+        assert_eq!(eurusd.best_ask_price(), Some(Price::from("1.00020")));
+
+        let xnas = engine.get_venue(&Venue::from("XNAS")).unwrap();
+        let xnas = xnas.borrow();
+        let aapl = xnas
+            .get_matching_engine(&InstrumentId::from("AAPL.XNAS"))
+            .unwrap();
+        assert_eq!(aapl.best_bid_price(), Some(Price::from("100.10")));
+        assert_eq!(aapl.best_ask_price(), Some(Price::from("100.20")));
+
+        let result = engine.get_result();
+        assert_eq!(result.iterations, 4);
+        assert!(result.run_id.is_some());
+        assert_eq!(result.backtest_start, Some(1_000.into()));
+        assert_eq!(result.backtest_end, Some(3_000.into()));
+        assert_eq!(result.total_orders, 0);
+        assert_eq!(result.total_positions, 0);
+        assert_eq!(engine.clock.get_time_ns(), UnixNanos::from(3_000));
+    }
+
+    #[rstest]
+    fn test_run_with_start_and_end_filters_data() {
+        let mut engine = get_backtest_engine();
+        let quotes = record_topic(&engine, "data.quotes.*");
+        let data = vec![
+            quote("EUR/USD.SIM", "1.00000", "1.00010", 1_000),
+            quote("AAPL.XNAS", "100.00", "100.10", 2_000),
+            quote("EUR/USD.SIM", "1.00010", "1.00020", 3_000),
+            quote("AAPL.XNAS", "100.10", "100.20", 4_000),
+        ];
+
+        engine
+            .run(data, Some(2_000.into()), Some(3_000.into()))
+            .unwrap();
+
+        assert_eq!(*quotes.borrow(), vec!["AAPL.XNAS@2000", "EUR/USD.SIM@3000"]);
+        let result = engine.get_result();
+        assert_eq!(result.iterations, 2);
+        assert_eq!(result.backtest_start, Some(2_000.into()));
+        assert_eq!(result.backtest_end, Some(3_000.into()));
+        assert_eq!(result.elapsed_time, 1e-6);
+    }
+
+    #[rstest]
+    fn test_run_when_start_not_before_end_returns_error() {
+        let mut engine = get_backtest_engine();
+
+        let result = engine.run(Vec::new(), Some(2_000.into()), Some(1_000.into()));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_run_interleaves_time_events_with_data() {
+        pyo3::prepare_freethreaded_python();
+
+        let mut engine = get_backtest_engine();
+        let records = record_topic(&engine, "*");
+        let py_events: Py<PyList> = Python::with_gil(|py| PyList::empty(py).into());
+        let handler =
+            Python::with_gil(|py| EventHandler::new(py_events.getattr(py, "append").unwrap()));
+        engine
+            .clock_mut()
+            .set_timer_ns("TIMER", 1_000, 0.into(), None, Some(handler))
+            .unwrap();
+        let data = vec![
+            quote("EUR/USD.SIM", "1.00000", "1.00010", 1_500),
+            quote("AAPL.XNAS", "100.00", "100.10", 2_000),
+            quote("EUR/USD.SIM", "1.00010", "1.00020", 2_000),
+        ];
+
+        engine.run(data, Some(500.into()), None).unwrap();
+
+        assert_eq!(
+            *records.borrow(),
+            vec![
+                "TIMER@1000",
+                "EUR/USD.SIM@1500",
+                "AAPL.XNAS@2000",
+                "EUR/USD.SIM@2000",
+                "TIMER@2000",
+            ]
+        );
+        assert_eq!(engine.get_result().total_events, 2);
+
+        // The handler registered on the clock receives each time event
+        let handled: Vec<UnixNanos> = Python::with_gil(|py| {
+            py_events
+                .as_ref(py)
+                .iter()
+                .map(|event| event.extract::<TimeEvent>().unwrap().ts_event)
+                .collect()
+        });
+        assert_eq!(
+            handled,
+            vec![UnixNanos::from(1_000), UnixNanos::from(2_000)]
+        );
+    }
+
+    #[rstest]
+    fn test_run_session() {
+        let mut engine = get_backtest_engine();
+        let mut session = DataBackendSession::new(1_000);
+        session
+            .add_file::<QuoteTick>(
+                "quotes",
+                "../../tests/test_data/nautilus/quotes.parquet",
+                None,
+            )
+            .unwrap();
+
+        engine.run_session(&mut session, None, None).unwrap();
+
+        let result = engine.get_result();
+        assert_eq!(result.iterations, 9_500);
+        assert!(result.backtest_start < result.backtest_end);
+        let instrument_id = InstrumentId::from("EUR/USD.SIM");
+        let last_quote = *engine.cache().borrow().quote_tick(&instrument_id).unwrap();
+        let sim = engine.get_venue(&Venue::from("SIM")).unwrap();
+        let matching_engine_bid = sim
+            .borrow()
+            .get_matching_engine(&instrument_id)
+            .unwrap()
+            .best_bid_price();
+        assert_eq!(matching_engine_bid, Some(last_quote.bid_price));
+        assert_eq!(result.backtest_end, Some(last_quote.ts_init));
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut engine = get_backtest_engine();
+        engine
+            .run(
+                vec![quote("EUR/USD.SIM", "1.00000", "1.00010", 1_000)],
+                None,
+                None,
+            )
+            .unwrap();
+
+        engine.reset();
+
+        let result = engine.get_result();
+        assert_eq!(result.iterations, 0);
+        assert_eq!(result.run_id, None);
+        assert_eq!(result.backtest_start, None);
+        let sim = engine.get_venue(&Venue::from("SIM")).unwrap();
+        let best_bid = sim
+            .borrow()
+            .get_matching_engine(&InstrumentId::from("EUR/USD.SIM"))
+            .unwrap()
+            .best_bid_price();
+        assert_eq!(best_bid, None);
+    }
+
     #[rstest]
     fn test_accumulator_drain_sorted() {
         pyo3::prepare_freethreaded_python();
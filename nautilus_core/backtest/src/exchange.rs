@@ -0,0 +1,786 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a `SimulatedExchange` venue for use in research, backtesting and sandbox environments.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+};
+
+use indexmap::IndexMap;
+use log::{debug, error, info};
+use nautilus_accounting::account::any::AccountAny;
+use nautilus_common::{
+    cache::Cache,
+    msgbus::{endpoints::PORTFOLIO_UPDATE_ACCOUNT, MessageBus},
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::messages::TradingCommand;
+use nautilus_model::{
+    data::Data,
+    enums::{AccountType, BookType, HaltReason, MarketStatus, OmsType},
+    events::account::state::AccountState,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, venue::Venue},
+    instruments::{any::InstrumentAny, Instrument},
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+        money::Money,
+    },
+};
+
+use crate::{
+    matching_engine::{OrderMatchingEngine, OrderMatchingEngineConfig},
+    models::{LatencyModel, ProbabilisticFillModel},
+};
+
+/// Provides a simulated exchange venue which owns an `OrderMatchingEngine` per instrument.
+pub struct SimulatedExchange {
+    /// The venue for the exchange.
+    pub id: Venue,
+    /// The order management system (OMS) type for the exchange.
+    pub oms_type: OmsType,
+    /// The account type for the exchange.
+    pub account_type: AccountType,
+    /// The account base currency (if `None` then the account is multi-currency).
+    pub base_currency: Option<Currency>,
+    /// The account starting balances.
+    pub starting_balances: Vec<Money>,
+    /// The account default leverage (for margin accounts).
+    pub default_leverage: f64,
+    /// The instrument specific leverages (for margin accounts).
+    pub leverages: HashMap<InstrumentId, f64>,
+    /// If the account for the exchange is frozen (balances will not change).
+    pub frozen_account: bool,
+    /// The order book type for the exchanges matching engines.
+    pub book_type: BookType,
+    /// The fill model for the exchanges matching engines.
+    pub fill_model: ProbabilisticFillModel,
+    /// The latency model for the exchanges matching engines.
+    pub latency_model: Option<LatencyModel>,
+    /// The config for the exchanges matching engines.
+    pub config: OrderMatchingEngineConfig,
+    clock: &'static AtomicTime,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    account_id: AccountId,
+    account: Option<AccountAny>,
+    matching_engines: IndexMap<InstrumentId, OrderMatchingEngine>,
+    message_queue: VecDeque<TradingCommand>,
+}
+
+impl SimulatedExchange {
+    /// Creates a new [`SimulatedExchange`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `starting_balances` is empty.
+    /// - If `base_currency` is set with more than one starting balance.
+    /// - If leverages are defined when `account_type` is not `MARGIN`.
+    /// - If any leverage is not positive.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        venue: Venue,
+        oms_type: OmsType,
+        account_type: AccountType,
+        starting_balances: Vec<Money>,
+        base_currency: Option<Currency>,
+        default_leverage: f64,
+        leverages: HashMap<InstrumentId, f64>,
+        book_type: BookType,
+        clock: &'static AtomicTime,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        fill_model: ProbabilisticFillModel,
+        latency_model: Option<LatencyModel>,
+        frozen_account: bool,
+        config: OrderMatchingEngineConfig,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            !starting_balances.is_empty(),
+            "`starting_balances` was empty"
+        );
+        if base_currency.is_some() {
+            anyhow::ensure!(
+                starting_balances.len() == 1,
+                "single-currency account has multiple starting currencies"
+            );
+        }
+        if default_leverage > 1.0 || !leverages.is_empty() {
+            anyhow::ensure!(
+                account_type == AccountType::Margin,
+                "leverages defined when account type is not `MARGIN`"
+            );
+        }
+        anyhow::ensure!(
+            default_leverage > 0.0 && leverages.values().all(|leverage| *leverage > 0.0),
+            "leverages must be positive"
+        );
+
+        Ok(Self {
+            account_id: AccountId::from(format!("{venue}-001").as_str()),
+            id: venue,
+            oms_type,
+            account_type,
+            base_currency,
+            starting_balances,
+            default_leverage,
+            leverages,
+            frozen_account,
+            book_type,
+            fill_model,
+            latency_model,
+            config,
+            clock,
+            msgbus,
+            cache,
+            account: None,
+            matching_engines: IndexMap::new(),
+            message_queue: VecDeque::new(),
+        })
+    }
+
+    /// Returns the account ID used for orders processed by the exchange.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the account for the exchange (if initialized).
+    #[must_use]
+    pub fn get_account(&self) -> Option<&AccountAny> {
+        self.account.as_ref()
+    }
+
+    /// Returns the instrument IDs traded on the exchange.
+    #[must_use]
+    pub fn instrument_ids(&self) -> Vec<InstrumentId> {
+        self.matching_engines.keys().copied().collect()
+    }
+
+    /// Returns the matching engine for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn get_matching_engine(
+        &self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&OrderMatchingEngine> {
+        self.matching_engines.get(instrument_id)
+    }
+
+    /// Returns a mutable reference to the matching engine for the given `instrument_id` (if found).
+    pub fn get_matching_engine_mut(
+        &mut self,
+        instrument_id: &InstrumentId,
+    ) -> Option<&mut OrderMatchingEngine> {
+        self.matching_engines.get_mut(instrument_id)
+    }
+
+    /// Adds the given `instrument` to the exchange, creating a matching engine for it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the instruments venue does not match the exchange.
+    /// - If the instrument has already been added to the exchange.
+    /// - If the instrument is a crypto derivative and the account type is `CASH`.
+    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+        let instrument_id = instrument.id();
+        anyhow::ensure!(
+            instrument_id.venue == self.id,
+            "Venue of instrument {instrument_id} does not match exchange venue {}",
+            self.id
+        );
+        if self.account_type == AccountType::Cash {
+            anyhow::ensure!(
+                !matches!(
+                    instrument,
+                    InstrumentAny::CryptoPerpetual(_) | InstrumentAny::CryptoFuture(_)
+                ),
+                "Cannot add crypto derivative {instrument_id} to a venue with a `CASH` account type"
+            );
+        }
+        anyhow::ensure!(
+            !self.matching_engines.contains_key(&instrument_id),
+            "Instrument {instrument_id} already added to exchange {}",
+            self.id
+        );
+
+        let raw_id = self.matching_engines.len() as u32;
+        let matching_engine = OrderMatchingEngine::new(
+            boxed_instrument(instrument),
+            raw_id,
+            self.book_type,
+            self.oms_type,
+            self.account_type,
+            self.clock,
+            self.msgbus.clone(),
+            self.cache.clone(),
+            Box::new(self.fill_model.clone()),
+            self.latency_model,
+            self.config.clone(),
+        );
+        self.matching_engines.insert(instrument_id, matching_engine);
+
+        debug!("Added instrument {instrument_id} to exchange {}", self.id);
+        Ok(())
+    }
+
+    /// Initialize the account to the starting balances.
+    pub fn initialize_account(&mut self) {
+        self.generate_fresh_account_state();
+    }
+
+    /// Adjust the account at the exchange with the given `adjustment`.
+    ///
+    /// The adjustment is ignored when the account is frozen.
+    pub fn adjust_account(&mut self, adjustment: Money) {
+        if self.frozen_account {
+            return; // Nothing to adjust
+        }
+
+        let Some(account) = &self.account else {
+            error!("Cannot adjust account: no account found for {}", self.id);
+            return;
+        };
+
+        let Some(mut balance) = account.balance(&adjustment.currency) else {
+            error!(
+                "Cannot adjust account: no balance found for {}",
+                adjustment.currency
+            );
+            return;
+        };
+
+        balance.total += adjustment;
+        balance.free += adjustment;
+
+        let margins = account.margins();
+        self.generate_account_state(vec![balance], margins);
+    }
+
+    /// Queues the given trading `command` for processing on the next call to `process`.
+    pub fn send(&mut self, command: TradingCommand) {
+        self.message_queue.push_back(command);
+    }
+
+    /// Process the exchanges markets for the given `data`.
+    pub fn process_data(&mut self, data: &Data) {
+        let instrument_id = data_instrument_id(data);
+        let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) else {
+            error!("No matching engine found for {instrument_id}");
+            return;
+        };
+
+        match data {
+            Data::Delta(delta) => matching_engine.process_order_book_delta(*delta),
+            Data::Deltas(deltas) => matching_engine.process_order_book_deltas((**deltas).clone()),
+            Data::Depth10(depth) => matching_engine.process_order_book_depth10(*depth),
+            Data::Quote(quote) => matching_engine.process_quote_tick(quote),
+            Data::Trade(trade) => matching_engine.process_trade_tick(trade),
+            Data::Bar(bar) => matching_engine.process_bar(bar),
+        }
+    }
+
+    /// Process the given market `status` for all instruments on the venue.
+    pub fn process_venue_status(&mut self, status: MarketStatus, halt_reason: HaltReason) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.process_status(status, halt_reason);
+        }
+    }
+
+    /// Process the given market `status` for the instrument with the given `instrument_id`.
+    pub fn process_instrument_status(
+        &mut self,
+        instrument_id: InstrumentId,
+        status: MarketStatus,
+        halt_reason: HaltReason,
+    ) {
+        match self.matching_engines.get_mut(&instrument_id) {
+            Some(matching_engine) => matching_engine.process_status(status, halt_reason),
+            None => error!("No matching engine found for {instrument_id}"),
+        }
+    }
+
+    /// Process all queued trading commands, then iterate each matching engine at `ts_now`.
+    pub fn process(&mut self, ts_now: UnixNanos) {
+        while let Some(command) = self.message_queue.pop_front() {
+            self.process_command(command);
+        }
+
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.iterate(ts_now);
+        }
+    }
+
+    /// Reset the exchange by clearing all stateful values.
+    ///
+    /// The account is reset to the starting balances if it was initialized.
+    pub fn reset(&mut self) {
+        if self.account.is_some() {
+            self.account = None;
+            self.generate_fresh_account_state();
+        }
+
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.reset();
+        }
+        self.message_queue.clear();
+
+        debug!("Reset exchange {}", self.id);
+    }
+
+    fn generate_fresh_account_state(&mut self) {
+        let balances = self
+            .starting_balances
+            .iter()
+            .map(|money| AccountBalance {
+                currency: money.currency,
+                total: *money,
+                locked: Money::new(0.0, money.currency).unwrap(),
+                free: *money,
+            })
+            .collect();
+
+        self.generate_account_state(balances, Vec::new());
+
+        // Set leverages
+        if let Some(AccountAny::Margin(account)) = &mut self.account {
+            account.set_default_leverage(self.default_leverage);
+            for (instrument_id, leverage) in &self.leverages {
+                account.set_leverage(*instrument_id, *leverage);
+            }
+        }
+    }
+
+    fn generate_account_state(
+        &mut self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+    ) {
+        let ts_now = self.clock.get_time_ns();
+        let account_state = AccountState {
+            account_id: self.account_id,
+            account_type: self.account_type,
+            base_currency: self.base_currency,
+            balances,
+            margins,
+            is_reported: true,
+            event_id: UUID4::new(),
+            ts_event: ts_now,
+            ts_init: ts_now,
+        };
+
+        match &mut self.account {
+            Some(account) => account.apply(account_state.clone()),
+            None => match AccountAny::from_state(account_state.clone(), false) {
+                Ok(account) => self.account = Some(account),
+                Err(e) => {
+                    error!("Cannot create account for {}: {e}", self.id);
+                    return;
+                }
+            },
+        }
+
+        info!("{account_state}");
+        self.msgbus
+            .borrow()
+            .send(PORTFOLIO_UPDATE_ACCOUNT, &account_state);
+    }
+
+    fn process_command(&mut self, command: TradingCommand) {
+        let instrument_id = command.instrument_id();
+        let account_id = self.account_id;
+        let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) else {
+            error!("No matching engine found for {instrument_id}");
+            return;
+        };
+
+        match command {
+            TradingCommand::SubmitOrder(command) => {
+                matching_engine.process_submit(&command, account_id);
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                for order in command.order_list.orders {
+                    matching_engine.process_order(order, account_id);
+                }
+            }
+            TradingCommand::ModifyOrder(command) => {
+                matching_engine.process_modify(&command, account_id);
+            }
+            TradingCommand::CancelOrder(command) => {
+                matching_engine.process_cancel(&command, account_id);
+            }
+            TradingCommand::CancelAllOrders(command) => {
+                matching_engine.process_cancel_all(&command, account_id);
+            }
+            TradingCommand::BatchCancelOrders(command) => {
+                matching_engine.process_batch_cancel(&command, account_id);
+            }
+            TradingCommand::QueryOrder(command) => {
+                debug!(
+                    "Query order {} not supported by exchange",
+                    command.client_order_id
+                );
+            }
+        }
+    }
+}
+
+/// Returns the instrument ID for the given `data`.
+#[must_use]
+pub fn data_instrument_id(data: &Data) -> InstrumentId {
+    match data {
+        Data::Delta(delta) => delta.instrument_id,
+        Data::Deltas(deltas) => deltas.instrument_id,
+        Data::Depth10(depth) => depth.instrument_id,
+        Data::Quote(quote) => quote.instrument_id,
+        Data::Trade(trade) => trade.instrument_id,
+        Data::Bar(bar) => bar.bar_type.instrument_id,
+    }
+}
+
+fn boxed_instrument(instrument: InstrumentAny) -> Box<dyn Instrument> {
+    match instrument {
+        InstrumentAny::CryptoFuture(inst) => Box::new(inst),
+        InstrumentAny::CryptoPerpetual(inst) => Box::new(inst),
+        InstrumentAny::CurrencyPair(inst) => Box::new(inst),
+        InstrumentAny::Equity(inst) => Box::new(inst),
+        InstrumentAny::FuturesContract(inst) => Box::new(inst),
+        InstrumentAny::FuturesSpread(inst) => Box::new(inst),
+        InstrumentAny::OptionsContract(inst) => Box::new(inst),
+        InstrumentAny::OptionsSpread(inst) => Box::new(inst),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+
+    use nautilus_common::{cache::Cache, handlers::AnyMessageHandler, msgbus::MessageBus};
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+    use nautilus_execution::messages::{submit::SubmitOrder, TradingCommand};
+    use nautilus_model::{
+        enums::{AccountType, BookType, HaltReason, MarketStatus, OmsType, OrderSide},
+        events::{
+            account::state::AccountState,
+            order::{event::OrderEventAny, submitted::OrderSubmitted},
+        },
+        identifiers::{
+            client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            stubs::trader_id, venue::Venue, venue_order_id::VenueOrderId,
+        },
+        instruments::{
+            any::InstrumentAny,
+            stubs::{crypto_perpetual_ethusdt, equity_aapl},
+        },
+        orders::{any::OrderAny, stubs::TestOrderStubs},
+        polymorphism::{ApplyOrderEventAny, GetClientOrderId},
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::{AccountAny, SimulatedExchange, PORTFOLIO_UPDATE_ACCOUNT};
+    use crate::{matching_engine::OrderMatchingEngineConfig, models::ProbabilisticFillModel};
+
+    type AccountStates = Rc<RefCell<Vec<AccountState>>>;
+
+    fn get_exchange(
+        account_type: AccountType,
+        starting_balances: Vec<Money>,
+        default_leverage: f64,
+        leverages: HashMap<InstrumentId, f64>,
+        frozen_account: bool,
+    ) -> anyhow::Result<(SimulatedExchange, AccountStates)> {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap(),
+        ));
+        let states: AccountStates = Rc::new(RefCell::new(Vec::new()));
+        let states_clone = states.clone();
+        let handler = AnyMessageHandler::new(Ustr::from("Portfolio"), move |msg: &dyn Any| {
+            let state = msg.downcast_ref::<AccountState>().unwrap();
+            states_clone.borrow_mut().push(state.clone());
+        });
+        msgbus
+            .borrow_mut()
+            .register_any(PORTFOLIO_UPDATE_ACCOUNT, handler);
+
+        let exchange = SimulatedExchange::new(
+            Venue::from("XNAS"),
+            OmsType::Netting,
+            account_type,
+            starting_balances,
+            None,
+            default_leverage,
+            leverages,
+            BookType::L1_MBP,
+            clock,
+            msgbus,
+            Rc::new(RefCell::new(Cache::default())),
+            ProbabilisticFillModel::default(),
+            None,
+            frozen_account,
+            OrderMatchingEngineConfig::default(),
+        )?;
+        Ok((exchange, states))
+    }
+
+    fn get_cash_exchange(frozen_account: bool) -> (SimulatedExchange, AccountStates) {
+        get_exchange(
+            AccountType::Cash,
+            vec![Money::from("1000000 USD")],
+            1.0,
+            HashMap::new(),
+            frozen_account,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_new_with_empty_starting_balances_returns_error() {
+        let result = get_exchange(AccountType::Cash, vec![], 1.0, HashMap::new(), false);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_with_leverage_for_cash_account_returns_error() {
+        let result = get_exchange(
+            AccountType::Cash,
+            vec![Money::from("1000000 USD")],
+            10.0,
+            HashMap::new(),
+            false,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_new_with_base_currency_and_multiple_balances_returns_error() {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus = MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap();
+
+        let result = SimulatedExchange::new(
+            Venue::from("XNAS"),
+            OmsType::Netting,
+            AccountType::Cash,
+            vec![Money::from("1000000 USD"), Money::from("10 BTC")],
+            Some(Currency::USD()),
+            1.0,
+            HashMap::new(),
+            BookType::L1_MBP,
+            clock,
+            Rc::new(RefCell::new(msgbus)),
+            Rc::new(RefCell::new(Cache::default())),
+            ProbabilisticFillModel::default(),
+            None,
+            false,
+            OrderMatchingEngineConfig::default(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_add_instrument_crypto_perpetual_to_cash_account_returns_error() {
+        let (mut exchange, _) = get_cash_exchange(false);
+        let mut instrument = crypto_perpetual_ethusdt();
+        instrument.id = InstrumentId::from("ETHUSDT-PERP.XNAS");
+
+        let result = exchange.add_instrument(InstrumentAny::CryptoPerpetual(instrument));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_add_instrument_for_other_venue_returns_error() {
+        let (mut exchange, _) = get_cash_exchange(false);
+
+        let result =
+            exchange.add_instrument(InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt()));
+
+        assert!(result.is_err());
+        assert!(exchange.instrument_ids().is_empty());
+    }
+
+    #[rstest]
+    fn test_initialize_cash_account_sends_account_state() {
+        let (mut exchange, states) = get_cash_exchange(false);
+
+        exchange.initialize_account();
+
+        let account = exchange.get_account().unwrap();
+        assert_eq!(account.id(), exchange.account_id());
+        assert_eq!(account.account_type(), AccountType::Cash);
+        let balance = account.balance(&Currency::USD()).unwrap();
+        assert_eq!(balance.total, Money::from("1000000 USD"));
+        assert_eq!(balance.locked, Money::from("0 USD"));
+        assert_eq!(balance.free, Money::from("1000000 USD"));
+        assert_eq!(states.borrow().len(), 1);
+        assert_eq!(states.borrow()[0].account_id, exchange.account_id());
+        assert!(states.borrow()[0].is_reported);
+    }
+
+    #[rstest]
+    fn test_initialize_margin_account_sets_leverages() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let (mut exchange, _) = get_exchange(
+            AccountType::Margin,
+            vec![Money::from("1000000 USD")],
+            10.0,
+            HashMap::from([(instrument_id, 2.0)]),
+            false,
+        )
+        .unwrap();
+
+        exchange.initialize_account();
+
+        let Some(AccountAny::Margin(account)) = exchange.get_account() else {
+            panic!("expected margin account")
+        };
+        assert_eq!(account.get_leverage(&instrument_id), 2.0);
+        assert_eq!(account.get_leverage(&InstrumentId::from("MSFT.XNAS")), 10.0);
+    }
+
+    #[rstest]
+    fn test_adjust_account() {
+        let (mut exchange, states) = get_cash_exchange(false);
+        exchange.initialize_account();
+
+        exchange.adjust_account(Money::from("-1000 USD"));
+
+        let balance = exchange
+            .get_account()
+            .unwrap()
+            .balance(&Currency::USD())
+            .unwrap();
+        assert_eq!(balance.total, Money::from("999000 USD"));
+        assert_eq!(balance.free, Money::from("999000 USD"));
+        assert_eq!(states.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_adjust_account_when_frozen_does_nothing() {
+        let (mut exchange, states) = get_cash_exchange(true);
+        exchange.initialize_account();
+
+        exchange.adjust_account(Money::from("-1000 USD"));
+
+        let balance = exchange
+            .get_account()
+            .unwrap()
+            .balance(&Currency::USD())
+            .unwrap();
+        assert_eq!(balance.total, Money::from("1000000 USD"));
+        assert_eq!(states.borrow().len(), 1);
+    }
+
+    #[rstest]
+    fn test_reset_restores_starting_balances() {
+        let (mut exchange, states) = get_cash_exchange(false);
+        exchange.initialize_account();
+        exchange.adjust_account(Money::from("-1000 USD"));
+
+        exchange.reset();
+
+        let account = exchange.get_account().unwrap();
+        let balance = account.balance(&Currency::USD()).unwrap();
+        assert_eq!(balance.total, Money::from("1000000 USD"));
+        assert_eq!(account.event_count(), 1);
+        assert_eq!(states.borrow().len(), 3);
+    }
+
+    #[rstest]
+    fn test_send_routes_command_to_matching_engine() {
+        let (mut exchange, _) = get_cash_exchange(false);
+        exchange
+            .add_instrument(InstrumentAny::Equity(equity_aapl()))
+            .unwrap();
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let client_order_id = ClientOrderId::from("O-1");
+        let mut order = OrderAny::Limit(TestOrderStubs::limit_order(
+            instrument_id,
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("100"),
+            Some(client_order_id),
+            None,
+        ));
+        let submitted = OrderSubmitted::new(
+            order.as_order().trader_id(),
+            order.as_order().strategy_id(),
+            instrument_id,
+            client_order_id,
+            exchange.account_id(),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        let command = SubmitOrder::new(
+            trader_id(),
+            ClientId::from("XNAS"),
+            order.as_order().strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            VenueOrderId::from("1"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        exchange.send(TradingCommand::SubmitOrder(command));
+        let matching_engine = exchange.get_matching_engine(&instrument_id).unwrap();
+        assert!(!matching_engine.order_exists(client_order_id));
+
+        exchange.process(UnixNanos::default());
+
+        let matching_engine = exchange.get_matching_engine(&instrument_id).unwrap();
+        assert!(matching_engine.order_exists(client_order_id));
+    }
+
+    #[rstest]
+    fn test_process_status_updates_matching_engines() {
+        let (mut exchange, _) = get_cash_exchange(false);
+        exchange
+            .add_instrument(InstrumentAny::Equity(equity_aapl()))
+            .unwrap();
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+
+        exchange.process_instrument_status(instrument_id, MarketStatus::Halt, HaltReason::General);
+
+        let matching_engine = exchange.get_matching_engine(&instrument_id).unwrap();
+        assert_eq!(matching_engine.market_status, MarketStatus::Halt);
+        assert_eq!(matching_engine.halt_reason, HaltReason::General);
+
+        exchange.process_venue_status(MarketStatus::Closed, HaltReason::NotHalted);
+
+        let matching_engine = exchange.get_matching_engine(&instrument_id).unwrap();
+        assert_eq!(matching_engine.market_status, MarketStatus::Closed);
+        assert_eq!(matching_engine.halt_reason, HaltReason::NotHalted);
+    }
+}
@@ -28,4 +28,6 @@
 //! - `python`: Enables Python bindings from `pyo3`
 
 pub mod engine;
+pub mod exchange;
 pub mod matching_engine;
+pub mod models;
@@ -19,32 +19,65 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use std::collections::HashMap;
+use std::{cell::RefCell, cmp::Reverse, collections::HashMap, rc::Rc};
 
-use log::{debug, info};
-use nautilus_common::{cache::Cache, msgbus::MessageBus};
-use nautilus_core::{nanos::UnixNanos, time::AtomicTime};
-use nautilus_execution::matching_core::OrderMatchingCore;
+use log::{debug, error, info, warn};
+use nautilus_common::{
+    cache::Cache,
+    msgbus::{endpoints::EXEC_ENGINE_PROCESS, MessageBus},
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::{
+    matching_core::OrderMatchingCore,
+    messages::{
+        cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
+        modify::ModifyOrder, submit::SubmitOrder,
+    },
+    trailing::trailing_stop_calculate,
+};
 use nautilus_model::{
     data::{
         bar::{Bar, BarType},
         delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::OrderBookDepth10,
+        order::BookOrder,
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{
+        AccountType, AggressorSide, BookType, ContingencyType, HaltReason, LiquiditySide,
+        MarketStatus, OmsType, OrderSide, OrderSideSpecified, OrderStatus, OrderType, PositionSide,
+        PriceType, TimeInForce,
+    },
+    events::order::{
+        accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+        event::OrderEventAny, expired::OrderExpired, filled::OrderFilled,
+        modify_rejected::OrderModifyRejected, rejected::OrderRejected, triggered::OrderTriggered,
+        updated::OrderUpdated,
     },
-    enums::{AccountType, BookType, MarketStatus, OmsType},
     identifiers::{
         account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
-        trader_id::TraderId, venue::Venue,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, trader_id::TraderId,
+        venue::Venue, venue_order_id::VenueOrderId,
     },
     instruments::Instrument,
-    orderbook::book::OrderBook,
-    orders::{
-        any::{PassiveOrderAny, StopOrderAny},
-        trailing_stop_limit::TrailingStopLimitOrder,
-        trailing_stop_market::TrailingStopMarketOrder,
+    orderbook::{book::OrderBook, level::Level},
+    orders::any::{OrderAny, PassiveOrderAny},
+    polymorphism::{
+        ApplyOrderEventAny, GetClientOrderId, GetInstrumentId, GetOrderFilledQty,
+        GetOrderLeavesQty, GetOrderQuantity, GetOrderSide, GetOrderSideSpecified, GetStrategyId,
+        GetTraderId, GetVenueOrderId, IsClosed, IsOpen,
     },
-    types::price::Price,
+    position::Position,
+    types::{fixed::FIXED_PRECISION, money::Money, price::Price, quantity::Quantity},
 };
+use rust_decimal::prelude::ToPrimitive;
+use ustr::Ustr;
+
+use crate::models::{FillModel, LatencyModel};
 
+#[derive(Clone, Debug)]
 pub struct OrderMatchingEngineConfig {
     pub bar_execution: bool,
     pub reject_stop_orders: bool,
@@ -53,6 +86,23 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    /// The price tiers (tier start price, tier offset) for `PRICE_TIER` trailing offsets.
+    pub price_tiers: Vec<(Price, Price)>,
+}
+
+impl Default for OrderMatchingEngineConfig {
+    fn default() -> Self {
+        Self {
+            bar_execution: false,
+            reject_stop_orders: true,
+            support_gtd_orders: true,
+            support_contingent_orders: true,
+            use_position_ids: true,
+            use_random_ids: false,
+            use_reduce_only: true,
+            price_tiers: Vec::new(),
+        }
+    }
 }
 
 /// Provides an order matching engine for a single market.
@@ -71,11 +121,17 @@ pub struct OrderMatchingEngine {
     pub account_type: AccountType,
     /// The market status for the matching engine.
     pub market_status: MarketStatus,
+    /// The halt reason for the matching engine (only applicable for a `HALT` market status).
+    pub halt_reason: HaltReason,
     /// The config for the matching engine.
     pub config: OrderMatchingEngineConfig,
+    /// The fill model for the matching engine.
+    pub fill_model: Box<dyn FillModel>,
+    /// The latency model for the matching engine (if `None` then commands are processed immediately).
+    pub latency_model: Option<LatencyModel>,
     clock: &'static AtomicTime,
-    msgbus: &'static MessageBus,
-    cache: &'static Cache,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
     book: OrderBook,
     core: OrderMatchingCore,
     target_bid: Option<Price>,
@@ -86,12 +142,27 @@ pub struct OrderMatchingEngine {
     execution_bar_types: HashMap<InstrumentId, BarType>,
     execution_bar_deltas: HashMap<InstrumentId, u64>,
     account_ids: HashMap<TraderId, AccountId>,
+    cached_filled_qty: HashMap<ClientOrderId, Quantity>,
+    queue_ahead: HashMap<ClientOrderId, Quantity>,
+    queue_ahead_orders: HashMap<ClientOrderId, Vec<BookOrder>>,
+    inflight_commands: Vec<(UnixNanos, InflightCommand)>,
+    pending_contingent_orders: HashMap<ClientOrderId, OrderAny>,
+    auction_orders: Vec<OrderAny>,
     position_count: usize,
     order_count: usize,
     execution_count: usize,
 }
 
-// Note: we'll probably be changing the `FillModel` (don't add for now)
+/// Represents a trading command which is in flight to the matching engine.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+enum InflightCommand {
+    Submit(OrderAny, AccountId),
+    Modify(ModifyOrder, AccountId),
+    Cancel(CancelOrder, AccountId),
+    CancelAll(CancelAllOrders, AccountId),
+}
+
 impl OrderMatchingEngine {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -101,17 +172,19 @@ impl OrderMatchingEngine {
         oms_type: OmsType,
         account_type: AccountType,
         clock: &'static AtomicTime,
-        msgbus: &'static MessageBus,
-        cache: &'static Cache,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        fill_model: Box<dyn FillModel>,
+        latency_model: Option<LatencyModel>,
         config: OrderMatchingEngineConfig,
     ) -> Self {
         let book = OrderBook::new(book_type, instrument.id());
         let core = OrderMatchingCore::new(
             instrument.id(),
             instrument.price_increment(),
-            None, // TBD (will be a function on the engine)
-            None, // TBD (will be a function on the engine)
-            None, // TBD (will be a function on the engine)
+            None, // Matching is driven directly by the engine
+            None, // Matching is driven directly by the engine
+            None, // Matching is driven directly by the engine
         );
         Self {
             venue: instrument.venue(),
@@ -126,7 +199,10 @@ impl OrderMatchingEngine {
             book,
             core,
             market_status: MarketStatus::Open,
+            halt_reason: HaltReason::NotHalted,
             config,
+            fill_model,
+            latency_model,
             target_bid: None,
             target_ask: None,
             target_last: None,
@@ -135,6 +211,12 @@ impl OrderMatchingEngine {
             execution_bar_types: HashMap::new(),
             execution_bar_deltas: HashMap::new(),
             account_ids: HashMap::new(),
+            cached_filled_qty: HashMap::new(),
+            queue_ahead: HashMap::new(),
+            queue_ahead_orders: HashMap::new(),
+            inflight_commands: Vec::new(),
+            pending_contingent_orders: HashMap::new(),
+            auction_orders: Vec::new(),
             position_count: 0,
             order_count: 0,
             execution_count: 0,
@@ -146,10 +228,21 @@ impl OrderMatchingEngine {
         self.execution_bar_types.clear();
         self.execution_bar_deltas.clear();
         self.account_ids.clear();
+        self.cached_filled_qty.clear();
+        self.queue_ahead.clear();
+        self.queue_ahead_orders.clear();
+        self.inflight_commands.clear();
+        self.pending_contingent_orders.clear();
+        self.auction_orders.clear();
+        self.fill_model.reset();
         self.core.reset();
         self.target_bid = None;
         self.target_ask = None;
         self.target_last = None;
+        self.last_bar_bid = None;
+        self.last_bar_ask = None;
+        self.market_status = MarketStatus::Open;
+        self.halt_reason = HaltReason::NotHalted;
         self.position_count = 0;
         self.order_count = 0;
         self.execution_count = 0;
@@ -193,7 +286,557 @@ impl OrderMatchingEngine {
     pub fn process_order_book_delta(&mut self, delta: OrderBookDelta) {
         debug!("Processing {delta}");
 
-        self.book.apply_delta(delta);
+        if let Err(e) = self.book.apply_delta(delta) {
+            error!("Error applying delta: {e}");
+        }
+        self.iterate(delta.ts_init);
+    }
+
+    /// Process the venues market for the given order book deltas.
+    pub fn process_order_book_deltas(&mut self, deltas: OrderBookDeltas) {
+        debug!("Processing {deltas}");
+
+        let ts_init = deltas.ts_init;
+        if let Err(e) = self.book.apply_deltas(deltas) {
+            error!("Error applying deltas: {e}");
+        }
+        self.iterate(ts_init);
+    }
+
+    /// Process the venues market for the given order book depth snapshot.
+    pub fn process_order_book_depth10(&mut self, depth: OrderBookDepth10) {
+        debug!("Processing {depth}");
+
+        let ts_init = depth.ts_init;
+        if let Err(e) = self.book.apply_depth(depth) {
+            error!("Error applying depth: {e}");
+        }
+        self.iterate(ts_init);
+    }
+
+    /// Process the venues market for the given quote tick.
+    pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
+        debug!("Processing {quote}");
+
+        if self.book_type == BookType::L1_MBP {
+            if let Err(e) = self.book.update_quote_tick(quote) {
+                error!("Error updating book with quote, skipping {quote}: {e}");
+                return;
+            }
+        }
+
+        self.iterate(quote.ts_init);
+    }
+
+    /// Process the venues market for the given trade tick.
+    pub fn process_trade_tick(&mut self, trade: &TradeTick) {
+        debug!("Processing {trade}");
+
+        if self.book_type == BookType::L1_MBP {
+            // SAFETY: We know the book type is L1_MBP
+            self.book.update_trade_tick(trade).unwrap();
+        }
+        self.core.last = Some(trade.price);
+
+        if self.book_type == BookType::L3_MBO {
+            self.match_trade_against_queues(trade);
+        }
+
+        self.iterate(trade.ts_init);
+    }
+
+    /// Process the venues market for the given `mark` price.
+    pub fn process_mark_price(&mut self, mark: Price, ts_init: UnixNanos) {
+        debug!("Processing mark price {mark}");
+
+        self.core.mark = Some(mark);
+        self.iterate(ts_init);
+    }
+
+    /// Process the venues market for the given bar.
+    ///
+    /// Each bar is converted into a sequence of four synthetic top-of-book updates with the
+    /// bars volume split evenly across them (any remainder goes to the close). The path runs
+    /// open, high, low, close when the open is closer to the high, otherwise open, low, high,
+    /// close. Trade points without price movement are skipped, their volume being folded into
+    /// the next trade.
+    ///
+    /// Bars are only processed when `bar_execution` is enabled for an `L1_MBP` book. Quote
+    /// updates are generated once both the bid and ask bars for the same timestamp arrive.
+    pub fn process_bar(&mut self, bar: &Bar) {
+        debug!("Processing {bar}");
+
+        if !self.config.bar_execution || self.book_type != BookType::L1_MBP {
+            return;
+        }
+
+        // Execution is driven by the first bar specification received for the instrument,
+        // so bars aggregated at other intervals do not replay the market out of sequence.
+        let bar_type = bar.bar_type;
+        let execution_bar_type = *self
+            .execution_bar_types
+            .entry(bar_type.instrument_id)
+            .or_insert(bar_type);
+        if execution_bar_type.spec.step != bar_type.spec.step
+            || execution_bar_type.spec.aggregation != bar_type.spec.aggregation
+            || execution_bar_type.aggregation_source != bar_type.aggregation_source
+        {
+            return;
+        }
+
+        match bar_type.spec.price_type {
+            PriceType::Last | PriceType::Mid => self.process_trades_from_bar(bar),
+            PriceType::Bid => {
+                self.last_bar_bid = Some(*bar);
+                self.process_quotes_from_bars();
+            }
+            PriceType::Ask => {
+                self.last_bar_ask = Some(*bar);
+                self.process_quotes_from_bars();
+            }
+        }
+    }
+
+    fn process_trades_from_bar(&mut self, bar: &Bar) {
+        for trade in self.trades_from_bar(bar) {
+            self.process_trade_tick(&trade);
+        }
+    }
+
+    /// Returns the synthetic trades for the given `bar`, whose sizes sum to the bar volume.
+    fn trades_from_bar(&mut self, bar: &Bar) -> Vec<TradeTick> {
+        let path = bar_price_path(bar, is_high_first(bar));
+        let sizes = split_bar_volume(bar.volume);
+
+        let mut trades = Vec::with_capacity(path.len());
+        let mut last = self.core.last;
+        let mut carried_raw = 0;
+        for (i, (price, size)) in path.into_iter().zip(sizes).enumerate() {
+            carried_raw += size.raw;
+            let is_close = i == path.len() - 1;
+            let aggressor_side = match last {
+                // No price movement, the volume is folded into the next trade
+                Some(last) if last == price && !is_close => continue,
+                Some(last) if price > last => AggressorSide::Buyer,
+                Some(last) if price < last => AggressorSide::Seller,
+                _ => AggressorSide::NoAggressor,
+            };
+            trades.push(TradeTick::new(
+                bar.bar_type.instrument_id,
+                price,
+                Quantity::from_raw(carried_raw, bar.volume.precision).unwrap(),
+                aggressor_side,
+                self.generate_trade_id(),
+                bar.ts_event,
+                bar.ts_event,
+            ));
+            last = Some(price);
+            carried_raw = 0;
+        }
+        trades
+    }
+
+    fn process_quotes_from_bars(&mut self) {
+        let (Some(bid_bar), Some(ask_bar)) = (self.last_bar_bid, self.last_bar_ask) else {
+            return; // Wait for both bid and ask bars
+        };
+        if bid_bar.ts_event != ask_bar.ts_event {
+            return; // Wait for bars with the same timestamp
+        }
+
+        let bid_sizes = split_bar_volume(bid_bar.volume);
+        let ask_sizes = split_bar_volume(ask_bar.volume);
+
+        // Bid and ask move together, so both follow the path determined by the bid bar
+        let is_high_first = is_high_first(&bid_bar);
+        let bid_path = bar_price_path(&bid_bar, is_high_first);
+        let ask_path = bar_price_path(&ask_bar, is_high_first);
+
+        for (i, (bid_price, ask_price)) in bid_path.into_iter().zip(ask_path).enumerate() {
+            let (bid_size, ask_size) = (bid_sizes[i], ask_sizes[i]);
+            let quote = QuoteTick::new(
+                bid_bar.bar_type.instrument_id,
+                bid_price,
+                ask_price,
+                bid_size,
+                ask_size,
+                bid_bar.ts_event,
+                bid_bar.ts_event,
+            )
+            .unwrap();
+            self.process_quote_tick(&quote);
+        }
+
+        self.last_bar_bid = None;
+        self.last_bar_ask = None;
+    }
+
+    // -- TRADING COMMANDS ----------------------------------------------------
+
+    /// Process the given submit order `command` for the `account_id`.
+    pub fn process_submit(&mut self, command: &SubmitOrder, account_id: AccountId) {
+        self.process_order(command.order.clone(), account_id);
+    }
+
+    /// Process the given `order` for the `account_id`.
+    ///
+    /// The order is expected to already be in a `SUBMITTED` state. If a latency model is set
+    /// then the order arrives at the matching engine after the insert latency.
+    pub fn process_order(&mut self, order: OrderAny, account_id: AccountId) {
+        match self.latency_model {
+            Some(latency_model) => self.add_inflight_command(
+                latency_model.insert_latency_nanos,
+                InflightCommand::Submit(order, account_id),
+            ),
+            None => self.execute_order(order, account_id),
+        }
+    }
+
+    /// Process the given modify order `command` for the `account_id`.
+    pub fn process_modify(&mut self, command: &ModifyOrder, account_id: AccountId) {
+        match self.latency_model {
+            Some(latency_model) => self.add_inflight_command(
+                latency_model.update_latency_nanos,
+                InflightCommand::Modify(command.clone(), account_id),
+            ),
+            None => self.execute_modify(command, account_id),
+        }
+    }
+
+    /// Process the given cancel order `command` for the `account_id`.
+    pub fn process_cancel(&mut self, command: &CancelOrder, account_id: AccountId) {
+        match self.latency_model {
+            Some(latency_model) => self.add_inflight_command(
+                latency_model.cancel_latency_nanos,
+                InflightCommand::Cancel(command.clone(), account_id),
+            ),
+            None => self.execute_cancel(command, account_id),
+        }
+    }
+
+    /// Process the given cancel all orders `command` for the `account_id`.
+    pub fn process_cancel_all(&mut self, command: &CancelAllOrders, account_id: AccountId) {
+        match self.latency_model {
+            Some(latency_model) => self.add_inflight_command(
+                latency_model.cancel_latency_nanos,
+                InflightCommand::CancelAll(command.clone(), account_id),
+            ),
+            None => self.execute_cancel_all(command, account_id),
+        }
+    }
+
+    /// Process the given batch cancel orders `command` for the `account_id`.
+    pub fn process_batch_cancel(&mut self, command: &BatchCancelOrders, account_id: AccountId) {
+        for cancel in &command.cancels {
+            self.process_cancel(cancel, account_id);
+        }
+    }
+
+    /// Process the given market `status` update for the instrument.
+    ///
+    /// New orders are rejected while the market is halted, paused or closed, and resting orders
+    /// only match during continuous trading. Orders received during the `PRE_OPEN` and
+    /// `PRE_CLOSE` call phases are queued for an auction which uncrosses at a single clearing
+    /// price when the market opens (or reopens) and closes respectively.
+    pub fn process_status(&mut self, status: MarketStatus, halt_reason: HaltReason) {
+        let previous_status = self.market_status;
+        self.market_status = status;
+        self.halt_reason = if status == MarketStatus::Halt {
+            halt_reason
+        } else {
+            HaltReason::NotHalted
+        };
+
+        if status == previous_status {
+            return; // No transition
+        }
+
+        info!(
+            "Market status for {} changed from {previous_status} to {status}",
+            self.instrument.id()
+        );
+
+        match status {
+            MarketStatus::Open | MarketStatus::Reopen
+                if !matches!(previous_status, MarketStatus::Open | MarketStatus::Reopen) =>
+            {
+                self.uncross_auction(TimeInForce::AtTheOpen);
+            }
+            MarketStatus::Closed => self.uncross_auction(TimeInForce::AtTheClose),
+            _ => {}
+        }
+
+        if self.is_continuous_trading() {
+            self.iterate(self.clock.get_time_ns());
+        }
+    }
+
+    fn add_inflight_command(&mut self, latency_nanos: u64, command: InflightCommand) {
+        let ts_arrival = self.clock.get_time_ns() + latency_nanos;
+        // Keep commands sorted by arrival time (stable for equal arrival times)
+        let index = self
+            .inflight_commands
+            .partition_point(|(ts, _)| *ts <= ts_arrival);
+        self.inflight_commands.insert(index, (ts_arrival, command));
+    }
+
+    fn process_inflight_commands(&mut self, timestamp_ns: UnixNanos) {
+        let count = self
+            .inflight_commands
+            .partition_point(|(ts, _)| *ts <= timestamp_ns);
+        let commands: Vec<(UnixNanos, InflightCommand)> =
+            self.inflight_commands.drain(..count).collect();
+
+        for (ts_arrival, command) in commands {
+            self.clock.set_time(ts_arrival);
+            match command {
+                InflightCommand::Submit(order, account_id) => {
+                    self.execute_order(order, account_id);
+                }
+                InflightCommand::Modify(command, account_id) => {
+                    self.execute_modify(&command, account_id);
+                }
+                InflightCommand::Cancel(command, account_id) => {
+                    self.execute_cancel(&command, account_id);
+                }
+                InflightCommand::CancelAll(command, account_id) => {
+                    self.execute_cancel_all(&command, account_id);
+                }
+            }
+        }
+    }
+
+    fn execute_order(&mut self, mut order: OrderAny, account_id: AccountId) {
+        let client_order_id = order.client_order_id();
+        if self.core.order_exists(client_order_id) {
+            error!("Order {client_order_id} already exists in the matching engine");
+            return;
+        }
+
+        self.account_ids.insert(order.trader_id(), account_id);
+
+        let now = self.clock.get_time_ns();
+        let instrument_id = self.instrument.id();
+
+        // Check instrument is tradable
+        if let Some(activation_ns) = self.instrument.activation_ns() {
+            if now < activation_ns {
+                self.generate_order_rejected(
+                    &order,
+                    &format!(
+                        "Contract {instrument_id} is not yet active, activation {activation_ns}"
+                    ),
+                );
+                return;
+            }
+        }
+        if let Some(expiration_ns) = self.instrument.expiration_ns() {
+            if now >= expiration_ns {
+                self.generate_order_rejected(
+                    &order,
+                    &format!("Contract {instrument_id} has expired, expiration {expiration_ns}"),
+                );
+                return;
+            }
+        }
+
+        // Check contingent orders
+        if self.config.support_contingent_orders && !self.check_contingencies(&order) {
+            return; // Order rejected or held pending its parent
+        }
+
+        // Check market status
+        let reject_reason = match self.market_status {
+            MarketStatus::Closed => Some(format!("Market closed for {instrument_id}")),
+            MarketStatus::Halt => Some(format!(
+                "Market halted for {instrument_id}, reason {}",
+                self.halt_reason
+            )),
+            MarketStatus::Pause => Some(format!("Market paused for {instrument_id}")),
+            MarketStatus::Open | MarketStatus::Reopen | MarketStatus::PreClose
+                if order.as_order().time_in_force() == TimeInForce::AtTheOpen =>
+            {
+                Some(format!(
+                    "{} order not accepted after the opening auction for {instrument_id}",
+                    TimeInForce::AtTheOpen
+                ))
+            }
+            _ => None,
+        };
+        if let Some(reason) = reject_reason {
+            self.generate_order_rejected(&order, &reason);
+            return;
+        }
+
+        // Check reduce-only instruction
+        if self.config.use_reduce_only
+            && order.as_order().is_reduce_only()
+            && !order.is_closed()
+            && would_increase_position(&order, self.position_for_order(&order).as_ref())
+        {
+            self.generate_order_rejected(
+                &order,
+                &format!(
+                    "REDUCE_ONLY {} {} order would have increased position",
+                    order.as_order().order_type(),
+                    order.order_side(),
+                ),
+            );
+            return;
+        }
+
+        // Check GTD support
+        if !self.config.support_gtd_orders && order.as_order().time_in_force() == TimeInForce::Gtd {
+            self.generate_order_rejected(
+                &order,
+                &format!("GTD orders not supported for {}", self.venue),
+            );
+            return;
+        }
+
+        // Check order quantity precision
+        let size_precision = self.instrument.size_precision();
+        let quantity = order.quantity();
+        if quantity.precision != size_precision {
+            self.generate_order_rejected(
+                &order,
+                &format!(
+                    "Invalid order quantity precision for order {client_order_id}, was {} when {instrument_id} size precision is {size_precision}",
+                    quantity.precision
+                ),
+            );
+            return;
+        }
+
+        // Check order price precision
+        let price_precision = self.instrument.price_precision();
+        if let Some(price) = order.as_order().price() {
+            if price.precision != price_precision {
+                self.generate_order_rejected(
+                    &order,
+                    &format!(
+                        "Invalid order price precision for order {client_order_id}, was {} when {instrument_id} price precision is {price_precision}",
+                        price.precision
+                    ),
+                );
+                return;
+            }
+        }
+
+        // Check order trigger price precision
+        if let Some(trigger_price) = order.as_order().trigger_price() {
+            if trigger_price.precision != price_precision {
+                self.generate_order_rejected(
+                    &order,
+                    &format!(
+                        "Invalid order trigger price precision for order {client_order_id}, was {} when {instrument_id} price precision is {price_precision}",
+                        trigger_price.precision
+                    ),
+                );
+                return;
+            }
+        }
+
+        if self.is_auction_order(&order) {
+            self.queue_auction_order(order);
+            return;
+        }
+
+        if !self.is_continuous_trading() {
+            // Stop orders rest until continuous trading resumes
+            self.accept_order(&mut order);
+            return;
+        }
+
+        match order.as_order().order_type() {
+            OrderType::Market => self.process_market_order(&mut order),
+            OrderType::MarketToLimit => self.process_market_to_limit_order(&mut order),
+            OrderType::Limit => self.process_limit_order(&mut order),
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+                self.process_stop_market_order(&mut order)
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                self.process_stop_limit_order(&mut order);
+            }
+        }
+    }
+
+    fn execute_modify(&mut self, command: &ModifyOrder, account_id: AccountId) {
+        match self.lookup_working_order(command.client_order_id) {
+            Some(mut order) if !self.core.order_exists(command.client_order_id) => {
+                // Order is held by the engine and not yet working in the market
+                self.update_queued_order(
+                    &mut order,
+                    command.quantity,
+                    command.price,
+                    command.trigger_price,
+                );
+            }
+            Some(mut order) => {
+                self.update_order(
+                    &mut order,
+                    command.quantity,
+                    command.price,
+                    command.trigger_price,
+                    true,
+                );
+            }
+            None => self.generate_order_modify_rejected(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                Some(command.venue_order_id),
+                Some(account_id),
+                &format!("{} not found", command.client_order_id),
+            ),
+        }
+    }
+
+    fn execute_cancel(&mut self, command: &CancelOrder, account_id: AccountId) {
+        match self.lookup_working_order(command.client_order_id) {
+            Some(mut order) => {
+                if order.as_order().is_inflight() || order.is_open() {
+                    self.cancel_order(&mut order, true);
+                }
+            }
+            None => self.generate_order_cancel_rejected(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                Some(command.venue_order_id),
+                Some(account_id),
+                &format!("{} not found", command.client_order_id),
+            ),
+        }
+    }
+
+    fn execute_cancel_all(&mut self, command: &CancelAllOrders, account_id: AccountId) {
+        let mut orders: Vec<OrderAny> = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .cloned()
+            .map(OrderAny::from)
+            .collect();
+        orders.extend(self.auction_orders.iter().cloned());
+        orders.extend(self.pending_contingent_orders.values().cloned());
+
+        for mut order in orders {
+            if order.strategy_id() != command.strategy_id {
+                continue;
+            }
+            if command.order_side != OrderSide::NoOrderSide
+                && command.order_side != order.order_side()
+            {
+                continue;
+            }
+            if order.as_order().is_inflight() || order.is_open() {
+                self.cancel_order(&mut order, true);
+            }
+        }
     }
 
     // -- ORDER PROCESSING ----------------------------------------------------
@@ -201,11 +844,17 @@ impl OrderMatchingEngine {
     /// Iterate the matching engine by processing the bid and ask order sides
     /// and advancing time up to the given UNIX `timestamp_ns`.
     pub fn iterate(&mut self, timestamp_ns: UnixNanos) {
-        self.clock.set_time(timestamp_ns);
-
         self.core.bid = self.book.best_bid_price();
         self.core.ask = self.book.best_ask_price();
 
+        // Commands which have arrived are processed against the current market
+        self.process_inflight_commands(timestamp_ns);
+        self.clock.set_time(timestamp_ns);
+
+        if !self.is_continuous_trading() {
+            return; // Orders only match during continuous trading
+        }
+
         let orders_bid = self.core.get_orders_bid().to_vec();
         let orders_ask = self.core.get_orders_ask().to_vec();
 
@@ -215,6 +864,10 @@ impl OrderMatchingEngine {
 
     fn iterate_orders(&mut self, timestamp_ns: UnixNanos, orders: &[PassiveOrderAny]) {
         for order in orders {
+            // Orders may have been filled or canceled earlier in this iteration
+            let Some(order) = self.core.get_order(order.client_order_id()).cloned() else {
+                continue;
+            };
             if order.is_closed() {
                 continue;
             };
@@ -222,27 +875,39 @@ impl OrderMatchingEngine {
             // Check expiration
             if self.config.support_gtd_orders {
                 if let Some(expire_time) = order.expire_time() {
-                    if timestamp_ns >= expire_time {
-                        // SAFTEY: We know this order is in the core
-                        self.core.delete_order(order).unwrap();
-                        self.expire_order(order);
+                    if expire_time.as_u64() > 0 && timestamp_ns >= expire_time {
+                        // SAFETY: We know this order is in the core
+                        self.core.delete_order(&order).unwrap();
+                        self.purge_order_state(order.client_order_id());
+                        self.expire_order(&mut order.into());
+                        continue;
                     }
                 }
             }
 
+            let mut order: OrderAny = order.into();
+
             // Manage trailing stop
-            if let PassiveOrderAny::Stop(o) = order {
-                match o {
-                    StopOrderAny::TrailingStopMarket(o) => self.update_trailing_stop_market(o),
-                    StopOrderAny::TrailingStopLimit(o) => self.update_trailing_stop_limit(o),
-                    _ => {}
-                }
+            if matches!(
+                order,
+                OrderAny::TrailingStopMarket(_) | OrderAny::TrailingStopLimit(_)
+            ) {
+                self.update_trailing_stop_order(&mut order);
             }
 
+            self.update_queue_ahead(&order);
+            self.match_order(&mut order);
+
             // Move market back to targets
-            self.core.bid = self.target_bid;
-            self.core.ask = self.target_ask;
-            self.core.last = self.target_last;
+            if let Some(target_bid) = self.target_bid {
+                self.core.bid = Some(target_bid);
+            }
+            if let Some(target_ask) = self.target_ask {
+                self.core.ask = Some(target_ask);
+            }
+            if let Some(target_last) = self.target_last {
+                self.core.last = Some(target_last);
+            }
         }
 
         // Reset any targets after iteration
@@ -251,15 +916,3358 @@ impl OrderMatchingEngine {
         self.target_last = None;
     }
 
-    fn expire_order(&mut self, order: &PassiveOrderAny) {
-        todo!();
+    fn process_market_order(&mut self, order: &mut OrderAny) {
+        if !self.has_market(order.order_side_specified()) {
+            self.generate_order_rejected(order, &format!("No market for {}", self.instrument.id()));
+            return;
+        }
+
+        self.fill_market_order(order);
+    }
+
+    fn process_market_to_limit_order(&mut self, order: &mut OrderAny) {
+        if !self.has_market(order.order_side_specified()) {
+            self.generate_order_rejected(order, &format!("No market for {}", self.instrument.id()));
+            return;
+        }
+
+        // Immediately fill marketable order
+        self.fill_market_order(order);
+
+        // Any remaining quantity rests at the price of the first fill
+        if order.is_open() {
+            self.accept_order(order);
+        }
+    }
+
+    fn process_limit_order(&mut self, order: &mut OrderAny) {
+        let side = order.order_side_specified();
+        let price = order
+            .as_order()
+            .price()
+            .expect("Limit order must have a price");
+
+        if order.as_order().is_post_only() && self.core.is_limit_price_matched(side, price) {
+            self.generate_order_rejected(
+                order,
+                &format!(
+                    "POST_ONLY {} {} order limit px of {price} would have been a TAKER: bid={}, ask={}",
+                    order.as_order().order_type(),
+                    order.order_side(),
+                    format_price(self.core.bid),
+                    format_price(self.core.ask),
+                ),
+            );
+            return;
+        }
+
+        // Order is valid and accepted
+        self.accept_order(order);
+
+        // Check for immediate fill
+        if self.core.is_limit_price_matched(side, price) {
+            self.fill_limit_order(order, LiquiditySide::Taker);
+        } else if matches!(
+            order.as_order().time_in_force(),
+            TimeInForce::Fok | TimeInForce::Ioc
+        ) {
+            self.cancel_order(order, true);
+        }
+    }
+
+    fn process_stop_market_order(&mut self, order: &mut OrderAny) {
+        if self.is_trigger_matched(order) {
+            if self.config.reject_stop_orders {
+                self.generate_order_rejected(order, &self.stop_in_market_reason(order));
+                return;
+            }
+
+            // Triggers immediately
+            self.fill_market_order(order);
+            return;
+        }
+
+        // Order is valid and accepted
+        self.accept_order(order);
+    }
+
+    fn process_stop_limit_order(&mut self, order: &mut OrderAny) {
+        if self.is_trigger_matched(order) {
+            if self.config.reject_stop_orders {
+                self.generate_order_rejected(order, &self.stop_in_market_reason(order));
+                return;
+            }
+
+            // Order is valid and accepted, then triggers immediately
+            self.accept_order(order);
+            self.trigger_stop_order(order);
+            return;
+        }
+
+        // Order is valid and accepted
+        self.accept_order(order);
+    }
+
+    fn update_order(
+        &mut self,
+        order: &mut OrderAny,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        update_contingencies: bool,
+    ) {
+        let quantity = quantity.unwrap_or_else(|| order.quantity());
+        if quantity <= order.filled_qty() {
+            self.reject_modify(
+                order,
+                &format!(
+                    "New quantity {quantity} is not greater than the filled quantity {}",
+                    order.filled_qty()
+                ),
+            );
+            return;
+        }
+
+        let side = order.order_side_specified();
+        match order {
+            OrderAny::Limit(_) | OrderAny::MarketToLimit(_) => {
+                let price = price.or_else(|| order.as_order().price()).unwrap();
+                if self.core.is_limit_price_matched(side, price) {
+                    if order.as_order().is_post_only() {
+                        self.reject_modify(
+                            order,
+                            &format!(
+                                "POST_ONLY {} {} order with new limit px of {price} would have been a TAKER: bid={}, ask={}",
+                                order.as_order().order_type(),
+                                order.order_side(),
+                                format_price(self.core.bid),
+                                format_price(self.core.ask),
+                            ),
+                        );
+                        return;
+                    }
+
+                    self.generate_order_updated(order, quantity, Some(price), None);
+                    self.fill_limit_order(order, LiquiditySide::Taker); // Immediate fill as TAKER
+                } else {
+                    self.generate_order_updated(order, quantity, Some(price), None);
+                }
+            }
+            OrderAny::StopMarket(_)
+            | OrderAny::MarketIfTouched(_)
+            | OrderAny::TrailingStopMarket(_) => {
+                let trigger_price = trigger_price
+                    .or_else(|| order.as_order().trigger_price())
+                    .unwrap();
+                if self.is_trigger_price_matched(order, trigger_price) {
+                    self.reject_modify(
+                        order,
+                        &format!(
+                            "{} {} order new stop px of {trigger_price} was in the market: bid={}, ask={}",
+                            order.as_order().order_type(),
+                            order.order_side(),
+                            format_price(self.core.bid),
+                            format_price(self.core.ask),
+                        ),
+                    );
+                    return;
+                }
+
+                self.generate_order_updated(order, quantity, None, Some(trigger_price));
+            }
+            OrderAny::StopLimit(_)
+            | OrderAny::LimitIfTouched(_)
+            | OrderAny::TrailingStopLimit(_) => {
+                let price = price.or_else(|| order.as_order().price()).unwrap();
+                let trigger_price = trigger_price
+                    .or_else(|| order.as_order().trigger_price())
+                    .unwrap();
+
+                if !is_triggered(order) {
+                    // Updating stop price
+                    if self.is_trigger_price_matched(order, trigger_price) {
+                        self.reject_modify(
+                            order,
+                            &format!(
+                                "{} {} order new trigger px of {trigger_price} was in the market: bid={}, ask={}",
+                                order.as_order().order_type(),
+                                order.order_side(),
+                                format_price(self.core.bid),
+                                format_price(self.core.ask),
+                            ),
+                        );
+                        return;
+                    }
+                } else if self.core.is_limit_price_matched(side, price) {
+                    // Updating limit price
+                    if order.as_order().is_post_only() {
+                        self.reject_modify(
+                            order,
+                            &format!(
+                                "POST_ONLY {} {} order with new limit px of {price} would have been a TAKER: bid={}, ask={}",
+                                order.as_order().order_type(),
+                                order.order_side(),
+                                format_price(self.core.bid),
+                                format_price(self.core.ask),
+                            ),
+                        );
+                        return;
+                    }
+
+                    self.generate_order_updated(order, quantity, Some(price), None);
+                    self.fill_limit_order(order, LiquiditySide::Taker); // Immediate fill as TAKER
+                } else {
+                    self.generate_order_updated(order, quantity, Some(price), Some(trigger_price));
+                }
+            }
+            OrderAny::Market(_) => {
+                self.reject_modify(order, "Cannot modify a MARKET order");
+                return;
+            }
+        }
+
+        if update_contingencies
+            && self.config.support_contingent_orders
+            && order.as_order().contingency_type() == Some(ContingencyType::Ouo)
+        {
+            self.update_contingent_orders(order);
+        }
+    }
+
+    fn match_order(&mut self, order: &mut OrderAny) {
+        let side = order.order_side_specified();
+        match order {
+            OrderAny::Limit(_) | OrderAny::MarketToLimit(_) => {
+                let price = order.as_order().price().unwrap();
+                if self.core.is_limit_price_matched(side, price) {
+                    self.fill_limit_order(order, LiquiditySide::Maker);
+                }
+            }
+            OrderAny::StopLimit(_)
+            | OrderAny::LimitIfTouched(_)
+            | OrderAny::TrailingStopLimit(_) => {
+                if is_triggered(order) {
+                    let price = order.as_order().price().unwrap();
+                    if self.core.is_limit_price_matched(side, price) {
+                        self.fill_limit_order(order, LiquiditySide::Maker);
+                    }
+                } else if self.is_trigger_matched(order) {
+                    self.trigger_stop_order(order);
+                }
+            }
+            OrderAny::StopMarket(_)
+            | OrderAny::MarketIfTouched(_)
+            | OrderAny::TrailingStopMarket(_) => {
+                if self.is_trigger_matched(order) {
+                    if self.is_trigger_at_touch(order) && !self.fill_model.is_stop_filled() {
+                        return; // Not filled
+                    }
+                    self.fill_market_order(order);
+                }
+            }
+            OrderAny::Market(_) => {}
+        }
+    }
+
+    fn trigger_stop_order(&mut self, order: &mut OrderAny) {
+        self.generate_order_triggered(order);
+
+        // Check for immediate fill
+        let price = order.as_order().price().unwrap();
+        if self
+            .core
+            .is_limit_price_matched(order.order_side_specified(), price)
+        {
+            self.fill_limit_order(order, LiquiditySide::Taker);
+        }
+    }
+
+    fn determine_market_price_and_volume(&mut self, order: &OrderAny) -> Vec<(Price, Quantity)> {
+        let side = order.order_side_specified();
+        let price = match side {
+            OrderSideSpecified::Buy => Price::max(self.instrument.price_precision()),
+            OrderSideSpecified::Sell => Price::min(self.instrument.price_precision()),
+        };
+        let book_order = BookOrder::new(order.order_side(), price, order.leaves_qty(), 0);
+        let fills = self.book.simulate_fills(&book_order);
+
+        if self.book_type == BookType::L1_MBP && !fills.is_empty() && self.fill_model.is_slipped() {
+            // Slip the fill prices by one tick against the order
+            let tick = self.instrument.price_increment();
+            return fills
+                .into_iter()
+                .map(|(fill_px, fill_qty)| match side {
+                    OrderSideSpecified::Buy => (fill_px + tick, fill_qty),
+                    OrderSideSpecified::Sell => (fill_px - tick, fill_qty),
+                })
+                .collect();
+        }
+
+        fills
+    }
+
+    fn determine_limit_price_and_volume(
+        &self,
+        order: &OrderAny,
+        liquidity_side: LiquiditySide,
+    ) -> Vec<(Price, Quantity)> {
+        let price = order.as_order().price().unwrap();
+        let book_order = BookOrder::new(order.order_side(), price, order.leaves_qty(), 0);
+        let fills = self.book.simulate_fills(&book_order);
+
+        match liquidity_side {
+            // Passive orders are filled at their limit price for the volume which crossed them
+            LiquiditySide::Maker => {
+                let fill_qty = fills.iter().fold(
+                    Quantity::zero(order.quantity().precision),
+                    |acc, (_, qty)| acc + *qty,
+                );
+                if fill_qty.is_positive() {
+                    vec![(price, fill_qty)]
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => fills,
+        }
     }
 
-    fn update_trailing_stop_market(&mut self, order: &TrailingStopMarketOrder) {
-        todo!()
+    fn fill_market_order(&mut self, order: &mut OrderAny) {
+        if let Some(filled_qty) = self.cached_filled_qty.get(&order.client_order_id()) {
+            if *filled_qty >= order.quantity() {
+                return; // Already filled
+            }
+        }
+
+        let fills = self.determine_market_price_and_volume(order);
+        self.apply_fills(order, fills, LiquiditySide::Taker);
     }
 
-    fn update_trailing_stop_limit(&mut self, order: &TrailingStopLimitOrder) {
-        todo!()
+    fn fill_limit_order(&mut self, order: &mut OrderAny, liquidity_side: LiquiditySide) {
+        if let Some(filled_qty) = self.cached_filled_qty.get(&order.client_order_id()) {
+            if *filled_qty >= order.quantity() {
+                return; // Already filled
+            }
+        }
+
+        if liquidity_side == LiquiditySide::Maker && self.is_limit_at_touch(order) {
+            // The market rests on the orders price, so quantity queued ahead must be
+            // consumed first, then the fill model determines whether the order filled.
+            if self
+                .queue_ahead
+                .get(&order.client_order_id())
+                .is_some_and(Quantity::is_positive)
+            {
+                return; // Not filled
+            }
+            // Queue position is tracked exactly for L3 books
+            if self.book_type != BookType::L3_MBO && !self.fill_model.is_limit_filled() {
+                return; // Not filled
+            }
+        }
+
+        let fills = self.determine_limit_price_and_volume(order, liquidity_side);
+        self.apply_fills(order, fills, liquidity_side);
+    }
+
+    fn apply_fills(
+        &mut self,
+        order: &mut OrderAny,
+        fills: Vec<(Price, Quantity)>,
+        liquidity_side: LiquiditySide,
+    ) {
+        if fills.is_empty() {
+            return; // No fills
+        }
+
+        // The remaining position quantity a reduce-only order can fill
+        let mut reduce_only_qty = None;
+        if self.config.use_reduce_only && order.as_order().is_reduce_only() {
+            let position = self.position_for_order(order);
+            if would_increase_position(order, position.as_ref()) {
+                warn!(
+                    "Canceling REDUCE_ONLY {} {} order as would increase position",
+                    order.as_order().order_type(),
+                    order.order_side(),
+                );
+                self.cancel_order(order, true);
+                return;
+            }
+            reduce_only_qty = position.map(|position| position.quantity);
+        }
+
+        let time_in_force = order.as_order().time_in_force();
+        if time_in_force == TimeInForce::Fok {
+            let fill_qty = fills.iter().fold(
+                Quantity::zero(order.quantity().precision),
+                |acc, (_, qty)| acc + *qty,
+            );
+            if fill_qty < order.leaves_qty() {
+                // FOK order cannot fill fully, cancel
+                self.cancel_order(order, true);
+                return;
+            }
+        }
+
+        let order_type = order.as_order().order_type();
+        let mut last_fill_px = None;
+        for (fill_px, fill_qty) in fills {
+            if !fill_qty.is_positive() {
+                return; // Done
+            }
+
+            let is_initial_market_to_limit_fill =
+                order_type == OrderType::MarketToLimit && order.filled_qty().is_zero();
+            if is_initial_market_to_limit_fill {
+                let quantity = order.quantity();
+                self.generate_order_updated(order, quantity, Some(fill_px), None);
+            }
+
+            let fill_qty = self.clip_reduce_only_fill(order, fill_qty, &mut reduce_only_qty);
+            if fill_qty.is_zero() {
+                return; // Done
+            }
+
+            self.fill_order(order, fill_px, fill_qty, liquidity_side);
+            last_fill_px = Some(fill_px);
+
+            if is_initial_market_to_limit_fill {
+                return; // Filled initial level
+            }
+        }
+
+        if time_in_force == TimeInForce::Ioc && order.is_open() {
+            // IOC order has filled all available size
+            self.cancel_order(order, true);
+            return;
+        }
+
+        if order.is_open()
+            && self.book_type == BookType::L1_MBP
+            && matches!(
+                order_type,
+                OrderType::Market | OrderType::StopMarket | OrderType::TrailingStopMarket
+            )
+        {
+            // Exhausted simulated book volume (continue aggressive filling into next level).
+            // This is a very basic implementation of slipping by a single tick.
+            let fill_px = last_fill_px.unwrap();
+            let fill_px = match order.order_side_specified() {
+                OrderSideSpecified::Buy => fill_px + self.instrument.price_increment(),
+                OrderSideSpecified::Sell => fill_px - self.instrument.price_increment(),
+            };
+            let leaves_qty = order.leaves_qty();
+            let fill_qty = self.clip_reduce_only_fill(order, leaves_qty, &mut reduce_only_qty);
+            if fill_qty.is_positive() {
+                self.fill_order(order, fill_px, fill_qty, liquidity_side);
+            }
+        }
+    }
+
+    /// Returns the `fill_qty` clipped to the remaining `reduce_only_qty` (if any), updating the
+    /// orders quantity so that it reduces the position only.
+    fn clip_reduce_only_fill(
+        &mut self,
+        order: &mut OrderAny,
+        fill_qty: Quantity,
+        reduce_only_qty: &mut Option<Quantity>,
+    ) -> Quantity {
+        let Some(position_qty) = reduce_only_qty.as_mut() else {
+            return fill_qty;
+        };
+
+        let mut fill_qty = fill_qty;
+        if fill_qty > *position_qty {
+            // Adjust fill to honor reduce only execution (fill remaining position size only)
+            fill_qty = *position_qty;
+            if fill_qty.is_positive() {
+                let quantity = order.filled_qty() + fill_qty;
+                self.generate_order_updated(order, quantity, None, None);
+            }
+        }
+        *position_qty -= fill_qty;
+        fill_qty
+    }
+
+    fn fill_order(
+        &mut self,
+        order: &mut OrderAny,
+        last_px: Price,
+        last_qty: Quantity,
+        liquidity_side: LiquiditySide,
+    ) {
+        let client_order_id = order.client_order_id();
+        let last_qty = match self.cached_filled_qty.get_mut(&client_order_id) {
+            Some(filled_qty) => {
+                if *filled_qty >= order.quantity() {
+                    return; // Already filled
+                }
+                let leaves_qty = order.quantity() - *filled_qty;
+                let last_qty = last_qty.min(leaves_qty);
+                *filled_qty += last_qty;
+                last_qty
+            }
+            None => {
+                self.cached_filled_qty.insert(client_order_id, last_qty);
+                last_qty
+            }
+        };
+
+        if !last_qty.is_positive() {
+            return; // Nothing to fill
+        }
+
+        let commission = self.calculate_commission(last_qty, last_px, liquidity_side);
+        self.generate_order_filled(order, last_px, last_qty, liquidity_side, commission);
+
+        if order.is_closed() {
+            // Remove order from market
+            self.remove_order_from_core(client_order_id);
+            self.purge_order_state(client_order_id);
+        }
+
+        if self.config.support_contingent_orders {
+            self.handle_contingencies_on_fill(order);
+        }
+    }
+
+    fn accept_order(&mut self, order: &mut OrderAny) {
+        if order.is_closed() {
+            return; // Temporary guard to prevent invalid processing
+        }
+
+        let passive_order = match PassiveOrderAny::try_from(order.clone()) {
+            Ok(passive_order) => passive_order,
+            Err(e) => {
+                error!("Cannot accept order: {e}");
+                return;
+            }
+        };
+        let venue_order_id = order
+            .venue_order_id()
+            .unwrap_or_else(|| self.generate_venue_order_id());
+        self.core
+            .add_order(passive_order)
+            .expect("Order side should be specified");
+        self.init_queue_ahead(order);
+        self.generate_order_accepted(order, venue_order_id);
+    }
+
+    fn expire_order(&mut self, order: &mut OrderAny) {
+        if self.config.support_contingent_orders {
+            self.cancel_contingent_orders(order);
+        }
+
+        self.generate_order_expired(order);
+    }
+
+    fn cancel_order(&mut self, order: &mut OrderAny, cancel_contingencies: bool) {
+        if order.as_order().is_active_local() {
+            error!(
+                "Cannot cancel an order with {} from the matching engine",
+                order.as_order().status()
+            );
+            return;
+        }
+
+        let client_order_id = order.client_order_id();
+        self.remove_order_from_core(client_order_id);
+        self.pending_contingent_orders.remove(&client_order_id);
+        self.auction_orders
+            .retain(|auction_order| auction_order.client_order_id() != client_order_id);
+        self.purge_order_state(client_order_id);
+        self.generate_order_canceled(order);
+
+        if cancel_contingencies && self.config.support_contingent_orders {
+            self.cancel_contingent_orders(order);
+        }
+    }
+
+    // -- CONTINGENT ORDERS ---------------------------------------------------
+
+    /// Returns whether the `order` should continue to be processed, otherwise the order was
+    /// rejected or is being held until its `OTO` parent order fills.
+    fn check_contingencies(&mut self, order: &OrderAny) -> bool {
+        let client_order_id = order.client_order_id();
+
+        if let Some(parent_order_id) = order.as_order().parent_order_id() {
+            if let Some(parent) = self.lookup_order(parent_order_id) {
+                if parent.as_order().contingency_type() == Some(ContingencyType::Oto) {
+                    match parent.as_order().status() {
+                        OrderStatus::Rejected if order.is_open() => {
+                            self.generate_order_rejected(
+                                order,
+                                &format!("REJECT OTO from {parent_order_id}"),
+                            );
+                            return false;
+                        }
+                        OrderStatus::Accepted | OrderStatus::Triggered => {
+                            info!("Pending OTO {client_order_id} triggers from {parent_order_id}");
+                            self.pending_contingent_orders
+                                .insert(client_order_id, order.clone());
+                            return false;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        if matches!(
+            order.as_order().contingency_type(),
+            Some(ContingencyType::Oco | ContingencyType::Ouo)
+        ) && !order.is_closed()
+        {
+            for linked_order_id in order.as_order().linked_order_ids().unwrap_or_default() {
+                let is_closed = self
+                    .lookup_order(*linked_order_id)
+                    .is_some_and(|linked_order| linked_order.is_closed());
+                if is_closed {
+                    self.generate_order_rejected(
+                        order,
+                        &format!("Contingent order {linked_order_id} already closed"),
+                    );
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn handle_contingencies_on_fill(&mut self, order: &OrderAny) {
+        let linked_order_ids = order
+            .as_order()
+            .linked_order_ids()
+            .unwrap_or_default()
+            .to_vec();
+
+        match order.as_order().contingency_type() {
+            Some(ContingencyType::Oto) => {
+                let account_id = self.account_id(order);
+                for client_order_id in linked_order_ids {
+                    // Only child orders held by the engine are released (others arrive later)
+                    let Some(child_order) = self.pending_contingent_orders.remove(&client_order_id)
+                    else {
+                        continue;
+                    };
+                    if child_order.is_closed() || child_order.as_order().is_active_local() {
+                        continue;
+                    }
+
+                    if child_order.as_order().position_id().is_none() {
+                        if let Some(position_id) = order.as_order().position_id() {
+                            match self.cache.borrow_mut().add_position_id(
+                                &position_id,
+                                &self.venue,
+                                &client_order_id,
+                                &child_order.strategy_id(),
+                            ) {
+                                Ok(()) => info!("Indexed {position_id} for {client_order_id}"),
+                                Err(e) => error!("Error indexing {position_id}: {e}"),
+                            }
+                        }
+                    }
+
+                    self.execute_order(child_order, account_id);
+                }
+            }
+            Some(ContingencyType::Oco) => {
+                for client_order_id in linked_order_ids {
+                    if let Some(mut oco_order) = self.lookup_working_order(client_order_id) {
+                        if !oco_order.is_closed() && !oco_order.as_order().is_active_local() {
+                            self.cancel_order(&mut oco_order, false);
+                        }
+                    }
+                }
+            }
+            Some(ContingencyType::Ouo) => {
+                for client_order_id in linked_order_ids {
+                    let Some(mut ouo_order) = self.lookup_working_order(client_order_id) else {
+                        continue;
+                    };
+                    if ouo_order.as_order().is_active_local() {
+                        continue;
+                    }
+
+                    if order.is_closed() && ouo_order.is_open() {
+                        self.cancel_order(&mut ouo_order, false);
+                    } else if !order.leaves_qty().is_zero()
+                        && order.leaves_qty() != ouo_order.leaves_qty()
+                    {
+                        self.resize_contingent_order(&mut ouo_order, order.leaves_qty());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn update_contingent_orders(&mut self, order: &OrderAny) {
+        let linked_order_ids = order
+            .as_order()
+            .linked_order_ids()
+            .unwrap_or_default()
+            .to_vec();
+        let leaves_qty = order.leaves_qty();
+
+        for client_order_id in linked_order_ids {
+            let Some(mut ouo_order) = self.lookup_working_order(client_order_id) else {
+                continue;
+            };
+            if ouo_order.is_closed() || ouo_order.as_order().is_active_local() {
+                continue;
+            }
+
+            if leaves_qty.is_zero() {
+                self.cancel_order(&mut ouo_order, false);
+            } else if ouo_order.leaves_qty() != leaves_qty {
+                self.resize_contingent_order(&mut ouo_order, leaves_qty);
+            }
+        }
+    }
+
+    fn cancel_contingent_orders(&mut self, order: &OrderAny) {
+        if matches!(
+            order.as_order().contingency_type(),
+            None | Some(ContingencyType::NoContingency)
+        ) {
+            return;
+        }
+
+        for client_order_id in order
+            .as_order()
+            .linked_order_ids()
+            .unwrap_or_default()
+            .to_vec()
+        {
+            if let Some(mut contingent_order) = self.lookup_working_order(client_order_id) {
+                if !contingent_order.is_closed() && !contingent_order.as_order().is_active_local() {
+                    self.cancel_order(&mut contingent_order, false);
+                }
+            }
+        }
+    }
+
+    /// Updates the `order` so that its leaves quantity matches the given `leaves_qty`.
+    fn resize_contingent_order(&mut self, order: &mut OrderAny, leaves_qty: Quantity) {
+        if order.as_order().order_type() == OrderType::Market {
+            return; // Cannot modify a MARKET order
+        }
+
+        let quantity = order.filled_qty() + leaves_qty;
+        if self.core.order_exists(order.client_order_id()) {
+            self.update_order(order, Some(quantity), None, None, false);
+        } else {
+            self.update_queued_order(order, Some(quantity), None, None);
+        }
+    }
+
+    /// Returns the latest state of the order with the given `client_order_id`, preferring the
+    /// copies held by the engine over the cache.
+    fn lookup_order(&self, client_order_id: ClientOrderId) -> Option<OrderAny> {
+        self.lookup_working_order(client_order_id)
+            .or_else(|| self.cache.borrow().order(&client_order_id).cloned())
+    }
+
+    /// Returns the copy of the order with the given `client_order_id` held by the engine (either
+    /// working in the market, pending its parent order or queued for an auction).
+    fn lookup_working_order(&self, client_order_id: ClientOrderId) -> Option<OrderAny> {
+        self.core
+            .get_order(client_order_id)
+            .cloned()
+            .map(OrderAny::from)
+            .or_else(|| {
+                self.pending_contingent_orders
+                    .get(&client_order_id)
+                    .cloned()
+            })
+            .or_else(|| {
+                self.auction_orders
+                    .iter()
+                    .find(|order| order.client_order_id() == client_order_id)
+                    .cloned()
+            })
+    }
+
+    /// Updates an `order` held by the engine which is not yet working in the market (so there
+    /// is nothing to match against).
+    fn update_queued_order(
+        &mut self,
+        order: &mut OrderAny,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) {
+        let quantity = quantity.unwrap_or_else(|| order.quantity());
+        if quantity <= order.filled_qty() {
+            self.reject_modify(
+                order,
+                &format!(
+                    "New quantity {quantity} is not greater than the filled quantity {}",
+                    order.filled_qty()
+                ),
+            );
+            return;
+        }
+
+        let price = price.or_else(|| order.as_order().price());
+        let trigger_price = trigger_price.or_else(|| order.as_order().trigger_price());
+        self.generate_order_updated(order, quantity, price, trigger_price);
+
+        let client_order_id = order.client_order_id();
+        if let Some(pending_order) = self.pending_contingent_orders.get_mut(&client_order_id) {
+            *pending_order = order.clone();
+        } else if let Some(auction_order) = self
+            .auction_orders
+            .iter_mut()
+            .find(|auction_order| auction_order.client_order_id() == client_order_id)
+        {
+            *auction_order = order.clone();
+        }
+    }
+
+    // -- AUCTIONS ------------------------------------------------------------
+
+    fn is_continuous_trading(&self) -> bool {
+        matches!(
+            self.market_status,
+            MarketStatus::Open | MarketStatus::Reopen
+        )
+    }
+
+    fn is_auction_order(&self, order: &OrderAny) -> bool {
+        let is_call_phase = matches!(
+            self.market_status,
+            MarketStatus::PreOpen | MarketStatus::PreClose
+        );
+        matches!(
+            order.as_order().order_type(),
+            OrderType::Market | OrderType::MarketToLimit | OrderType::Limit
+        ) && (is_call_phase || order.as_order().time_in_force() == TimeInForce::AtTheClose)
+    }
+
+    fn queue_auction_order(&mut self, mut order: OrderAny) {
+        let venue_order_id = self.generate_venue_order_id();
+        self.generate_order_accepted(&mut order, venue_order_id);
+        self.auction_orders.push(order);
+    }
+
+    /// Uncrosses the auction for the given `time_in_force` (either `AT_THE_OPEN` or
+    /// `AT_THE_CLOSE`) at a single clearing price, then releases the remaining auction orders
+    /// into the market.
+    ///
+    /// The clearing price maximizes the executable volume between the book and the simulated
+    /// orders, then minimizes the order imbalance, then the distance from the last price.
+    fn uncross_auction(&mut self, time_in_force: TimeInForce) {
+        let is_closing = time_in_force == TimeInForce::AtTheClose;
+        let auction_order_ids: Vec<ClientOrderId> = self
+            .auction_orders
+            .iter()
+            .filter(|order| {
+                // Orders for the closing auction are held until the close
+                is_closing || order.as_order().time_in_force() != TimeInForce::AtTheClose
+            })
+            .map(GetClientOrderId::client_order_id)
+            .collect();
+        let resting_orders: Vec<OrderAny> = self
+            .core
+            .get_orders_bid()
+            .iter()
+            .chain(self.core.get_orders_ask())
+            .cloned()
+            .map(OrderAny::from)
+            .filter(is_resting_limit)
+            .collect();
+
+        let participants: Vec<OrderAny> = auction_order_ids
+            .iter()
+            .filter_map(|client_order_id| self.lookup_working_order(*client_order_id))
+            .chain(resting_orders.iter().cloned())
+            .collect();
+
+        if !auction_order_ids.is_empty() {
+            self.match_auction(&participants);
+        }
+
+        // Release remaining auction orders into the market
+        for client_order_id in auction_order_ids {
+            let Some(index) = self
+                .auction_orders
+                .iter()
+                .position(|order| order.client_order_id() == client_order_id)
+            else {
+                continue; // Canceled during the auction
+            };
+            let mut order = self.auction_orders.remove(index);
+            if order.is_closed() {
+                continue;
+            }
+
+            if is_auction_only(&order, time_in_force) {
+                self.cancel_order(&mut order, true);
+            } else {
+                match PassiveOrderAny::try_from(order.clone()) {
+                    Ok(passive_order) => {
+                        self.core
+                            .add_order(passive_order)
+                            .expect("Order side should be specified");
+                        self.init_queue_ahead(&order);
+                    }
+                    Err(e) => error!("Cannot rest order after the auction: {e}"),
+                }
+            }
+        }
+
+        for order in resting_orders {
+            let Some(mut order) = self.lookup_working_order(order.client_order_id()) else {
+                continue; // Filled during the auction
+            };
+            if order.is_open() && order.as_order().time_in_force() == time_in_force {
+                self.cancel_order(&mut order, true);
+            }
+        }
+    }
+
+    fn match_auction(&mut self, participants: &[OrderAny]) {
+        let mut bids: Vec<AuctionInterest> = self
+            .book
+            .bids()
+            .map(|level| AuctionInterest::new(Some(level.price.value), level.size_raw(), None))
+            .collect();
+        let mut asks: Vec<AuctionInterest> = self
+            .book
+            .asks()
+            .map(|level| AuctionInterest::new(Some(level.price.value), level.size_raw(), None))
+            .collect();
+        for (index, order) in participants.iter().enumerate() {
+            let price = match order.as_order().order_type() {
+                OrderType::Market | OrderType::MarketToLimit => None,
+                _ => order.as_order().price(),
+            };
+            let interest = AuctionInterest::new(price, order.leaves_qty().raw, Some(index));
+            match order.order_side_specified() {
+                OrderSideSpecified::Buy => bids.push(interest),
+                OrderSideSpecified::Sell => asks.push(interest),
+            }
+        }
+
+        let Some((clearing_px, volume_raw)) = find_clearing_price(&bids, &asks, self.core.last)
+        else {
+            return; // Auction did not cross
+        };
+
+        info!(
+            "Auction for {} uncrossed at {clearing_px}",
+            self.instrument.id()
+        );
+
+        let mut fills =
+            allocate_auction_fills(bids, OrderSideSpecified::Buy, clearing_px, volume_raw);
+        fills.extend(allocate_auction_fills(
+            asks,
+            OrderSideSpecified::Sell,
+            clearing_px,
+            volume_raw,
+        ));
+
+        let size_precision = self.instrument.size_precision();
+        for (index, fill_raw) in fills {
+            // Fetch the latest state, as contingent orders may have changed during the auction
+            let client_order_id = participants[index].client_order_id();
+            let Some(mut order) = self.lookup_working_order(client_order_id) else {
+                continue;
+            };
+            if order.is_closed() {
+                continue;
+            }
+
+            let fill_qty = Quantity::from_raw(fill_raw, size_precision).unwrap();
+            self.fill_order(&mut order, clearing_px, fill_qty, LiquiditySide::Taker);
+
+            if let Some(auction_order) = self
+                .auction_orders
+                .iter_mut()
+                .find(|auction_order| auction_order.client_order_id() == client_order_id)
+            {
+                *auction_order = order;
+            }
+        }
+
+        self.core.last = Some(clearing_px);
+    }
+
+    fn update_trailing_stop_order(&mut self, order: &mut OrderAny) {
+        let (new_trigger_price, new_price) = match trailing_stop_calculate(
+            self.instrument.price_increment(),
+            &self.config.price_tiers,
+            order,
+            self.core.bid,
+            self.core.ask,
+            self.core.last,
+            self.core.mark,
+        ) {
+            Ok(output) => output,
+            Err(e) => {
+                warn!("Cannot calculate trailing stop order: {e}");
+                return;
+            }
+        };
+
+        if new_trigger_price.is_none() && new_price.is_none() {
+            return; // No updates
+        }
+
+        let quantity = order.quantity();
+        self.generate_order_updated(order, quantity, new_price, new_trigger_price);
+    }
+
+    // -- HELPERS -------------------------------------------------------------
+
+    fn has_market(&self, side: OrderSideSpecified) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.core.ask.is_some(),
+            OrderSideSpecified::Sell => self.core.bid.is_some(),
+        }
+    }
+
+    fn is_trigger_matched(&self, order: &OrderAny) -> bool {
+        let trigger_price = order
+            .as_order()
+            .trigger_price()
+            .expect("Stop order must have a trigger price");
+        self.is_trigger_price_matched(order, trigger_price)
+    }
+
+    fn is_trigger_price_matched(&self, order: &OrderAny, trigger_price: Price) -> bool {
+        let side = order.order_side_specified();
+        match order {
+            OrderAny::MarketIfTouched(_) | OrderAny::LimitIfTouched(_) => {
+                self.core.is_touch_price_triggered(side, trigger_price)
+            }
+            _ => self.core.is_stop_price_triggered(side, trigger_price),
+        }
+    }
+
+    fn is_limit_at_touch(&self, order: &OrderAny) -> bool {
+        let price = order.as_order().price();
+        match order.order_side_specified() {
+            OrderSideSpecified::Buy => self.core.ask.is_some() && self.core.ask == price,
+            OrderSideSpecified::Sell => self.core.bid.is_some() && self.core.bid == price,
+        }
+    }
+
+    fn is_trigger_at_touch(&self, order: &OrderAny) -> bool {
+        let trigger_price = order.as_order().trigger_price();
+        match order.order_side_specified() {
+            OrderSideSpecified::Buy => self.core.ask.is_some() && self.core.ask == trigger_price,
+            OrderSideSpecified::Sell => self.core.bid.is_some() && self.core.bid == trigger_price,
+        }
+    }
+
+    /// Returns the order book level on the orders own side at the orders price (if found).
+    fn own_level(&self, order: &OrderAny) -> Option<&Level> {
+        let price = order.as_order().price()?;
+        match order.order_side_specified() {
+            OrderSideSpecified::Buy => self.book.bids().find(|level| level.price.value == price),
+            OrderSideSpecified::Sell => self.book.asks().find(|level| level.price.value == price),
+        }
+    }
+
+    fn init_queue_ahead(&mut self, order: &OrderAny) {
+        if order.as_order().price().is_none() {
+            return; // No price level to queue at
+        }
+
+        let client_order_id = order.client_order_id();
+        let orders_ahead = self
+            .own_level(order)
+            .map(Level::get_orders)
+            .unwrap_or_default();
+
+        if self.book_type == BookType::L3_MBO {
+            // Every order resting at the level when the order arrives is ahead of it
+            self.queue_ahead
+                .insert(client_order_id, self.sum_order_sizes(&orders_ahead));
+            self.queue_ahead_orders
+                .insert(client_order_id, orders_ahead);
+        } else {
+            let level_size = self.sum_order_sizes(&orders_ahead);
+            let queue_ahead = self.fill_model.queue_ahead(level_size);
+            self.queue_ahead.insert(client_order_id, queue_ahead);
+        }
+    }
+
+    fn update_queue_ahead(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        if !self.queue_ahead.contains_key(&client_order_id) {
+            return; // Not queued
+        }
+
+        let level_orders = self
+            .own_level(order)
+            .map(Level::get_orders)
+            .unwrap_or_default();
+
+        // Quantity ahead can only be reduced as the level is traded or canceled away
+        if let Some(mut orders_ahead) = self.queue_ahead_orders.remove(&client_order_id) {
+            for order_ahead in &mut orders_ahead {
+                let current_size = level_orders
+                    .iter()
+                    .find(|o| o.order_id == order_ahead.order_id)
+                    .map_or(Quantity::zero(order_ahead.size.precision), |o| o.size);
+                order_ahead.size = order_ahead.size.min(current_size);
+            }
+            orders_ahead.retain(|o| o.size.is_positive());
+            self.queue_ahead
+                .insert(client_order_id, self.sum_order_sizes(&orders_ahead));
+            self.queue_ahead_orders
+                .insert(client_order_id, orders_ahead);
+        } else {
+            let level_size = self.sum_order_sizes(&level_orders);
+            if let Some(queue_ahead) = self.queue_ahead.get_mut(&client_order_id) {
+                *queue_ahead = (*queue_ahead).min(level_size);
+            }
+        }
+    }
+
+    /// Matches the given `trade` against resting orders at the trades price.
+    ///
+    /// The trade first consumes the queue ahead of each order, then any remaining trade size
+    /// fills the order as a maker.
+    fn match_trade_against_queues(&mut self, trade: &TradeTick) {
+        let orders = match trade.aggressor_side {
+            AggressorSide::Buyer => self.core.get_orders_ask().to_vec(),
+            AggressorSide::Seller => self.core.get_orders_bid().to_vec(),
+            AggressorSide::NoAggressor => return, // Cannot determine the passive side
+        };
+
+        let mut trade_remaining = trade.size;
+        for order in orders {
+            let mut order: OrderAny = order.into();
+            if order.as_order().price() != Some(trade.price) || !is_resting_limit(&order) {
+                continue;
+            }
+
+            let client_order_id = order.client_order_id();
+            let mut remaining = trade_remaining;
+            if let Some(orders_ahead) = self.queue_ahead_orders.get_mut(&client_order_id) {
+                for order_ahead in orders_ahead.iter_mut() {
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    let consumed = order_ahead.size.min(remaining);
+                    order_ahead.size -= consumed;
+                    remaining -= consumed;
+                }
+                orders_ahead.retain(|o| o.size.is_positive());
+                let queue_ahead = self.sum_order_sizes(&self.queue_ahead_orders[&client_order_id]);
+                self.queue_ahead.insert(client_order_id, queue_ahead);
+            }
+
+            if remaining.is_positive()
+                && !self
+                    .queue_ahead
+                    .get(&client_order_id)
+                    .is_some_and(Quantity::is_positive)
+            {
+                let fill_qty = remaining.min(order.leaves_qty());
+                self.fill_order(&mut order, trade.price, fill_qty, LiquiditySide::Maker);
+                trade_remaining -= fill_qty;
+            }
+        }
+    }
+
+    fn sum_order_sizes(&self, orders: &[BookOrder]) -> Quantity {
+        orders.iter().fold(
+            Quantity::zero(self.instrument.size_precision()),
+            |acc, order| acc + order.size,
+        )
+    }
+
+    fn purge_order_state(&mut self, client_order_id: ClientOrderId) {
+        self.cached_filled_qty.remove(&client_order_id);
+        self.queue_ahead.remove(&client_order_id);
+        self.queue_ahead_orders.remove(&client_order_id);
+    }
+
+    fn stop_in_market_reason(&self, order: &OrderAny) -> String {
+        format!(
+            "{} {} order trigger px of {} was in the market: bid={}, ask={}",
+            order.as_order().order_type(),
+            order.order_side(),
+            format_price(order.as_order().trigger_price()),
+            format_price(self.core.bid),
+            format_price(self.core.ask),
+        )
+    }
+
+    fn remove_order_from_core(&mut self, client_order_id: ClientOrderId) {
+        if let Some(order) = self.core.get_order(client_order_id).cloned() {
+            // SAFETY: We know this order is in the core
+            self.core.delete_order(&order).unwrap();
+        }
+    }
+
+    fn calculate_commission(
+        &self,
+        last_qty: Quantity,
+        last_px: Price,
+        liquidity_side: LiquiditySide,
+    ) -> Money {
+        let notional = self
+            .instrument
+            .calculate_notional_value(last_qty, last_px, None)
+            .as_f64();
+        let fee = match liquidity_side {
+            LiquiditySide::Maker => self.instrument.maker_fee(),
+            LiquiditySide::Taker => self.instrument.taker_fee(),
+            LiquiditySide::NoLiquiditySide => panic!("Invalid `LiquiditySide` {liquidity_side}"),
+        };
+        let commission = notional * fee.to_f64().unwrap();
+        let currency = if self.instrument.is_inverse() {
+            self.instrument.base_currency().unwrap()
+        } else {
+            self.instrument.quote_currency()
+        };
+        Money::new(commission, currency).unwrap()
+    }
+
+    /// Returns the position the `order` relates to from the cache (if any).
+    fn position_for_order(&self, order: &OrderAny) -> Option<Position> {
+        let cache = self.cache.borrow();
+        let client_order_id = order.client_order_id();
+        let position_id = match self.oms_type {
+            OmsType::Hedging => order
+                .as_order()
+                .position_id()
+                .or_else(|| cache.position_id(&client_order_id).copied()),
+            // Netting position IDs are `{instrument_id}-{strategy_id}`
+            _ => cache.position_id(&client_order_id).copied().or_else(|| {
+                Some(
+                    PositionId::new(&format!(
+                        "{}-{}",
+                        order.instrument_id(),
+                        order.strategy_id()
+                    ))
+                    .unwrap(),
+                )
+            }),
+        }?;
+        cache.position(&position_id).cloned()
+    }
+
+    /// Returns the venue position ID for the `order`, generating and indexing a new position ID
+    /// if the order is not yet related to a position.
+    ///
+    /// Venue position IDs are only assigned for a `HEDGING` OMS.
+    fn get_venue_position_id(&mut self, order: &OrderAny) -> Option<PositionId> {
+        if self.oms_type != OmsType::Hedging {
+            return None; // No position IDs generated by the venue
+        }
+
+        let client_order_id = order.client_order_id();
+        let existing_position_id = order
+            .as_order()
+            .position_id()
+            .or_else(|| self.cache.borrow().position_id(&client_order_id).copied());
+        if existing_position_id.is_some() {
+            return existing_position_id;
+        }
+
+        let position_id = self.generate_venue_position_id()?;
+        if let Err(e) = self.cache.borrow_mut().add_position_id(
+            &position_id,
+            &self.venue,
+            &client_order_id,
+            &order.strategy_id(),
+        ) {
+            error!("Error indexing {position_id}: {e}");
+        }
+        Some(position_id)
+    }
+
+    fn generate_venue_position_id(&mut self) -> Option<PositionId> {
+        if !self.config.use_position_ids {
+            return None;
+        }
+
+        self.position_count += 1;
+        if self.config.use_random_ids {
+            Some(PositionId::new(&UUID4::new().to_string()).unwrap())
+        } else {
+            Some(
+                PositionId::new(&format!(
+                    "{}-{}-{:03}",
+                    self.venue, self.raw_id, self.position_count
+                ))
+                .unwrap(),
+            )
+        }
+    }
+
+    fn generate_venue_order_id(&mut self) -> VenueOrderId {
+        self.order_count += 1;
+        if self.config.use_random_ids {
+            VenueOrderId::new(&UUID4::new().to_string()).unwrap()
+        } else {
+            VenueOrderId::new(&format!(
+                "{}-{}-{:03}",
+                self.venue, self.raw_id, self.order_count
+            ))
+            .unwrap()
+        }
+    }
+
+    fn generate_trade_id(&mut self) -> TradeId {
+        self.execution_count += 1;
+        if self.config.use_random_ids {
+            TradeId::new(&UUID4::new().to_string()).unwrap()
+        } else {
+            TradeId::new(&format!(
+                "{}-{}-{:03}",
+                self.venue, self.raw_id, self.execution_count
+            ))
+            .unwrap()
+        }
+    }
+
+    fn account_id(&self, order: &OrderAny) -> AccountId {
+        order
+            .as_order()
+            .account_id()
+            .or_else(|| self.account_ids.get(&order.trader_id()).copied())
+            .expect("No account ID for order trader")
+    }
+
+    // -- EVENT HANDLING ------------------------------------------------------
+
+    /// Applies the `event` to the engines working copy of the `order` (and the resting
+    /// copy held by the matching core), then sends the event to the execution engine.
+    fn apply_and_send_event(&mut self, order: &mut OrderAny, event: OrderEventAny) {
+        if let Err(e) = order.apply(event.clone()) {
+            error!(
+                "Error applying {event} event to {}: {e}",
+                order.client_order_id()
+            );
+        }
+
+        if let Some(resting_order) = self.core.get_order_mut(order.client_order_id()) {
+            match PassiveOrderAny::try_from(order.clone()) {
+                Ok(passive_order) => *resting_order = passive_order,
+                Err(e) => error!("Cannot update resting order: {e}"),
+            }
+        }
+
+        self.send_event(event);
+    }
+
+    fn send_event(&self, event: OrderEventAny) {
+        self.msgbus.borrow().send(EXEC_ENGINE_PROCESS, &event);
+    }
+
+    fn generate_order_rejected(&self, order: &OrderAny, reason: &str) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            self.account_id(order),
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        )
+        .unwrap();
+        self.send_event(OrderEventAny::Rejected(event));
+    }
+
+    fn generate_order_accepted(&mut self, order: &mut OrderAny, venue_order_id: VenueOrderId) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            self.account_id(order),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+        )
+        .unwrap();
+        self.apply_and_send_event(order, OrderEventAny::Accepted(event));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_order_modify_rejected(
+        &self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        account_id: Option<AccountId>,
+        reason: &str,
+    ) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderModifyRejected::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            venue_order_id,
+            account_id,
+        )
+        .unwrap();
+        self.send_event(OrderEventAny::ModifyRejected(event));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn generate_order_cancel_rejected(
+        &self,
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: Option<VenueOrderId>,
+        account_id: Option<AccountId>,
+        reason: &str,
+    ) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderCancelRejected::new(
+            trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            venue_order_id,
+            account_id,
+        )
+        .unwrap();
+        self.send_event(OrderEventAny::CancelRejected(event));
+    }
+
+    fn reject_modify(&self, order: &OrderAny, reason: &str) {
+        self.generate_order_modify_rejected(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id(),
+            Some(self.account_id(order)),
+            reason,
+        );
+    }
+
+    fn generate_order_updated(
+        &mut self,
+        order: &mut OrderAny,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) {
+        let is_price_changed = price.is_some() && price != order.as_order().price();
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            quantity,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(order)),
+            price,
+            trigger_price,
+        )
+        .unwrap();
+        self.apply_and_send_event(order, OrderEventAny::Updated(event));
+
+        if is_price_changed {
+            self.init_queue_ahead(order); // Order loses its queue priority
+        }
+    }
+
+    fn generate_order_canceled(&mut self, order: &mut OrderAny) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(order)),
+        )
+        .unwrap();
+        self.apply_and_send_event(order, OrderEventAny::Canceled(event));
+    }
+
+    fn generate_order_triggered(&mut self, order: &mut OrderAny) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderTriggered::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(order)),
+        )
+        .unwrap();
+        self.apply_and_send_event(order, OrderEventAny::Triggered(event));
+    }
+
+    fn generate_order_expired(&mut self, order: &mut OrderAny) {
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderExpired::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.venue_order_id(),
+            Some(self.account_id(order)),
+        )
+        .unwrap();
+        self.apply_and_send_event(order, OrderEventAny::Expired(event));
+    }
+
+    fn generate_order_filled(
+        &mut self,
+        order: &mut OrderAny,
+        last_px: Price,
+        last_qty: Quantity,
+        liquidity_side: LiquiditySide,
+        commission: Money,
+    ) {
+        let venue_order_id = order
+            .venue_order_id()
+            .unwrap_or_else(|| self.generate_venue_order_id());
+        let venue_position_id = self.get_venue_position_id(order);
+        let trade_id = self.generate_trade_id();
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            self.account_id(order),
+            trade_id,
+            order.order_side(),
+            order.as_order().order_type(),
+            last_qty,
+            last_px,
+            self.instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            venue_position_id,
+            Some(commission),
+        )
+        .unwrap();
+
+        let event = if last_qty < order.leaves_qty() {
+            OrderEventAny::PartiallyFilled(event)
+        } else {
+            OrderEventAny::Filled(event)
+        };
+        self.apply_and_send_event(order, event);
+    }
+}
+
+/// Returns whether the order rests at its limit price.
+fn is_resting_limit(order: &OrderAny) -> bool {
+    matches!(order, OrderAny::Limit(_) | OrderAny::MarketToLimit(_)) || is_triggered(order)
+}
+
+fn is_triggered(order: &OrderAny) -> bool {
+    match order {
+        OrderAny::StopLimit(order) => order.is_triggered,
+        OrderAny::LimitIfTouched(order) => order.is_triggered,
+        OrderAny::TrailingStopLimit(order) => order.is_triggered,
+        _ => false,
+    }
+}
+
+/// Returns whether filling the reduce-only `order` would increase the given `position`.
+fn would_increase_position(order: &OrderAny, position: Option<&Position>) -> bool {
+    match position {
+        Some(position) if !position.is_closed() && position.side != PositionSide::Flat => {
+            match order.order_side_specified() {
+                OrderSideSpecified::Buy => position.is_long(),
+                OrderSideSpecified::Sell => position.is_short(),
+            }
+        }
+        _ => true,
+    }
+}
+
+/// Returns whether the `order` can only execute in the auction for the given `time_in_force`.
+fn is_auction_only(order: &OrderAny, time_in_force: TimeInForce) -> bool {
+    matches!(
+        order.as_order().order_type(),
+        OrderType::Market | OrderType::MarketToLimit
+    ) || order.as_order().time_in_force() == time_in_force
+}
+
+/// Represents the interest of a book level or simulated order in an auction.
+#[derive(Clone, Copy, Debug)]
+struct AuctionInterest {
+    /// The limit price (`None` for a market order).
+    price: Option<Price>,
+    /// The raw size of the interest.
+    size_raw: u64,
+    /// The index of the simulated order (`None` for book liquidity).
+    index: Option<usize>,
+}
+
+impl AuctionInterest {
+    fn new(price: Option<Price>, size_raw: u64, index: Option<usize>) -> Self {
+        Self {
+            price,
+            size_raw,
+            index,
+        }
+    }
+}
+
+/// Returns the auction clearing price and raw executable volume for the given interest, or
+/// `None` if the auction does not cross.
+fn find_clearing_price(
+    bids: &[AuctionInterest],
+    asks: &[AuctionInterest],
+    reference_px: Option<Price>,
+) -> Option<(Price, u64)> {
+    let mut prices: Vec<Price> = bids.iter().chain(asks).filter_map(|i| i.price).collect();
+    prices.sort();
+    prices.dedup();
+
+    let mut best: Option<(Price, u64, u64, u64)> = None;
+    for price in prices {
+        let demand: u64 = bids
+            .iter()
+            .filter(|i| !matches!(i.price, Some(px) if px < price))
+            .map(|i| i.size_raw)
+            .sum();
+        let supply: u64 = asks
+            .iter()
+            .filter(|i| !matches!(i.price, Some(px) if px > price))
+            .map(|i| i.size_raw)
+            .sum();
+        let volume = demand.min(supply);
+        if volume == 0 {
+            continue;
+        }
+
+        let imbalance = demand.abs_diff(supply);
+        let distance = reference_px.map_or(0, |px| price.raw.abs_diff(px.raw));
+        let is_better = match best {
+            Some((_, best_volume, best_imbalance, best_distance)) => {
+                (volume, Reverse(imbalance), Reverse(distance))
+                    > (best_volume, Reverse(best_imbalance), Reverse(best_distance))
+            }
+            None => true,
+        };
+        if is_better {
+            best = Some((price, volume, imbalance, distance));
+        }
+    }
+
+    best.map(|(price, volume, _, _)| (price, volume))
+}
+
+/// Allocates the raw auction `volume_raw` across the `interest` for one `side` by price
+/// priority (market orders first), returning the raw fill size for each simulated order.
+///
+/// Simulated orders queue behind book liquidity at the same price.
+fn allocate_auction_fills(
+    mut interest: Vec<AuctionInterest>,
+    side: OrderSideSpecified,
+    clearing_px: Price,
+    volume_raw: u64,
+) -> Vec<(usize, u64)> {
+    interest.retain(|i| match (i.price, side) {
+        (None, _) => true,
+        (Some(px), OrderSideSpecified::Buy) => px >= clearing_px,
+        (Some(px), OrderSideSpecified::Sell) => px <= clearing_px,
+    });
+    interest.sort_by_key(|i| {
+        let price_rank = match (i.price, side) {
+            (None, _) => i64::MIN,
+            (Some(px), OrderSideSpecified::Buy) => -px.raw,
+            (Some(px), OrderSideSpecified::Sell) => px.raw,
+        };
+        (price_rank, i.index.is_some())
+    });
+
+    let mut remaining_raw = volume_raw;
+    let mut fills = Vec::new();
+    for i in interest {
+        if remaining_raw == 0 {
+            break;
+        }
+        let fill_raw = i.size_raw.min(remaining_raw);
+        remaining_raw -= fill_raw;
+        if let Some(index) = i.index {
+            fills.push((index, fill_raw));
+        }
+    }
+    fills
+}
+
+/// Returns whether the bars open is closer to its high than its low.
+fn is_high_first(bar: &Bar) -> bool {
+    bar.high.raw - bar.open.raw <= bar.open.raw - bar.low.raw
+}
+
+/// Returns the simulated price path through the given `bar`.
+fn bar_price_path(bar: &Bar, is_high_first: bool) -> [Price; 4] {
+    if is_high_first {
+        [bar.open, bar.high, bar.low, bar.close]
+    } else {
+        [bar.open, bar.low, bar.high, bar.close]
+    }
+}
+
+/// Splits the `volume` of a bar evenly across the four points of its price path, in units of
+/// the volume precision, with the remainder going to the last point.
+fn split_bar_volume(volume: Quantity) -> [Quantity; 4] {
+    let unit = 10_u64.pow(u32::from(FIXED_PRECISION - volume.precision));
+    let size = Quantity::from_raw(volume.raw / unit / 4 * unit, volume.precision).unwrap();
+    let last = Quantity::from_raw(volume.raw - 3 * size.raw, volume.precision).unwrap();
+    [size, size, size, last]
+}
+
+fn format_price(price: Option<Price>) -> String {
+    price.map_or("None".to_string(), |price| format!("{price}"))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    use nautilus_common::{cache::Cache, handlers::AnyMessageHandler, msgbus::MessageBus};
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+    use nautilus_execution::messages::{
+        cancel::CancelOrder, modify::ModifyOrder, submit::SubmitOrder,
+    };
+    use nautilus_model::{
+        data::{
+            bar::{Bar, BarType},
+            delta::OrderBookDelta,
+            order::BookOrder,
+            quote::QuoteTick,
+            trade::TradeTick,
+        },
+        enums::{
+            AccountType, AggressorSide, BookAction, BookType, ContingencyType, CrossedBookPolicy,
+            HaltReason, LiquiditySide, MarketStatus, OmsType, OrderSide, OrderSideSpecified,
+            TimeInForce, TrailingOffsetType, TriggerType,
+        },
+        events::order::{event::OrderEventAny, submitted::OrderSubmitted},
+        identifiers::{
+            account_id::AccountId,
+            client_id::ClientId,
+            client_order_id::ClientOrderId,
+            order_list_id::OrderListId,
+            position_id::PositionId,
+            stubs::{strategy_id_ema_cross, trader_id},
+            trade_id::TradeId,
+            trader_id::TraderId,
+            venue_order_id::VenueOrderId,
+        },
+        instruments::stubs::equity_aapl,
+        orders::{
+            any::OrderAny,
+            limit::LimitOrder,
+            market::MarketOrder,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+        polymorphism::{ApplyOrderEventAny, GetClientOrderId, GetInstrumentId, GetStrategyId},
+        position::Position,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::{
+        allocate_auction_fills, bar_price_path, find_clearing_price, is_high_first,
+        AuctionInterest, OrderMatchingEngine, OrderMatchingEngineConfig, EXEC_ENGINE_PROCESS,
+    };
+    use crate::models::{FillModel, LatencyModel, ProbabilisticFillModel, QueuePositionModel};
+
+    type Events = Rc<RefCell<Vec<OrderEventAny>>>;
+
+    fn get_matching_engine(
+        book_type: BookType,
+        fill_model: Option<Box<dyn FillModel>>,
+        latency_model: Option<LatencyModel>,
+        config: Option<OrderMatchingEngineConfig>,
+    ) -> (OrderMatchingEngine, Events) {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(TraderId::from("TRADER-001"), UUID4::new(), None, None).unwrap(),
+        ));
+        let events: Events = Rc::new(RefCell::new(Vec::new()));
+
+        let events_clone = events.clone();
+        let handler = AnyMessageHandler::new(Ustr::from("ExecEngine"), move |msg: &dyn Any| {
+            let event = msg.downcast_ref::<OrderEventAny>().unwrap();
+            events_clone.borrow_mut().push(event.clone());
+        });
+        msgbus
+            .borrow_mut()
+            .register_any(EXEC_ENGINE_PROCESS, handler);
+
+        let engine = OrderMatchingEngine::new(
+            Box::new(equity_aapl()),
+            1,
+            book_type,
+            OmsType::Netting,
+            AccountType::Cash,
+            clock,
+            msgbus,
+            Rc::new(RefCell::new(Cache::default())),
+            fill_model.unwrap_or_else(|| Box::new(ProbabilisticFillModel::default())),
+            latency_model,
+            config.unwrap_or_default(),
+        );
+        (engine, events)
+    }
+
+    fn account_id() -> AccountId {
+        AccountId::from("SIM-001")
+    }
+
+    fn submitted(mut order: OrderAny) -> OrderAny {
+        let event = OrderSubmitted::new(
+            order.as_order().trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            account_id(),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Submitted(event)).unwrap();
+        order
+    }
+
+    fn add_level(engine: &mut OrderMatchingEngine, side: OrderSide, price: &str, size: &str) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        let delta = OrderBookDelta::new(
+            engine.instrument.id(),
+            BookAction::Add,
+            order,
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine.process_order_book_delta(delta);
+    }
+
+    fn limit_order(side: OrderSide, price: &str, quantity: &str, id: &str) -> OrderAny {
+        OrderAny::Limit(TestOrderStubs::limit_order(
+            equity_aapl().id,
+            side,
+            Price::from(price),
+            Quantity::from(quantity),
+            Some(ClientOrderId::from(id)),
+            None,
+        ))
+    }
+
+    #[rstest]
+    fn test_process_market_order_with_no_market_rejected() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Quantity::from("100"),
+            None,
+            None,
+        ));
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEventAny::Rejected(event) => {
+                assert_eq!(event.reason, Ustr::from("No market for AAPL.XNAS"));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_process_order_with_invalid_quantity_precision_rejected() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "100.00", "100.5", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        assert!(matches!(events.borrow()[0], OrderEventAny::Rejected(_)));
+        assert!(!engine.order_exists(ClientOrderId::from("O-1")));
+    }
+
+    #[rstest]
+    fn test_process_market_order_fills_across_book_levels() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.20", "200");
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Quantity::from("250"),
+            None,
+            None,
+        ));
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match (&events[0], &events[1]) {
+            (OrderEventAny::PartiallyFilled(fill1), OrderEventAny::Filled(fill2)) => {
+                assert_eq!(fill1.last_px, Price::from("100.10"));
+                assert_eq!(fill1.last_qty, Quantity::from("100"));
+                assert_eq!(fill1.liquidity_side, LiquiditySide::Taker);
+                assert_eq!(fill1.venue_order_id, VenueOrderId::from("XNAS-1-001"));
+                assert_eq!(fill2.last_px, Price::from("100.20"));
+                assert_eq!(fill2.last_qty, Quantity::from("150"));
+                assert_eq!(fill2.liquidity_side, LiquiditySide::Taker);
+                assert_eq!(fill2.venue_order_id, fill1.venue_order_id);
+                assert_ne!(fill2.trade_id, fill1.trade_id);
+            }
+            events => panic!("Unexpected events {events:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_process_marketable_limit_order_fills_as_taker() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.20", "200");
+        let order = limit_order(OrderSide::Buy, "100.10", "150", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        match &events[1] {
+            OrderEventAny::PartiallyFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.10"));
+                assert_eq!(fill.last_qty, Quantity::from("100"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert!(engine.order_exists(ClientOrderId::from("O-1")));
+    }
+
+    #[rstest]
+    fn test_process_ioc_limit_order_cancels_remainder() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = OrderAny::Limit(TestOrderStubs::limit_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Price::from("100.10"),
+            Quantity::from("150"),
+            None,
+            Some(TimeInForce::Ioc),
+        ));
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        assert!(matches!(events[1], OrderEventAny::PartiallyFilled(_)));
+        assert!(matches!(events[2], OrderEventAny::Canceled(_)));
+        assert!(engine.get_open_bid_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_passive_limit_order_filled_as_maker_when_book_crosses() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Sell, "100.20", "100", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+        assert_eq!(engine.get_open_ask_orders().len(), 1);
+
+        add_level(&mut engine, OrderSide::Buy, "100.30", "40");
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+        match &events[1] {
+            OrderEventAny::PartiallyFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.20"));
+                assert_eq!(fill.last_qty, Quantity::from("40"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert_eq!(engine.get_open_ask_orders().len(), 1);
+    }
+
+    #[rstest]
+    fn test_post_only_limit_order_rejected_when_marketable() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let mut order = TestOrderStubs::limit_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Price::from("100.10"),
+            Quantity::from("100"),
+            None,
+            None,
+        );
+        order.is_post_only = true;
+
+        engine.process_order(submitted(OrderAny::Limit(order)), account_id());
+
+        assert!(matches!(events.borrow()[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_stop_market_order_rejected_when_in_the_market() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = OrderAny::StopMarket(TestOrderStubs::stop_market_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("100"),
+            None,
+            None,
+            None,
+        ));
+
+        engine.process_order(submitted(order), account_id());
+
+        assert!(matches!(events.borrow()[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_stop_market_order_filled_when_triggered() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = OrderAny::StopMarket(TestOrderStubs::stop_market_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Price::from("100.20"),
+            Quantity::from("100"),
+            None,
+            None,
+            None,
+        ));
+
+        engine.process_order(submitted(order), account_id());
+        add_level(&mut engine, OrderSide::Sell, "100.30", "200");
+        assert_eq!(events.borrow().len(), 1);
+
+        // Remove best ask so the market moves up through the trigger
+        let delta = OrderBookDelta::new(
+            engine.instrument.id(),
+            BookAction::Delete,
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.10"),
+                Quantity::from("0"),
+                0,
+            ),
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine.process_order_book_delta(delta);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.30"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_trailing_stop_market_order_trails_bid_then_filled() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = OrderAny::TrailingStopMarket(TestOrderStubs::trailing_stop_market_order(
+            engine.instrument.id(),
+            OrderSide::Sell,
+            Price::from("99.00"),
+            Price::from("0.50"),
+            TrailingOffsetType::Price,
+            Quantity::from("100"),
+            Some(TriggerType::BidAsk),
+            None,
+        ));
+
+        engine.process_order(submitted(order), account_id());
+        add_level(&mut engine, OrderSide::Buy, "101.00", "100");
+
+        {
+            let events = events.borrow();
+            assert_eq!(events.len(), 2);
+            match &events[1] {
+                OrderEventAny::Updated(updated) => {
+                    assert_eq!(updated.trigger_price, Some(Price::from("100.50")));
+                    assert_eq!(updated.price, None);
+                }
+                event => panic!("Unexpected event {event}"),
+            }
+        }
+
+        // Remove best bid so the market moves down through the trailed trigger
+        let delta = OrderBookDelta::new(
+            engine.instrument.id(),
+            BookAction::Delete,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("101.00"),
+                Quantity::from("0"),
+                0,
+            ),
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine.process_order_book_delta(delta);
+
+        let events = events.borrow();
+        match events.last().unwrap() {
+            OrderEventAny::Filled(fill) => assert_eq!(fill.last_px, Price::from("100.00")),
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_process_modify_updates_resting_order() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "99.00", "100", "O-1");
+        engine.process_order(submitted(order.clone()), account_id());
+
+        let command = ModifyOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("XNAS-1-001"),
+            Some(Quantity::from("50")),
+            Some(Price::from("99.50")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine.process_modify(&command, account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::Updated(event) => {
+                assert_eq!(event.quantity, Quantity::from("50"));
+                assert_eq!(event.price, Some(Price::from("99.50")));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_process_modify_to_marketable_price_fills_as_taker() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "99.00", "100", "O-1");
+        engine.process_order(submitted(order.clone()), account_id());
+
+        let command = ModifyOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("XNAS-1-001"),
+            None,
+            Some(Price::from("100.10")),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine.process_modify(&command, account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], OrderEventAny::Updated(_)));
+        match &events[2] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.10"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert!(!engine.order_exists(order.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_process_cancel() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "99.00", "100", "O-1");
+        engine.process_order(submitted(order.clone()), account_id());
+
+        let command = CancelOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("XNAS-1-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine.process_cancel(&command, account_id());
+        engine.process_cancel(&command, account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], OrderEventAny::Canceled(_)));
+        assert!(matches!(events[2], OrderEventAny::CancelRejected(_)));
+        assert!(!engine.order_exists(order.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_gtd_order_expires_on_iterate() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let mut order = TestOrderStubs::limit_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Price::from("99.00"),
+            Quantity::from("100"),
+            None,
+            None,
+        );
+        order.time_in_force = TimeInForce::Gtd;
+        order.expire_time = Some(UnixNanos::from(1_000));
+        let client_order_id = order.client_order_id;
+        engine.process_order(submitted(OrderAny::Limit(order)), account_id());
+
+        engine.iterate(UnixNanos::from(999));
+        assert!(engine.order_exists(client_order_id));
+
+        engine.iterate(UnixNanos::from(1_000));
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], OrderEventAny::Expired(_)));
+        assert!(!engine.order_exists(client_order_id));
+    }
+
+    #[rstest]
+    fn test_process_submit_command() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = submitted(limit_order(OrderSide::Buy, "99.00", "100", "O-1"));
+        let command = SubmitOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("XNAS-1-001"),
+            order,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        engine.process_submit(&command, account_id());
+
+        assert!(matches!(events.borrow()[0], OrderEventAny::Accepted(_)));
+        assert_eq!(engine.get_open_bid_orders().len(), 1);
+    }
+
+    #[rstest]
+    fn test_process_order_with_latency_model() {
+        let latency_model = LatencyModel::new(1_000, 0, 0, 0);
+        let (mut engine, events) =
+            get_matching_engine(BookType::L2_MBP, None, Some(latency_model), None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "99.00", "100", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+        engine.iterate(UnixNanos::from(999));
+        assert!(events.borrow().is_empty());
+
+        engine.iterate(UnixNanos::from(1_000));
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEventAny::Accepted(event) => assert_eq!(event.ts_event, 1_000),
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_limit_order_at_touch_waits_for_queue_ahead() {
+        let fill_model =
+            ProbabilisticFillModel::new(1.0, 1.0, 0.0, QueuePositionModel::Back, Some(42)).unwrap();
+        let (mut engine, events) =
+            get_matching_engine(BookType::L2_MBP, Some(Box::new(fill_model)), None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Sell, "100.10", "100", "O-1");
+        engine.process_order(submitted(order), account_id());
+
+        // Market rests on the order price with 100 queued ahead
+        add_level(&mut engine, OrderSide::Buy, "100.10", "50");
+        assert_eq!(events.borrow().len(), 1);
+
+        // Quantity ahead is consumed
+        let delta = OrderBookDelta::new(
+            engine.instrument.id(),
+            BookAction::Delete,
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.10"),
+                Quantity::from("0"),
+                0,
+            ),
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine.process_order_book_delta(delta);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::PartiallyFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.10"));
+                assert_eq!(fill.last_qty, Quantity::from("50"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_limit_order_at_touch_not_filled_when_fill_model_rejects() {
+        let fill_model =
+            ProbabilisticFillModel::new(0.0, 1.0, 0.0, QueuePositionModel::Front, Some(42))
+                .unwrap();
+        let (mut engine, events) =
+            get_matching_engine(BookType::L2_MBP, Some(Box::new(fill_model)), None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        let order = limit_order(OrderSide::Sell, "100.10", "100", "O-1");
+        engine.process_order(submitted(order), account_id());
+
+        add_level(&mut engine, OrderSide::Buy, "100.10", "50");
+        assert_eq!(events.borrow().len(), 1);
+
+        // Market trading through the order price always fills
+        add_level(&mut engine, OrderSide::Buy, "100.20", "50");
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], OrderEventAny::Filled(_)));
+    }
+
+    fn get_bar_execution_engine() -> (OrderMatchingEngine, Events) {
+        let config = OrderMatchingEngineConfig {
+            bar_execution: true,
+            ..Default::default()
+        };
+        get_matching_engine(BookType::L1_MBP, None, None, Some(config))
+    }
+
+    fn bar(bar_type: &str, open: &str, high: &str, low: &str, close: &str, ts: u64) -> Bar {
+        Bar::new(
+            BarType::from(bar_type),
+            Price::from(open),
+            Price::from(high),
+            Price::from(low),
+            Price::from(close),
+            Quantity::from("400"),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
+    #[rstest]
+    #[case("100.00", true)]
+    #[case("100.08", true)]
+    #[case("99.60", false)]
+    fn test_bar_price_path_by_proximity(#[case] open: &str, #[case] expected_high_first: bool) {
+        let bar = bar(
+            "AAPL.XNAS-1-MINUTE-LAST-EXTERNAL",
+            open,
+            "100.10",
+            "99.50",
+            "100.05",
+            0,
+        );
+
+        assert_eq!(is_high_first(&bar), expected_high_first);
+        let path = bar_price_path(&bar, is_high_first(&bar));
+        assert_eq!(path[0], bar.open);
+        assert_eq!(path[3], bar.close);
+        if expected_high_first {
+            assert_eq!(path[1], bar.high);
+        } else {
+            assert_eq!(path[1], bar.low);
+        }
+    }
+
+    #[rstest]
+    #[case("100.00", "100.10", "99.50", "100.05", "10", &["2", "2", "2", "4"])]
+    #[case("100.00", "100.00", "99.50", "100.05", "10", &["2", "4", "4"])]
+    #[case("100.00", "100.10", "100.00", "100.00", "7", &["1", "2", "4"])]
+    #[case("100.00", "100.10", "99.50", "100.05", "0.003", &["0.000", "0.000", "0.000", "0.003"])]
+    fn test_trades_from_bar_sizes_sum_to_volume(
+        #[case] open: &str,
+        #[case] high: &str,
+        #[case] low: &str,
+        #[case] close: &str,
+        #[case] volume: &str,
+        #[case] expected_sizes: &[&str],
+    ) {
+        let (mut engine, _) = get_bar_execution_engine();
+        let mut bar = bar(
+            "AAPL.XNAS-1-MINUTE-LAST-EXTERNAL",
+            open,
+            high,
+            low,
+            close,
+            0,
+        );
+        bar.volume = Quantity::from(volume);
+
+        let trades = engine.trades_from_bar(&bar);
+
+        let sizes: Vec<Quantity> = trades.iter().map(|trade| trade.size).collect();
+        let expected: Vec<Quantity> = expected_sizes.iter().map(|s| Quantity::from(*s)).collect();
+        assert_eq!(sizes, expected);
+        assert_eq!(
+            sizes.iter().map(|size| size.raw).sum::<u64>(),
+            bar.volume.raw
+        );
+        assert_eq!(trades.last().unwrap().price, bar.close);
+    }
+
+    #[rstest]
+    fn test_process_bar_when_bar_execution_disabled() {
+        let (mut engine, _) = get_matching_engine(BookType::L1_MBP, None, None, None);
+        let bar = bar(
+            "AAPL.XNAS-1-MINUTE-LAST-EXTERNAL",
+            "100.00",
+            "100.10",
+            "99.50",
+            "100.05",
+            0,
+        );
+
+        engine.process_bar(&bar);
+
+        assert_eq!(engine.best_bid_price(), None);
+    }
+
+    #[rstest]
+    fn test_process_trade_bar_fills_limit_order_at_low() {
+        let (mut engine, events) = get_bar_execution_engine();
+        let order = limit_order(OrderSide::Buy, "99.60", "100", "O-1");
+        engine.process_order(submitted(order), account_id());
+
+        let bar = bar(
+            "AAPL.XNAS-1-MINUTE-LAST-EXTERNAL",
+            "100.00",
+            "100.10",
+            "99.50",
+            "100.05",
+            60_000_000_000,
+        );
+        engine.process_bar(&bar);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("99.60"));
+                assert_eq!(fill.last_qty, Quantity::from("100"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+                assert_eq!(fill.ts_event, 60_000_000_000);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert_eq!(engine.best_bid_price(), Some(Price::from("100.05")));
+        assert_eq!(engine.best_ask_price(), Some(Price::from("100.05")));
+    }
+
+    #[rstest]
+    fn test_process_crossed_quote_tick_skipped_with_reject_policy() {
+        let (mut engine, _) = get_matching_engine(BookType::L1_MBP, None, None, None);
+        engine.book.crossed_policy = CrossedBookPolicy::Reject;
+        let quote = |bid: &str, ask: &str| {
+            QuoteTick::new(
+                engine.instrument.id(),
+                Price::from(bid),
+                Price::from(ask),
+                Quantity::from("100"),
+                Quantity::from("100"),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            )
+            .unwrap()
+        };
+        let valid = quote("100.00", "100.10");
+        let crossed = quote("100.20", "100.10");
+
+        engine.process_quote_tick(&valid);
+        engine.process_quote_tick(&crossed);
+
+        assert_eq!(engine.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(engine.best_ask_price(), Some(Price::from("100.10")));
+    }
+
+    #[rstest]
+    fn test_process_quote_bars_waits_for_bid_and_ask() {
+        let (mut engine, _) = get_bar_execution_engine();
+        let bid_bar = bar(
+            "AAPL.XNAS-1-MINUTE-BID-EXTERNAL",
+            "100.00",
+            "100.10",
+            "99.50",
+            "100.05",
+            0,
+        );
+        let ask_bar = bar(
+            "AAPL.XNAS-1-MINUTE-ASK-EXTERNAL",
+            "100.02",
+            "100.12",
+            "99.52",
+            "100.07",
+            0,
+        );
+
+        engine.process_bar(&bid_bar);
+        assert_eq!(engine.best_bid_price(), None);
+
+        engine.process_bar(&ask_bar);
+        assert_eq!(engine.best_bid_price(), Some(Price::from("100.05")));
+        assert_eq!(engine.best_ask_price(), Some(Price::from("100.07")));
+    }
+
+    #[rstest]
+    fn test_process_bar_ignores_other_bar_specifications() {
+        let (mut engine, _) = get_bar_execution_engine();
+        let bar1 = bar(
+            "AAPL.XNAS-1-MINUTE-LAST-EXTERNAL",
+            "100.00",
+            "100.10",
+            "99.50",
+            "100.05",
+            0,
+        );
+        let bar2 = bar(
+            "AAPL.XNAS-5-MINUTE-LAST-EXTERNAL",
+            "101.00",
+            "101.10",
+            "100.50",
+            "101.05",
+            0,
+        );
+
+        engine.process_bar(&bar1);
+        engine.process_bar(&bar2);
+
+        assert_eq!(engine.best_bid_price(), Some(Price::from("100.05")));
+    }
+
+    fn apply_book_order(
+        engine: &mut OrderMatchingEngine,
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+    ) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), order_id);
+        let delta = OrderBookDelta::new(
+            engine.instrument.id(),
+            action,
+            order,
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine.process_order_book_delta(delta);
+    }
+
+    #[rstest]
+    fn test_l3_limit_order_fills_at_touch_once_orders_ahead_deleted() {
+        let (mut engine, events) = get_matching_engine(BookType::L3_MBO, None, None, None);
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "50",
+            1,
+        );
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "30",
+            2,
+        );
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Sell,
+            "100.10",
+            "100",
+            3,
+        );
+        let order = limit_order(OrderSide::Buy, "100.00", "10", "O-1");
+        engine.process_order(submitted(order), account_id());
+
+        // Orders joining the level behind the simulated order do not affect its queue position
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "500",
+            4,
+        );
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Sell,
+            "100.00",
+            "20",
+            5,
+        );
+        apply_book_order(
+            &mut engine,
+            BookAction::Delete,
+            OrderSide::Buy,
+            "100.00",
+            "0",
+            1,
+        );
+        assert_eq!(events.borrow().len(), 1);
+
+        apply_book_order(
+            &mut engine,
+            BookAction::Delete,
+            OrderSide::Buy,
+            "100.00",
+            "0",
+            2,
+        );
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.00"));
+                assert_eq!(fill.last_qty, Quantity::from("10"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_l3_trades_consume_queue_ahead_before_filling() {
+        let (mut engine, events) = get_matching_engine(BookType::L3_MBO, None, None, None);
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Buy,
+            "100.00",
+            "50",
+            1,
+        );
+        apply_book_order(
+            &mut engine,
+            BookAction::Add,
+            OrderSide::Sell,
+            "100.10",
+            "100",
+            2,
+        );
+        let order = limit_order(OrderSide::Buy, "100.00", "20", "O-1");
+        engine.process_order(submitted(order), account_id());
+
+        let mut trade = TradeTick::new(
+            engine.instrument.id(),
+            Price::from("100.00"),
+            Quantity::from("30"),
+            AggressorSide::Seller,
+            TradeId::from("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        engine.process_trade_tick(&trade);
+        assert_eq!(events.borrow().len(), 1);
+
+        trade.size = Quantity::from("35");
+        trade.trade_id = TradeId::from("2");
+        engine.process_trade_tick(&trade);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::PartiallyFilled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.00"));
+                assert_eq!(fill.last_qty, Quantity::from("15"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn contingent_limit_order(
+        side: OrderSide,
+        price: &str,
+        quantity: &str,
+        id: &str,
+        contingency_type: ContingencyType,
+        linked_order_ids: Option<Vec<&str>>,
+        parent_order_id: Option<&str>,
+    ) -> OrderAny {
+        let client_order_id = ClientOrderId::from(id);
+        OrderAny::Limit(
+            LimitOrder::new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                equity_aapl().id,
+                client_order_id,
+                side,
+                Quantity::from(quantity),
+                Price::from(price),
+                TimeInForce::Gtc,
+                None,
+                false,
+                false,
+                false,
+                None,
+                None,
+                None,
+                Some(contingency_type),
+                Some(OrderListId::from("OL-1")),
+                linked_order_ids.map(|ids| ids.into_iter().map(ClientOrderId::from).collect()),
+                parent_order_id.map(ClientOrderId::from),
+                None,
+                None,
+                Some(client_order_id),
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_oto_child_order_held_until_parent_fills() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "99.90", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let parent = contingent_limit_order(
+            OrderSide::Buy,
+            "100.00",
+            "100",
+            "O-1",
+            ContingencyType::Oto,
+            Some(vec!["O-2"]),
+            None,
+        );
+        let child = contingent_limit_order(
+            OrderSide::Sell,
+            "101.00",
+            "100",
+            "O-2",
+            ContingencyType::NoContingency,
+            None,
+            Some("O-1"),
+        );
+
+        engine.process_order(submitted(parent), account_id());
+        engine.process_order(submitted(child), account_id());
+
+        assert_eq!(events.borrow().len(), 1);
+        assert!(!engine.order_exists(ClientOrderId::from("O-2")));
+
+        add_level(&mut engine, OrderSide::Sell, "100.00", "100");
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[1], OrderEventAny::Filled(_)));
+        match &events[2] {
+            OrderEventAny::Accepted(event) => {
+                assert_eq!(event.client_order_id, ClientOrderId::from("O-2"));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert!(engine.order_exists(ClientOrderId::from("O-2")));
+    }
+
+    #[rstest]
+    fn test_oto_child_order_canceled_with_parent() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let parent = contingent_limit_order(
+            OrderSide::Buy,
+            "100.00",
+            "100",
+            "O-1",
+            ContingencyType::Oto,
+            Some(vec!["O-2"]),
+            None,
+        );
+        let child = contingent_limit_order(
+            OrderSide::Sell,
+            "101.00",
+            "100",
+            "O-2",
+            ContingencyType::NoContingency,
+            None,
+            Some("O-1"),
+        );
+        engine.process_order(submitted(parent.clone()), account_id());
+        engine.process_order(submitted(child), account_id());
+
+        let command = CancelOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            parent.strategy_id(),
+            parent.instrument_id(),
+            parent.client_order_id(),
+            VenueOrderId::from("XNAS-1-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        engine.process_cancel(&command, account_id());
+
+        // Parent filling afterwards must not release the canceled child
+        add_level(&mut engine, OrderSide::Sell, "100.00", "100");
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        match (&events[1], &events[2]) {
+            (OrderEventAny::Canceled(event1), OrderEventAny::Canceled(event2)) => {
+                assert_eq!(event1.client_order_id, ClientOrderId::from("O-1"));
+                assert_eq!(event2.client_order_id, ClientOrderId::from("O-2"));
+            }
+            events => panic!("Unexpected events {events:?}"),
+        }
+        assert!(!engine.order_exists(ClientOrderId::from("O-2")));
+    }
+
+    #[rstest]
+    fn test_oco_fill_cancels_linked_order() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        let order1 = contingent_limit_order(
+            OrderSide::Sell,
+            "100.50",
+            "100",
+            "O-1",
+            ContingencyType::Oco,
+            Some(vec!["O-2"]),
+            None,
+        );
+        let order2 = contingent_limit_order(
+            OrderSide::Sell,
+            "100.60",
+            "100",
+            "O-2",
+            ContingencyType::Oco,
+            Some(vec!["O-1"]),
+            None,
+        );
+        engine.process_order(submitted(order1), account_id());
+        engine.process_order(submitted(order2), account_id());
+
+        add_level(&mut engine, OrderSide::Buy, "100.55", "100");
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[2], OrderEventAny::Filled(_)));
+        match &events[3] {
+            OrderEventAny::Canceled(event) => {
+                assert_eq!(event.client_order_id, ClientOrderId::from("O-2"));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert!(engine.get_open_ask_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_ouo_partial_fill_reduces_linked_order() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        let order1 = contingent_limit_order(
+            OrderSide::Sell,
+            "100.50",
+            "100",
+            "O-1",
+            ContingencyType::Ouo,
+            Some(vec!["O-2"]),
+            None,
+        );
+        let order2 = contingent_limit_order(
+            OrderSide::Sell,
+            "100.60",
+            "100",
+            "O-2",
+            ContingencyType::Ouo,
+            Some(vec!["O-1"]),
+            None,
+        );
+        engine.process_order(submitted(order1), account_id());
+        engine.process_order(submitted(order2), account_id());
+
+        add_level(&mut engine, OrderSide::Buy, "100.55", "40");
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[2], OrderEventAny::PartiallyFilled(_)));
+        match &events[3] {
+            OrderEventAny::Updated(event) => {
+                assert_eq!(event.client_order_id, ClientOrderId::from("O-2"));
+                assert_eq!(event.quantity, Quantity::from("60"));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert_eq!(engine.get_open_ask_orders().len(), 2);
+    }
+
+    #[rstest]
+    fn test_contingent_orders_ignored_when_not_supported() {
+        let config = OrderMatchingEngineConfig {
+            support_contingent_orders: false,
+            ..Default::default()
+        };
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, Some(config));
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let parent = contingent_limit_order(
+            OrderSide::Buy,
+            "100.00",
+            "100",
+            "O-1",
+            ContingencyType::Oto,
+            Some(vec!["O-2"]),
+            None,
+        );
+        let child = contingent_limit_order(
+            OrderSide::Sell,
+            "101.00",
+            "100",
+            "O-2",
+            ContingencyType::NoContingency,
+            None,
+            Some("O-1"),
+        );
+
+        engine.process_order(submitted(parent), account_id());
+        engine.process_order(submitted(child), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[1], OrderEventAny::Accepted(_)));
+        assert!(engine.order_exists(ClientOrderId::from("O-2")));
+    }
+
+    #[rstest]
+    #[case(MarketStatus::Halt, "Market halted for AAPL.XNAS, reason VOLATILITY")]
+    #[case(MarketStatus::Pause, "Market paused for AAPL.XNAS")]
+    #[case(MarketStatus::Closed, "Market closed for AAPL.XNAS")]
+    fn test_order_rejected_when_market_not_trading(
+        #[case] status: MarketStatus,
+        #[case] expected_reason: &str,
+    ) {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        engine.process_status(status, HaltReason::Volatility);
+        let order = limit_order(OrderSide::Buy, "100.00", "100", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEventAny::Rejected(event) => {
+                assert_eq!(event.reason, Ustr::from(expected_reason));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_resting_order_does_not_match_while_halted() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "100.00", "100", "O-1");
+        engine.process_order(submitted(order), account_id());
+
+        engine.process_status(MarketStatus::Halt, HaltReason::General);
+        add_level(&mut engine, OrderSide::Sell, "99.90", "100");
+        assert_eq!(events.borrow().len(), 1);
+
+        engine.process_status(MarketStatus::Reopen, HaltReason::NotHalted);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.00"));
+                assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert_eq!(engine.halt_reason, HaltReason::NotHalted);
+    }
+
+    #[rstest]
+    fn test_opening_auction_uncrosses_at_single_clearing_price() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        engine.process_status(MarketStatus::PreOpen, HaltReason::NotHalted);
+        add_level(&mut engine, OrderSide::Buy, "100.10", "30");
+        add_level(&mut engine, OrderSide::Sell, "100.00", "50");
+        add_level(&mut engine, OrderSide::Sell, "100.20", "100");
+        let order1 = limit_order(OrderSide::Buy, "100.30", "100", "O-1");
+        let order2 = OrderAny::Market(TestOrderStubs::market_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Quantity::from("20"),
+            Some(ClientOrderId::from("O-2")),
+            None,
+        ));
+
+        engine.process_order(submitted(order1), account_id());
+        engine.process_order(submitted(order2), account_id());
+
+        assert_eq!(events.borrow().len(), 2);
+        assert!(engine.get_open_bid_orders().is_empty());
+
+        engine.process_status(MarketStatus::Open, HaltReason::NotHalted);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 4);
+        match (&events[2], &events[3]) {
+            (OrderEventAny::Filled(fill1), OrderEventAny::Filled(fill2)) => {
+                assert_eq!(fill1.client_order_id, ClientOrderId::from("O-2"));
+                assert_eq!(fill1.last_px, Price::from("100.20"));
+                assert_eq!(fill1.last_qty, Quantity::from("20"));
+                assert_eq!(fill2.client_order_id, ClientOrderId::from("O-1"));
+                assert_eq!(fill2.last_px, Price::from("100.20"));
+                assert_eq!(fill2.last_qty, Quantity::from("100"));
+            }
+            events => panic!("Unexpected events {events:?}"),
+        }
+        assert!(engine.get_open_bid_orders().is_empty());
+    }
+
+    #[rstest]
+    fn test_opening_auction_releases_unfilled_limit_orders() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        engine.process_status(MarketStatus::PreOpen, HaltReason::NotHalted);
+        let order1 = limit_order(OrderSide::Buy, "99.00", "100", "O-1");
+        let order2 = OrderAny::Market(TestOrderStubs::market_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Quantity::from("20"),
+            Some(ClientOrderId::from("O-2")),
+            None,
+        ));
+        engine.process_order(submitted(order1), account_id());
+        engine.process_order(submitted(order2), account_id());
+
+        engine.process_status(MarketStatus::Open, HaltReason::NotHalted);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 3);
+        match &events[2] {
+            OrderEventAny::Canceled(event) => {
+                assert_eq!(event.client_order_id, ClientOrderId::from("O-2"));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+        assert!(engine.order_exists(ClientOrderId::from("O-1")));
+        assert_eq!(engine.get_open_bid_orders().len(), 1);
+    }
+
+    #[rstest]
+    fn test_at_the_close_order_queued_for_closing_auction() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = OrderAny::Limit(TestOrderStubs::limit_order(
+            engine.instrument.id(),
+            OrderSide::Buy,
+            Price::from("100.20"),
+            Quantity::from("100"),
+            Some(ClientOrderId::from("O-1")),
+            Some(TimeInForce::AtTheClose),
+        ));
+
+        engine.process_order(submitted(order), account_id());
+        engine.process_status(MarketStatus::PreClose, HaltReason::NotHalted);
+        assert_eq!(events.borrow().len(), 1);
+
+        engine.process_status(MarketStatus::Closed, HaltReason::NotHalted);
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match &events[1] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.last_px, Price::from("100.10"));
+                assert_eq!(fill.last_qty, Quantity::from("100"));
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    #[case(None, "9.00")]
+    #[case(Some("10.00"), "10.00")]
+    fn test_find_clearing_price_breaks_ties_by_reference_price(
+        #[case] reference_px: Option<&str>,
+        #[case] expected_px: &str,
+    ) {
+        let bids = [AuctionInterest::new(Some(Price::from("10.00")), 100, None)];
+        let asks = [AuctionInterest::new(Some(Price::from("9.00")), 100, None)];
+
+        let result = find_clearing_price(&bids, &asks, reference_px.map(Price::from));
+
+        assert_eq!(result, Some((Price::from(expected_px), 100)));
+    }
+
+    #[rstest]
+    fn test_find_clearing_price_when_not_crossed() {
+        let bids = [AuctionInterest::new(Some(Price::from("9.00")), 100, None)];
+        let asks = [AuctionInterest::new(Some(Price::from("10.00")), 100, None)];
+
+        assert_eq!(find_clearing_price(&bids, &asks, None), None);
+    }
+
+    #[rstest]
+    fn test_allocate_auction_fills_queues_behind_book_at_same_price() {
+        let interest = vec![
+            AuctionInterest::new(Some(Price::from("10.00")), 50, Some(0)),
+            AuctionInterest::new(Some(Price::from("10.00")), 100, None),
+            AuctionInterest::new(None, 30, Some(1)),
+            AuctionInterest::new(Some(Price::from("9.00")), 100, Some(2)),
+        ];
+
+        let fills =
+            allocate_auction_fills(interest, OrderSideSpecified::Buy, Price::from("10.00"), 150);
+
+        assert_eq!(fills, vec![(1, 30), (0, 20)]);
+    }
+
+    fn reduce_only_market_order(side: OrderSide, quantity: &str, id: &str) -> OrderAny {
+        OrderAny::Market(
+            MarketOrder::new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                equity_aapl().id,
+                ClientOrderId::from(id),
+                side,
+                Quantity::from(quantity),
+                TimeInForce::Gtc,
+                UUID4::new(),
+                UnixNanos::default(),
+                true,
+                false,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn add_long_position(engine: &OrderMatchingEngine, quantity: &str) {
+        let instrument = equity_aapl();
+        let order = TestOrderStubs::market_order(
+            instrument.id,
+            OrderSide::Buy,
+            Quantity::from(quantity),
+            Some(ClientOrderId::from("O-0")),
+            None,
+        );
+        let position_id =
+            PositionId::from(format!("{}-{}", instrument.id, strategy_id_ema_cross()).as_str());
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            Some(position_id),
+            Some(Price::from("100.00")),
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(instrument, fill).unwrap();
+        engine
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Netting)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn test_reduce_only_order_rejected_when_no_position() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_level(&mut engine, OrderSide::Buy, "100.00", "100");
+        let order = reduce_only_market_order(OrderSide::Sell, "100", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            OrderEventAny::Rejected(event) => {
+                assert_eq!(
+                    event.reason,
+                    Ustr::from("REDUCE_ONLY MARKET SELL order would have increased position")
+                );
+            }
+            event => panic!("Unexpected event {event}"),
+        }
+    }
+
+    #[rstest]
+    fn test_reduce_only_order_rejected_when_increasing_position() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_long_position(&engine, "50");
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = reduce_only_market_order(OrderSide::Buy, "10", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        assert!(matches!(events.borrow()[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_reduce_only_order_fill_clipped_to_position_quantity() {
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, None);
+        add_long_position(&engine, "50");
+        add_level(&mut engine, OrderSide::Buy, "100.00", "200");
+        let order = reduce_only_market_order(OrderSide::Sell, "100", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        let events = events.borrow();
+        assert_eq!(events.len(), 2);
+        match (&events[0], &events[1]) {
+            (OrderEventAny::Updated(updated), OrderEventAny::Filled(fill)) => {
+                assert_eq!(updated.quantity, Quantity::from("50"));
+                assert_eq!(fill.last_qty, Quantity::from("50"));
+                assert_eq!(fill.position_id, None); // No venue position IDs when netting
+            }
+            events => panic!("Unexpected events {events:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(true, Some(PositionId::from("XNAS-1-001")))]
+    #[case(false, None)]
+    fn test_hedging_fill_assigns_venue_position_id(
+        #[case] use_position_ids: bool,
+        #[case] expected_position_id: Option<PositionId>,
+    ) {
+        let config = OrderMatchingEngineConfig {
+            use_position_ids,
+            ..Default::default()
+        };
+        let (mut engine, events) = get_matching_engine(BookType::L2_MBP, None, None, Some(config));
+        engine.oms_type = OmsType::Hedging;
+        add_level(&mut engine, OrderSide::Sell, "100.10", "100");
+        let order = limit_order(OrderSide::Buy, "100.10", "100", "O-1");
+
+        engine.process_order(submitted(order), account_id());
+
+        match &events.borrow()[1] {
+            OrderEventAny::Filled(fill) => assert_eq!(fill.position_id, expected_position_id),
+            event => panic!("Unexpected event {event}"),
+        }
+        let cache = engine.cache.borrow();
+        assert_eq!(
+            cache.position_id(&ClientOrderId::from("O-1")).copied(),
+            expected_position_id
+        );
     }
 }
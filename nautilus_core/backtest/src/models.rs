@@ -0,0 +1,285 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides fill and latency models for simulated venues.
+
+use nautilus_core::correctness::check_in_range_inclusive_f64;
+use nautilus_model::types::quantity::Quantity;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The number of nanoseconds in one millisecond.
+const NANOSECONDS_IN_MILLISECOND: u64 = 1_000_000;
+
+/// Represents a model of order fill dynamics for a simulated venue.
+///
+/// Implementations which draw random numbers should be seeded, so that backtest runs can be
+/// reproduced exactly.
+pub trait FillModel {
+    /// Returns whether a `LIMIT` order filled when the market rests on its price.
+    fn is_limit_filled(&mut self) -> bool;
+    /// Returns whether a `STOP` order filled when the market rests on its trigger price.
+    fn is_stop_filled(&mut self) -> bool;
+    /// Returns whether an order fill slipped by one tick.
+    fn is_slipped(&mut self) -> bool;
+    /// Returns the quantity queued ahead of an order joining a price level of `level_size`.
+    fn queue_ahead(&mut self, level_size: Quantity) -> Quantity;
+    /// Resets the model to its initial state (reseeding any random number generator).
+    fn reset(&mut self);
+}
+
+/// The model for an orders initial position in the queue at its price level.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum QueuePositionModel {
+    /// The order joins the front of the queue (no quantity ahead).
+    #[default]
+    Front,
+    /// The order joins the back of the queue (the entire level is ahead).
+    Back,
+    /// The order joins at a uniformly random position in the queue.
+    Random,
+}
+
+/// Provides probabilistic modeling for order fill dynamics including probability
+/// of fills and slippage by order type.
+#[derive(Clone, Debug)]
+pub struct ProbabilisticFillModel {
+    /// The probability of limit order filling if the market rests on its price.
+    pub prob_fill_on_limit: f64,
+    /// The probability of stop orders filling if the market rests on its price.
+    pub prob_fill_on_stop: f64,
+    /// The probability of order fill prices slipping by one tick.
+    pub prob_slippage: f64,
+    /// The model for an orders initial queue position.
+    pub queue_position: QueuePositionModel,
+    /// The random seed (if `None` then seeded from system entropy).
+    pub random_seed: Option<u64>,
+    rng: StdRng,
+}
+
+impl ProbabilisticFillModel {
+    /// Creates a new [`ProbabilisticFillModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any probability argument is not within range [0, 1].
+    pub fn new(
+        prob_fill_on_limit: f64,
+        prob_fill_on_stop: f64,
+        prob_slippage: f64,
+        queue_position: QueuePositionModel,
+        random_seed: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        check_in_range_inclusive_f64(prob_fill_on_limit, 0.0, 1.0, "prob_fill_on_limit")?;
+        check_in_range_inclusive_f64(prob_fill_on_stop, 0.0, 1.0, "prob_fill_on_stop")?;
+        check_in_range_inclusive_f64(prob_slippage, 0.0, 1.0, "prob_slippage")?;
+
+        Ok(Self {
+            prob_fill_on_limit,
+            prob_fill_on_stop,
+            prob_slippage,
+            queue_position,
+            random_seed,
+            rng: Self::create_rng(random_seed),
+        })
+    }
+
+    fn create_rng(random_seed: Option<u64>) -> StdRng {
+        match random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    fn event_success(&mut self, probability: f64) -> bool {
+        // Return a result indicating whether an event occurred based on the
+        // given probability of the event occurring [0, 1].
+        if probability == 0.0 {
+            false
+        } else if probability == 1.0 {
+            true
+        } else {
+            probability >= self.rng.gen::<f64>()
+        }
+    }
+}
+
+impl Default for ProbabilisticFillModel {
+    /// Creates a new default [`ProbabilisticFillModel`] instance (always fills, never slips).
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 0.0, QueuePositionModel::Front, Some(0)).unwrap()
+    }
+}
+
+impl FillModel for ProbabilisticFillModel {
+    fn is_limit_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_limit)
+    }
+
+    fn is_stop_filled(&mut self) -> bool {
+        self.event_success(self.prob_fill_on_stop)
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        self.event_success(self.prob_slippage)
+    }
+
+    fn queue_ahead(&mut self, level_size: Quantity) -> Quantity {
+        match self.queue_position {
+            QueuePositionModel::Front => Quantity::zero(level_size.precision),
+            QueuePositionModel::Back => level_size,
+            QueuePositionModel::Random => {
+                let raw = self.rng.gen_range(0..=level_size.raw);
+                Quantity::from_raw(raw, level_size.precision).unwrap()
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.rng = Self::create_rng(self.random_seed);
+    }
+}
+
+/// Provides a latency model for simulated exchange message I/O.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LatencyModel {
+    /// The base latency (nanoseconds) for the model.
+    pub base_latency_nanos: u64,
+    /// The order insert latency (nanoseconds) for the model, including the base latency.
+    pub insert_latency_nanos: u64,
+    /// The order update latency (nanoseconds) for the model, including the base latency.
+    pub update_latency_nanos: u64,
+    /// The order cancel latency (nanoseconds) for the model, including the base latency.
+    pub cancel_latency_nanos: u64,
+}
+
+impl LatencyModel {
+    /// Creates a new [`LatencyModel`] instance.
+    ///
+    /// The `base_latency_nanos` is added to each of the insert, update and cancel latencies.
+    #[must_use]
+    pub fn new(
+        base_latency_nanos: u64,
+        insert_latency_nanos: u64,
+        update_latency_nanos: u64,
+        cancel_latency_nanos: u64,
+    ) -> Self {
+        Self {
+            base_latency_nanos,
+            insert_latency_nanos: base_latency_nanos + insert_latency_nanos,
+            update_latency_nanos: base_latency_nanos + update_latency_nanos,
+            cancel_latency_nanos: base_latency_nanos + cancel_latency_nanos,
+        }
+    }
+}
+
+impl Default for LatencyModel {
+    /// Creates a new default [`LatencyModel`] instance (1 millisecond base latency).
+    fn default() -> Self {
+        Self::new(NANOSECONDS_IN_MILLISECOND, 0, 0, 0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::quantity::Quantity;
+    use rstest::rstest;
+
+    use super::{FillModel, LatencyModel, ProbabilisticFillModel, QueuePositionModel};
+
+    #[rstest]
+    #[case(-0.1, 1.0, 0.0)]
+    #[case(1.0, 1.1, 0.0)]
+    #[case(1.0, 1.0, 2.0)]
+    fn test_fill_model_invalid_probability(
+        #[case] prob_fill_on_limit: f64,
+        #[case] prob_fill_on_stop: f64,
+        #[case] prob_slippage: f64,
+    ) {
+        let result = ProbabilisticFillModel::new(
+            prob_fill_on_limit,
+            prob_fill_on_stop,
+            prob_slippage,
+            QueuePositionModel::Front,
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_fill_model_defaults() {
+        let mut fill_model = ProbabilisticFillModel::default();
+
+        assert!(fill_model.is_limit_filled());
+        assert!(fill_model.is_stop_filled());
+        assert!(!fill_model.is_slipped());
+        assert_eq!(
+            fill_model.queue_ahead(Quantity::from("100")),
+            Quantity::from("0")
+        );
+    }
+
+    #[rstest]
+    fn test_fill_model_seeded_is_deterministic() {
+        let mut fill_model1 =
+            ProbabilisticFillModel::new(0.5, 0.5, 0.5, QueuePositionModel::Random, Some(42))
+                .unwrap();
+        let mut fill_model2 =
+            ProbabilisticFillModel::new(0.5, 0.5, 0.5, QueuePositionModel::Random, Some(42))
+                .unwrap();
+
+        let draw = |model: &mut ProbabilisticFillModel| {
+            (0..100)
+                .map(|_| {
+                    (
+                        model.is_limit_filled(),
+                        model.is_slipped(),
+                        model.queue_ahead(Quantity::from("1000")),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let results1 = draw(&mut fill_model1);
+        assert_eq!(results1, draw(&mut fill_model2));
+
+        fill_model1.reset();
+        assert_eq!(results1, draw(&mut fill_model1));
+    }
+
+    #[rstest]
+    fn test_fill_model_queue_position_back() {
+        let mut fill_model =
+            ProbabilisticFillModel::new(1.0, 1.0, 0.0, QueuePositionModel::Back, None).unwrap();
+
+        assert_eq!(
+            fill_model.queue_ahead(Quantity::from("100")),
+            Quantity::from("100")
+        );
+    }
+
+    #[rstest]
+    fn test_latency_model_includes_base_latency() {
+        let latency_model = LatencyModel::new(1_000, 100, 200, 300);
+
+        assert_eq!(latency_model.base_latency_nanos, 1_000);
+        assert_eq!(latency_model.insert_latency_nanos, 1_100);
+        assert_eq!(latency_model.update_latency_nanos, 1_200);
+        assert_eq!(latency_model.cancel_latency_nanos, 1_300);
+    }
+}
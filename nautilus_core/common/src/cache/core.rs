@@ -509,7 +509,7 @@ impl Cache {
     /// All data should be loaded from the database prior to this call.
     /// If an error is found then a log error message will also be produced.
     #[must_use]
-    fn check_integrity(&mut self) -> bool {
+    pub fn check_integrity(&mut self) -> bool {
         let mut error_count = 0;
         let failure = "Integrity failure";
 
@@ -1101,7 +1101,11 @@ impl Cache {
             database.add_account(account.as_ref())?;
         }
 
-        self.accounts.insert(account.id(), account);
+        let account_id = account.id();
+        self.accounts.insert(account_id, account);
+        self.index
+            .venue_account
+            .insert(account_id.get_issuer(), account_id);
         Ok(())
     }
 
@@ -1234,10 +1238,10 @@ impl Cache {
 
         // Update emulation index
         match order.emulation_trigger() {
-            Some(_) => {
+            Some(TriggerType::NoTrigger) | None => {
                 self.index.orders_emulated.remove(&client_order_id);
             }
-            None => {
+            Some(_) => {
                 self.index.orders_emulated.insert(client_order_id);
             }
         }
@@ -1314,6 +1318,7 @@ impl Cache {
         self.positions.insert(position.id, position.clone());
         self.index.positions.insert(position.id);
         self.index.positions_open.insert(position.id);
+        self.index.positions_closed.remove(&position.id); // Cover NETTING OMS (position may be reopened)
 
         log::debug!("Adding {position}");
 
@@ -1392,12 +1397,12 @@ impl Cache {
         }
 
         // Update emulation
-        if let Some(emulation_trigger) = order.emulation_trigger() {
-            match emulation_trigger {
-                TriggerType::NoTrigger => self.index.orders_emulated.remove(&client_order_id),
-                _ => self.index.orders_emulated.insert(client_order_id),
-            };
-        }
+        match order.emulation_trigger() {
+            Some(trigger) if trigger != TriggerType::NoTrigger && !order.is_closed() => {
+                self.index.orders_emulated.insert(client_order_id)
+            }
+            _ => self.index.orders_emulated.remove(&client_order_id),
+        };
 
         if let Some(database) = &mut self.database {
             database.update_order(order)?;
@@ -1407,6 +1412,9 @@ impl Cache {
             // }
         }
 
+        // Replace the cached order with the updated order
+        self.orders.insert(client_order_id, order.clone());
+
         Ok(())
     }
 
@@ -1435,6 +1443,10 @@ impl Cache {
             //     database.snapshot_order_state(order)?;
             // }
         }
+
+        // Replace the cached position with the updated position
+        self.positions.insert(position.id, position.clone());
+
         Ok(())
     }
 
@@ -2011,21 +2023,11 @@ impl Cache {
         exec_spawn_id: &ClientOrderId,
         active_only: bool,
     ) -> Option<Quantity> {
-        let exec_spawn_orders = self.orders_for_exec_spawn(exec_spawn_id);
-
-        let mut total_quantity: Option<Quantity> = None;
-
-        for spawn_order in exec_spawn_orders {
-            if !active_only || !spawn_order.is_closed() {
-                if let Some(mut total_quantity) = total_quantity {
-                    total_quantity += spawn_order.quantity();
-                }
-            } else {
-                total_quantity = Some(spawn_order.quantity());
-            }
-        }
-
-        total_quantity
+        self.orders_for_exec_spawn(exec_spawn_id)
+            .into_iter()
+            .filter(|spawn_order| !active_only || !spawn_order.is_closed())
+            .map(|spawn_order| spawn_order.quantity())
+            .reduce(|total_quantity, quantity| total_quantity + quantity)
     }
 
     #[must_use]
@@ -2034,21 +2036,11 @@ impl Cache {
         exec_spawn_id: &ClientOrderId,
         active_only: bool,
     ) -> Option<Quantity> {
-        let exec_spawn_orders = self.orders_for_exec_spawn(exec_spawn_id);
-
-        let mut total_quantity: Option<Quantity> = None;
-
-        for spawn_order in exec_spawn_orders {
-            if !active_only || !spawn_order.is_closed() {
-                if let Some(mut total_quantity) = total_quantity {
-                    total_quantity += spawn_order.filled_qty();
-                }
-            } else {
-                total_quantity = Some(spawn_order.filled_qty());
-            }
-        }
-
-        total_quantity
+        self.orders_for_exec_spawn(exec_spawn_id)
+            .into_iter()
+            .filter(|spawn_order| !active_only || !spawn_order.is_closed())
+            .map(|spawn_order| spawn_order.filled_qty())
+            .reduce(|total_quantity, quantity| total_quantity + quantity)
     }
 
     #[must_use]
@@ -2057,21 +2049,11 @@ impl Cache {
         exec_spawn_id: &ClientOrderId,
         active_only: bool,
     ) -> Option<Quantity> {
-        let exec_spawn_orders = self.orders_for_exec_spawn(exec_spawn_id);
-
-        let mut total_quantity: Option<Quantity> = None;
-
-        for spawn_order in exec_spawn_orders {
-            if !active_only || !spawn_order.is_closed() {
-                if let Some(mut total_quantity) = total_quantity {
-                    total_quantity += spawn_order.leaves_qty();
-                }
-            } else {
-                total_quantity = Some(spawn_order.leaves_qty());
-            }
-        }
-
-        total_quantity
+        self.orders_for_exec_spawn(exec_spawn_id)
+            .into_iter()
+            .filter(|spawn_order| !active_only || !spawn_order.is_closed())
+            .map(|spawn_order| spawn_order.leaves_qty())
+            .reduce(|total_quantity, quantity| total_quantity + quantity)
     }
 
     // -- POSITION QUERIES ------------------------------------------------------------------------
@@ -2388,7 +2370,7 @@ impl Cache {
     // -- ACCOUNT QUERIES -----------------------------------------------------------------------
 
     #[must_use]
-    pub fn account(&self, account_id: &AccountId) -> Option<&dyn Account> {
+    pub fn account(&self, account_id: &AccountId) -> Option<&(dyn Account + 'static)> {
         self.accounts
             .get(account_id)
             .map(std::convert::AsRef::as_ref)
@@ -2425,17 +2407,21 @@ mod tests {
     use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
     use nautilus_model::{
         data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
-        enums::OrderSide,
-        events::order::{accepted::OrderAccepted, event::OrderEventAny, submitted::OrderSubmitted},
+        enums::{OrderSide, TimeInForce},
+        events::order::{
+            accepted::OrderAccepted, denied::OrderDenied, event::OrderEventAny,
+            submitted::OrderSubmitted,
+        },
         identifiers::{
-            account_id::AccountId, client_order_id::ClientOrderId, position_id::PositionId,
-            venue_order_id::VenueOrderId,
+            account_id::AccountId, client_order_id::ClientOrderId,
+            exec_algorithm_id::ExecAlgorithmId, position_id::PositionId, strategy_id::StrategyId,
+            trader_id::TraderId, venue_order_id::VenueOrderId,
         },
         instruments::{
             any::InstrumentAny, currency_pair::CurrencyPair, stubs::*,
             synthetic::SyntheticInstrument,
         },
-        orders::{any::OrderAny, stubs::TestOrderStubs},
+        orders::{any::OrderAny, market::MarketOrder, stubs::TestOrderStubs},
         polymorphism::{
             ApplyOrderEventAny, GetAccountId, GetClientOrderId, GetInstrumentId, GetStrategyId,
             GetTraderId, GetVenueOrderId, IsOpen,
@@ -2443,6 +2429,7 @@ mod tests {
         types::{price::Price, quantity::Quantity},
     };
     use rstest::*;
+    use ustr::Ustr;
 
     use super::Cache;
 
@@ -2712,6 +2699,77 @@ mod tests {
         assert_eq!(cache.orders_for_position(&position_id), vec![&order]);
     }
 
+    #[rstest]
+    fn test_exec_spawn_totals(mut cache: Cache, audusd_sim: CurrencyPair) {
+        let primary_id = ClientOrderId::from("O-001");
+        let spawn_order = |client_order_id: &str, quantity: i64| {
+            let order = MarketOrder::new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                audusd_sim.id,
+                ClientOrderId::from(client_order_id),
+                OrderSide::Buy,
+                Quantity::from(quantity),
+                TimeInForce::Gtc,
+                UUID4::new(),
+                UnixNanos::default(),
+                false,
+                false,
+                None,
+                None,
+                None,
+                None,
+                Some(ExecAlgorithmId::from("TWAP")),
+                None,
+                Some(primary_id),
+                None,
+            )
+            .unwrap();
+            OrderAny::Market(order)
+        };
+        let primary = spawn_order("O-001", 60_000);
+        let spawned = spawn_order("O-001-E1", 40_000);
+        cache.add_order(primary, None, None, false).unwrap();
+        cache.add_order(spawned.clone(), None, None, false).unwrap();
+
+        let mut spawned = spawned;
+        let denied = OrderDenied::new(
+            spawned.trader_id(),
+            spawned.strategy_id(),
+            spawned.instrument_id(),
+            spawned.client_order_id(),
+            Ustr::from("TEST"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        spawned.apply(OrderEventAny::Denied(denied)).unwrap();
+        cache.update_order(&spawned).unwrap();
+
+        assert_eq!(cache.orders_for_exec_spawn(&primary_id).len(), 2);
+        assert_eq!(
+            cache.exec_spawn_total_quantity(&primary_id, false),
+            Some(Quantity::from(100_000))
+        );
+        assert_eq!(
+            cache.exec_spawn_total_quantity(&primary_id, true),
+            Some(Quantity::from(60_000))
+        );
+        assert_eq!(
+            cache.exec_spawn_total_filled_qty(&primary_id, false),
+            Some(Quantity::from(0))
+        );
+        assert_eq!(
+            cache.exec_spawn_total_leaves_qty(&primary_id, true),
+            Some(Quantity::from(60_000))
+        );
+        assert_eq!(
+            cache.exec_spawn_total_quantity(&ClientOrderId::from("O-002"), false),
+            None
+        );
+    }
+
     #[rstest]
     fn test_instrument_when_empty(cache: Cache, audusd_sim: CurrencyPair) {
         let result = cache.instrument(&audusd_sim.id);
@@ -17,16 +17,22 @@
 
 use std::collections::HashMap;
 
-use nautilus_core::{time::AtomicTime, uuid::UUID4};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
 use nautilus_model::{
-    enums::{ContingencyType, OrderSide, TimeInForce},
+    enums::{ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType},
     identifiers::{
         client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
         instrument_id::InstrumentId, order_list_id::OrderListId, strategy_id::StrategyId,
         trader_id::TraderId,
     },
-    orders::market::MarketOrder,
-    types::quantity::Quantity,
+    orders::{
+        any::OrderAny, base::OrderCore, limit::LimitOrder, limit_if_touched::LimitIfTouchedOrder,
+        list::OrderList, market::MarketOrder, market_if_touched::MarketIfTouchedOrder,
+        market_to_limit::MarketToLimitOrder, stop_limit::StopLimitOrder,
+        stop_market::StopMarketOrder, trailing_stop_limit::TrailingStopLimitOrder,
+        trailing_stop_market::TrailingStopMarketOrder,
+    },
+    types::{price::Price, quantity::Quantity},
 };
 use ustr::Ustr;
 
@@ -107,11 +113,6 @@ impl OrderFactory {
         tags: Option<Vec<Ustr>>,
     ) -> MarketOrder {
         let client_order_id = self.generate_client_order_id();
-        let exec_spawn_id: Option<ClientOrderId> = if exec_algorithm_id.is_none() {
-            None
-        } else {
-            Some(client_order_id)
-        };
         MarketOrder::new(
             self.trader_id,
             self.strategy_id,
@@ -130,11 +131,726 @@ impl OrderFactory {
             None,
             exec_algorithm_id,
             exec_algorithm_params,
-            exec_spawn_id,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> LimitOrder {
+        let client_order_id = self.generate_client_order_id();
+        LimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            post_only.unwrap_or(false),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> StopMarketOrder {
+        let client_order_id = self.generate_client_order_id();
+        StopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> StopLimitOrder {
+        let client_order_id = self.generate_client_order_id();
+        StopLimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            post_only.unwrap_or(false),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn market_to_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> MarketToLimitOrder {
+        let client_order_id = self.generate_client_order_id();
+        MarketToLimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            false,
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn market_if_touched(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> MarketIfTouchedOrder {
+        let client_order_id = self.generate_client_order_id();
+        MarketIfTouchedOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            None,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit_if_touched(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> LimitIfTouchedOrder {
+        let client_order_id = self.generate_client_order_id();
+        LimitIfTouchedOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            post_only.unwrap_or(false),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trailing_offset: Price,
+        trailing_offset_type: Option<TrailingOffsetType>,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> TrailingStopMarketOrder {
+        let client_order_id = self.generate_client_order_id();
+        TrailingStopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            trailing_offset,
+            trailing_offset_type.unwrap_or(TrailingOffsetType::Price),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )
+        .unwrap()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: Option<TrailingOffsetType>,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> TrailingStopLimitOrder {
+        let client_order_id = self.generate_client_order_id();
+        TrailingStopLimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type.unwrap_or(TrailingOffsetType::Price),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            post_only.unwrap_or(false),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(ContingencyType::NoContingency),
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id(client_order_id, exec_algorithm_id),
             tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
         )
         .unwrap()
     }
+
+    /// Creates a bracket order list of an entry order, with a stop-loss and take-profit order
+    /// which are contingent on the entry (`OTO`) and on each other (`OUO` by default).
+    ///
+    /// The entry order type is one of `MARKET` (default), `LIMIT`, `MARKET_IF_TOUCHED` or
+    /// `LIMIT_IF_TOUCHED`. The take-profit order type is one of `LIMIT` (default),
+    /// `MARKET_IF_TOUCHED` or `LIMIT_IF_TOUCHED`. The stop-loss order is always `STOP_MARKET`.
+    /// The stop-loss and take-profit orders are reduce-only with a time in force of `GTC`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If an order type is not supported for the entry or take-profit order.
+    /// - If a price required by an order type is `None`.
+    /// - If any of the orders are invalid.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bracket(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        entry_price: Option<Price>,
+        entry_trigger_price: Option<Price>,
+        sl_trigger_price: Price,
+        tp_price: Option<Price>,
+        tp_trigger_price: Option<Price>,
+        entry_order_type: Option<OrderType>,
+        tp_order_type: Option<OrderType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        entry_post_only: Option<bool>,
+        tp_post_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        contingency_type: Option<ContingencyType>,
+        entry_exec_algorithm_id: Option<ExecAlgorithmId>,
+        entry_exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+        entry_tags: Option<Vec<Ustr>>,
+        tp_tags: Option<Vec<Ustr>>,
+        sl_tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<OrderList> {
+        let order_list_id = self.generate_order_list_id();
+        let entry_client_order_id = self.generate_client_order_id();
+        let sl_client_order_id = self.generate_client_order_id();
+        let tp_client_order_id = self.generate_client_order_id();
+
+        let ts_init = self.clock.get_time_ns();
+        let time_in_force = time_in_force.unwrap_or(TimeInForce::Gtc);
+        let quote_quantity = quote_quantity.unwrap_or(false);
+        let entry_post_only = entry_post_only.unwrap_or(false);
+        let tp_post_only = tp_post_only.unwrap_or(true);
+        let contingency_type = contingency_type.unwrap_or(ContingencyType::Ouo);
+        let entry_linked_order_ids = Some(vec![sl_client_order_id, tp_client_order_id]);
+        let entry_exec_spawn_id = exec_spawn_id(entry_client_order_id, entry_exec_algorithm_id);
+        let exit_side = OrderCore::opposite_side(order_side);
+
+        let entry_order = match entry_order_type.unwrap_or(OrderType::Market) {
+            OrderType::Market => OrderAny::Market(MarketOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_client_order_id,
+                order_side,
+                quantity,
+                TimeInForce::Gtc,
+                UUID4::new(),
+                ts_init,
+                false,
+                quote_quantity,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                entry_linked_order_ids,
+                None,
+                entry_exec_algorithm_id,
+                entry_exec_algorithm_params,
+                entry_exec_spawn_id,
+                entry_tags,
+            )?),
+            OrderType::Limit => OrderAny::Limit(LimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_client_order_id,
+                order_side,
+                quantity,
+                required_price(entry_price, "entry_price")?,
+                time_in_force,
+                expire_time,
+                entry_post_only,
+                false,
+                quote_quantity,
+                None,
+                emulation_trigger,
+                trigger_instrument_id,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                entry_linked_order_ids,
+                None,
+                entry_exec_algorithm_id,
+                entry_exec_algorithm_params,
+                entry_exec_spawn_id,
+                entry_tags,
+                UUID4::new(),
+                ts_init,
+            )?),
+            OrderType::MarketIfTouched => OrderAny::MarketIfTouched(MarketIfTouchedOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_client_order_id,
+                order_side,
+                quantity,
+                required_price(entry_trigger_price, "entry_trigger_price")?,
+                TriggerType::Default,
+                time_in_force,
+                expire_time,
+                false,
+                quote_quantity,
+                None,
+                emulation_trigger,
+                trigger_instrument_id,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                entry_linked_order_ids,
+                None,
+                entry_exec_algorithm_id,
+                entry_exec_algorithm_params,
+                entry_exec_spawn_id,
+                entry_tags,
+                UUID4::new(),
+                ts_init,
+            )?),
+            OrderType::LimitIfTouched => OrderAny::LimitIfTouched(LimitIfTouchedOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_client_order_id,
+                order_side,
+                quantity,
+                required_price(entry_price, "entry_price")?,
+                required_price(entry_trigger_price, "entry_trigger_price")?,
+                TriggerType::Default,
+                time_in_force,
+                expire_time,
+                entry_post_only,
+                false,
+                quote_quantity,
+                None,
+                emulation_trigger,
+                trigger_instrument_id,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                entry_linked_order_ids,
+                None,
+                entry_exec_algorithm_id,
+                entry_exec_algorithm_params,
+                entry_exec_spawn_id,
+                entry_tags,
+                UUID4::new(),
+                ts_init,
+            )?),
+            order_type => anyhow::bail!("invalid `entry_order_type`, was {order_type}"),
+        };
+
+        let sl_order = OrderAny::StopMarket(StopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            sl_client_order_id,
+            exit_side,
+            quantity,
+            sl_trigger_price,
+            TriggerType::Default,
+            TimeInForce::Gtc,
+            None,
+            true,
+            quote_quantity,
+            None,
+            emulation_trigger,
+            trigger_instrument_id,
+            Some(contingency_type),
+            Some(order_list_id),
+            Some(vec![tp_client_order_id]),
+            Some(entry_client_order_id),
+            None,
+            None,
+            None,
+            sl_tags,
+            UUID4::new(),
+            ts_init,
+        )?);
+
+        let tp_order = match tp_order_type.unwrap_or(OrderType::Limit) {
+            OrderType::Limit => OrderAny::Limit(LimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                tp_client_order_id,
+                exit_side,
+                quantity,
+                required_price(tp_price, "tp_price")?,
+                TimeInForce::Gtc,
+                None,
+                tp_post_only,
+                true,
+                quote_quantity,
+                None,
+                emulation_trigger,
+                trigger_instrument_id,
+                Some(contingency_type),
+                Some(order_list_id),
+                Some(vec![sl_client_order_id]),
+                Some(entry_client_order_id),
+                None,
+                None,
+                None,
+                tp_tags,
+                UUID4::new(),
+                ts_init,
+            )?),
+            OrderType::MarketIfTouched => OrderAny::MarketIfTouched(MarketIfTouchedOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                tp_client_order_id,
+                exit_side,
+                quantity,
+                required_price(tp_trigger_price, "tp_trigger_price")?,
+                TriggerType::Default,
+                TimeInForce::Gtc,
+                None,
+                true,
+                quote_quantity,
+                None,
+                emulation_trigger,
+                trigger_instrument_id,
+                Some(contingency_type),
+                Some(order_list_id),
+                Some(vec![sl_client_order_id]),
+                Some(entry_client_order_id),
+                None,
+                None,
+                None,
+                tp_tags,
+                UUID4::new(),
+                ts_init,
+            )?),
+            OrderType::LimitIfTouched => OrderAny::LimitIfTouched(LimitIfTouchedOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                tp_client_order_id,
+                exit_side,
+                quantity,
+                required_price(tp_price, "tp_price")?,
+                required_price(tp_trigger_price, "tp_trigger_price")?,
+                TriggerType::Default,
+                TimeInForce::Gtc,
+                None,
+                tp_post_only,
+                true,
+                quote_quantity,
+                None,
+                emulation_trigger,
+                trigger_instrument_id,
+                Some(contingency_type),
+                Some(order_list_id),
+                Some(vec![sl_client_order_id]),
+                Some(entry_client_order_id),
+                None,
+                None,
+                None,
+                tp_tags,
+                UUID4::new(),
+                ts_init,
+            )?),
+            order_type => anyhow::bail!("invalid `tp_order_type`, was {order_type}"),
+        };
+
+        OrderList::new(
+            order_list_id,
+            instrument_id,
+            self.strategy_id,
+            vec![entry_order, sl_order, tp_order],
+            ts_init,
+        )
+    }
+}
+
+/// Returns the execution spawn ID for a primary order, which is the order's own client order ID
+/// when an execution algorithm is specified.
+fn exec_spawn_id(
+    client_order_id: ClientOrderId,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+) -> Option<ClientOrderId> {
+    exec_algorithm_id.map(|_| client_order_id)
+}
+
+fn required_price(price: Option<Price>, param: &str) -> anyhow::Result<Price> {
+    price.ok_or_else(|| anyhow::anyhow!("`{param}` was `None` for the order type"))
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -143,10 +859,15 @@ impl OrderFactory {
 #[cfg(test)]
 pub mod tests {
     use nautilus_model::{
-        enums::{OrderSide, TimeInForce},
+        enums::{
+            ContingencyType, OrderSide, OrderType, TimeInForce, TrailingOffsetType, TriggerType,
+        },
         identifiers::{
-            client_order_id::ClientOrderId, instrument_id::InstrumentId, order_list_id::OrderListId,
+            client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+            instrument_id::InstrumentId, order_list_id::OrderListId,
         },
+        orders::any::OrderAny,
+        types::price::Price,
     };
     use rstest::rstest;
 
@@ -236,4 +957,374 @@ pub mod tests {
         );
         assert_eq!(market_order.order_list_id, None);
     }
+
+    #[rstest]
+    fn test_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            100.into(),
+            Price::from("50000.00"),
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            Some(TriggerType::BidAsk),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.side, OrderSide::Sell);
+        assert_eq!(order.price, Price::from("50000.00"));
+        assert_eq!(order.time_in_force, TimeInForce::Gtc);
+        assert!(order.is_post_only);
+        assert!(!order.is_reduce_only);
+        assert_eq!(order.emulation_trigger, Some(TriggerType::BidAsk));
+        assert_eq!(order.contingency_type, Some(ContingencyType::NoContingency));
+        assert_eq!(
+            order.client_order_id,
+            ClientOrderId::new("O-19700101-0000-001-001-1").unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_limit_order_with_exec_algorithm(mut order_factory: OrderFactory) {
+        let order = order_factory.limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            Price::from("50000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(ExecAlgorithmId::new("TWAP").unwrap()),
+            None,
+            None,
+        );
+        assert_eq!(order.exec_spawn_id, Some(order.client_order_id));
+    }
+
+    #[rstest]
+    fn test_stop_market_order(mut order_factory: OrderFactory) {
+        let order = order_factory.stop_market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            100.into(),
+            Price::from("49000.00"),
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.trigger_price, Price::from("49000.00"));
+        assert_eq!(order.trigger_type, TriggerType::Default);
+        assert!(order.is_reduce_only);
+    }
+
+    #[rstest]
+    fn test_stop_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.stop_limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            Price::from("51010.00"),
+            Price::from("51000.00"),
+            Some(TriggerType::LastTrade),
+            Some(TimeInForce::Day),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.price, Price::from("51010.00"));
+        assert_eq!(order.trigger_price, Price::from("51000.00"));
+        assert_eq!(order.trigger_type, TriggerType::LastTrade);
+        assert_eq!(order.time_in_force, TimeInForce::Day);
+    }
+
+    #[rstest]
+    fn test_market_to_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.market_to_limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type, OrderType::MarketToLimit);
+        assert_eq!(order.price, None);
+        assert_eq!(order.time_in_force, TimeInForce::Gtc);
+    }
+
+    #[rstest]
+    fn test_market_if_touched_order(mut order_factory: OrderFactory) {
+        let order = order_factory.market_if_touched(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            Price::from("48000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type, OrderType::MarketIfTouched);
+        assert_eq!(order.trigger_price, Price::from("48000.00"));
+    }
+
+    #[rstest]
+    fn test_limit_if_touched_order(mut order_factory: OrderFactory) {
+        let order = order_factory.limit_if_touched(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            Price::from("48010.00"),
+            Price::from("48000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type, OrderType::LimitIfTouched);
+        assert_eq!(order.price, Price::from("48010.00"));
+        assert_eq!(order.trigger_price, Price::from("48000.00"));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_market_order(mut order_factory: OrderFactory) {
+        let order = order_factory.trailing_stop_market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            100.into(),
+            Price::from("49000.00"),
+            Price::from("100.00"),
+            Some(TrailingOffsetType::BasisPoints),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type, OrderType::TrailingStopMarket);
+        assert_eq!(order.trailing_offset, Price::from("100.00"));
+        assert_eq!(order.trailing_offset_type, TrailingOffsetType::BasisPoints);
+    }
+
+    #[rstest]
+    fn test_trailing_stop_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.trailing_stop_limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            100.into(),
+            Price::from("48990.00"),
+            Price::from("49000.00"),
+            Price::from("10.00"),
+            Price::from("100.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.order_type, OrderType::TrailingStopLimit);
+        assert_eq!(order.limit_offset, Price::from("10.00"));
+        assert_eq!(order.trailing_offset, Price::from("100.00"));
+        assert_eq!(order.trailing_offset_type, TrailingOffsetType::Price);
+    }
+
+    #[rstest]
+    fn test_bracket_market_entry(mut order_factory: OrderFactory) {
+        let order_list = order_factory
+            .bracket(
+                InstrumentId::from("BTCUSDT.BINANCE"),
+                OrderSide::Buy,
+                100.into(),
+                None,
+                None,
+                Price::from("49000.00"),
+                Some(Price::from("51000.00")),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let entry = order_list.orders[0].as_order();
+        let sl = order_list.orders[1].as_order();
+        let tp = order_list.orders[2].as_order();
+
+        assert_eq!(
+            order_list.id,
+            OrderListId::new("OL-19700101-0000-001-001-1").unwrap()
+        );
+        assert_eq!(entry.order_type(), OrderType::Market);
+        assert_eq!(sl.order_type(), OrderType::StopMarket);
+        assert_eq!(tp.order_type(), OrderType::Limit);
+        assert_eq!(entry.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(sl.contingency_type(), Some(ContingencyType::Ouo));
+        assert_eq!(tp.contingency_type(), Some(ContingencyType::Ouo));
+        assert_eq!(
+            entry.linked_order_ids(),
+            Some(&[sl.client_order_id(), tp.client_order_id()][..])
+        );
+        assert_eq!(sl.linked_order_ids(), Some(&[tp.client_order_id()][..]));
+        assert_eq!(tp.linked_order_ids(), Some(&[sl.client_order_id()][..]));
+        assert_eq!(sl.parent_order_id(), Some(entry.client_order_id()));
+        assert_eq!(tp.parent_order_id(), Some(entry.client_order_id()));
+        assert_eq!(sl.side(), OrderSide::Sell);
+        assert_eq!(tp.side(), OrderSide::Sell);
+        assert!(sl.is_reduce_only());
+        assert!(tp.is_reduce_only());
+        assert!(tp.is_post_only());
+        assert!(order_list
+            .orders
+            .iter()
+            .all(|order| order.as_order().order_list_id() == Some(order_list.id)));
+    }
+
+    #[rstest]
+    fn test_bracket_limit_if_touched_entry_and_take_profit(mut order_factory: OrderFactory) {
+        let order_list = order_factory
+            .bracket(
+                InstrumentId::from("BTCUSDT.BINANCE"),
+                OrderSide::Sell,
+                100.into(),
+                Some(Price::from("50010.00")),
+                Some(Price::from("50000.00")),
+                Price::from("51000.00"),
+                Some(Price::from("48990.00")),
+                Some(Price::from("49000.00")),
+                Some(OrderType::LimitIfTouched),
+                Some(OrderType::LimitIfTouched),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(ContingencyType::Oco),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert!(matches!(order_list.orders[0], OrderAny::LimitIfTouched(_)));
+        assert!(matches!(order_list.orders[2], OrderAny::LimitIfTouched(_)));
+        assert_eq!(order_list.orders[1].as_order().side(), OrderSide::Buy);
+        assert_eq!(
+            order_list.orders[2].as_order().contingency_type(),
+            Some(ContingencyType::Oco)
+        );
+    }
+
+    #[rstest]
+    #[case(Some(OrderType::StopMarket), None, Some(Price::from("51000.00")))]
+    #[case(Some(OrderType::Limit), None, Some(Price::from("51000.00")))]
+    #[case(None, Some(OrderType::MarketToLimit), Some(Price::from("51000.00")))]
+    #[case(None, None, None)]
+    fn test_bracket_with_invalid_parameters_returns_error(
+        mut order_factory: OrderFactory,
+        #[case] entry_order_type: Option<OrderType>,
+        #[case] tp_order_type: Option<OrderType>,
+        #[case] tp_price: Option<Price>,
+    ) {
+        let result = order_factory.bracket(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            100.into(),
+            None,
+            None,
+            Price::from("49000.00"),
+            tp_price,
+            None,
+            entry_order_type,
+            tp_order_type,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(result.is_err());
+    }
 }
@@ -17,7 +17,7 @@
 
 #[cfg(not(feature = "python"))]
 use std::ffi::c_char;
-use std::{fmt, sync::Arc};
+use std::{any::Any, fmt, rc::Rc, sync::Arc};
 
 #[cfg(not(feature = "python"))]
 use nautilus_core::message::Message;
@@ -82,6 +82,50 @@ impl fmt::Debug for MessageHandler {
     }
 }
 
+type AnyCallback = Rc<dyn Fn(&dyn Any)>;
+
+/// Provides a Rust native message handler which receives type-erased messages by reference.
+///
+/// The handler is cheap to clone (reference counted), and is intended for single-threaded
+/// components such as the backtest venues and engines.
+#[derive(Clone)]
+pub struct AnyMessageHandler {
+    pub handler_id: Ustr,
+    callback: AnyCallback,
+}
+
+impl AnyMessageHandler {
+    #[must_use]
+    pub fn new<F>(handler_id: Ustr, callback: F) -> Self
+    where
+        F: Fn(&dyn Any) + 'static,
+    {
+        Self {
+            handler_id,
+            callback: Rc::new(callback),
+        }
+    }
+
+    /// Handles the given type-erased `message`.
+    pub fn handle(&self, message: &dyn Any) {
+        (self.callback)(message);
+    }
+}
+
+impl PartialEq for AnyMessageHandler {
+    fn eq(&self, other: &Self) -> bool {
+        self.handler_id == other.handler_id
+    }
+}
+
+impl fmt::Debug for AnyMessageHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(AnyMessageHandler))
+            .field("handler_id", &self.handler_id)
+            .finish()
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(
     feature = "python",
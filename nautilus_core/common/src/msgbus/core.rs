@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    any::Any,
     collections::HashMap,
     fmt,
     hash::{Hash, Hasher},
@@ -26,7 +27,7 @@ use nautilus_model::identifiers::trader_id::TraderId;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
-use crate::handlers::MessageHandler;
+use crate::handlers::{AnyMessageHandler, MessageHandler};
 
 pub const CLOSE_TOPIC: &str = "CLOSE";
 
@@ -162,6 +163,10 @@ pub struct MessageBus {
     /// a request maps it's id to a handler so that a response
     /// with the same id can later be handled.
     correlation_index: IndexMap<UUID4, MessageHandler>,
+    /// handles type-erased Rust messages destined for a specific endpoint.
+    any_endpoints: IndexMap<Ustr, AnyMessageHandler>,
+    /// maps a topic (which may contain wildcards) to its type-erased Rust handlers.
+    any_subscriptions: IndexMap<Ustr, Vec<AnyMessageHandler>>,
 }
 
 impl MessageBus {
@@ -184,6 +189,8 @@ impl MessageBus {
             patterns: IndexMap::new(),
             endpoints: IndexMap::new(),
             correlation_index: IndexMap::new(),
+            any_endpoints: IndexMap::new(),
+            any_subscriptions: IndexMap::new(),
             has_backing: false,
         })
     }
@@ -235,7 +242,8 @@ impl MessageBus {
     /// Returns whether there are subscribers for the given `pattern`.
     #[must_use]
     pub fn is_registered(&self, endpoint: &str) -> bool {
-        self.endpoints.contains_key(&Ustr::from(endpoint))
+        let endpoint = Ustr::from(endpoint);
+        self.endpoints.contains_key(&endpoint) || self.any_endpoints.contains_key(&endpoint)
     }
 
     /// Returns whether there are subscribers for the given `pattern`.
@@ -337,6 +345,58 @@ impl MessageBus {
         self.correlation_index.shift_remove(correlation_id)
     }
 
+    /// Registers the given type-erased `handler` for the `endpoint` address.
+    pub fn register_any(&mut self, endpoint: &str, handler: AnyMessageHandler) {
+        // Updates value if key already exists
+        self.any_endpoints.insert(Ustr::from(endpoint), handler);
+    }
+
+    /// Deregisters the type-erased handler for the `endpoint` address.
+    pub fn deregister_any(&mut self, endpoint: &str) {
+        self.any_endpoints.shift_remove(&Ustr::from(endpoint));
+    }
+
+    /// Subscribes the given type-erased `handler` to the `topic`.
+    pub fn subscribe_any(&mut self, topic: &str, handler: AnyMessageHandler) {
+        let handlers = self.any_subscriptions.entry(Ustr::from(topic)).or_default();
+        if handlers.contains(&handler) {
+            error!("{handler:?} already subscribed to {topic}");
+            return;
+        }
+        handlers.push(handler);
+    }
+
+    /// Unsubscribes the given type-erased `handler` from the `topic`.
+    pub fn unsubscribe_any(&mut self, topic: &str, handler: &AnyMessageHandler) {
+        if let Some(handlers) = self.any_subscriptions.get_mut(&Ustr::from(topic)) {
+            handlers.retain(|h| h != handler);
+        }
+    }
+
+    /// Sends the `message` to the handler registered for the `endpoint` address.
+    ///
+    /// Handlers are invoked synchronously and may re-enter the bus through a shared reference.
+    pub fn send(&self, endpoint: &str, message: &dyn Any) {
+        match self.any_endpoints.get(&Ustr::from(endpoint)) {
+            Some(handler) => handler.handle(message),
+            None => error!("send: no registered endpoint '{endpoint}'"),
+        }
+    }
+
+    /// Publishes the `message` to all handlers with a subscription matching the `topic`.
+    ///
+    /// Handlers are invoked synchronously and may re-enter the bus through a shared reference.
+    pub fn publish(&self, topic: &str, message: &dyn Any) {
+        let topic = Ustr::from(topic);
+        for (pattern, handlers) in &self.any_subscriptions {
+            if is_matching(&topic, pattern) {
+                for handler in handlers {
+                    handler.handle(message);
+                }
+            }
+        }
+    }
+
     #[must_use]
     pub fn matching_subscriptions<'a>(&'a self, pattern: &'a Ustr) -> Vec<&'a Subscription> {
         let mut matching_subs: Vec<&'a Subscription> = Vec::new();
@@ -419,7 +479,7 @@ pub fn is_matching(topic: &Ustr, pattern: &Ustr) -> bool {
 #[cfg(not(feature = "python"))]
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
 
     use nautilus_core::{message::Message, uuid::UUID4};
     use rstest::*;
@@ -587,6 +647,48 @@ mod tests {
         assert_eq!(msgbus.response_handler(&correlation_id), Some(handler));
     }
 
+    #[rstest]
+    fn test_send_to_any_endpoint() {
+        let mut msgbus = stub_msgbus();
+        let endpoint = "MyEndpoint";
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let received_clone = received.clone();
+        let handler = AnyMessageHandler::new(Ustr::from("1"), move |msg: &dyn Any| {
+            if let Some(value) = msg.downcast_ref::<u64>() {
+                received_clone.borrow_mut().push(*value);
+            }
+        });
+
+        msgbus.register_any(endpoint, handler);
+        msgbus.send(endpoint, &1_u64);
+        msgbus.send("OtherEndpoint", &2_u64);
+
+        assert!(msgbus.is_registered(endpoint));
+        assert_eq!(*received.borrow(), vec![1]);
+    }
+
+    #[rstest]
+    fn test_publish_to_any_subscribers() {
+        let mut msgbus = stub_msgbus();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        let received_clone = received.clone();
+        let handler = AnyMessageHandler::new(Ustr::from("1"), move |msg: &dyn Any| {
+            if let Some(value) = msg.downcast_ref::<&str>() {
+                received_clone.borrow_mut().push((*value).to_string());
+            }
+        });
+
+        msgbus.subscribe_any("events.order.*", handler.clone());
+        msgbus.publish("events.order.S-001", &"filled");
+        msgbus.publish("events.position.S-001", &"opened");
+        msgbus.unsubscribe_any("events.order.*", &handler);
+        msgbus.publish("events.order.S-001", &"canceled");
+
+        assert_eq!(*received.borrow(), vec!["filled".to_string()]);
+    }
+
     #[rstest]
     fn test_matching_subscriptions() {
         let mut msgbus = stub_msgbus();
@@ -0,0 +1,37 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The well-known message bus endpoint addresses of the system components.
+//!
+//! Components send to and register on these addresses through the [`MessageBus`], so they
+//! must only ever be referred to by these constants.
+//!
+//! [`MessageBus`]: super::MessageBus
+
+/// Receives trading commands for the `ExecutionEngine`.
+pub const EXEC_ENGINE_EXECUTE: &str = "ExecEngine.execute";
+/// Receives order events for the `ExecutionEngine`.
+pub const EXEC_ENGINE_PROCESS: &str = "ExecEngine.process";
+/// Receives single execution reports (order status and fill reports) for reconciliation by the
+/// `ExecutionEngine`.
+pub const EXEC_ENGINE_RECONCILE_REPORT: &str = "ExecEngine.reconcile_report";
+/// Receives execution mass status reports for reconciliation by the `ExecutionEngine`.
+pub const EXEC_ENGINE_RECONCILE_MASS_STATUS: &str = "ExecEngine.reconcile_mass_status";
+/// Receives trading commands for the `RiskEngine`.
+pub const RISK_ENGINE_EXECUTE: &str = "RiskEngine.execute";
+/// Receives order events for the `RiskEngine`.
+pub const RISK_ENGINE_PROCESS: &str = "RiskEngine.process";
+/// Receives account state events for the `Portfolio`.
+pub const PORTFOLIO_UPDATE_ACCOUNT: &str = "Portfolio.update_account";
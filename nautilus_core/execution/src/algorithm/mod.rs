@@ -0,0 +1,670 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution algorithms which slice a primary order into smaller spawned orders.
+//!
+//! A primary order is routed to an algorithm when it has an `exec_algorithm_id`. The algorithm
+//! spawns child orders (with client order IDs `{primary}-E{n}` and an `exec_spawn_id` of the
+//! primary) which are submitted to the `RiskEngine`, reducing the primary order quantity by the
+//! spawned quantity. The final slice is always the (reduced) primary order itself.
+
+pub mod twap;
+pub mod vwap;
+
+#[cfg(test)]
+pub mod stubs;
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    rc::Rc,
+    str::FromStr,
+};
+
+use log::{debug, error, info, warn};
+use nautilus_common::{
+    cache::Cache,
+    clock::Clock,
+    msgbus::{
+        endpoints::{EXEC_ENGINE_EXECUTE, RISK_ENGINE_EXECUTE},
+        MessageBus,
+    },
+    timer::TimeEvent,
+};
+use nautilus_core::{time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    enums::OrderType,
+    events::order::{canceled::OrderCanceled, event::OrderEventAny, updated::OrderUpdated},
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        position_id::PositionId,
+    },
+    instruments::any::InstrumentAny,
+    orders::{any::OrderAny, market::MarketOrder},
+    polymorphism::{ApplyOrderEventAny, GetClientOrderId, GetInstrumentId, GetStrategyId},
+    types::quantity::Quantity,
+};
+use ustr::Ustr;
+
+use crate::messages::{cancel::CancelOrder, submit::SubmitOrder, TradingCommand};
+
+/// Configuration for `ExecAlgorithm` instances.
+#[derive(Clone, Debug, Default)]
+pub struct ExecAlgorithmConfig {
+    /// The execution algorithm ID (will override the algorithms default ID).
+    pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    /// If debug mode is active (will provide extra debug logging).
+    pub debug: bool,
+}
+
+/// Represents an execution algorithm which receives primary orders and executes them by
+/// spawning smaller child orders.
+///
+/// Implementations provide `on_order` (and access to their `ExecAlgorithmCore`), while command,
+/// order event and time event handling is common to all algorithms. Time events for the timers
+/// an algorithm sets must be routed to `on_time_event` by the owner of the clock.
+pub trait ExecAlgorithm {
+    /// Returns the shared execution algorithm state.
+    fn core(&self) -> &ExecAlgorithmCore;
+
+    /// Returns the shared execution algorithm state mutably.
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore;
+
+    /// Handles the primary `order` received for execution.
+    fn on_order(&mut self, order: OrderAny);
+
+    /// Returns the ID of the algorithm.
+    fn id(&self) -> ExecAlgorithmId {
+        self.core().id
+    }
+
+    fn execute(&mut self, command: TradingCommand) {
+        let core = self.core_mut();
+        if core.config.debug {
+            debug!("<--[CMD] {command:?}");
+        }
+        core.command_count += 1;
+
+        let id = self.id();
+        match command {
+            TradingCommand::SubmitOrder(command) => {
+                if command.exec_algorith_id != Some(id) {
+                    error!("Cannot execute order: not for {id}, {command:?}");
+                    return;
+                }
+                let order = command.order.clone();
+                if let Err(e) = self.core_mut().add_primary_order(
+                    &order,
+                    command.position_id,
+                    command.client_id,
+                ) {
+                    error!("Cannot execute order: {e}");
+                    return;
+                }
+                self.on_order(order);
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                if command.exec_algorith_id != Some(id) {
+                    error!("Cannot execute order list: not for {id}, {command:?}");
+                    return;
+                }
+                for order in command.order_list.orders {
+                    if let Err(e) = self.core_mut().add_primary_order(
+                        &order,
+                        command.position_id,
+                        command.client_id,
+                    ) {
+                        error!("Cannot execute order: {e}");
+                        continue;
+                    }
+                    self.on_order(order);
+                }
+            }
+            TradingCommand::CancelOrder(command) => self.core_mut().handle_cancel_order(&command),
+            _ => error!("Cannot handle command: unrecognized {command:?}"),
+        }
+    }
+
+    /// Handles the given order `event`, completing the execution of a primary order once it
+    /// has closed.
+    fn on_order_event(&mut self, event: &OrderEventAny) {
+        self.core_mut().handle_order_event(event);
+    }
+
+    /// Handles the given time `event`, executing the next scheduled slice of the primary order
+    /// named by the event.
+    fn on_time_event(&mut self, event: &TimeEvent) {
+        self.core_mut().handle_time_event(event);
+    }
+
+    /// Resets the algorithm, canceling all timers and discarding all execution schedules.
+    fn reset(&mut self) {
+        self.core_mut().reset();
+    }
+}
+
+/// Provides the state and order handling shared by all execution algorithms.
+pub struct ExecAlgorithmCore {
+    pub id: ExecAlgorithmId,
+    pub command_count: u64,
+    pub event_count: u64,
+    clock: &'static AtomicTime,
+    timer_clock: Rc<RefCell<dyn Clock>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    exec_spawn_ids: HashMap<ClientOrderId, u32>,
+    scheduled_sizes: HashMap<ClientOrderId, VecDeque<Quantity>>,
+    config: ExecAlgorithmConfig,
+}
+
+impl ExecAlgorithmCore {
+    /// Creates a new [`ExecAlgorithmCore`] instance.
+    ///
+    /// Execution schedule timers are set on the `timer_clock` without a callback, so rely on the
+    /// clocks default handler.
+    #[must_use]
+    pub fn new(
+        id: ExecAlgorithmId,
+        clock: &'static AtomicTime,
+        timer_clock: Rc<RefCell<dyn Clock>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        config: ExecAlgorithmConfig,
+    ) -> Self {
+        Self {
+            id,
+            command_count: 0,
+            event_count: 0,
+            clock,
+            timer_clock,
+            msgbus,
+            cache,
+            exec_spawn_ids: HashMap::new(),
+            scheduled_sizes: HashMap::new(),
+            config,
+        }
+    }
+
+    /// Returns the cache shared with the algorithm.
+    #[must_use]
+    pub fn cache(&self) -> &Rc<RefCell<Cache>> {
+        &self.cache
+    }
+
+    /// Returns the sizes remaining to be executed for the primary order (if scheduled).
+    #[must_use]
+    pub fn scheduled_sizes(&self, primary_id: &ClientOrderId) -> Option<Vec<Quantity>> {
+        self.scheduled_sizes
+            .get(primary_id)
+            .map(|sizes| sizes.iter().copied().collect())
+    }
+
+    /// Returns the total quantity of the primary and its spawned orders.
+    #[must_use]
+    pub fn total_quantity(&self, primary_id: &ClientOrderId) -> Option<Quantity> {
+        self.cache
+            .borrow()
+            .exec_spawn_total_quantity(primary_id, false)
+    }
+
+    /// Returns the total filled quantity of the primary and its spawned orders.
+    #[must_use]
+    pub fn total_filled_qty(&self, primary_id: &ClientOrderId) -> Option<Quantity> {
+        self.cache
+            .borrow()
+            .exec_spawn_total_filled_qty(primary_id, false)
+    }
+
+    /// Returns the total leaves quantity of the open primary and spawned orders.
+    #[must_use]
+    pub fn total_leaves_qty(&self, primary_id: &ClientOrderId) -> Option<Quantity> {
+        self.cache
+            .borrow()
+            .exec_spawn_total_leaves_qty(primary_id, true)
+    }
+
+    /// Returns the instrument for a primary `order` which can be executed by slicing.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `order` is not a `MARKET` order.
+    /// - If the instrument for the `order` is not found in the cache.
+    pub fn primary_instrument(&self, order: &OrderAny) -> anyhow::Result<InstrumentAny> {
+        let order_type = order.as_order().order_type();
+        if order_type != OrderType::Market {
+            anyhow::bail!("only implemented for market orders, was {order_type}");
+        }
+        let instrument_id = order.instrument_id();
+        self.cache
+            .borrow()
+            .instrument(&instrument_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("instrument {instrument_id} not found"))
+    }
+
+    // -- COMMANDS --------------------------------------------------------------------------------
+
+    /// Spawns a new `MARKET` order for `quantity` from the `primary` order, reducing the
+    /// primary order quantity by the spawned `quantity`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `primary` order is not for this algorithm.
+    /// - If `quantity` is not less than the `primary` order quantity.
+    pub fn spawn_market(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+    ) -> anyhow::Result<OrderAny> {
+        let primary_order = primary.as_order();
+        if primary_order.exec_algorithm_id() != Some(self.id) {
+            anyhow::bail!(
+                "primary order {} not for {}",
+                primary.client_order_id(),
+                self.id
+            );
+        }
+        if quantity >= primary_order.quantity() {
+            anyhow::bail!(
+                "spawn quantity {quantity} was not less than primary order quantity {}",
+                primary_order.quantity()
+            );
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        let spawned = MarketOrder::new(
+            primary_order.trader_id(),
+            primary_order.strategy_id(),
+            primary_order.instrument_id(),
+            self.spawn_client_order_id(&primary_order.client_order_id()),
+            primary_order.side(),
+            quantity,
+            primary_order.time_in_force(),
+            UUID4::new(),
+            ts_now,
+            primary_order.is_reduce_only(),
+            primary_order.is_quote_quantity(),
+            primary_order.contingency_type(),
+            primary_order.order_list_id(),
+            primary_order
+                .linked_order_ids()
+                .map(<[ClientOrderId]>::to_vec),
+            primary_order.parent_order_id(),
+            Some(self.id),
+            None,
+            Some(primary_order.client_order_id()),
+            primary_order.tags().map(<[_]>::to_vec),
+        )?;
+
+        self.reduce_primary_order(primary, quantity)?;
+        Ok(OrderAny::Market(spawned))
+    }
+
+    /// Submits the given `order` (may be the primary or a spawned order) to the `RiskEngine`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the primary order of a spawned `order` is not found in the cache.
+    /// - If a spawned `order` already exists in the cache.
+    pub fn submit_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        let primary_id = order.as_order().exec_spawn_id().unwrap_or(client_order_id);
+
+        let (position_id, client_id) = {
+            let cache = self.cache.borrow();
+            if !cache.order_exists(&primary_id) {
+                anyhow::bail!("primary order {primary_id} not found");
+            }
+            (
+                cache.position_id(&primary_id).copied(),
+                cache.client_id(&primary_id).copied(),
+            )
+        };
+        let client_id =
+            client_id.unwrap_or_else(|| ClientId::from(order.instrument_id().venue.as_str()));
+
+        if order.as_order().is_spawned() {
+            self.cache.borrow_mut().add_order(
+                order.clone(),
+                position_id,
+                Some(client_id),
+                false,
+            )?;
+            self.publish_order_event(order.as_order().last_event());
+        }
+
+        let command = SubmitOrder::new(
+            order.as_order().trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            client_order_id,
+            order.as_order().venue_order_id().unwrap_or_default(),
+            order,
+            Some(self.id),
+            position_id,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        )?;
+        self.send_risk_command(TradingCommand::SubmitOrder(command));
+        Ok(())
+    }
+
+    /// Starts executing the `primary` order over the scheduled `sizes`, one slice per interval.
+    ///
+    /// The first slice is spawned immediately, then a timer named by the primary client order ID
+    /// fires every `interval_ns` to execute the next slice. The final slice is the primary order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the primary order is already being executed.
+    /// - If the timer cannot be set.
+    pub fn start_schedule(
+        &mut self,
+        mut primary: OrderAny,
+        sizes: Vec<Quantity>,
+        interval_ns: u64,
+    ) -> anyhow::Result<()> {
+        let primary_id = primary.client_order_id();
+        if self.scheduled_sizes.contains_key(&primary_id) {
+            anyhow::bail!("primary order {primary_id} already scheduled");
+        }
+
+        let mut sizes: VecDeque<Quantity> = sizes.into();
+        if sizes.len() <= 1 {
+            return self.submit_order(primary);
+        }
+
+        let start_time_ns = self.clock.get_time_ns();
+        self.timer_clock.borrow_mut().set_timer_ns(
+            primary_id.as_str(),
+            interval_ns,
+            start_time_ns,
+            None,
+            None,
+        )?;
+
+        info!("Order execution size schedule for {primary_id}: {sizes:?}");
+        let first_qty = sizes.pop_front().expect("sizes should not be empty");
+        self.scheduled_sizes.insert(primary_id, sizes);
+        if !first_qty.is_zero() {
+            let spawned = self.spawn_market(&mut primary, first_qty)?;
+            self.submit_order(spawned)?;
+        }
+        Ok(())
+    }
+
+    /// Completes the execution schedule for the primary order, canceling its timer.
+    pub fn complete_schedule(&mut self, primary_id: &ClientOrderId) {
+        let mut timer_clock = self.timer_clock.borrow_mut();
+        if timer_clock.timer_names().contains(&primary_id.as_str()) {
+            timer_clock.cancel_timer(primary_id.as_str());
+        }
+        if self.scheduled_sizes.remove(primary_id).is_some() {
+            info!("Completed {} execution for {primary_id}", self.id);
+        }
+    }
+
+    /// Resets the algorithm state, canceling all execution schedule timers.
+    pub fn reset(&mut self) {
+        let primary_ids: Vec<ClientOrderId> = self.scheduled_sizes.keys().copied().collect();
+        for primary_id in &primary_ids {
+            self.complete_schedule(primary_id);
+        }
+        self.exec_spawn_ids.clear();
+        self.command_count = 0;
+        self.event_count = 0;
+    }
+
+    // -- HANDLERS --------------------------------------------------------------------------------
+
+    fn add_primary_order(
+        &mut self,
+        order: &OrderAny,
+        position_id: Option<PositionId>,
+        client_id: ClientId,
+    ) -> anyhow::Result<()> {
+        if self.cache.borrow().order_exists(&order.client_order_id()) {
+            return Ok(());
+        }
+        self.cache
+            .borrow_mut()
+            .add_order(order.clone(), position_id, Some(client_id), false)
+    }
+
+    fn handle_cancel_order(&mut self, command: &CancelOrder) {
+        let Some(order) = self.cache.borrow().order(&command.client_order_id).cloned() else {
+            error!("Cannot cancel order: {} not found", command.client_order_id);
+            return;
+        };
+
+        if order.as_order().is_spawned() {
+            // Spawned orders are working with the venue
+            self.send_exec_command(TradingCommand::CancelOrder(command.clone()));
+            return;
+        }
+
+        if order.as_order().is_closed() {
+            warn!("Order already closed for {command:?}");
+            return;
+        }
+
+        let primary_id = order.client_order_id();
+        self.complete_schedule(&primary_id);
+        self.cancel_spawned_orders(&primary_id);
+
+        if !order.as_order().is_active_local() {
+            // Primary order already submitted, so cancel with the venue
+            self.send_exec_command(TradingCommand::CancelOrder(command.clone()));
+            return;
+        }
+
+        let mut order = order;
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Canceled(
+            OrderCanceled::new(
+                order.as_order().trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                primary_id,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.as_order().venue_order_id(),
+                order.as_order().account_id(),
+            )
+            .unwrap(),
+        );
+        if let Err(e) = order.apply(event.clone()) {
+            warn!("Cannot cancel order {primary_id}: {e}");
+            return;
+        }
+        if let Err(e) = self.cache.borrow_mut().update_order(&order) {
+            error!("Cannot update order: {e}");
+        }
+        self.publish_order_event(&event);
+    }
+
+    fn handle_order_event(&mut self, event: &OrderEventAny) {
+        let Some(order) = self.cache.borrow().order(&event.client_order_id()).cloned() else {
+            return;
+        };
+        if order.as_order().exec_algorithm_id() != Some(self.id) {
+            return; // Not for this algorithm
+        }
+
+        if self.config.debug {
+            debug!("<--[EVT] {event:?}");
+        }
+        self.event_count += 1;
+
+        let primary_id = order
+            .as_order()
+            .exec_spawn_id()
+            .unwrap_or(order.client_order_id());
+        if order.as_order().is_primary() && order.as_order().is_closed() {
+            self.complete_schedule(&primary_id);
+        }
+    }
+
+    fn handle_time_event(&mut self, event: &TimeEvent) {
+        let primary_id = ClientOrderId::from(event.name.as_str());
+        let Some(sizes) = self.scheduled_sizes.get_mut(&primary_id) else {
+            return; // Not an execution schedule timer
+        };
+        let Some(quantity) = sizes.pop_front() else {
+            warn!("No more size to execute for {primary_id}");
+            return;
+        };
+        let is_final = sizes.is_empty();
+
+        let Some(mut primary) = self.cache.borrow().order(&primary_id).cloned() else {
+            error!("Cannot find primary order for {primary_id}");
+            self.complete_schedule(&primary_id);
+            return;
+        };
+        if primary.as_order().is_closed() {
+            self.complete_schedule(&primary_id);
+            return;
+        }
+
+        if is_final || quantity >= primary.as_order().quantity() {
+            self.complete_schedule(&primary_id);
+            if let Err(e) = self.submit_order(primary) {
+                error!("Cannot submit primary order {primary_id}: {e}");
+            }
+            return;
+        }
+        if quantity.is_zero() {
+            return; // Nothing to execute this interval
+        }
+
+        let result = self
+            .spawn_market(&mut primary, quantity)
+            .and_then(|spawned| self.submit_order(spawned));
+        if let Err(e) = result {
+            error!("Cannot execute slice for {primary_id}: {e}");
+        }
+    }
+
+    // -- INTERNAL --------------------------------------------------------------------------------
+
+    fn spawn_client_order_id(&mut self, primary_id: &ClientOrderId) -> ClientOrderId {
+        let spawn_sequence = self.exec_spawn_ids.entry(*primary_id).or_default();
+        *spawn_sequence += 1;
+        ClientOrderId::from(format!("{primary_id}-E{spawn_sequence}").as_str())
+    }
+
+    fn reduce_primary_order(
+        &mut self,
+        primary: &mut OrderAny,
+        spawn_qty: Quantity,
+    ) -> anyhow::Result<()> {
+        let ts_now = self.clock.get_time_ns();
+        let primary_order = primary.as_order();
+        let event = OrderEventAny::Updated(OrderUpdated::new(
+            primary_order.trader_id(),
+            primary_order.strategy_id(),
+            primary_order.instrument_id(),
+            primary_order.client_order_id(),
+            primary_order.quantity() - spawn_qty,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            primary_order.venue_order_id(),
+            primary_order.account_id(),
+            None,
+            None,
+        )?);
+        primary.apply(event)?;
+        self.cache.borrow_mut().update_order(primary)
+    }
+
+    /// Cancels the open spawned orders for the primary order with the venue.
+    fn cancel_spawned_orders(&self, primary_id: &ClientOrderId) {
+        let spawned: Vec<OrderAny> = self
+            .cache
+            .borrow()
+            .orders_for_exec_spawn(primary_id)
+            .into_iter()
+            .filter(|order| order.as_order().is_spawned())
+            .filter(|order| !order.as_order().is_closed() && !order.as_order().is_pending_cancel())
+            .cloned()
+            .collect();
+
+        for order in spawned {
+            let client_id = self
+                .cache
+                .borrow()
+                .client_id(&order.client_order_id())
+                .copied()
+                .unwrap_or_else(|| ClientId::from(order.instrument_id().venue.as_str()));
+            let command = CancelOrder::new(
+                order.as_order().trader_id(),
+                client_id,
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                order.as_order().venue_order_id().unwrap_or_default(),
+                UUID4::new(),
+                self.clock.get_time_ns(),
+            )
+            .unwrap();
+            self.send_exec_command(TradingCommand::CancelOrder(command));
+        }
+    }
+
+    fn publish_order_event(&self, event: &OrderEventAny) {
+        let topic = format!("events.order.{}", event.strategy_id());
+        self.msgbus.borrow().publish(&topic, event);
+    }
+
+    fn send_risk_command(&self, command: TradingCommand) {
+        self.msgbus.borrow().send(RISK_ENGINE_EXECUTE, &command);
+    }
+
+    fn send_exec_command(&self, command: TradingCommand) {
+        self.msgbus.borrow().send(EXEC_ENGINE_EXECUTE, &command);
+    }
+}
+
+/// Returns the execution algorithm parameter `key` of the `order`, parsed as a `T`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the parameter is not found in the orders `exec_algorithm_params`.
+/// - If the parameter value cannot be parsed.
+pub fn exec_param<T>(order: &OrderAny, key: &str) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let value = order
+        .as_order()
+        .exec_algorithm_params()
+        .and_then(|params| params.get(&Ustr::from(key)))
+        .ok_or_else(|| anyhow::anyhow!("`{key}` not found in `exec_algorithm_params`"))?;
+    value
+        .as_str()
+        .parse::<T>()
+        .map_err(|e| anyhow::anyhow!("invalid `{key}` '{value}': {e}"))
+}
@@ -0,0 +1,200 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{any::Any, cell::RefCell, collections::HashMap, rc::Rc};
+
+use nautilus_common::{
+    cache::Cache,
+    clock::{Clock, TestClock},
+    handlers::{AnyMessageHandler, EventHandler},
+    msgbus::{
+        endpoints::{EXEC_ENGINE_EXECUTE, RISK_ENGINE_EXECUTE},
+        MessageBus,
+    },
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    enums::{OrderSide, TimeInForce},
+    identifiers::{
+        client_id::ClientId,
+        client_order_id::ClientOrderId,
+        exec_algorithm_id::ExecAlgorithmId,
+        stubs::{strategy_id_ema_cross, trader_id},
+        venue_order_id::VenueOrderId,
+    },
+    instruments::{any::InstrumentAny, stubs::audusd_sim},
+    orders::{any::OrderAny, market::MarketOrder},
+    polymorphism::{GetClientOrderId, GetInstrumentId},
+    types::quantity::Quantity,
+};
+use pyo3::Python;
+use rstest::fixture;
+use ustr::Ustr;
+
+use super::ExecAlgorithm;
+use crate::messages::{submit::SubmitOrder, TradingCommand};
+
+pub type Messages<T> = Rc<RefCell<Vec<T>>>;
+
+pub struct TestContext {
+    pub clock: &'static AtomicTime,
+    pub timer_clock: Rc<RefCell<TestClock>>,
+    pub msgbus: Rc<RefCell<MessageBus>>,
+    pub cache: Rc<RefCell<Cache>>,
+    pub risk_commands: Messages<TradingCommand>,
+    pub exec_commands: Messages<TradingCommand>,
+}
+
+impl TestContext {
+    pub fn order(&self, client_order_id: &str) -> OrderAny {
+        self.cache
+            .borrow()
+            .order(&ClientOrderId::from(client_order_id))
+            .cloned()
+            .unwrap()
+    }
+
+    /// Returns the client order IDs and quantities of the orders submitted to the `RiskEngine`.
+    pub fn submitted(&self) -> Vec<(String, Quantity)> {
+        self.risk_commands
+            .borrow()
+            .iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some((
+                    command.client_order_id.to_string(),
+                    command.order.as_order().quantity(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Advances both clocks to `to_time_ns`, routing the time events to the `algorithm`.
+    pub fn advance_time(&self, algorithm: &mut dyn ExecAlgorithm, to_time_ns: u64) {
+        self.clock.set_time(to_time_ns.into());
+        let events = self
+            .timer_clock
+            .borrow_mut()
+            .advance_time(to_time_ns.into(), true);
+        for event in &events {
+            algorithm.on_time_event(event);
+        }
+    }
+
+    pub fn timer_names(&self) -> Vec<String> {
+        self.timer_clock
+            .borrow()
+            .timer_names()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+}
+
+pub fn register<T: Clone + 'static>(
+    msgbus: &Rc<RefCell<MessageBus>>,
+    endpoint: &str,
+) -> Messages<T> {
+    let messages: Messages<T> = Rc::new(RefCell::new(Vec::new()));
+    let messages_clone = messages.clone();
+    let handler = AnyMessageHandler::new(Ustr::from(endpoint), move |msg: &dyn Any| {
+        messages_clone
+            .borrow_mut()
+            .push(msg.downcast_ref::<T>().unwrap().clone());
+    });
+    msgbus.borrow_mut().register_any(endpoint, handler);
+    messages
+}
+
+/// Returns a primary market order for `exec_algorithm_id` with the given `params`.
+pub fn primary_order(exec_algorithm_id: &str, quantity: &str, params: &[(&str, &str)]) -> OrderAny {
+    let params: HashMap<Ustr, Ustr> = params
+        .iter()
+        .map(|(key, value)| (Ustr::from(key), Ustr::from(value)))
+        .collect();
+    let order = MarketOrder::new(
+        trader_id(),
+        strategy_id_ema_cross(),
+        audusd_sim().id,
+        ClientOrderId::from("O-001"),
+        OrderSide::Buy,
+        Quantity::from(quantity),
+        TimeInForce::Gtc,
+        UUID4::new(),
+        UnixNanos::default(),
+        false,
+        false,
+        None,
+        None,
+        None,
+        None,
+        Some(ExecAlgorithmId::from(exec_algorithm_id)),
+        Some(params),
+        None,
+        None,
+    )
+    .unwrap();
+    OrderAny::Market(order)
+}
+
+pub fn submit_command(order: OrderAny) -> TradingCommand {
+    TradingCommand::SubmitOrder(
+        SubmitOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            strategy_id_ema_cross(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order.clone(),
+            order.as_order().exec_algorithm_id(),
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap(),
+    )
+}
+
+#[fixture]
+pub fn context() -> TestContext {
+    pyo3::prepare_freethreaded_python();
+
+    let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+    let timer_clock = Rc::new(RefCell::new(TestClock::new()));
+    let handler = Python::with_gil(|py| EventHandler::new(py.None()));
+    timer_clock.borrow_mut().register_default_handler(handler);
+
+    let msgbus = Rc::new(RefCell::new(
+        MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap(),
+    ));
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    cache
+        .borrow_mut()
+        .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+        .unwrap();
+
+    let risk_commands = register(&msgbus, RISK_ENGINE_EXECUTE);
+    let exec_commands = register(&msgbus, EXEC_ENGINE_EXECUTE);
+
+    TestContext {
+        clock,
+        timer_clock,
+        msgbus,
+        cache,
+        risk_commands,
+        exec_commands,
+    }
+}
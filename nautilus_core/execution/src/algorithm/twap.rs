@@ -0,0 +1,478 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a Time-Weighted Average Price (TWAP) execution algorithm.
+
+use std::{cell::RefCell, rc::Rc};
+
+use log::{error, info, warn};
+use nautilus_common::{cache::Cache, clock::Clock, msgbus::MessageBus};
+use nautilus_core::{
+    correctness::check_positive_u64, datetime::NANOSECONDS_IN_SECOND, time::AtomicTime,
+};
+use nautilus_model::{
+    identifiers::exec_algorithm_id::ExecAlgorithmId, orders::any::OrderAny,
+    polymorphism::GetClientOrderId, types::quantity::Quantity,
+};
+
+use super::{exec_param, ExecAlgorithm, ExecAlgorithmConfig, ExecAlgorithmCore};
+
+/// Provides a Time-Weighted Average Price (TWAP) execution algorithm.
+///
+/// The algorithm executes a primary order by spreading it evenly over a time horizon. The first
+/// spawned order is submitted immediately, then one every interval, with the final slice being
+/// the primary order itself at the end of the horizon.
+///
+/// The primary order must be a `MARKET` order with `exec_algorithm_params`:
+/// - `horizon_secs`: the execution horizon in seconds.
+/// - `interval_secs`: the interval between slices in seconds (<= `horizon_secs`).
+pub struct TwapExecAlgorithm {
+    core: ExecAlgorithmCore,
+}
+
+impl TwapExecAlgorithm {
+    /// Creates a new [`TwapExecAlgorithm`] instance (with the ID `TWAP` unless configured).
+    #[must_use]
+    pub fn new(
+        clock: &'static AtomicTime,
+        timer_clock: Rc<RefCell<dyn Clock>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        config: ExecAlgorithmConfig,
+    ) -> Self {
+        let id = config
+            .exec_algorithm_id
+            .unwrap_or_else(|| ExecAlgorithmId::from("TWAP"));
+        Self {
+            core: ExecAlgorithmCore::new(id, clock, timer_clock, msgbus, cache, config),
+        }
+    }
+
+    fn execute_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let instrument = self.core.primary_instrument(&order)?;
+        let horizon_secs: f64 = exec_param(&order, "horizon_secs")?;
+        let interval_secs: f64 = exec_param(&order, "interval_secs")?;
+        if horizon_secs < interval_secs {
+            anyhow::bail!(
+                "`horizon_secs` {horizon_secs} was less than `interval_secs` {interval_secs}"
+            );
+        }
+        let interval_ns = (interval_secs * NANOSECONDS_IN_SECOND as f64) as u64;
+        check_positive_u64(interval_ns, "interval_ns")?;
+
+        let num_intervals = (horizon_secs / interval_secs).floor() as u64;
+        let quantity = order.as_order().quantity();
+        let sizes = twap_schedule(
+            quantity,
+            num_intervals,
+            instrument.size_increment(),
+            instrument.min_quantity(),
+        );
+        if sizes.len() == 1 {
+            warn!("Submitting for entire size {quantity}");
+            return self.core.submit_order(order);
+        }
+
+        let client_order_id = order.client_order_id();
+        self.core.start_schedule(order, sizes, interval_ns)?;
+        info!(
+            "Started TWAP execution for {client_order_id}: horizon_secs={horizon_secs}, interval_secs={interval_secs}"
+        );
+        Ok(())
+    }
+}
+
+impl ExecAlgorithm for TwapExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) {
+        let client_order_id = order.client_order_id();
+        if let Err(e) = self.execute_order(order) {
+            error!("Cannot execute order {client_order_id}: {e}");
+        }
+    }
+}
+
+/// Returns the TWAP execution schedule, splitting `quantity` evenly over `num_intervals`
+/// (in multiples of `size_increment`) with any remainder as a final slice.
+///
+/// The schedule is the entire `quantity` if the sliced size would be zero or less than the
+/// `min_quantity`.
+#[must_use]
+pub fn twap_schedule(
+    quantity: Quantity,
+    num_intervals: u64,
+    size_increment: Quantity,
+    min_quantity: Option<Quantity>,
+) -> Vec<Quantity> {
+    if num_intervals <= 1 || size_increment.raw == 0 {
+        return vec![quantity];
+    }
+
+    let mut slice_raw = quantity.raw / num_intervals;
+    slice_raw -= slice_raw % size_increment.raw;
+    if slice_raw == 0 || min_quantity.is_some_and(|min_quantity| slice_raw < min_quantity.raw) {
+        return vec![quantity];
+    }
+
+    let slice = Quantity::from_raw(slice_raw, quantity.precision).unwrap();
+    let mut sizes = vec![slice; num_intervals as usize];
+    let remainder_raw = quantity.raw - slice_raw * num_intervals;
+    if remainder_raw > 0 {
+        sizes.push(Quantity::from_raw(remainder_raw, quantity.precision).unwrap());
+    }
+    sizes
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::timer::TimeEvent;
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::OrderStatus,
+        events::order::{denied::OrderDenied, event::OrderEventAny, submitted::OrderSubmitted},
+        identifiers::{
+            account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+            venue_order_id::VenueOrderId,
+        },
+        orders::any::OrderAny,
+        polymorphism::{ApplyOrderEventAny, GetClientOrderId, GetInstrumentId, GetStrategyId},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+    use ustr::Ustr;
+
+    use super::{twap_schedule, TwapExecAlgorithm};
+    use crate::{
+        algorithm::{
+            stubs::{context, primary_order, submit_command, TestContext},
+            ExecAlgorithm, ExecAlgorithmConfig,
+        },
+        messages::{cancel::CancelOrder, TradingCommand},
+    };
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn twap(context: &TestContext) -> TwapExecAlgorithm {
+        TwapExecAlgorithm::new(
+            context.clock,
+            context.timer_clock.clone(),
+            context.msgbus.clone(),
+            context.cache.clone(),
+            ExecAlgorithmConfig::default(),
+        )
+    }
+
+    fn submit_to_venue(context: &TestContext, client_order_id: &str) {
+        let mut order = context.order(client_order_id);
+        let submitted = OrderSubmitted::new(
+            order.as_order().trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            AccountId::from("SIM-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        context.cache.borrow_mut().update_order(&order).unwrap();
+    }
+
+    #[rstest]
+    #[case("100000", 4, vec!["25000", "25000", "25000", "25000"])]
+    #[case("100000", 3, vec!["33333", "33333", "33333", "1"])]
+    #[case("100000", 1, vec!["100000"])]
+    #[case("300", 4, vec!["300"])] // Slice less than min quantity
+    fn test_twap_schedule(
+        #[case] quantity: &str,
+        #[case] num_intervals: u64,
+        #[case] expected: Vec<&str>,
+    ) {
+        let sizes = twap_schedule(
+            Quantity::from(quantity),
+            num_intervals,
+            Quantity::from("1"),
+            Some(Quantity::from("100")),
+        );
+
+        let expected: Vec<Quantity> = expected.into_iter().map(Quantity::from).collect();
+        assert_eq!(sizes, expected);
+    }
+
+    #[rstest]
+    fn test_execute_when_missing_params_does_not_submit(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order("TWAP", "100000", &[("horizon_secs", "3")]);
+
+        algorithm.execute(submit_command(order));
+
+        assert!(context.submitted().is_empty());
+        assert!(context.timer_names().is_empty());
+        assert_eq!(algorithm.core().command_count, 1);
+    }
+
+    #[rstest]
+    fn test_execute_when_not_for_algorithm_does_not_submit(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "VWAP",
+            "100000",
+            &[("horizon_secs", "3"), ("interval_secs", "1")],
+        );
+
+        algorithm.execute(submit_command(order));
+
+        assert!(context.submitted().is_empty());
+        assert!(!context
+            .cache
+            .borrow()
+            .order_exists(&ClientOrderId::from("O-001")));
+    }
+
+    #[rstest]
+    fn test_execute_when_single_interval_submits_primary(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "1"), ("interval_secs", "1")],
+        );
+
+        algorithm.execute(submit_command(order));
+
+        assert_eq!(
+            context.submitted(),
+            vec![("O-001".to_string(), Quantity::from("100000"))]
+        );
+        assert!(context.timer_names().is_empty());
+    }
+
+    #[rstest]
+    fn test_execute_spawns_first_slice_and_sets_timer(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "4"), ("interval_secs", "1")],
+        );
+
+        algorithm.execute(submit_command(order));
+
+        let spawned = context.order("O-001-E1");
+        assert_eq!(
+            context.submitted(),
+            vec![("O-001-E1".to_string(), Quantity::from("25000"))]
+        );
+        assert_eq!(
+            spawned.as_order().exec_spawn_id(),
+            Some(ClientOrderId::from("O-001"))
+        );
+        assert_eq!(spawned.as_order().exec_algorithm_id(), Some(algorithm.id()));
+        assert_eq!(
+            context.order("O-001").as_order().quantity(),
+            Quantity::from("75000")
+        );
+        assert_eq!(
+            context.cache.borrow().client_id(&spawned.client_order_id()),
+            Some(&ClientId::from("SIM"))
+        );
+        assert_eq!(context.timer_names(), vec!["O-001".to_string()]);
+        assert_eq!(
+            algorithm
+                .core()
+                .scheduled_sizes(&ClientOrderId::from("O-001")),
+            Some(vec![Quantity::from("25000"); 3])
+        );
+        assert_eq!(
+            algorithm
+                .core()
+                .total_quantity(&ClientOrderId::from("O-001")),
+            Some(Quantity::from("100000"))
+        );
+        assert_eq!(
+            algorithm
+                .core()
+                .total_filled_qty(&ClientOrderId::from("O-001")),
+            Some(Quantity::from("0"))
+        );
+        assert_eq!(
+            algorithm
+                .core()
+                .total_leaves_qty(&ClientOrderId::from("O-001")),
+            Some(Quantity::from("100000"))
+        );
+    }
+
+    #[rstest]
+    fn test_time_events_execute_slices_then_primary(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "3"), ("interval_secs", "1")],
+        );
+        algorithm.execute(submit_command(order));
+
+        context.advance_time(&mut algorithm, SECOND);
+        context.advance_time(&mut algorithm, 2 * SECOND);
+        context.advance_time(&mut algorithm, 3 * SECOND);
+
+        assert_eq!(
+            context.submitted(),
+            vec![
+                ("O-001-E1".to_string(), Quantity::from("33333")),
+                ("O-001-E2".to_string(), Quantity::from("33333")),
+                ("O-001-E3".to_string(), Quantity::from("33333")),
+                ("O-001".to_string(), Quantity::from("1")),
+            ]
+        );
+        assert!(context.timer_names().is_empty());
+        assert_eq!(
+            algorithm
+                .core()
+                .scheduled_sizes(&ClientOrderId::from("O-001")),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_time_event_for_other_timer_is_ignored(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "4"), ("interval_secs", "1")],
+        );
+        algorithm.execute(submit_command(order));
+
+        let event = TimeEvent::new(
+            Ustr::from("OTHER"),
+            UUID4::new(),
+            SECOND.into(),
+            SECOND.into(),
+        );
+        algorithm.on_time_event(&event);
+
+        assert_eq!(context.submitted().len(), 1);
+    }
+
+    #[rstest]
+    fn test_cancel_primary_cancels_spawned_orders(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "4"), ("interval_secs", "1")],
+        );
+        algorithm.execute(submit_command(order));
+        submit_to_venue(&context, "O-001-E1");
+
+        let primary = context.order("O-001");
+        algorithm.execute(TradingCommand::CancelOrder(
+            CancelOrder::new(
+                primary.as_order().trader_id(),
+                ClientId::from("SIM"),
+                primary.strategy_id(),
+                primary.instrument_id(),
+                primary.client_order_id(),
+                VenueOrderId::default(),
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        ));
+        context.advance_time(&mut algorithm, SECOND);
+
+        let exec_commands = context.exec_commands.borrow();
+        assert_eq!(exec_commands.len(), 1);
+        match &exec_commands[0] {
+            TradingCommand::CancelOrder(command) => {
+                assert_eq!(command.client_order_id, ClientOrderId::from("O-001-E1"));
+            }
+            command => panic!("unexpected command {command:?}"),
+        }
+        assert_eq!(
+            context.order("O-001").as_order().status(),
+            OrderStatus::Canceled
+        );
+        assert_eq!(context.submitted().len(), 1);
+        assert!(context.timer_names().is_empty());
+    }
+
+    #[rstest]
+    fn test_on_order_event_when_primary_closed_completes(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "4"), ("interval_secs", "1")],
+        );
+        algorithm.execute(submit_command(order));
+        let mut primary: OrderAny = context.order("O-001");
+        let denied = OrderEventAny::Denied(
+            OrderDenied::new(
+                primary.as_order().trader_id(),
+                primary.strategy_id(),
+                primary.instrument_id(),
+                primary.client_order_id(),
+                Ustr::from("TEST"),
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        );
+        primary.apply(denied.clone()).unwrap();
+        context.cache.borrow_mut().update_order(&primary).unwrap();
+
+        algorithm.on_order_event(&denied);
+
+        assert_eq!(algorithm.core().event_count, 1);
+        assert!(context.timer_names().is_empty());
+        assert_eq!(
+            algorithm
+                .core()
+                .scheduled_sizes(&ClientOrderId::from("O-001")),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_reset_cancels_timers(context: TestContext) {
+        let mut algorithm = twap(&context);
+        let order = primary_order(
+            "TWAP",
+            "100000",
+            &[("horizon_secs", "4"), ("interval_secs", "1")],
+        );
+        algorithm.execute(submit_command(order));
+
+        algorithm.reset();
+
+        assert!(context.timer_names().is_empty());
+        assert_eq!(algorithm.core().command_count, 0);
+    }
+}
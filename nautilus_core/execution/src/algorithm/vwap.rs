@@ -0,0 +1,263 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a Volume-Weighted Average Price (VWAP) execution algorithm.
+
+use std::{cell::RefCell, rc::Rc};
+
+use log::{error, info, warn};
+use nautilus_common::{cache::Cache, clock::Clock, msgbus::MessageBus};
+use nautilus_core::{
+    correctness::check_positive_u64, datetime::NANOSECONDS_IN_SECOND, time::AtomicTime,
+};
+use nautilus_model::{
+    identifiers::exec_algorithm_id::ExecAlgorithmId, orders::any::OrderAny,
+    polymorphism::GetClientOrderId, types::quantity::Quantity,
+};
+
+use super::{exec_param, ExecAlgorithm, ExecAlgorithmConfig, ExecAlgorithmCore};
+
+/// Provides a Volume-Weighted Average Price (VWAP) execution algorithm.
+///
+/// The algorithm executes a primary order by slicing it in proportion to an expected volume
+/// profile, with one slice per interval. The first spawned order is submitted immediately, with
+/// the final slice being the primary order itself.
+///
+/// The primary order must be a `MARKET` order with `exec_algorithm_params`:
+/// - `interval_secs`: the interval between slices in seconds.
+/// - `volume_profile`: the comma separated relative volumes expected for each interval.
+pub struct VwapExecAlgorithm {
+    core: ExecAlgorithmCore,
+}
+
+impl VwapExecAlgorithm {
+    /// Creates a new [`VwapExecAlgorithm`] instance (with the ID `VWAP` unless configured).
+    #[must_use]
+    pub fn new(
+        clock: &'static AtomicTime,
+        timer_clock: Rc<RefCell<dyn Clock>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        config: ExecAlgorithmConfig,
+    ) -> Self {
+        let id = config
+            .exec_algorithm_id
+            .unwrap_or_else(|| ExecAlgorithmId::from("VWAP"));
+        Self {
+            core: ExecAlgorithmCore::new(id, clock, timer_clock, msgbus, cache, config),
+        }
+    }
+
+    fn execute_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let instrument = self.core.primary_instrument(&order)?;
+        let interval_secs: f64 = exec_param(&order, "interval_secs")?;
+        let volume_profile: String = exec_param(&order, "volume_profile")?;
+        let volume_profile = parse_volume_profile(&volume_profile)?;
+        let interval_ns = (interval_secs * NANOSECONDS_IN_SECOND as f64) as u64;
+        check_positive_u64(interval_ns, "interval_ns")?;
+
+        let quantity = order.as_order().quantity();
+        let sizes = vwap_schedule(
+            quantity,
+            &volume_profile,
+            instrument.size_increment(),
+            instrument.min_quantity(),
+        );
+        if sizes.len() == 1 {
+            warn!("Submitting for entire size {quantity}");
+            return self.core.submit_order(order);
+        }
+
+        let client_order_id = order.client_order_id();
+        self.core.start_schedule(order, sizes, interval_ns)?;
+        info!(
+            "Started VWAP execution for {client_order_id}: interval_secs={interval_secs}, volume_profile={volume_profile:?}"
+        );
+        Ok(())
+    }
+}
+
+impl ExecAlgorithm for VwapExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) {
+        let client_order_id = order.client_order_id();
+        if let Err(e) = self.execute_order(order) {
+            error!("Cannot execute order {client_order_id}: {e}");
+        }
+    }
+}
+
+fn parse_volume_profile(value: &str) -> anyhow::Result<Vec<f64>> {
+    value
+        .split(',')
+        .map(|volume| {
+            let volume: f64 = volume
+                .trim()
+                .parse()
+                .map_err(|e| anyhow::anyhow!("invalid `volume_profile` '{value}': {e}"))?;
+            if !volume.is_finite() || volume < 0.0 {
+                anyhow::bail!("invalid `volume_profile` '{value}': volumes must be non-negative");
+            }
+            Ok(volume)
+        })
+        .collect()
+}
+
+/// Returns the VWAP execution schedule, splitting `quantity` in proportion to the
+/// `volume_profile` (in multiples of `size_increment`), one slice per interval.
+///
+/// Slices less than the `min_quantity` are deferred, and any remainder is executed with the final
+/// slice. The schedule is the entire `quantity` if it cannot be split.
+#[must_use]
+pub fn vwap_schedule(
+    quantity: Quantity,
+    volume_profile: &[f64],
+    size_increment: Quantity,
+    min_quantity: Option<Quantity>,
+) -> Vec<Quantity> {
+    // Trailing intervals without volume cannot execute the final slice
+    let num_intervals = volume_profile
+        .iter()
+        .rposition(|volume| *volume > 0.0)
+        .map_or(0, |index| index + 1);
+    let total_volume: f64 = volume_profile[..num_intervals].iter().sum();
+    if num_intervals <= 1 || size_increment.raw == 0 {
+        return vec![quantity];
+    }
+
+    let mut sizes = Vec::with_capacity(num_intervals);
+    let mut scheduled_raw = 0;
+    for volume in &volume_profile[..num_intervals - 1] {
+        let mut slice_raw = (quantity.raw as f64 * volume / total_volume).floor() as u64;
+        slice_raw -= slice_raw % size_increment.raw;
+        if min_quantity.is_some_and(|min_quantity| slice_raw < min_quantity.raw) {
+            slice_raw = 0;
+        }
+        scheduled_raw += slice_raw;
+        sizes.push(Quantity::from_raw(slice_raw, quantity.precision).unwrap());
+    }
+    if scheduled_raw == 0 {
+        return vec![quantity];
+    }
+
+    sizes.push(Quantity::from_raw(quantity.raw - scheduled_raw, quantity.precision).unwrap());
+    sizes
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{identifiers::client_order_id::ClientOrderId, types::quantity::Quantity};
+    use rstest::rstest;
+
+    use super::{parse_volume_profile, vwap_schedule, VwapExecAlgorithm};
+    use crate::algorithm::{
+        stubs::{context, primary_order, submit_command, TestContext},
+        ExecAlgorithm, ExecAlgorithmConfig,
+    };
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn vwap(context: &TestContext) -> VwapExecAlgorithm {
+        VwapExecAlgorithm::new(
+            context.clock,
+            context.timer_clock.clone(),
+            context.msgbus.clone(),
+            context.cache.clone(),
+            ExecAlgorithmConfig::default(),
+        )
+    }
+
+    #[rstest]
+    #[case(vec![1.0, 2.0, 1.0], vec!["25000", "50000", "25000"])]
+    #[case(vec![1.0, 1.0, 1.0], vec!["33333", "33333", "33334"])]
+    #[case(vec![0.001, 1.0, 1.0], vec!["0", "49975", "50025"])] // Slice less than min quantity
+    #[case(vec![1.0, 1.0, 0.0], vec!["50000", "50000"])]
+    #[case(vec![1.0], vec!["100000"])]
+    #[case(vec![0.0, 0.0], vec!["100000"])]
+    fn test_vwap_schedule(#[case] volume_profile: Vec<f64>, #[case] expected: Vec<&str>) {
+        let sizes = vwap_schedule(
+            Quantity::from("100000"),
+            &volume_profile,
+            Quantity::from("1"),
+            Some(Quantity::from("100")),
+        );
+
+        let expected: Vec<Quantity> = expected.into_iter().map(Quantity::from).collect();
+        assert_eq!(sizes, expected);
+    }
+
+    #[rstest]
+    #[case("1, 2,3", Some(vec![1.0, 2.0, 3.0]))]
+    #[case("1,-2", None)]
+    #[case("1,a", None)]
+    fn test_parse_volume_profile(#[case] value: &str, #[case] expected: Option<Vec<f64>>) {
+        assert_eq!(parse_volume_profile(value).ok(), expected);
+    }
+
+    #[rstest]
+    fn test_execute_when_invalid_profile_does_not_submit(context: TestContext) {
+        let mut algorithm = vwap(&context);
+        let order = primary_order(
+            "VWAP",
+            "100000",
+            &[("interval_secs", "1"), ("volume_profile", "1,x")],
+        );
+
+        algorithm.execute(submit_command(order));
+
+        assert!(context.submitted().is_empty());
+    }
+
+    #[rstest]
+    fn test_execute_slices_by_volume_profile(context: TestContext) {
+        let mut algorithm = vwap(&context);
+        let order = primary_order(
+            "VWAP",
+            "100000",
+            &[("interval_secs", "60"), ("volume_profile", "2,0,5,3")],
+        );
+
+        algorithm.execute(submit_command(order));
+        for interval in 1..=3 {
+            context.advance_time(&mut algorithm, interval * 60 * SECOND);
+        }
+
+        assert_eq!(
+            context.submitted(),
+            vec![
+                ("O-001-E1".to_string(), Quantity::from("20000")),
+                ("O-001-E2".to_string(), Quantity::from("50000")),
+                ("O-001".to_string(), Quantity::from("30000")),
+            ]
+        );
+        assert_eq!(
+            algorithm
+                .core()
+                .total_quantity(&ClientOrderId::from("O-001")),
+            Some(Quantity::from("100000"))
+        );
+        assert!(context.timer_names().is_empty());
+    }
+}
@@ -0,0 +1,863 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides execution client base functionality.
+
+use std::{
+    cell::{Ref, RefCell},
+    rc::Rc,
+};
+
+use log::warn;
+use nautilus_common::{
+    cache::Cache,
+    interface::account::Account,
+    msgbus::{
+        endpoints::{
+            EXEC_ENGINE_PROCESS, EXEC_ENGINE_RECONCILE_MASS_STATUS, EXEC_ENGINE_RECONCILE_REPORT,
+            PORTFOLIO_UPDATE_ACCOUNT,
+        },
+        MessageBus,
+    },
+};
+use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
+    events::{
+        account::state::AccountState,
+        order::{
+            accepted::OrderAccepted, cancel_rejected::OrderCancelRejected, canceled::OrderCanceled,
+            event::OrderEventAny, expired::OrderExpired, filled::OrderFilled,
+            modify_rejected::OrderModifyRejected, rejected::OrderRejected,
+            submitted::OrderSubmitted, triggered::OrderTriggered, updated::OrderUpdated,
+        },
+    },
+    identifiers::{
+        account_id::AccountId, client_id::ClientId, client_order_id::ClientOrderId,
+        instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
+        trade_id::TradeId, trader_id::TraderId, venue::Venue, venue_order_id::VenueOrderId,
+    },
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        currency::Currency,
+        money::Money,
+        price::Price,
+        quantity::Quantity,
+    },
+};
+use ustr::Ustr;
+
+use crate::reports::{
+    fill::FillReport, mass_status::ExecutionMassStatus, order::OrderStatusReport,
+};
+
+/// Provides the common state and event generation for execution clients.
+///
+/// Each `generate_*` method builds the event with a new `UUID4` and the clocks current time
+/// as `ts_init`, then sends it to the relevant endpoint on the message bus.
+pub struct BaseExecutionClient {
+    pub trader_id: TraderId,
+    pub client_id: ClientId,
+    pub venue: Venue,
+    pub oms_type: OmsType,
+    pub account_id: AccountId,
+    pub account_type: AccountType,
+    pub base_currency: Option<Currency>,
+    pub is_connected: bool,
+    clock: &'static AtomicTime,
+    cache: Rc<RefCell<Cache>>,
+    msgbus: Rc<RefCell<MessageBus>>,
+}
+
+impl BaseExecutionClient {
+    /// Creates a new [`BaseExecutionClient`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        venue: Venue,
+        oms_type: OmsType,
+        account_id: AccountId,
+        account_type: AccountType,
+        base_currency: Option<Currency>,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+    ) -> Self {
+        let trader_id = msgbus.borrow().trader_id;
+        Self {
+            trader_id,
+            client_id,
+            venue,
+            oms_type,
+            account_id,
+            account_type,
+            base_currency,
+            is_connected: false,
+            clock,
+            cache,
+            msgbus,
+        }
+    }
+
+    /// Returns the clients account from the cache (if found).
+    #[must_use]
+    pub fn get_account(&self) -> Option<Ref<'_, dyn Account>> {
+        Ref::filter_map(self.cache.borrow(), |cache| cache.account(&self.account_id)).ok()
+    }
+
+    // -- EVENT GENERATORS ----------------------------------------------------
+
+    /// Generates and sends an [`AccountState`] event for the clients account.
+    pub fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let account_state = AccountState::new(
+            self.account_id,
+            self.account_type,
+            balances,
+            margins,
+            reported,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            self.base_currency,
+        )?;
+        self.send_account_state(account_state);
+        Ok(())
+    }
+
+    pub fn generate_order_submitted(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderSubmitted::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+        )?;
+        self.send_order_event(OrderEventAny::Submitted(event));
+        Ok(())
+    }
+
+    pub fn generate_order_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        reason: &str,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            self.account_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+        )?;
+        self.send_order_event(OrderEventAny::Rejected(event));
+        Ok(())
+    }
+
+    pub fn generate_order_accepted(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderAccepted::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+        )?;
+        self.send_order_event(OrderEventAny::Accepted(event));
+        Ok(())
+    }
+
+    pub fn generate_order_modify_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        reason: &str,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderModifyRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        )?;
+        self.send_order_event(OrderEventAny::ModifyRejected(event));
+        Ok(())
+    }
+
+    pub fn generate_order_cancel_rejected(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        reason: &str,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderCancelRejected::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        )?;
+        self.send_order_event(OrderEventAny::CancelRejected(event));
+        Ok(())
+    }
+
+    /// Generates and sends an [`OrderUpdated`] event.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `venue_order_id_modified` is false and the `venue_order_id` does not match the
+    ///   venue order ID already cached for the order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_order_updated(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        ts_event: UnixNanos,
+        venue_order_id_modified: bool,
+    ) -> anyhow::Result<()> {
+        // Only allow the venue order ID to change when flagged as modified by the venue
+        if !venue_order_id_modified {
+            match self.cache.borrow().venue_order_id(&client_order_id) {
+                Some(existing) if *existing != venue_order_id => anyhow::bail!(
+                    "{venue_order_id} does not match existing {existing} for {client_order_id}"
+                ),
+                Some(_) => {}
+                None => warn!("No existing venue order ID for {client_order_id}"),
+            }
+        }
+
+        let event = OrderUpdated::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            quantity,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+            price,
+            trigger_price,
+        )?;
+        self.send_order_event(OrderEventAny::Updated(event));
+        Ok(())
+    }
+
+    pub fn generate_order_canceled(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderCanceled::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        )?;
+        self.send_order_event(OrderEventAny::Canceled(event));
+        Ok(())
+    }
+
+    pub fn generate_order_triggered(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderTriggered::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        )?;
+        self.send_order_event(OrderEventAny::Triggered(event));
+        Ok(())
+    }
+
+    pub fn generate_order_expired(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderExpired::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            Some(venue_order_id),
+            Some(self.account_id),
+        )?;
+        self.send_order_event(OrderEventAny::Expired(event));
+        Ok(())
+    }
+
+    /// Generates and sends an [`OrderFilled`] event, as a partial fill if the `last_qty`
+    /// is less than the leaves quantity of the cached order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn generate_order_filled(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+        venue_position_id: Option<PositionId>,
+        trade_id: TradeId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        last_qty: Quantity,
+        last_px: Price,
+        quote_currency: Currency,
+        commission: Money,
+        liquidity_side: LiquiditySide,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        let event = OrderFilled::new(
+            self.trader_id,
+            strategy_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            self.account_id,
+            trade_id,
+            order_side,
+            order_type,
+            last_qty,
+            last_px,
+            quote_currency,
+            liquidity_side,
+            UUID4::new(),
+            ts_event,
+            self.clock.get_time_ns(),
+            false,
+            venue_position_id,
+            Some(commission),
+        )?;
+
+        // A fill for less than the cached orders leaves quantity is a partial fill
+        let is_partial_fill = self
+            .cache
+            .borrow()
+            .order(&client_order_id)
+            .is_some_and(|order| last_qty < order.as_order().leaves_qty());
+        let event = if is_partial_fill {
+            OrderEventAny::PartiallyFilled(event)
+        } else {
+            OrderEventAny::Filled(event)
+        };
+        self.send_order_event(event);
+        Ok(())
+    }
+
+    fn send_account_state(&self, account_state: AccountState) {
+        self.msgbus
+            .borrow()
+            .send(PORTFOLIO_UPDATE_ACCOUNT, &account_state);
+    }
+
+    fn send_order_event(&self, event: OrderEventAny) {
+        self.msgbus.borrow().send(EXEC_ENGINE_PROCESS, &event);
+    }
+
+    // -- EXECUTION REPORTS ---------------------------------------------------
+
+    /// Sends the execution mass status `report` to the execution engine for reconciliation.
+    pub fn send_mass_status_report(&self, report: ExecutionMassStatus) {
+        self.msgbus
+            .borrow()
+            .send(EXEC_ENGINE_RECONCILE_MASS_STATUS, &report);
+    }
+
+    /// Sends the order status `report` to the execution engine for reconciliation.
+    pub fn send_order_status_report(&self, report: OrderStatusReport) {
+        self.msgbus
+            .borrow()
+            .send(EXEC_ENGINE_RECONCILE_REPORT, &report);
+    }
+
+    /// Sends the fill `report` to the execution engine for reconciliation.
+    pub fn send_fill_report(&self, report: FillReport) {
+        self.msgbus
+            .borrow()
+            .send(EXEC_ENGINE_RECONCILE_REPORT, &report);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    use nautilus_common::{cache::Cache, handlers::AnyMessageHandler, msgbus::MessageBus};
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+    use nautilus_model::{
+        enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType},
+        events::{account::state::AccountState, order::event::OrderEventAny},
+        identifiers::{
+            account_id::AccountId,
+            client_id::ClientId,
+            client_order_id::ClientOrderId,
+            instrument_id::InstrumentId,
+            stubs::{strategy_id_ema_cross, trader_id},
+            trade_id::TradeId,
+            venue::Venue,
+            venue_order_id::VenueOrderId,
+        },
+        orders::{any::OrderAny, stubs::TestOrderStubs},
+        types::{
+            balance::AccountBalance, currency::Currency, money::Money, price::Price,
+            quantity::Quantity,
+        },
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{BaseExecutionClient, EXEC_ENGINE_PROCESS, PORTFOLIO_UPDATE_ACCOUNT};
+
+    type Events<T> = Rc<RefCell<Vec<T>>>;
+
+    struct TestContext {
+        client: BaseExecutionClient,
+        cache: Rc<RefCell<Cache>>,
+        order_events: Events<OrderEventAny>,
+        account_states: Events<AccountState>,
+    }
+
+    fn register<T: Clone + 'static>(msgbus: &Rc<RefCell<MessageBus>>, endpoint: &str) -> Events<T> {
+        let events: Events<T> = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = events.clone();
+        let handler = AnyMessageHandler::new(Ustr::from(endpoint), move |msg: &dyn Any| {
+            events_clone
+                .borrow_mut()
+                .push(msg.downcast_ref::<T>().unwrap().clone());
+        });
+        msgbus.borrow_mut().register_any(endpoint, handler);
+        events
+    }
+
+    #[fixture]
+    fn context() -> TestContext {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::from(1_000))));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap(),
+        ));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let order_events = register(&msgbus, EXEC_ENGINE_PROCESS);
+        let account_states = register(&msgbus, PORTFOLIO_UPDATE_ACCOUNT);
+
+        let client = BaseExecutionClient::new(
+            ClientId::from("SIM"),
+            Venue::from("SIM"),
+            OmsType::Netting,
+            AccountId::from("SIM-001"),
+            AccountType::Cash,
+            None,
+            clock,
+            cache.clone(),
+            msgbus,
+        );
+
+        TestContext {
+            client,
+            cache,
+            order_events,
+            account_states,
+        }
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("AUD/USD.SIM")
+    }
+
+    #[rstest]
+    fn test_new_has_trader_id_and_is_disconnected(context: TestContext) {
+        assert_eq!(context.client.trader_id, trader_id());
+        assert!(!context.client.is_connected);
+        assert!(context.client.get_account().is_none());
+    }
+
+    #[rstest]
+    fn test_generate_account_state(context: TestContext) {
+        let balance = AccountBalance::new(
+            Money::from("1000000 USD"),
+            Money::from("0 USD"),
+            Money::from("1000000 USD"),
+        )
+        .unwrap();
+
+        context
+            .client
+            .generate_account_state(vec![balance], vec![], true, UnixNanos::from(500))
+            .unwrap();
+
+        let account_states = context.account_states.borrow();
+        assert_eq!(account_states.len(), 1);
+        assert_eq!(account_states[0].account_id, AccountId::from("SIM-001"));
+        assert_eq!(account_states[0].account_type, AccountType::Cash);
+        assert_eq!(account_states[0].balances, vec![balance]);
+        assert!(account_states[0].is_reported);
+        assert_eq!(account_states[0].ts_event, 500);
+        assert_eq!(account_states[0].ts_init, 1_000);
+    }
+
+    #[rstest]
+    fn test_generate_order_submitted(context: TestContext) {
+        context
+            .client
+            .generate_order_submitted(
+                strategy_id_ema_cross(),
+                instrument_id(),
+                ClientOrderId::from("O-1"),
+                UnixNanos::from(500),
+            )
+            .unwrap();
+
+        let order_events = context.order_events.borrow();
+        assert_eq!(order_events.len(), 1);
+        match &order_events[0] {
+            OrderEventAny::Submitted(event) => {
+                assert_eq!(event.trader_id, trader_id());
+                assert_eq!(event.strategy_id, strategy_id_ema_cross());
+                assert_eq!(event.client_order_id, ClientOrderId::from("O-1"));
+                assert_eq!(event.account_id, AccountId::from("SIM-001"));
+                assert_eq!(event.ts_event, 500);
+                assert_eq!(event.ts_init, 1_000);
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_generate_order_status_events(context: TestContext) {
+        let client = &context.client;
+        let strategy_id = strategy_id_ema_cross();
+        let client_order_id = ClientOrderId::from("O-1");
+        let venue_order_id = VenueOrderId::from("V-1");
+        let ts_event = UnixNanos::from(500);
+
+        client
+            .generate_order_rejected(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                "INSUFFICIENT_MARGIN",
+                ts_event,
+            )
+            .unwrap();
+        client
+            .generate_order_accepted(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                ts_event,
+            )
+            .unwrap();
+        client
+            .generate_order_modify_rejected(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                "ORDER_NOT_FOUND",
+                ts_event,
+            )
+            .unwrap();
+        client
+            .generate_order_cancel_rejected(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                "ORDER_NOT_FOUND",
+                ts_event,
+            )
+            .unwrap();
+        client
+            .generate_order_triggered(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                ts_event,
+            )
+            .unwrap();
+        client
+            .generate_order_canceled(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                ts_event,
+            )
+            .unwrap();
+        client
+            .generate_order_expired(
+                strategy_id,
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                ts_event,
+            )
+            .unwrap();
+
+        let order_events = context.order_events.borrow();
+        assert_eq!(order_events.len(), 7);
+        assert!(matches!(order_events[0], OrderEventAny::Rejected(_)));
+        assert!(matches!(order_events[1], OrderEventAny::Accepted(_)));
+        assert!(matches!(order_events[2], OrderEventAny::ModifyRejected(_)));
+        assert!(matches!(order_events[3], OrderEventAny::CancelRejected(_)));
+        assert!(matches!(order_events[4], OrderEventAny::Triggered(_)));
+        assert!(matches!(order_events[5], OrderEventAny::Canceled(_)));
+        assert!(matches!(order_events[6], OrderEventAny::Expired(_)));
+        for event in order_events.iter().skip(1) {
+            assert_eq!(event.client_order_id(), client_order_id);
+            assert_eq!(event.venue_order_id(), Some(venue_order_id));
+        }
+    }
+
+    #[rstest]
+    fn test_generate_order_updated(context: TestContext) {
+        let client_order_id = ClientOrderId::from("O-1");
+        let venue_order_id = VenueOrderId::from("V-1");
+        context
+            .cache
+            .borrow_mut()
+            .add_venue_order_id(&client_order_id, &venue_order_id, false)
+            .unwrap();
+
+        context
+            .client
+            .generate_order_updated(
+                strategy_id_ema_cross(),
+                instrument_id(),
+                client_order_id,
+                venue_order_id,
+                Quantity::from(50_000),
+                Some(Price::from("0.80000")),
+                None,
+                UnixNanos::from(500),
+                false,
+            )
+            .unwrap();
+
+        let order_events = context.order_events.borrow();
+        match &order_events[0] {
+            OrderEventAny::Updated(event) => {
+                assert_eq!(event.quantity, Quantity::from(50_000));
+                assert_eq!(event.price, Some(Price::from("0.80000")));
+                assert_eq!(event.trigger_price, None);
+                assert_eq!(event.venue_order_id, Some(venue_order_id));
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(false, true)]
+    #[case(true, false)]
+    fn test_generate_order_updated_with_different_venue_order_id(
+        context: TestContext,
+        #[case] venue_order_id_modified: bool,
+        #[case] expect_error: bool,
+    ) {
+        let client_order_id = ClientOrderId::from("O-1");
+        context
+            .cache
+            .borrow_mut()
+            .add_venue_order_id(&client_order_id, &VenueOrderId::from("V-1"), false)
+            .unwrap();
+
+        let result = context.client.generate_order_updated(
+            strategy_id_ema_cross(),
+            instrument_id(),
+            client_order_id,
+            VenueOrderId::from("V-2"),
+            Quantity::from(50_000),
+            None,
+            None,
+            UnixNanos::from(500),
+            venue_order_id_modified,
+        );
+
+        assert_eq!(result.is_err(), expect_error);
+        assert_eq!(
+            context.order_events.borrow().len(),
+            usize::from(!expect_error)
+        );
+    }
+
+    #[rstest]
+    fn test_generate_order_filled(context: TestContext) {
+        context
+            .client
+            .generate_order_filled(
+                strategy_id_ema_cross(),
+                instrument_id(),
+                ClientOrderId::from("O-1"),
+                VenueOrderId::from("V-1"),
+                None,
+                TradeId::from("E-1"),
+                OrderSide::Buy,
+                OrderType::Market,
+                Quantity::from(100_000),
+                Price::from("0.80000"),
+                Currency::USD(),
+                Money::from("2 USD"),
+                LiquiditySide::Taker,
+                UnixNanos::from(500),
+            )
+            .unwrap();
+
+        let order_events = context.order_events.borrow();
+        match &order_events[0] {
+            OrderEventAny::Filled(fill) => {
+                assert_eq!(fill.trader_id, trader_id());
+                assert_eq!(fill.account_id, AccountId::from("SIM-001"));
+                assert_eq!(fill.trade_id, TradeId::from("E-1"));
+                assert_eq!(fill.last_qty, Quantity::from(100_000));
+                assert_eq!(fill.last_px, Price::from("0.80000"));
+                assert_eq!(fill.commission, Some(Money::from("2 USD")));
+                assert_eq!(fill.position_id, None);
+                assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+                assert_eq!(fill.ts_init, 1_000);
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_generate_order_filled_for_less_than_leaves_qty_is_partial_fill(context: TestContext) {
+        let order = TestOrderStubs::market_order(
+            instrument_id(),
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        );
+        context
+            .cache
+            .borrow_mut()
+            .add_order(OrderAny::Market(order), None, None, false)
+            .unwrap();
+
+        context
+            .client
+            .generate_order_filled(
+                strategy_id_ema_cross(),
+                instrument_id(),
+                ClientOrderId::from("O-1"),
+                VenueOrderId::from("V-1"),
+                None,
+                TradeId::from("E-1"),
+                OrderSide::Buy,
+                OrderType::Market,
+                Quantity::from(50_000),
+                Price::from("0.80000"),
+                Currency::USD(),
+                Money::from("1 USD"),
+                LiquiditySide::Taker,
+                UnixNanos::from(500),
+            )
+            .unwrap();
+
+        let order_events = context.order_events.borrow();
+        assert!(matches!(order_events[0], OrderEventAny::PartiallyFilled(_)));
+    }
+}
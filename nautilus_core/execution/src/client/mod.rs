@@ -0,0 +1,61 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the execution client interface for trading venue adapters.
+
+pub mod base;
+
+use nautilus_model::{
+    enums::OmsType,
+    identifiers::{account_id::AccountId, client_id::ClientId, venue::Venue},
+};
+
+use crate::messages::{
+    cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
+    modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
+};
+
+/// The interface for an execution client which sends trading commands to a single venue.
+///
+/// Implementations are only responsible for the transport of commands to the venue, the
+/// resulting order events are generated and sent to the execution engine through a
+/// [`base::BaseExecutionClient`].
+pub trait ExecutionClient {
+    /// Returns the clients ID.
+    fn client_id(&self) -> ClientId;
+    /// Returns the venue the client routes commands to.
+    fn venue(&self) -> Venue;
+    /// Returns the order management system type for the venue.
+    fn oms_type(&self) -> OmsType;
+    /// Returns the account ID for the client.
+    fn account_id(&self) -> AccountId;
+    /// Returns whether the client is connected to the venue.
+    fn is_connected(&self) -> bool;
+
+    /// Starts the client, connecting to the venue.
+    fn start(&mut self) -> anyhow::Result<()>;
+    /// Stops the client, disconnecting from the venue.
+    fn stop(&mut self) -> anyhow::Result<()>;
+
+    // -- COMMAND HANDLERS ----------------------------------------------------
+
+    fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()>;
+    fn submit_order_list(&self, command: SubmitOrderList) -> anyhow::Result<()>;
+    fn modify_order(&self, command: ModifyOrder) -> anyhow::Result<()>;
+    fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()>;
+    fn cancel_all_orders(&self, command: CancelAllOrders) -> anyhow::Result<()>;
+    fn batch_cancel_orders(&self, command: BatchCancelOrders) -> anyhow::Result<()>;
+    fn query_order(&self, command: QueryOrder) -> anyhow::Result<()>;
+}
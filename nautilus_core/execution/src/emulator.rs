@@ -0,0 +1,1728 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides an `OrderEmulator` which locally emulates orders until they are triggered.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use log::{debug, error, info, warn};
+use nautilus_common::{
+    cache::Cache,
+    msgbus::{
+        endpoints::{EXEC_ENGINE_EXECUTE, EXEC_ENGINE_PROCESS, RISK_ENGINE_PROCESS},
+        MessageBus,
+    },
+};
+use nautilus_core::{correctness::check_key_not_in_map, time::AtomicTime, uuid::UUID4};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::{OrderSide, OrderStatus, TriggerType},
+    events::order::{
+        canceled::OrderCanceled, emulated::OrderEmulated, event::OrderEventAny,
+        released::OrderReleased, updated::OrderUpdated,
+    },
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    orders::{
+        any::{LimitOrderAny, OrderAny, PassiveOrderAny, StopOrderAny},
+        limit::LimitOrder,
+        market::MarketOrder,
+    },
+    polymorphism::{
+        ApplyOrderEventAny, GetClientOrderId, GetEmulationTrigger, GetInstrumentId, GetStrategyId,
+        IsClosed,
+    },
+    types::price::Price,
+};
+
+use crate::{
+    matching_core::OrderMatchingCore,
+    messages::{
+        cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+        submit_list::SubmitOrderList, TradingCommand,
+    },
+    trailing::trailing_stop_calculate,
+};
+
+/// Configuration for `OrderEmulator` instances.
+#[derive(Clone, Debug, Default)]
+pub struct OrderEmulatorConfig {
+    /// If debug mode is active (will provide extra debug logging).
+    pub debug: bool,
+    /// The price tiers (tier start price, tier offset) per trigger instrument, for `PRICE_TIER`
+    /// trailing offsets.
+    pub price_tiers: HashMap<InstrumentId, Vec<(Price, Price)>>,
+}
+
+/// Provides order emulation for the `DEFAULT`, `BID_ASK` and `LAST_TRADE` trigger types.
+///
+/// Emulated orders are held in an `OrderMatchingCore` per trigger instrument until the market
+/// (quotes or trades) triggers them. Triggered stop orders are then transformed into market or
+/// limit orders, and released to the `ExecutionEngine` (or their execution algorithm).
+///
+/// Synthetic trigger instruments are priced from the quotes and trades of their components.
+pub struct OrderEmulator {
+    pub command_count: u64,
+    pub event_count: u64,
+    clock: &'static AtomicTime,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    matching_cores: HashMap<InstrumentId, OrderMatchingCore>,
+    submit_order_commands: HashMap<ClientOrderId, SubmitOrder>,
+    subscribed_quotes: HashSet<InstrumentId>,
+    subscribed_trades: HashSet<InstrumentId>,
+    config: OrderEmulatorConfig,
+}
+
+impl OrderEmulator {
+    /// Creates a new [`OrderEmulator`] instance.
+    #[must_use]
+    pub fn new(
+        clock: &'static AtomicTime,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        config: OrderEmulatorConfig,
+    ) -> Self {
+        Self {
+            command_count: 0,
+            event_count: 0,
+            clock,
+            msgbus,
+            cache,
+            matching_cores: HashMap::new(),
+            submit_order_commands: HashMap::new(),
+            subscribed_quotes: HashSet::new(),
+            subscribed_trades: HashSet::new(),
+            config,
+        }
+    }
+
+    /// Returns the instrument IDs of the quote feeds required by the emulator.
+    #[must_use]
+    pub fn subscribed_quotes(&self) -> Vec<InstrumentId> {
+        let mut instrument_ids: Vec<InstrumentId> =
+            self.subscribed_quotes.iter().copied().collect();
+        instrument_ids.sort();
+        instrument_ids
+    }
+
+    /// Returns the instrument IDs of the trade feeds required by the emulator.
+    #[must_use]
+    pub fn subscribed_trades(&self) -> Vec<InstrumentId> {
+        let mut instrument_ids: Vec<InstrumentId> =
+            self.subscribed_trades.iter().copied().collect();
+        instrument_ids.sort();
+        instrument_ids
+    }
+
+    /// Returns the submit order commands for the orders currently being emulated.
+    #[must_use]
+    pub fn get_submit_order_commands(&self) -> &HashMap<ClientOrderId, SubmitOrder> {
+        &self.submit_order_commands
+    }
+
+    /// Returns the matching core for the given trigger `instrument_id` (if found).
+    #[must_use]
+    pub fn get_matching_core(&self, instrument_id: &InstrumentId) -> Option<&OrderMatchingCore> {
+        self.matching_cores.get(instrument_id)
+    }
+
+    // -- COMMANDS --------------------------------------------------------------------------------
+
+    /// Creates a matching core for the given trigger `instrument_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a matching core for the `instrument_id` already exists.
+    pub fn create_matching_core(
+        &mut self,
+        instrument_id: InstrumentId,
+        price_increment: Price,
+    ) -> anyhow::Result<()> {
+        check_key_not_in_map(
+            &instrument_id,
+            &self.matching_cores,
+            stringify!(instrument_id),
+            stringify!(matching_cores),
+        )?;
+
+        let matching_core =
+            OrderMatchingCore::new(instrument_id, price_increment, None, None, None);
+        self.matching_cores.insert(instrument_id, matching_core);
+        if self.config.debug {
+            debug!("Created matching core for {instrument_id}");
+        }
+        Ok(())
+    }
+
+    /// Resets the emulator, discarding all matching cores, emulated order commands and
+    /// trigger feed subscriptions.
+    pub fn reset(&mut self) {
+        self.matching_cores.clear();
+        self.submit_order_commands.clear();
+        self.subscribed_quotes.clear();
+        self.subscribed_trades.clear();
+        self.command_count = 0;
+        self.event_count = 0;
+    }
+
+    pub fn execute(&mut self, command: TradingCommand) {
+        if self.config.debug {
+            debug!("<--[CMD] {command:?}");
+        }
+        self.command_count += 1;
+
+        match command {
+            TradingCommand::SubmitOrder(command) => self.handle_submit_order(command),
+            TradingCommand::SubmitOrderList(command) => self.handle_submit_order_list(command),
+            TradingCommand::ModifyOrder(command) => self.handle_modify_order(&command),
+            TradingCommand::CancelOrder(command) => self.handle_cancel_order(command),
+            TradingCommand::CancelAllOrders(command) => self.handle_cancel_all_orders(&command),
+            _ => error!("Cannot handle command: unrecognized {command:?}"),
+        }
+    }
+
+    /// Handles the given order `event`, removing any order which is now closed.
+    pub fn on_event(&mut self, event: &OrderEventAny) {
+        if self.config.debug {
+            debug!("<--[EVT] {event:?}");
+        }
+        self.event_count += 1;
+
+        let client_order_id = event.client_order_id();
+        let Some(order) = self.cache.borrow().order(&client_order_id).cloned() else {
+            return; // Order not in cache yet
+        };
+
+        if order.is_closed() {
+            self.submit_order_commands.remove(&client_order_id);
+            self.delete_from_matching_core(&order);
+        }
+    }
+
+    /// Handles the given `quote`, triggering any emulated orders for the quoted instrument or
+    /// any synthetic instrument it is a component of.
+    pub fn on_quote_tick(&mut self, quote: &QuoteTick) {
+        if self.config.debug {
+            debug!("Processing {quote}");
+        }
+
+        if let Some(matching_core) = self.matching_cores.get_mut(&quote.instrument_id) {
+            matching_core.bid = Some(quote.bid_price);
+            matching_core.ask = Some(quote.ask_price);
+            self.iterate_orders(&quote.instrument_id);
+        }
+
+        for synthetic_id in self.synthetic_ids_for_component(&quote.instrument_id) {
+            let prices = self.synthetic_prices(&synthetic_id, |component| {
+                if *component == quote.instrument_id {
+                    Some((quote.bid_price, quote.ask_price))
+                } else {
+                    self.cache
+                        .borrow()
+                        .quote_tick(component)
+                        .map(|q| (q.bid_price, q.ask_price))
+                }
+            });
+            let Some((bid, ask)) = prices else {
+                continue; // Not all components priced yet
+            };
+
+            if let Some(matching_core) = self.matching_cores.get_mut(&synthetic_id) {
+                matching_core.bid = Some(bid);
+                matching_core.ask = Some(ask);
+            }
+            self.iterate_orders(&synthetic_id);
+        }
+    }
+
+    /// Handles the given `trade`, triggering any emulated orders for the traded instrument or
+    /// any synthetic instrument it is a component of.
+    pub fn on_trade_tick(&mut self, trade: &TradeTick) {
+        if self.config.debug {
+            debug!("Processing {trade}");
+        }
+
+        if self.matching_cores.contains_key(&trade.instrument_id) {
+            self.update_last(trade.instrument_id, trade.price);
+        }
+
+        for synthetic_id in self.synthetic_ids_for_component(&trade.instrument_id) {
+            let prices = self.synthetic_prices(&synthetic_id, |component| {
+                if *component == trade.instrument_id {
+                    Some((trade.price, trade.price))
+                } else {
+                    self.cache
+                        .borrow()
+                        .trade_tick(component)
+                        .map(|t| (t.price, t.price))
+                }
+            });
+            if let Some((last, _)) = prices {
+                self.update_last(synthetic_id, last);
+            }
+        }
+    }
+
+    // -- COMMAND HANDLERS ------------------------------------------------------------------------
+
+    fn handle_submit_order(&mut self, command: SubmitOrder) {
+        let mut order = command.order.clone();
+        let client_order_id = order.client_order_id();
+
+        let Some(emulation_trigger) = order
+            .emulation_trigger()
+            .filter(|trigger| *trigger != TriggerType::NoTrigger)
+        else {
+            error!("Cannot emulate order: no emulation trigger for {client_order_id}");
+            return;
+        };
+
+        if self.submit_order_commands.contains_key(&client_order_id) {
+            error!("Cannot emulate order: {client_order_id} already emulated");
+            return;
+        }
+
+        if !self.cache.borrow().order_exists(&client_order_id) {
+            if let Err(e) = self.cache.borrow_mut().add_order(
+                order.clone(),
+                command.position_id,
+                Some(command.client_id),
+                false,
+            ) {
+                error!("Cannot emulate order: {e}");
+                return;
+            }
+        }
+
+        if !matches!(
+            emulation_trigger,
+            TriggerType::Default | TriggerType::BidAsk | TriggerType::LastTrade
+        ) {
+            error!("Cannot emulate order: `TriggerType` {emulation_trigger} not supported");
+            self.cancel_order(&order);
+            return;
+        }
+
+        if matches!(order, OrderAny::Market(_) | OrderAny::MarketToLimit(_)) {
+            error!(
+                "Cannot emulate order: {} orders not supported",
+                order.as_order().order_type()
+            );
+            self.cancel_order(&order);
+            return;
+        }
+
+        let trigger_instrument_id = trigger_instrument_id(&order);
+        if !self.matching_cores.contains_key(&trigger_instrument_id) {
+            let Some(price_increment) = self.trigger_price_increment(&trigger_instrument_id) else {
+                error!("Cannot emulate order: no instrument {trigger_instrument_id} for trigger");
+                self.cancel_order(&order);
+                return;
+            };
+            self.create_matching_core(trigger_instrument_id, price_increment)
+                .expect("matching core should not exist");
+        }
+
+        self.subscribe_trigger_feeds(trigger_instrument_id, emulation_trigger);
+        self.submit_order_commands
+            .insert(client_order_id, command.clone());
+
+        // Check if the order would trigger immediately
+        let passive_order = match PassiveOrderAny::try_from(order.clone()) {
+            Ok(passive_order) => passive_order,
+            Err(e) => {
+                error!("Cannot emulate order: {e}");
+                return;
+            }
+        };
+        if self.is_matched(&trigger_instrument_id, &passive_order) {
+            self.trigger_order(&trigger_instrument_id, &passive_order);
+            return; // Released
+        }
+
+        if order.as_order().status() == OrderStatus::Initialized {
+            let ts_now = self.clock.get_time_ns();
+            let event = OrderEventAny::Emulated(
+                OrderEmulated::new(
+                    order.as_order().trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    UUID4::new(),
+                    ts_now,
+                    ts_now,
+                )
+                .unwrap(),
+            );
+            if let Err(e) = order.apply(event.clone()) {
+                error!("Cannot emulate order: {e}");
+                return;
+            }
+            if let Err(e) = self.cache.borrow_mut().update_order(&order) {
+                error!("Cannot update order: {e}");
+            }
+            self.send_risk_event(&event);
+            self.publish_order_event(&event);
+        }
+
+        let passive_order = match PassiveOrderAny::try_from(order.clone()) {
+            Ok(passive_order) => passive_order,
+            Err(e) => {
+                error!("Cannot emulate order: {e}");
+                return;
+            }
+        };
+        if let Some(matching_core) = self.matching_cores.get_mut(&trigger_instrument_id) {
+            matching_core
+                .add_order(passive_order)
+                .expect("order side should be specified");
+        }
+
+        info!("Emulating {order:?}");
+    }
+
+    fn handle_submit_order_list(&mut self, command: SubmitOrderList) {
+        for order in command.order_list.orders {
+            let submit = SubmitOrder::new(
+                command.trader_id,
+                command.client_id,
+                command.strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                command.venue_order_id,
+                order,
+                command.exec_algorith_id,
+                command.position_id,
+                UUID4::new(),
+                command.ts_init,
+            )
+            .unwrap();
+            self.handle_submit_order(submit);
+        }
+    }
+
+    fn handle_modify_order(&mut self, command: &ModifyOrder) {
+        let Some(order) = self.cache.borrow().order(&command.client_order_id).cloned() else {
+            error!("Cannot modify order: {} not found", command.client_order_id);
+            return;
+        };
+
+        let trigger_instrument_id = trigger_instrument_id(&order);
+        if !self.matching_cores.contains_key(&trigger_instrument_id) {
+            error!(
+                "Cannot handle `ModifyOrder`: no matching core for trigger instrument {trigger_instrument_id}"
+            );
+            return;
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Updated(
+            OrderUpdated::new(
+                order.as_order().trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                command.quantity.unwrap_or(order.as_order().quantity()),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.as_order().venue_order_id(),
+                order.as_order().account_id(),
+                command.price.or(order.as_order().price()),
+                command.trigger_price.or(order.as_order().trigger_price()),
+            )
+            .unwrap(),
+        );
+        self.send_exec_event(&event);
+
+        // Replace the matching core order with the updated order
+        let Some(order) = self.cache.borrow().order(&command.client_order_id).cloned() else {
+            return;
+        };
+        let Some(matching_core) = self.matching_cores.get_mut(&trigger_instrument_id) else {
+            return;
+        };
+        if let Some(existing) = matching_core.get_order(order.client_order_id()).cloned() {
+            match PassiveOrderAny::try_from(order) {
+                Ok(passive_order) => {
+                    matching_core.delete_order(&existing).unwrap();
+                    matching_core
+                        .add_order(passive_order)
+                        .expect("order side should be specified");
+                }
+                Err(e) => error!("Cannot update emulated order: {e}"),
+            }
+        }
+        self.iterate_orders(&trigger_instrument_id);
+    }
+
+    fn handle_cancel_order(&mut self, command: CancelOrder) {
+        let Some(order) = self.cache.borrow().order(&command.client_order_id).cloned() else {
+            error!("Cannot cancel order: {} not found", command.client_order_id);
+            return;
+        };
+
+        let is_emulated = self
+            .matching_cores
+            .get(&trigger_instrument_id(&order))
+            .is_some_and(|core| core.order_exists(order.client_order_id()));
+
+        if !is_emulated && order.as_order().is_open() && !order.as_order().is_pending_cancel() {
+            // Order already released, so cancel with the venue
+            self.send_exec_command(TradingCommand::CancelOrder(command));
+        } else {
+            self.cancel_order(&order);
+        }
+    }
+
+    fn handle_cancel_all_orders(&mut self, command: &CancelAllOrders) {
+        // Orders are held by the matching core of their trigger instrument, which may differ
+        // from the order instrument, so every matching core is searched
+        let mut client_order_ids: Vec<ClientOrderId> = Vec::new();
+        for matching_core in self.matching_cores.values() {
+            let bids = matching_core.get_orders_bid().iter();
+            let asks = matching_core.get_orders_ask().iter();
+            match command.order_side {
+                OrderSide::Buy => {
+                    client_order_ids.extend(bids.map(GetClientOrderId::client_order_id))
+                }
+                OrderSide::Sell => {
+                    client_order_ids.extend(asks.map(GetClientOrderId::client_order_id))
+                }
+                OrderSide::NoOrderSide => {
+                    client_order_ids.extend(bids.chain(asks).map(GetClientOrderId::client_order_id))
+                }
+            }
+        }
+
+        for client_order_id in client_order_ids {
+            let order = self.cache.borrow().order(&client_order_id).cloned();
+            if let Some(order) = order {
+                if order.instrument_id() == command.instrument_id {
+                    self.cancel_order(&order);
+                }
+            }
+        }
+    }
+
+    // -- INTERNAL --------------------------------------------------------------------------------
+
+    fn cancel_order(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        if self.config.debug {
+            debug!("Canceling order {client_order_id}");
+        }
+
+        self.submit_order_commands.remove(&client_order_id);
+        self.delete_from_matching_core(order);
+        self.cache
+            .borrow_mut()
+            .update_order_pending_cancel_local(order);
+
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Canceled(
+            OrderCanceled::new(
+                order.as_order().trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.as_order().venue_order_id(),
+                order.as_order().account_id(),
+            )
+            .unwrap(),
+        );
+        self.send_exec_event(&event);
+    }
+
+    fn trigger_order(&mut self, trigger_instrument_id: &InstrumentId, order: &PassiveOrderAny) {
+        match order {
+            PassiveOrderAny::Limit(_)
+            | PassiveOrderAny::Stop(
+                StopOrderAny::StopMarket(_)
+                | StopOrderAny::MarketIfTouched(_)
+                | StopOrderAny::TrailingStopMarket(_),
+            ) => self.release_order(trigger_instrument_id, order, false),
+            PassiveOrderAny::Stop(
+                StopOrderAny::StopLimit(_)
+                | StopOrderAny::LimitIfTouched(_)
+                | StopOrderAny::TrailingStopLimit(_),
+            ) => self.release_order(trigger_instrument_id, order, true),
+        }
+    }
+
+    /// Releases the triggered `order`, transforming it into a limit order if `as_limit`,
+    /// otherwise into a market order.
+    fn release_order(
+        &mut self,
+        trigger_instrument_id: &InstrumentId,
+        order: &PassiveOrderAny,
+        as_limit: bool,
+    ) {
+        let client_order_id = order.client_order_id();
+        let Some(command) = self.submit_order_commands.get(&client_order_id) else {
+            return; // Order already released
+        };
+
+        let Some(matching_core) = self.matching_cores.get_mut(trigger_instrument_id) else {
+            return;
+        };
+        let released_price = match command.order.as_order().side() {
+            OrderSide::Buy => matching_core.ask,
+            _ => matching_core.bid,
+        };
+        let Some(released_price) = released_price else {
+            // Keep emulating until a market is available to release into
+            error!("Cannot release order {client_order_id}: no market for {trigger_instrument_id}");
+            return;
+        };
+
+        if matching_core.order_exists(client_order_id) {
+            matching_core.delete_order(order).unwrap();
+        }
+        // SAFETY: The command was checked to exist above
+        let mut command = self.submit_order_commands.remove(&client_order_id).unwrap();
+
+        let original = self
+            .cache
+            .borrow()
+            .order(&client_order_id)
+            .cloned()
+            .unwrap_or_else(|| command.order.clone());
+        let ts_now = self.clock.get_time_ns();
+        let transformed = if as_limit {
+            LimitOrder::transform(&original, ts_now, None).map(OrderAny::Limit)
+        } else {
+            MarketOrder::transform(&original, ts_now).map(OrderAny::Market)
+        };
+        let mut transformed = match transformed {
+            Ok(transformed) => transformed,
+            Err(e) => {
+                error!("Cannot release order {client_order_id}: {e}");
+                return;
+            }
+        };
+
+        if let Err(e) = self.cache.borrow_mut().add_order(
+            transformed.clone(),
+            command.position_id,
+            Some(command.client_id),
+            true,
+        ) {
+            error!("Cannot release order {client_order_id}: {e}");
+            return;
+        }
+        self.publish_order_event(transformed.as_order().last_event());
+
+        let event = OrderEventAny::Released(
+            OrderReleased::new(
+                transformed.as_order().trader_id(),
+                transformed.strategy_id(),
+                transformed.instrument_id(),
+                client_order_id,
+                released_price,
+                UUID4::new(),
+                ts_now,
+                ts_now,
+            )
+            .unwrap(),
+        );
+        if let Err(e) = transformed.apply(event.clone()) {
+            error!("Cannot release order {client_order_id}: {e}");
+            return;
+        }
+        if let Err(e) = self.cache.borrow_mut().update_order(&transformed) {
+            error!("Cannot update order: {e}");
+        }
+        self.send_risk_event(&event);
+
+        info!("Releasing {transformed:?}");
+        self.publish_order_event(&event);
+
+        let exec_algorithm_id = transformed.as_order().exec_algorithm_id();
+        command.order = transformed;
+        match exec_algorithm_id {
+            Some(exec_algorithm_id) => {
+                let endpoint = format!("{exec_algorithm_id}.execute");
+                self.msgbus
+                    .borrow()
+                    .send(&endpoint, &TradingCommand::SubmitOrder(command));
+            }
+            None => self.send_exec_command(TradingCommand::SubmitOrder(command)),
+        }
+    }
+
+    fn iterate_orders(&mut self, trigger_instrument_id: &InstrumentId) {
+        let Some(matching_core) = self.matching_cores.get(trigger_instrument_id) else {
+            return;
+        };
+
+        // Manage trailing stops
+        let trailing_orders: Vec<PassiveOrderAny> = matching_core
+            .get_orders_bid()
+            .iter()
+            .chain(matching_core.get_orders_ask())
+            .filter(|order| {
+                matches!(
+                    order,
+                    PassiveOrderAny::Stop(
+                        StopOrderAny::TrailingStopMarket(_) | StopOrderAny::TrailingStopLimit(_)
+                    )
+                )
+            })
+            .cloned()
+            .collect();
+        for order in &trailing_orders {
+            self.update_trailing_stop_order(trigger_instrument_id, order);
+        }
+
+        let Some(matching_core) = self.matching_cores.get(trigger_instrument_id) else {
+            return;
+        };
+
+        let triggered: Vec<PassiveOrderAny> = matching_core
+            .get_orders_bid()
+            .iter()
+            .chain(matching_core.get_orders_ask())
+            .filter(|order| is_core_matched(matching_core, order))
+            .cloned()
+            .collect();
+
+        for order in &triggered {
+            self.trigger_order(trigger_instrument_id, order);
+        }
+    }
+
+    fn update_trailing_stop_order(
+        &mut self,
+        trigger_instrument_id: &InstrumentId,
+        order: &PassiveOrderAny,
+    ) {
+        let client_order_id = order.client_order_id();
+        let Some(mut order) = self.cache.borrow().order(&client_order_id).cloned() else {
+            return;
+        };
+        let Some(matching_core) = self.matching_cores.get(trigger_instrument_id) else {
+            return;
+        };
+
+        let (bid, ask, last) = {
+            let cache = self.cache.borrow();
+            let quote = cache.quote_tick(trigger_instrument_id);
+            let trade = cache.trade_tick(trigger_instrument_id);
+            (
+                matching_core.bid.or(quote.map(|q| q.bid_price)),
+                matching_core.ask.or(quote.map(|q| q.ask_price)),
+                matching_core.last.or(trade.map(|t| t.price)),
+            )
+        };
+
+        let price_tiers = self
+            .config
+            .price_tiers
+            .get(trigger_instrument_id)
+            .map_or(&[][..], Vec::as_slice);
+        let (new_trigger_price, new_price) = match trailing_stop_calculate(
+            matching_core.price_increment,
+            price_tiers,
+            &order,
+            bid,
+            ask,
+            last,
+            matching_core.mark,
+        ) {
+            Ok(output) => output,
+            Err(e) => {
+                warn!("Cannot calculate trailing stop order: {e}");
+                return;
+            }
+        };
+
+        if new_trigger_price.is_none() && new_price.is_none() {
+            return; // No updates
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        let event = OrderEventAny::Updated(
+            OrderUpdated::new(
+                order.as_order().trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                order.as_order().quantity(),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                None, // Not yet assigned by any venue
+                order.as_order().account_id(),
+                new_price,
+                new_trigger_price,
+            )
+            .unwrap(),
+        );
+        if let Err(e) = order.apply(event.clone()) {
+            error!("Cannot update trailing stop order {client_order_id}: {e}");
+            return;
+        }
+        if let Err(e) = self.cache.borrow_mut().update_order(&order) {
+            error!("Cannot update order: {e}");
+        }
+
+        if let Some(matching_core) = self.matching_cores.get_mut(trigger_instrument_id) {
+            if let Some(resting_order) = matching_core.get_order_mut(client_order_id) {
+                match PassiveOrderAny::try_from(order) {
+                    Ok(passive_order) => *resting_order = passive_order,
+                    Err(e) => error!("Cannot update trailing stop order {client_order_id}: {e}"),
+                }
+            }
+        }
+
+        self.send_risk_event(&event);
+        self.publish_order_event(&event);
+    }
+
+    fn is_matched(&self, trigger_instrument_id: &InstrumentId, order: &PassiveOrderAny) -> bool {
+        self.matching_cores
+            .get(trigger_instrument_id)
+            .is_some_and(|matching_core| is_core_matched(matching_core, order))
+    }
+
+    fn update_last(&mut self, instrument_id: InstrumentId, last: Price) {
+        let has_quotes = self.subscribed_quotes.contains(&instrument_id);
+        let Some(matching_core) = self.matching_cores.get_mut(&instrument_id) else {
+            return;
+        };
+
+        matching_core.last = Some(last);
+        if !has_quotes {
+            // No quotes for the instrument, so the last price is the best available market
+            matching_core.bid = Some(last);
+            matching_core.ask = Some(last);
+        }
+        self.iterate_orders(&instrument_id);
+    }
+
+    fn delete_from_matching_core(&mut self, order: &OrderAny) {
+        let Some(matching_core) = self.matching_cores.get_mut(&trigger_instrument_id(order)) else {
+            return;
+        };
+        if let Some(existing) = matching_core.get_order(order.client_order_id()).cloned() {
+            matching_core.delete_order(&existing).unwrap();
+        }
+    }
+
+    fn subscribe_trigger_feeds(&mut self, instrument_id: InstrumentId, trigger: TriggerType) {
+        // Synthetic instruments are priced from their components
+        let instrument_ids = match self.cache.borrow().synthetic(&instrument_id) {
+            Some(synthetic) => synthetic.components.clone(),
+            None => vec![instrument_id],
+        };
+
+        let subscriptions = match trigger {
+            TriggerType::LastTrade => &mut self.subscribed_trades,
+            _ => &mut self.subscribed_quotes,
+        };
+        for instrument_id in instrument_ids {
+            if subscriptions.insert(instrument_id) {
+                info!("Subscribed to {trigger} feed for {instrument_id}");
+            }
+        }
+    }
+
+    fn trigger_price_increment(&self, instrument_id: &InstrumentId) -> Option<Price> {
+        let cache = self.cache.borrow();
+        if instrument_id.is_synthetic() {
+            cache.synthetic(instrument_id).map(|s| s.price_increment)
+        } else {
+            cache.instrument(instrument_id).map(|i| i.price_increment())
+        }
+    }
+
+    fn synthetic_ids_for_component(&self, component_id: &InstrumentId) -> Vec<InstrumentId> {
+        let cache = self.cache.borrow();
+        self.matching_cores
+            .keys()
+            .filter(|instrument_id| instrument_id.is_synthetic())
+            .filter(|instrument_id| {
+                cache
+                    .synthetic(instrument_id)
+                    .is_some_and(|synthetic| synthetic.components.contains(component_id))
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Calculates the synthetic (bid, ask) prices from the (bid, ask) prices of each component
+    /// provided by `component_prices`, returning `None` if any component is not yet priced.
+    fn synthetic_prices<F>(
+        &self,
+        synthetic_id: &InstrumentId,
+        component_prices: F,
+    ) -> Option<(Price, Price)>
+    where
+        F: Fn(&InstrumentId) -> Option<(Price, Price)>,
+    {
+        let mut synthetic = self.cache.borrow().synthetic(synthetic_id).cloned()?;
+
+        let mut bids = Vec::with_capacity(synthetic.components.len());
+        let mut asks = Vec::with_capacity(synthetic.components.len());
+        for component in &synthetic.components {
+            let (bid, ask) = component_prices(component)?;
+            bids.push(bid.as_f64());
+            asks.push(ask.as_f64());
+        }
+
+        match (synthetic.calculate(&bids), synthetic.calculate(&asks)) {
+            (Ok(bid), Ok(ask)) => Some((bid, ask)),
+            (Err(e), _) | (_, Err(e)) => {
+                error!("Cannot calculate synthetic {synthetic_id} prices: {e}");
+                None
+            }
+        }
+    }
+
+    fn publish_order_event(&self, event: &OrderEventAny) {
+        let topic = format!("events.order.{}", event.strategy_id());
+        self.msgbus.borrow().publish(&topic, event);
+    }
+
+    fn send_risk_event(&self, event: &OrderEventAny) {
+        self.msgbus.borrow().send(RISK_ENGINE_PROCESS, event);
+    }
+
+    fn send_exec_event(&self, event: &OrderEventAny) {
+        self.msgbus.borrow().send(EXEC_ENGINE_PROCESS, event);
+    }
+
+    fn send_exec_command(&self, command: TradingCommand) {
+        self.msgbus.borrow().send(EXEC_ENGINE_EXECUTE, &command);
+    }
+}
+
+/// Returns the instrument ID which triggers the `order`.
+fn trigger_instrument_id(order: &OrderAny) -> InstrumentId {
+    order
+        .as_order()
+        .trigger_instrument_id()
+        .unwrap_or_else(|| order.instrument_id())
+}
+
+fn is_core_matched(matching_core: &OrderMatchingCore, order: &PassiveOrderAny) -> bool {
+    match order {
+        PassiveOrderAny::Limit(order @ LimitOrderAny::Limit(_)) => {
+            matching_core.is_limit_matched(order)
+        }
+        PassiveOrderAny::Limit(_) => false,
+        PassiveOrderAny::Stop(order) => matching_core.is_stop_matched(order),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, rc::Rc};
+
+    use nautilus_common::{cache::Cache, handlers::AnyMessageHandler, msgbus::MessageBus};
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+    use nautilus_model::{
+        data::{quote::QuoteTick, trade::TradeTick},
+        enums::{
+            AggressorSide, OrderSide, OrderStatus, OrderType, TimeInForce, TrailingOffsetType,
+            TriggerType,
+        },
+        events::order::{canceled::OrderCanceled, event::OrderEventAny},
+        identifiers::{
+            client_id::ClientId,
+            client_order_id::ClientOrderId,
+            instrument_id::InstrumentId,
+            stubs::{strategy_id_ema_cross, trader_id},
+            trade_id::TradeId,
+            venue_order_id::VenueOrderId,
+        },
+        instruments::{any::InstrumentAny, stubs::audusd_sim, synthetic::SyntheticInstrument},
+        orders::{
+            any::OrderAny, stop_limit::StopLimitOrder, stop_market::StopMarketOrder,
+            stubs::TestOrderStubs,
+        },
+        polymorphism::{ApplyOrderEventAny, GetClientOrderId, GetInstrumentId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{
+        OrderEmulator, OrderEmulatorConfig, EXEC_ENGINE_EXECUTE, EXEC_ENGINE_PROCESS,
+        RISK_ENGINE_PROCESS,
+    };
+    use crate::messages::{
+        cancel::CancelOrder, cancel_all::CancelAllOrders, modify::ModifyOrder, submit::SubmitOrder,
+        TradingCommand,
+    };
+
+    type Messages<T> = Rc<RefCell<Vec<T>>>;
+
+    struct TestContext {
+        emulator: OrderEmulator,
+        cache: Rc<RefCell<Cache>>,
+        commands: Messages<TradingCommand>,
+        exec_events: Messages<OrderEventAny>,
+        risk_events: Messages<OrderEventAny>,
+    }
+
+    impl TestContext {
+        fn order(&self, client_order_id: &str) -> OrderAny {
+            self.cache
+                .borrow()
+                .order(&ClientOrderId::from(client_order_id))
+                .cloned()
+                .unwrap()
+        }
+    }
+
+    fn register<T: Clone + 'static>(
+        msgbus: &Rc<RefCell<MessageBus>>,
+        endpoint: &str,
+    ) -> Messages<T> {
+        let messages: Messages<T> = Rc::new(RefCell::new(Vec::new()));
+        let messages_clone = messages.clone();
+        let handler = AnyMessageHandler::new(Ustr::from(endpoint), move |msg: &dyn Any| {
+            messages_clone
+                .borrow_mut()
+                .push(msg.downcast_ref::<T>().unwrap().clone());
+        });
+        msgbus.borrow_mut().register_any(endpoint, handler);
+        messages
+    }
+
+    #[fixture]
+    fn context() -> TestContext {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap(),
+        ));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_synthetic(SyntheticInstrument::default())
+            .unwrap();
+
+        let commands = register(&msgbus, EXEC_ENGINE_EXECUTE);
+        let risk_events = register(&msgbus, RISK_ENGINE_PROCESS);
+
+        // Apply execution events to the cached orders, as the execution engine would
+        let exec_events: Messages<OrderEventAny> = Rc::new(RefCell::new(Vec::new()));
+        let exec_events_clone = exec_events.clone();
+        let cache_clone = cache.clone();
+        let handler =
+            AnyMessageHandler::new(Ustr::from(EXEC_ENGINE_PROCESS), move |msg: &dyn Any| {
+                let event = msg.downcast_ref::<OrderEventAny>().unwrap().clone();
+                let mut cache = cache_clone.borrow_mut();
+                let mut order = cache.order(&event.client_order_id()).cloned().unwrap();
+                order.apply(event.clone()).unwrap();
+                cache.update_order(&order).unwrap();
+                exec_events_clone.borrow_mut().push(event);
+            });
+        msgbus
+            .borrow_mut()
+            .register_any(EXEC_ENGINE_PROCESS, handler);
+
+        let emulator =
+            OrderEmulator::new(clock, msgbus, cache.clone(), OrderEmulatorConfig::default());
+
+        TestContext {
+            emulator,
+            cache,
+            commands,
+            exec_events,
+            risk_events,
+        }
+    }
+
+    fn stop_market_order(
+        side: OrderSide,
+        trigger_price: &str,
+        emulation_trigger: TriggerType,
+        trigger_instrument_id: Option<InstrumentId>,
+        client_order_id: &str,
+    ) -> OrderAny {
+        OrderAny::StopMarket(
+            StopMarketOrder::new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                audusd_sim().id,
+                ClientOrderId::from(client_order_id),
+                side,
+                Quantity::from(100_000),
+                Price::from(trigger_price),
+                TriggerType::Default,
+                TimeInForce::Gtc,
+                None,
+                false,
+                false,
+                None,
+                Some(emulation_trigger),
+                trigger_instrument_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn stop_limit_order(
+        side: OrderSide,
+        price: &str,
+        trigger_price: &str,
+        client_order_id: &str,
+    ) -> OrderAny {
+        OrderAny::StopLimit(
+            StopLimitOrder::new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                audusd_sim().id,
+                ClientOrderId::from(client_order_id),
+                side,
+                Quantity::from(100_000),
+                Price::from(price),
+                Price::from(trigger_price),
+                TriggerType::Default,
+                TimeInForce::Gtc,
+                None,
+                false,
+                false,
+                false,
+                None,
+                Some(TriggerType::BidAsk),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submit(order: OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                trader_id(),
+                ClientId::from("SIM"),
+                strategy_id_ema_cross(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(100_000),
+            Quantity::from(100_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    fn trade(instrument_id: InstrumentId, price: &str) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from(100_000),
+            AggressorSide::Buyer,
+            TradeId::from("T-1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn released_orders(context: &TestContext) -> Vec<OrderAny> {
+        context
+            .commands
+            .borrow()
+            .iter()
+            .filter_map(|command| match command {
+                TradingCommand::SubmitOrder(command) => Some(command.order.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_submit_order_emulates_order(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+
+        context.emulator.execute(submit(order));
+
+        let instrument_id = audusd_sim().id;
+        let matching_core = context.emulator.get_matching_core(&instrument_id).unwrap();
+        assert!(matching_core.order_exists(ClientOrderId::from("O-1")));
+        assert_eq!(
+            context.order("O-1").as_order().status(),
+            OrderStatus::Emulated
+        );
+        assert!(context
+            .cache
+            .borrow()
+            .is_order_emulated(&ClientOrderId::from("O-1")));
+        assert_eq!(context.emulator.subscribed_quotes(), vec![instrument_id]);
+        assert!(context.emulator.subscribed_trades().is_empty());
+        assert_eq!(context.emulator.get_submit_order_commands().len(), 1);
+        assert!(matches!(
+            context.risk_events.borrow()[0],
+            OrderEventAny::Emulated(_)
+        ));
+        assert!(context.commands.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_quote_not_triggering_keeps_emulating(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order));
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.90010", "0.90020"));
+
+        assert!(context.commands.borrow().is_empty());
+        assert_eq!(
+            context.order("O-1").as_order().status(),
+            OrderStatus::Emulated
+        );
+    }
+
+    #[rstest]
+    fn test_triggered_stop_market_order_released_as_market_order(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order));
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.89990", "0.90000"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].as_order().order_type(), OrderType::Market);
+        assert_eq!(released[0].as_order().status(), OrderStatus::Released);
+        assert_eq!(context.order("O-1"), released[0]);
+        assert!(!context
+            .cache
+            .borrow()
+            .is_order_emulated(&ClientOrderId::from("O-1")));
+        match context.risk_events.borrow().last().unwrap() {
+            OrderEventAny::Released(event) => {
+                assert_eq!(event.released_price, Price::from("0.89990"));
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+        let matching_core = context
+            .emulator
+            .get_matching_core(&audusd_sim().id)
+            .unwrap();
+        assert!(!matching_core.order_exists(ClientOrderId::from("O-1")));
+        assert!(context.emulator.get_submit_order_commands().is_empty());
+    }
+
+    #[rstest]
+    fn test_triggered_stop_limit_order_released_as_limit_order(mut context: TestContext) {
+        let order = stop_limit_order(OrderSide::Buy, "1.00010", "1.00000", "O-1");
+        context.emulator.execute(submit(order));
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.99990", "1.00000"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].as_order().order_type(), OrderType::Limit);
+        assert_eq!(released[0].as_order().price(), Some(Price::from("1.00010")));
+        assert_eq!(released[0].as_order().emulation_trigger(), None);
+    }
+
+    #[rstest]
+    fn test_last_trade_trigger_released_on_trade(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Buy,
+            "1.00000",
+            TriggerType::LastTrade,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order));
+
+        assert_eq!(context.emulator.subscribed_trades(), vec![audusd_sim().id]);
+        context
+            .emulator
+            .on_trade_tick(&trade(audusd_sim().id, "1.00000"));
+
+        assert_eq!(released_orders(&context).len(), 1);
+    }
+
+    #[rstest]
+    fn test_triggered_order_without_market_keeps_emulating(mut context: TestContext) {
+        // Subscribes to quotes, so trades no longer stand in for the market
+        let order1 = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-2",
+        );
+        context.emulator.execute(submit(order1));
+        let order2 = stop_market_order(
+            OrderSide::Buy,
+            "1.00000",
+            TriggerType::LastTrade,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order2));
+
+        context
+            .emulator
+            .on_trade_tick(&trade(audusd_sim().id, "1.00000"));
+
+        assert!(released_orders(&context).is_empty());
+        assert!(context
+            .emulator
+            .get_submit_order_commands()
+            .contains_key(&ClientOrderId::from("O-1")));
+        assert!(context
+            .emulator
+            .get_matching_core(&audusd_sim().id)
+            .unwrap()
+            .order_exists(ClientOrderId::from("O-1")));
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.99990", "1.00010"));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].client_order_id(), ClientOrderId::from("O-1"));
+        assert_eq!(context.emulator.get_submit_order_commands().len(), 1);
+    }
+
+    #[rstest]
+    fn test_submit_order_when_already_triggered_releases_immediately(mut context: TestContext) {
+        let order1 = stop_market_order(
+            OrderSide::Sell,
+            "0.80000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order1));
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.89990", "0.90000"));
+
+        let order2 = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-2",
+        );
+        context.emulator.execute(submit(order2));
+
+        let released = released_orders(&context);
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].client_order_id(), ClientOrderId::from("O-2"));
+        assert_eq!(context.risk_events.borrow().len(), 2); // Emulated O-1, Released O-2
+    }
+
+    #[rstest]
+    fn test_synthetic_trigger_instrument_priced_from_components(mut context: TestContext) {
+        let synthetic = SyntheticInstrument::default();
+        let order = stop_market_order(
+            OrderSide::Buy,
+            "1.00000",
+            TriggerType::Default,
+            Some(synthetic.id),
+            "O-1",
+        );
+        let order = match order {
+            OrderAny::StopMarket(mut order) => {
+                order.trigger_price = Price::from("150.00");
+                OrderAny::StopMarket(order)
+            }
+            _ => unreachable!(),
+        };
+        context.emulator.execute(submit(order));
+
+        assert!(context.emulator.get_matching_core(&synthetic.id).is_some());
+        assert_eq!(context.emulator.subscribed_quotes(), synthetic.components);
+
+        let btc = synthetic.components[0];
+        let ltc = synthetic.components[1];
+        let btc_quote = quote(btc, "99.00", "100.00");
+        context.cache.borrow_mut().add_quote(btc_quote).unwrap();
+        context.emulator.on_quote_tick(&btc_quote);
+        assert!(released_orders(&context).is_empty()); // LTC not yet priced
+
+        context
+            .emulator
+            .on_quote_tick(&quote(ltc, "199.00", "200.00"));
+
+        let matching_core = context.emulator.get_matching_core(&synthetic.id).unwrap();
+        assert_eq!(matching_core.bid, Some(Price::from("149.00")));
+        assert_eq!(matching_core.ask, Some(Price::from("150.00")));
+        assert_eq!(released_orders(&context).len(), 1);
+    }
+
+    #[rstest]
+    fn test_cancel_order_cancels_emulated_order(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order.clone()));
+
+        context
+            .emulator
+            .execute(TradingCommand::CancelOrder(CancelOrder {
+                trader_id: trader_id(),
+                client_id: ClientId::from("SIM"),
+                strategy_id: strategy_id_ema_cross(),
+                instrument_id: order.instrument_id(),
+                client_order_id: order.client_order_id(),
+                venue_order_id: VenueOrderId::default(),
+                command_id: UUID4::new(),
+                ts_init: UnixNanos::default(),
+            }));
+
+        assert!(matches!(
+            context.exec_events.borrow()[0],
+            OrderEventAny::Canceled(_)
+        ));
+        assert_eq!(
+            context.order("O-1").as_order().status(),
+            OrderStatus::Canceled
+        );
+        let matching_core = context
+            .emulator
+            .get_matching_core(&audusd_sim().id)
+            .unwrap();
+        assert!(!matching_core.order_exists(ClientOrderId::from("O-1")));
+        assert!(context.emulator.get_submit_order_commands().is_empty());
+        assert!(context.commands.borrow().is_empty());
+    }
+
+    #[rstest]
+    #[case(OrderSide::NoOrderSide, 2)]
+    #[case(OrderSide::Buy, 1)]
+    fn test_cancel_all_orders(
+        mut context: TestContext,
+        #[case] order_side: OrderSide,
+        #[case] expected_canceled: usize,
+    ) {
+        let order1 =
+            stop_market_order(OrderSide::Buy, "1.00000", TriggerType::Default, None, "O-1");
+        let order2 = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-2",
+        );
+        context.emulator.execute(submit(order1));
+        context.emulator.execute(submit(order2));
+
+        context
+            .emulator
+            .execute(TradingCommand::CancelAllOrders(CancelAllOrders {
+                trader_id: trader_id(),
+                client_id: ClientId::from("SIM"),
+                strategy_id: strategy_id_ema_cross(),
+                instrument_id: audusd_sim().id,
+                order_side,
+                command_id: UUID4::new(),
+                ts_init: UnixNanos::default(),
+            }));
+
+        assert_eq!(context.exec_events.borrow().len(), expected_canceled);
+    }
+
+    #[rstest]
+    fn test_cancel_all_orders_with_other_trigger_instrument(mut context: TestContext) {
+        let synthetic = SyntheticInstrument::default();
+        let order1 =
+            stop_market_order(OrderSide::Buy, "1.00000", TriggerType::Default, None, "O-1");
+        let order2 = stop_market_order(
+            OrderSide::Buy,
+            "1.00000",
+            TriggerType::Default,
+            Some(synthetic.id),
+            "O-2",
+        );
+        context.emulator.execute(submit(order1));
+        context.emulator.execute(submit(order2));
+
+        context
+            .emulator
+            .execute(TradingCommand::CancelAllOrders(CancelAllOrders {
+                trader_id: trader_id(),
+                client_id: ClientId::from("SIM"),
+                strategy_id: strategy_id_ema_cross(),
+                instrument_id: audusd_sim().id,
+                order_side: OrderSide::NoOrderSide,
+                command_id: UUID4::new(),
+                ts_init: UnixNanos::default(),
+            }));
+
+        assert_eq!(context.exec_events.borrow().len(), 2);
+        assert!(!context
+            .emulator
+            .get_matching_core(&synthetic.id)
+            .unwrap()
+            .order_exists(ClientOrderId::from("O-2")));
+    }
+
+    #[rstest]
+    fn test_reset_clears_subscriptions(mut context: TestContext) {
+        let order1 =
+            stop_market_order(OrderSide::Buy, "1.00000", TriggerType::Default, None, "O-1");
+        let order2 = stop_market_order(
+            OrderSide::Buy,
+            "1.00000",
+            TriggerType::LastTrade,
+            None,
+            "O-2",
+        );
+        context.emulator.execute(submit(order1));
+        context.emulator.execute(submit(order2));
+
+        context.emulator.reset();
+
+        assert!(context.emulator.subscribed_quotes().is_empty());
+        assert!(context.emulator.subscribed_trades().is_empty());
+        assert!(context.emulator.get_submit_order_commands().is_empty());
+    }
+
+    #[rstest]
+    fn test_modify_order_updates_trigger_price(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order.clone()));
+
+        context
+            .emulator
+            .execute(TradingCommand::ModifyOrder(ModifyOrder {
+                trader_id: trader_id(),
+                client_id: ClientId::from("SIM"),
+                strategy_id: strategy_id_ema_cross(),
+                instrument_id: order.instrument_id(),
+                client_order_id: order.client_order_id(),
+                venue_order_id: VenueOrderId::default(),
+                quantity: None,
+                price: None,
+                trigger_price: Some(Price::from("0.95000")),
+                command_id: UUID4::new(),
+                ts_init: UnixNanos::default(),
+            }));
+
+        assert!(matches!(
+            context.exec_events.borrow()[0],
+            OrderEventAny::Updated(_)
+        ));
+        assert_eq!(
+            context.order("O-1").as_order().trigger_price(),
+            Some(Price::from("0.95000"))
+        );
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.94990", "0.95000"));
+
+        assert_eq!(released_orders(&context).len(), 1);
+    }
+
+    #[rstest]
+    fn test_submit_order_with_unsupported_trigger_cancels_order(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::MarkPrice,
+            None,
+            "O-1",
+        );
+
+        context.emulator.execute(submit(order));
+
+        assert_eq!(
+            context.order("O-1").as_order().status(),
+            OrderStatus::Canceled
+        );
+        assert!(context.emulator.get_submit_order_commands().is_empty());
+    }
+
+    #[rstest]
+    fn test_on_event_removes_closed_order(mut context: TestContext) {
+        let order = stop_market_order(
+            OrderSide::Sell,
+            "0.90000",
+            TriggerType::Default,
+            None,
+            "O-1",
+        );
+        context.emulator.execute(submit(order));
+
+        // Order closed externally (e.g. canceled by the strategy)
+        let mut order = context.order("O-1");
+        let event = OrderEventAny::Canceled(
+            OrderCanceled::new(
+                trader_id(),
+                strategy_id_ema_cross(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                false,
+                None,
+                None,
+            )
+            .unwrap(),
+        );
+        order.apply(event.clone()).unwrap();
+        context.cache.borrow_mut().update_order(&order).unwrap();
+
+        context.emulator.on_event(&event);
+
+        let matching_core = context
+            .emulator
+            .get_matching_core(&audusd_sim().id)
+            .unwrap();
+        assert!(!matching_core.order_exists(ClientOrderId::from("O-1")));
+        assert!(context.emulator.get_submit_order_commands().is_empty());
+        assert_eq!(context.emulator.event_count, 1);
+    }
+
+    #[rstest]
+    fn test_trailing_stop_order_trails_market_then_released(mut context: TestContext) {
+        let mut order = TestOrderStubs::trailing_stop_market_order(
+            audusd_sim().id,
+            OrderSide::Sell,
+            Price::from("0.90000"),
+            Price::from("0.00050"),
+            TrailingOffsetType::Price,
+            Quantity::from(100_000),
+            Some(TriggerType::BidAsk),
+            Some(ClientOrderId::from("O-1")),
+        );
+        order.emulation_trigger = Some(TriggerType::BidAsk);
+        context
+            .emulator
+            .execute(submit(OrderAny::TrailingStopMarket(order)));
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.91000", "0.91010"));
+
+        assert!(matches!(
+            context.risk_events.borrow().last().unwrap(),
+            OrderEventAny::Updated(_)
+        ));
+        assert_eq!(
+            context.order("O-1").as_order().trigger_price(),
+            Some(Price::from("0.90950"))
+        );
+
+        // Market moving against the stop does not loosen the trigger price
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.90980", "0.90990"));
+
+        assert_eq!(
+            context.order("O-1").as_order().trigger_price(),
+            Some(Price::from("0.90950"))
+        );
+        assert!(released_orders(&context).is_empty());
+
+        context
+            .emulator
+            .on_quote_tick(&quote(audusd_sim().id, "0.90940", "0.90950"));
+
+        assert_eq!(released_orders(&context).len(), 1);
+    }
+}
@@ -15,25 +15,64 @@
 
 //! Provides a generic `ExecutionEngine` for backtesting and live environments.
 
-// Under development
-#![allow(dead_code)]
-#![allow(unused_variables)]
-
-use std::collections::{HashMap, HashSet};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+    rc::Rc,
+    time::Instant,
+};
 
-use log::debug;
-use nautilus_common::{cache::Cache, generators::position_id::PositionIdGenerator};
+use log::{debug, error, info, warn};
+use nautilus_common::{
+    cache::Cache,
+    generators::position_id::PositionIdGenerator,
+    handlers::AnyMessageHandler,
+    msgbus::{
+        endpoints::{
+            EXEC_ENGINE_EXECUTE, EXEC_ENGINE_PROCESS, EXEC_ENGINE_RECONCILE_MASS_STATUS,
+            EXEC_ENGINE_RECONCILE_REPORT,
+        },
+        MessageBus,
+    },
+};
+use nautilus_core::{
+    correctness::{check_key_in_map, check_key_not_in_map},
+    time::AtomicTime,
+    uuid::UUID4,
+};
 use nautilus_model::{
-    enums::{OmsType, OrderSide},
-    events::order::{event::OrderEventAny, filled::OrderFilled},
+    enums::{
+        ContingencyType, LiquiditySide, OmsType, OrderSide, OrderStatus, OrderType, PositionSide,
+        TimeInForce,
+    },
+    events::{
+        order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, denied::OrderDenied,
+            event::OrderEventAny, expired::OrderExpired, filled::OrderFilled,
+            initialized::OrderInitialized, rejected::OrderRejected, triggered::OrderTriggered,
+            updated::OrderUpdated,
+        },
+        position::{
+            changed::PositionChanged, closed::PositionClosed, opened::PositionOpened, PositionEvent,
+        },
+    },
     identifiers::{
-        client_id::ClientId, instrument_id::InstrumentId, strategy_id::StrategyId, venue::Venue,
+        client_id::ClientId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, strategy_id::StrategyId, trade_id::TradeId, venue::Venue,
     },
     instruments::any::InstrumentAny,
     orders::any::OrderAny,
+    polymorphism::{
+        ApplyOrderEventAny, GetClientOrderId, GetExecAlgorithmId, GetExecSpawnId, GetInstrumentId,
+        GetPositionId, GetStrategyId, GetTraderId,
+    },
     position::Position,
-    types::quantity::Quantity,
+    types::{money::Money, price::Price, quantity::Quantity},
 };
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use ustr::Ustr;
 
 use crate::{
     client::ExecutionClient,
@@ -42,247 +81,2923 @@ use crate::{
         modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder, submit_list::SubmitOrderList,
         TradingCommand,
     },
+    reports::{
+        fill::FillReport, mass_status::ExecutionMassStatus, order::OrderStatusReport,
+        position::PositionStatusReport,
+    },
 };
 
+/// Configuration for `ExecutionEngine` instances.
+#[derive(Clone, Debug, Default)]
 pub struct ExecutionEngineConfig {
+    /// If debug mode is active (will provide extra debug logging).
     pub debug: bool,
+    /// If order state snapshots should be published (when the message bus has a backing).
+    pub snapshot_orders: bool,
+    /// If position state snapshots should be published (when the message bus has a backing).
+    pub snapshot_positions: bool,
+    /// If unclaimed order events with an EXTERNAL strategy ID should be filtered/dropped
+    /// during reconciliation.
+    pub filter_unclaimed_external_orders: bool,
+    /// If position status reports are filtered from reconciliation.
+    pub filter_position_reports: bool,
+}
+
+/// A message received on one of the execution engine message bus endpoints.
+#[allow(clippy::large_enum_variant)]
+enum ExecEngineMessage {
+    Command(TradingCommand),
+    Event(OrderEventAny),
+    OrderReport(OrderStatusReport),
+    FillReport(FillReport),
+    PositionReport(PositionStatusReport),
+    MassStatus(ExecutionMassStatus),
+}
+
+impl ExecEngineMessage {
+    fn from_any(message: &dyn Any) -> Option<Self> {
+        if let Some(command) = message.downcast_ref::<TradingCommand>() {
+            Some(Self::Command(command.clone()))
+        } else if let Some(event) = message.downcast_ref::<OrderEventAny>() {
+            Some(Self::Event(event.clone()))
+        } else if let Some(report) = message.downcast_ref::<OrderStatusReport>() {
+            Some(Self::OrderReport(report.clone()))
+        } else if let Some(report) = message.downcast_ref::<FillReport>() {
+            Some(Self::FillReport(report.clone()))
+        } else if let Some(report) = message.downcast_ref::<PositionStatusReport>() {
+            Some(Self::PositionReport(report.clone()))
+        } else {
+            message
+                .downcast_ref::<ExecutionMassStatus>()
+                .map(|mass_status| Self::MassStatus(mass_status.clone()))
+        }
+    }
 }
 
+/// Provides a high-performance generic execution engine, which routes trading commands to
+/// registered execution clients and processes the resulting order events.
+///
+/// Order events are applied to the cached orders, fills are applied to the cached positions
+/// per the applicable `OmsType`, and all resulting order and position events are then
+/// published on the message bus.
+///
+/// Messages sent to the engine endpoints are queued and handled in order once the engine is
+/// not already handling a message, so execution clients can generate events from within a
+/// command handler without re-entering the engine.
 pub struct ExecutionEngine {
     pub command_count: u64,
     pub event_count: u64,
     pub report_count: u64,
-    cache: &'static Cache,
-    default_client: Option<ExecutionClient>,
+    clock: &'static AtomicTime,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    default_client: Option<Box<dyn ExecutionClient>>,
     pos_id_generator: PositionIdGenerator,
-    clients: HashMap<ClientId, ExecutionClient>,
+    clients: HashMap<ClientId, Box<dyn ExecutionClient>>,
     routing_map: HashMap<Venue, ClientId>,
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
+    pending_messages: Rc<RefCell<VecDeque<ExecEngineMessage>>>,
     config: ExecutionEngineConfig,
 }
 
 impl ExecutionEngine {
+    /// Creates a new [`ExecutionEngine`] instance.
+    #[must_use]
+    pub fn new(
+        clock: &'static AtomicTime,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        config: ExecutionEngineConfig,
+    ) -> Self {
+        let trader_id = msgbus.borrow().trader_id;
+        Self {
+            command_count: 0,
+            event_count: 0,
+            report_count: 0,
+            clock,
+            msgbus,
+            cache,
+            default_client: None,
+            pos_id_generator: PositionIdGenerator::new(trader_id, clock),
+            clients: HashMap::new(),
+            routing_map: HashMap::new(),
+            oms_overrides: HashMap::new(),
+            external_order_claims: HashMap::new(),
+            pending_messages: Rc::new(RefCell::new(VecDeque::new())),
+            config,
+        }
+    }
+
     #[must_use]
     pub fn position_id_count(&self, strategy_id: StrategyId) -> u64 {
-        todo!();
+        self.pos_id_generator.count(strategy_id) as u64
     }
 
     #[must_use]
     pub fn check_integrity(&self) -> bool {
-        todo!();
+        self.cache.borrow_mut().check_integrity()
     }
 
     #[must_use]
     pub fn check_connected(&self) -> bool {
-        todo!();
+        self.clients.values().all(|client| client.is_connected())
     }
 
     #[must_use]
     pub fn check_disconnected(&self) -> bool {
-        todo!();
+        self.clients.values().all(|client| !client.is_connected())
     }
 
     #[must_use]
     pub fn check_residuals(&self) -> bool {
-        todo!();
+        self.cache.borrow().check_residuals()
+    }
+
+    #[must_use]
+    pub fn get_external_order_claim(&self, instrument_id: &InstrumentId) -> Option<StrategyId> {
+        self.external_order_claims.get(instrument_id).copied()
     }
 
     #[must_use]
     pub fn get_external_order_claims_instruments(&self) -> HashSet<InstrumentId> {
-        todo!();
+        self.external_order_claims.keys().copied().collect()
     }
 
     // -- REGISTRATION --------------------------------------------------------
 
-    pub fn register_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
-        todo!();
+    /// Registers the `engine` on the execution engine endpoints of its message bus.
+    ///
+    /// Each message is queued, then handled immediately unless the engine is already
+    /// handling a message, in which case it is handled once the current message completes.
+    pub fn register_endpoints(engine: &Rc<RefCell<Self>>) {
+        let (msgbus, pending_messages) = {
+            let engine = engine.borrow();
+            (engine.msgbus.clone(), engine.pending_messages.clone())
+        };
+
+        for endpoint in [
+            EXEC_ENGINE_EXECUTE,
+            EXEC_ENGINE_PROCESS,
+            EXEC_ENGINE_RECONCILE_REPORT,
+            EXEC_ENGINE_RECONCILE_MASS_STATUS,
+        ] {
+            // The message bus must not keep the engine alive, as the engine holds the bus
+            let engine = Rc::downgrade(engine);
+            let pending_messages = pending_messages.clone();
+            let handler = AnyMessageHandler::new(Ustr::from(endpoint), move |msg: &dyn Any| {
+                let Some(message) = ExecEngineMessage::from_any(msg) else {
+                    error!("Cannot handle message on {endpoint}: unrecognized message type");
+                    return;
+                };
+                pending_messages.borrow_mut().push_back(message);
+
+                let Some(engine) = engine.upgrade() else {
+                    return; // Engine dropped
+                };
+                // Otherwise the engine is already handling a message and will drain the queue
+                if let Ok(mut engine) = engine.try_borrow_mut() {
+                    engine.handle_pending_messages();
+                };
+            });
+            msgbus.borrow_mut().register_any(endpoint, handler);
+        }
+    }
+
+    /// Registers the given execution `client` with the engine, routing orders for the
+    /// clients venue to it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a client with the same ID is already registered.
+    pub fn register_client(&mut self, client: Box<dyn ExecutionClient>) -> anyhow::Result<()> {
+        check_key_not_in_map(&client.client_id(), &self.clients, "client_id", "clients")?;
+
+        info!(
+            "Registered ExecutionClient-{} for routing to {}",
+            client.client_id(),
+            client.venue()
+        );
+        self.routing_map.insert(client.venue(), client.client_id());
+        self.clients.insert(client.client_id(), client);
+        Ok(())
     }
 
-    pub fn register_default_client(&mut self, client: ExecutionClient) -> anyhow::Result<()> {
-        todo!();
+    /// Registers the given `client` as the default routing client (when a specific venue
+    /// routing cannot be found), overwriting any existing default client.
+    pub fn register_default_client(
+        &mut self,
+        client: Box<dyn ExecutionClient>,
+    ) -> anyhow::Result<()> {
+        info!(
+            "Registered ExecutionClient-{} for default routing",
+            client.client_id()
+        );
+        self.default_client = Some(client);
+        Ok(())
     }
 
+    /// Registers the client with the given `client_id` to route orders to the `venue`,
+    /// overwriting any existing routing for the venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no client with the `client_id` is registered.
     pub fn register_venue_routing(
         &mut self,
         client_id: ClientId,
         venue: Venue,
     ) -> anyhow::Result<()> {
-        todo!();
+        check_key_in_map(&client_id, &self.clients, "client_id", "clients")?;
+
+        self.routing_map.insert(venue, client_id);
+        info!("Registered ExecutionClient-{client_id} for routing to {venue}");
+        Ok(())
+    }
+
+    /// Registers the `oms_type` for the given strategy, overriding the venues native OMS type.
+    pub fn register_oms_type(&mut self, strategy_id: StrategyId, oms_type: OmsType) {
+        self.oms_overrides.insert(strategy_id, oms_type);
+        info!("Registered OMS.{oms_type} for Strategy {strategy_id}");
     }
 
-    // TODO: Implement `Strategy`
-    // pub fn register_external_order_claims(&mut self, strategy: Strategy) -> anyhow::Result<()> {
-    //     todo!();
-    // }
+    /// Registers the given strategy to claim any external orders for the `instrument_ids`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If an external order claim already exists for any of the `instrument_ids`.
+    pub fn register_external_order_claims(
+        &mut self,
+        strategy_id: StrategyId,
+        instrument_ids: HashSet<InstrumentId>,
+    ) -> anyhow::Result<()> {
+        for instrument_id in &instrument_ids {
+            if let Some(existing) = self.external_order_claims.get(instrument_id) {
+                anyhow::bail!(
+                    "External order claim for {instrument_id} already exists for {existing}"
+                );
+            }
+        }
+
+        for instrument_id in &instrument_ids {
+            self.external_order_claims
+                .insert(*instrument_id, strategy_id);
+        }
+
+        if !instrument_ids.is_empty() {
+            info!("Registered external order claims for {strategy_id}: {instrument_ids:?}");
+        }
+        Ok(())
+    }
 
+    /// Deregisters the execution client with the given `client_id`, along with any venue
+    /// routings to it.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no client with the `client_id` is registered.
     pub fn deregister_client(&mut self, client_id: ClientId) -> anyhow::Result<()> {
-        todo!();
+        check_key_in_map(&client_id, &self.clients, "client_id", "clients")?;
+
+        self.clients.remove(&client_id);
+        self.routing_map
+            .retain(|_, routed_id| *routed_id != client_id);
+        info!("Deregistered ExecutionClient-{client_id}");
+        Ok(())
     }
 
     // -- COMMANDS ------------------------------------------------------------
 
-    pub fn load_cache(&self) {
-        todo!();
+    /// Loads the cache up from the execution database, then sets the position ID counts.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If loading any of the caches from the database fails.
+    pub fn load_cache(&mut self) -> anyhow::Result<()> {
+        let start = Instant::now();
+
+        {
+            let mut cache = self.cache.borrow_mut();
+            cache.cache_general()?;
+            cache.cache_currencies()?;
+            cache.cache_instruments()?;
+            cache.cache_accounts()?;
+            cache.cache_orders()?;
+            cache.cache_positions()?;
+            cache.build_index();
+            let _ = cache.check_integrity();
+        }
+        self.set_position_id_counts();
+
+        info!("Loaded cache in {}ms", start.elapsed().as_millis());
+        Ok(())
     }
 
+    /// Flushes the execution database which permanently removes all persisted data.
     pub fn flush_db(&self) {
-        todo!();
+        if let Err(e) = self.cache.borrow().flush_db() {
+            error!("Error flushing database: {e}");
+        }
     }
 
     pub fn execute(&mut self, command: TradingCommand) {
         self.execute_command(command);
+        self.handle_pending_messages();
     }
 
-    pub fn process(&self, event: &OrderEventAny) {
-        todo!();
+    pub fn process(&mut self, event: &OrderEventAny) {
+        self.handle_event(event.clone());
+        self.handle_pending_messages();
     }
 
-    // -- COMMAND HANDLERS ----------------------------------------------------
+    /// Handles all queued messages, including any queued while handling them.
+    fn handle_pending_messages(&mut self) {
+        loop {
+            // The queue must not stay borrowed while handling, as handling may queue messages
+            let message = self.pending_messages.borrow_mut().pop_front();
+            let Some(message) = message else {
+                break;
+            };
 
-    fn execute_command(&mut self, command: TradingCommand) {
-        debug!("<--[CMD] {:?}", command); // TODO: Log constants
-        self.command_count += 1;
+            match message {
+                ExecEngineMessage::Command(command) => self.execute_command(command),
+                ExecEngineMessage::Event(event) => self.handle_event(event),
+                ExecEngineMessage::OrderReport(report) => {
+                    self.reconcile_order_report(report);
+                }
+                ExecEngineMessage::FillReport(report) => {
+                    self.reconcile_fill_report(report);
+                }
+                ExecEngineMessage::PositionReport(report) => {
+                    self.reconcile_position_report(report);
+                }
+                ExecEngineMessage::MassStatus(mass_status) => {
+                    self.reconcile_mass_status(mass_status);
+                }
+            }
+        }
+    }
 
-        // TODO: Refine getting the client (no need for two expects)
-        let client = if let Some(client) = self.clients.get(&command.client_id()) {
-            client
-        } else if let Some(client_id) = self.routing_map.get(&command.instrument_id().venue) {
-            if let Some(client) = self.clients.get(client_id) {
-                client
-            } else {
-                self.default_client.as_ref().expect("No client found")
+    // -- RECONCILIATION ------------------------------------------------------
+
+    /// Reconciles the given order status `report` with the cache, generating any inferred
+    /// order events (and an external order if the order is not known to the system).
+    ///
+    /// Returns whether reconciliation was successful.
+    pub fn reconcile_order_report(&mut self, mut report: OrderStatusReport) -> bool {
+        if self.config.debug {
+            debug!("<--[RPT] {report}"); // TODO: Log constants
+        }
+        self.report_count += 1;
+        info!("Reconciling {report}");
+
+        let result = self.reconcile_order(&mut report, &[]); // No fills to reconcile
+        self.publish_report(&report.instrument_id, &report);
+        result
+    }
+
+    /// Reconciles the given fill `report` with the cached order it belongs to.
+    ///
+    /// Returns whether reconciliation was successful.
+    pub fn reconcile_fill_report(&mut self, report: FillReport) -> bool {
+        if self.config.debug {
+            debug!("<--[RPT] {report}"); // TODO: Log constants
+        }
+        self.report_count += 1;
+        info!("Reconciling {report}");
+
+        let result = self.reconcile_fill_single(&report);
+        self.publish_report(&report.instrument_id, &report);
+        result
+    }
+
+    /// Reconciles the given position status `report` with the cached open positions.
+    ///
+    /// Returns whether reconciliation was successful.
+    pub fn reconcile_position_report(&mut self, report: PositionStatusReport) -> bool {
+        if self.config.debug {
+            debug!("<--[RPT] {report}"); // TODO: Log constants
+        }
+        self.report_count += 1;
+        info!("Reconciling {report}");
+
+        let result = self.reconcile_position(&report);
+        self.publish_report(&report.instrument_id, &report);
+        result
+    }
+
+    /// Reconciles all order, fill and position reports in the given `mass_status` so that
+    /// the cache converges to the state reported by the venue.
+    ///
+    /// Returns whether reconciliation of every report was successful.
+    pub fn reconcile_mass_status(&mut self, mass_status: ExecutionMassStatus) -> bool {
+        if self.config.debug {
+            debug!("<--[RPT] {mass_status}"); // TODO: Log constants
+        }
+        self.report_count += 1;
+        info!("Reconciling ExecutionMassStatus for {}", mass_status.venue);
+
+        let mut results = Vec::new();
+        let mut reconciled_orders: HashSet<ClientOrderId> = HashSet::new();
+        let mut reconciled_trades: HashSet<TradeId> = HashSet::new();
+
+        // Reconcile all reported orders
+        for (venue_order_id, order_report) in mass_status.order_reports() {
+            let fills = mass_status
+                .fill_reports()
+                .get(venue_order_id)
+                .map_or(&[][..], Vec::as_slice);
+
+            // Check for duplicate client order IDs
+            if let Some(client_order_id) = order_report.client_order_id {
+                if reconciled_orders.contains(&client_order_id) {
+                    error!("Duplicate {client_order_id} detected: {order_report}");
+                    continue; // Determine how to handle this
+                }
             }
-        } else {
-            self.default_client.as_ref().expect("No client found")
-        };
 
-        match command {
-            TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(client, cmd),
-            TradingCommand::SubmitOrderList(cmd) => self.handle_submit_order_list(client, cmd),
-            TradingCommand::ModifyOrder(cmd) => self.handle_modify_order(client, cmd),
-            TradingCommand::CancelOrder(cmd) => self.handle_cancel_order(client, cmd),
-            TradingCommand::CancelAllOrders(cmd) => self.handle_cancel_all_orders(client, cmd),
-            TradingCommand::BatchCancelOrders(cmd) => self.handle_batch_cancel_orders(client, cmd),
-            TradingCommand::QueryOrder(cmd) => self.handle_query_order(client, cmd),
+            // Check for duplicate trade IDs
+            for fill_report in fills {
+                if !reconciled_trades.insert(fill_report.trade_id) {
+                    warn!("Duplicate {} detected: {fill_report}", fill_report.trade_id);
+                }
+            }
+
+            let mut order_report = order_report.clone();
+            results.push(self.reconcile_order(&mut order_report, fills));
+            if let Some(client_order_id) = order_report.client_order_id {
+                reconciled_orders.insert(client_order_id);
+            }
+        }
+
+        // Reconcile all reported positions
+        if !self.config.filter_position_reports {
+            for position_report in mass_status.position_reports().values().flatten() {
+                results.push(self.reconcile_position(position_report));
+            }
         }
+
+        let topic = format!("reports.execution.{}", mass_status.venue);
+        self.msgbus.borrow().publish(&topic, &mass_status);
+
+        results.into_iter().all(|result| result)
     }
 
-    fn handle_submit_order(&self, client: &ExecutionClient, command: SubmitOrder) {
-        todo!();
+    fn reconcile_order(&mut self, report: &mut OrderStatusReport, fills: &[FillReport]) -> bool {
+        let client_order_id = match report.client_order_id {
+            Some(client_order_id) => client_order_id,
+            None => {
+                let cached_id = self
+                    .cache
+                    .borrow()
+                    .client_order_id(&report.venue_order_id)
+                    .copied();
+                // Generate an external client order ID if the order is not known
+                cached_id.unwrap_or_else(|| {
+                    ClientOrderId::new(format!("O-{}", UUID4::new()).as_str()).unwrap()
+                })
+            }
+        };
+        report.client_order_id = Some(client_order_id);
+        info!("Reconciling order for {client_order_id}");
+
+        let order = match self.cached_order(&client_order_id) {
+            Some(order) => order,
+            None => {
+                let order = match self.generate_external_order(report) {
+                    Ok(Some(order)) => order,
+                    Ok(None) => return true, // External order filtered, no further reconciliation
+                    Err(e) => {
+                        error!("Cannot generate external order {client_order_id}: {e}");
+                        return false;
+                    }
+                };
+                // Add to cache without determining any position ID initially
+                if let Err(e) = self
+                    .cache
+                    .borrow_mut()
+                    .add_order(order.clone(), None, None, false)
+                {
+                    error!("Cannot reconcile order {client_order_id}: {e}");
+                    return false;
+                }
+                order
+            }
+        };
+
+        let instrument_id = order.instrument_id();
+        let Some(instrument) = self.cache.borrow().instrument(&instrument_id).cloned() else {
+            error!(
+                "Cannot reconcile order {client_order_id}: instrument {instrument_id} not found"
+            );
+            return false;
+        };
+
+        match report.order_status {
+            OrderStatus::Rejected => {
+                if order.as_order().status() != OrderStatus::Rejected {
+                    self.generate_order_rejected(&order, report);
+                }
+                return true; // Reconciled
+            }
+            OrderStatus::Accepted => {
+                if order.as_order().status() != OrderStatus::Accepted {
+                    self.generate_order_accepted(&order, report);
+                }
+                return true; // Reconciled
+            }
+            _ => {}
+        }
+
+        // Order must have been accepted from this point
+        if matches!(
+            order.as_order().status(),
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            self.generate_order_accepted(&order, report);
+        }
+
+        // Update order quantity and price differences
+        let Some(order) = self.cached_order(&client_order_id) else {
+            return false;
+        };
+        if should_update(&order, report) {
+            self.generate_order_updated(&order, report);
+        }
+
+        let Some(order) = self.cached_order(&client_order_id) else {
+            return false;
+        };
+        match report.order_status {
+            OrderStatus::Triggered => {
+                if order.as_order().status() != OrderStatus::Triggered {
+                    self.generate_order_triggered(&order, report);
+                }
+                return true; // Reconciled
+            }
+            OrderStatus::Canceled => {
+                if order.as_order().status() != OrderStatus::Canceled && order.as_order().is_open()
+                {
+                    if report.is_triggered() {
+                        self.generate_order_triggered(&order, report);
+                    }
+                    for fill in fills {
+                        self.reconcile_fill(&client_order_id, fill, &instrument);
+                    }
+                    self.generate_order_canceled(&order, report);
+                }
+                return true; // Reconciled
+            }
+            OrderStatus::Expired => {
+                if order.as_order().status() != OrderStatus::Expired && order.as_order().is_open() {
+                    if report.is_triggered() {
+                        self.generate_order_triggered(&order, report);
+                    }
+                    self.generate_order_expired(&order, report);
+                }
+                return true; // Reconciled
+            }
+            _ => {}
+        }
+
+        // Order has some fills from this point
+        for fill in fills {
+            self.reconcile_fill(&client_order_id, fill, &instrument);
+        }
+
+        if report.avg_px.is_none() {
+            warn!("`report.avg_px` was `None` when a value was expected");
+        }
+
+        let Some(order) = self.cached_order(&client_order_id) else {
+            return false;
+        };
+        if report.filled_qty != order.as_order().filled_qty() {
+            // This is due to missing fill report(s), there may now be some information
+            // loss if multiple fills occurred to reach the reported state, or if
+            // commissions differed from the default.
+            let fill = match self.generate_inferred_fill(&order, report, &instrument) {
+                Ok(fill) => fill,
+                Err(e) => {
+                    error!("Cannot generate inferred fill for {client_order_id}: {e}");
+                    return false;
+                }
+            };
+            self.handle_event(fill_event(&order, fill));
+
+            let Some(order) = self.cached_order(&client_order_id) else {
+                return false;
+            };
+            if report.filled_qty != order.as_order().filled_qty() {
+                error!(
+                    "Cannot reconcile {client_order_id}: reported filled qty {} != order filled qty {}",
+                    report.filled_qty,
+                    order.as_order().filled_qty()
+                );
+                return false;
+            }
+            if let (Some(report_avg_px), Some(avg_px)) = (report.avg_px, order.as_order().avg_px())
+            {
+                if (report_avg_px - avg_px).abs() > f64::EPSILON {
+                    warn!("`report.avg_px` {report_avg_px} != `order.avg_px` {avg_px}");
+                }
+            }
+        }
+
+        true // Reconciled
     }
 
-    fn handle_submit_order_list(&self, client: &ExecutionClient, command: SubmitOrderList) {
-        todo!();
+    fn reconcile_fill_single(&mut self, report: &FillReport) -> bool {
+        let client_order_id = report.client_order_id.or_else(|| {
+            self.cache
+                .borrow()
+                .client_order_id(&report.venue_order_id)
+                .copied()
+        });
+        let Some(client_order_id) = client_order_id else {
+            error!(
+                "Cannot reconcile fill report: client order ID for {} not found",
+                report.venue_order_id
+            );
+            return false;
+        };
+
+        let Some(order) = self.cached_order(&client_order_id) else {
+            error!("Cannot reconcile fill report: no order for {client_order_id}");
+            return false;
+        };
+
+        let instrument_id = order.instrument_id();
+        let Some(instrument) = self.cache.borrow().instrument(&instrument_id).cloned() else {
+            error!(
+                "Cannot reconcile order {client_order_id}: instrument {instrument_id} not found"
+            );
+            return false;
+        };
+
+        self.reconcile_fill(&client_order_id, report, &instrument)
     }
 
-    fn handle_modify_order(&self, client: &ExecutionClient, command: ModifyOrder) {
-        todo!();
+    fn reconcile_fill(
+        &mut self,
+        client_order_id: &ClientOrderId,
+        report: &FillReport,
+        instrument: &InstrumentAny,
+    ) -> bool {
+        let Some(order) = self.cached_order(client_order_id) else {
+            return false;
+        };
+        if order.as_order().trade_ids().contains(&&report.trade_id) {
+            return true; // Fill already applied (assumes consistent trades)
+        }
+
+        let event = match self.generate_order_filled(&order, report, instrument) {
+            Ok(fill) => fill_event(&order, fill),
+            Err(e) => {
+                error!("Cannot reconcile fill for {client_order_id}: {e}");
+                return false;
+            }
+        };
+        self.handle_event(event);
+
+        let Some(order) = self.cached_order(client_order_id) else {
+            return false;
+        };
+        if !order.as_order().trade_ids().contains(&&report.trade_id) {
+            return false; // Fill could not be applied
+        }
+
+        // Check correct ordering of fills
+        if report.ts_event < order.as_order().ts_last() {
+            warn!("OrderFilled applied out of chronological order from {report}");
+        }
+        true
     }
 
-    fn handle_cancel_order(&self, client: &ExecutionClient, command: CancelOrder) {
-        todo!();
+    fn reconcile_position(&self, report: &PositionStatusReport) -> bool {
+        let cache = self.cache.borrow();
+        let (position_qty, positions) = match report.venue_position_id {
+            Some(position_id) => {
+                let Some(position) = cache.position(&position_id) else {
+                    error!("Cannot reconcile position: {position_id} not found");
+                    return false;
+                };
+                (signed_decimal_qty(position), position_id.to_string())
+            }
+            None => {
+                let position_qty = cache
+                    .positions_open(None, Some(&report.instrument_id), None, None)
+                    .into_iter()
+                    .map(signed_decimal_qty)
+                    .sum::<Decimal>();
+                (position_qty, report.instrument_id.to_string())
+            }
+        };
+
+        if position_qty != report.signed_decimal_qty {
+            error!(
+                "Cannot reconcile {positions}: position net qty {position_qty} != reported net qty {}, {report}",
+                report.signed_decimal_qty
+            );
+            return false;
+        }
+        true // Reconciled
     }
 
-    fn handle_cancel_all_orders(&self, client: &ExecutionClient, command: CancelAllOrders) {
-        todo!();
+    fn cached_order(&self, client_order_id: &ClientOrderId) -> Option<OrderAny> {
+        self.cache.borrow().order(client_order_id).cloned()
     }
 
-    fn handle_batch_cancel_orders(&self, client: &ExecutionClient, command: BatchCancelOrders) {
-        todo!();
+    fn publish_report<T: Display + 'static>(&self, instrument_id: &InstrumentId, report: &T) {
+        let topic = format!(
+            "reports.execution.{}.{}",
+            instrument_id.venue, instrument_id.symbol
+        );
+        self.msgbus.borrow().publish(&topic, report);
     }
 
-    fn handle_query_order(&self, client: &ExecutionClient, command: QueryOrder) {
-        todo!();
+    /// Generates an order external to the system from the `report`, returning `None` if
+    /// the order was filtered.
+    fn generate_external_order(
+        &self,
+        report: &OrderStatusReport,
+    ) -> anyhow::Result<Option<OrderAny>> {
+        let client_order_id = report
+            .client_order_id
+            .ok_or_else(|| anyhow::anyhow!("No `ClientOrderId` assigned to report"))?;
+        info!("Generating order {client_order_id}");
+
+        let (strategy_id, tags) = match self.get_external_order_claim(&report.instrument_id) {
+            Some(strategy_id) => (strategy_id, None),
+            None => (StrategyId::external(), Some(vec![Ustr::from("EXTERNAL")])),
+        };
+
+        if self.config.filter_unclaimed_external_orders && strategy_id.is_external() {
+            warn!("Filtering report for unclaimed EXTERNAL order, {report}");
+            return Ok(None); // No further reconciliation
+        }
+
+        check_order_report_prices(report)?;
+
+        let ts_now = self.clock.get_time_ns();
+        let initialized = OrderInitialized::new(
+            self.msgbus.borrow().trader_id,
+            strategy_id,
+            report.instrument_id,
+            client_order_id,
+            report.order_side,
+            report.order_type,
+            report.quantity,
+            // Only keep the reported time in force when the order can expire
+            if report.expire_time.is_some() {
+                report.time_in_force
+            } else {
+                TimeInForce::Gtc
+            },
+            report.post_only,
+            report.reduce_only,
+            false,
+            true,
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            report.price,
+            report.trigger_price,
+            Some(report.trigger_type),
+            report.limit_offset,
+            report.trailing_offset,
+            Some(report.trailing_offset_type),
+            report.expire_time,
+            report.display_qty,
+            None,
+            None,
+            Some(report.contingency_type),
+            report.order_list_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            tags,
+        )?;
+
+        debug!("Generated {initialized:?}");
+        let order = OrderAny::from_events(vec![OrderEventAny::Initialized(initialized)])?;
+        Ok(Some(order))
     }
 
-    // -- EVENT HANDLERS ----------------------------------------------------
+    fn generate_inferred_fill(
+        &self,
+        order: &OrderAny,
+        report: &OrderStatusReport,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<OrderFilled> {
+        // Infer liquidity side
+        let liquidity_side = match order.as_order().order_type() {
+            OrderType::Market | OrderType::StopMarket | OrderType::TrailingStopMarket => {
+                LiquiditySide::Taker
+            }
+            _ if report.post_only => LiquiditySide::Maker,
+            _ => LiquiditySide::NoLiquiditySide,
+        };
+
+        let last_qty = instrument
+            .make_qty(report.filled_qty.as_f64() - order.as_order().filled_qty().as_f64())?;
+        let last_px = match order.as_order().avg_px() {
+            None => instrument.make_price(report.avg_px.unwrap_or(0.0))?,
+            Some(avg_px) => {
+                let report_cost = report.avg_px.unwrap_or(0.0) * report.filled_qty.as_f64();
+                let filled_cost = avg_px * order.as_order().filled_qty().as_f64();
+                instrument.make_price((report_cost - filled_cost) / last_qty.as_f64())?
+            }
+        };
+
+        let notional_value = instrument.calculate_notional_value(last_qty, last_px, None);
+        let commission = Money::new(
+            notional_value.as_f64() * instrument.taker_fee().to_f64().unwrap_or(0.0),
+            instrument.quote_currency(),
+        )?;
+
+        let filled = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            report.instrument_id,
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            TradeId::new(UUID4::new().to_string().as_str())?,
+            order.as_order().side(),
+            order.as_order().order_type(),
+            last_qty,
+            last_px,
+            instrument.quote_currency(),
+            liquidity_side,
+            UUID4::new(),
+            report.ts_last,
+            self.clock.get_time_ns(),
+            true,
+            Some(PositionId::new(
+                format!("{}-EXTERNAL", instrument.id()).as_str(),
+            )?),
+            Some(commission),
+        )?;
 
-    fn handle_event(&self, event: OrderEventAny) {
-        todo!();
+        warn!("Generated inferred {filled}");
+        Ok(filled)
     }
 
-    fn determine_oms_type(&self, fill: OrderFilled) {
-        todo!();
+    fn generate_order_filled(
+        &self,
+        order: &OrderAny,
+        report: &FillReport,
+        instrument: &InstrumentAny,
+    ) -> anyhow::Result<OrderFilled> {
+        let filled = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            report.instrument_id,
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            report.trade_id,
+            order.as_order().side(),
+            order.as_order().order_type(),
+            report.last_qty,
+            report.last_px,
+            instrument.quote_currency(),
+            report.liquidity_side,
+            UUID4::new(),
+            report.ts_event,
+            self.clock.get_time_ns(),
+            true,
+            report.venue_position_id,
+            report.commission,
+        )?;
+        debug!("Generated {filled}");
+        Ok(filled)
     }
 
-    fn determine_position_id(&self, fill: OrderFilled, oms_type: OmsType) {
-        todo!();
+    fn generate_order_rejected(&mut self, order: &OrderAny, report: &OrderStatusReport) {
+        let rejected = OrderRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.account_id,
+            Ustr::from(report.cancel_reason.as_deref().unwrap_or("UNKNOWN")),
+            UUID4::new(),
+            report.ts_last,
+            self.clock.get_time_ns(),
+            true,
+        )
+        .unwrap();
+        debug!("Generated {rejected}");
+        self.handle_event(OrderEventAny::Rejected(rejected));
     }
 
-    fn determine_hedging_position_id(&self, fill: OrderFilled) {
-        todo!();
+    fn generate_order_accepted(&mut self, order: &OrderAny, report: &OrderStatusReport) {
+        let accepted = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            UUID4::new(),
+            report.ts_accepted,
+            self.clock.get_time_ns(),
+            true,
+        )
+        .unwrap();
+        debug!("Generated {accepted}");
+        self.handle_event(OrderEventAny::Accepted(accepted));
     }
 
-    fn determine_netting_position_id(&self, fill: OrderFilled) {
-        todo!();
+    fn generate_order_triggered(&mut self, order: &OrderAny, report: &OrderStatusReport) {
+        let triggered = OrderTriggered::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            report.ts_triggered.unwrap_or(report.ts_last),
+            self.clock.get_time_ns(),
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        )
+        .unwrap();
+        debug!("Generated {triggered}");
+        self.handle_event(OrderEventAny::Triggered(triggered));
     }
 
-    fn apply_event_to_order(&self, order: &OrderAny, event: OrderEventAny) {
-        todo!();
+    fn generate_order_updated(&mut self, order: &OrderAny, report: &OrderStatusReport) {
+        let updated = OrderUpdated::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.quantity,
+            UUID4::new(),
+            report.ts_accepted,
+            self.clock.get_time_ns(),
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+            report.price,
+            report.trigger_price,
+        )
+        .unwrap();
+        debug!("Generated {updated}");
+        self.handle_event(OrderEventAny::Updated(updated));
     }
 
-    fn handle_order_fill(&self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
-        todo!();
+    fn generate_order_canceled(&mut self, order: &OrderAny, report: &OrderStatusReport) {
+        let canceled = OrderCanceled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            report.ts_last,
+            self.clock.get_time_ns(),
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        )
+        .unwrap();
+        debug!("Generated {canceled}");
+        self.handle_event(OrderEventAny::Canceled(canceled));
     }
 
-    fn open_position(
-        &self,
-        instrument: InstrumentAny,
-        position: &Position,
-        fill: OrderFilled,
-        oms_type: OmsType,
-    ) {
-        todo!();
+    fn generate_order_expired(&mut self, order: &OrderAny, report: &OrderStatusReport) {
+        let expired = OrderExpired::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            UUID4::new(),
+            report.ts_last,
+            self.clock.get_time_ns(),
+            true,
+            Some(report.venue_order_id),
+            Some(report.account_id),
+        )
+        .unwrap();
+        debug!("Generated {expired}");
+        self.handle_event(OrderEventAny::Expired(expired));
     }
 
-    fn update_position(
-        &self,
-        instrument: InstrumentAny,
-        position: &Position,
-        fill: OrderFilled,
-        oms_type: OmsType,
-    ) {
-        todo!();
+    // -- COMMAND HANDLERS ----------------------------------------------------
+
+    fn execute_command(&mut self, command: TradingCommand) {
+        if self.config.debug {
+            debug!("<--[CMD] {:?}", command); // TODO: Log constants
+        }
+        self.command_count += 1;
+
+        let client = self
+            .clients
+            .get(&command.client_id())
+            .or_else(|| {
+                self.routing_map
+                    .get(&command.instrument_id().venue)
+                    .and_then(|client_id| self.clients.get(client_id))
+            })
+            .or(self.default_client.as_ref())
+            .map(AsRef::as_ref);
+
+        let Some(client) = client else {
+            error!(
+                "Cannot execute command: no execution client configured for {} or `client_id` {}, {:?}",
+                command.instrument_id().venue,
+                command.client_id(),
+                command
+            );
+            return; // No client to handle command
+        };
+
+        match command {
+            TradingCommand::SubmitOrder(cmd) => self.handle_submit_order(client, cmd),
+            TradingCommand::SubmitOrderList(cmd) => self.handle_submit_order_list(client, cmd),
+            TradingCommand::ModifyOrder(cmd) => self.handle_modify_order(client, cmd),
+            TradingCommand::CancelOrder(cmd) => self.handle_cancel_order(client, cmd),
+            TradingCommand::CancelAllOrders(cmd) => self.handle_cancel_all_orders(client, cmd),
+            TradingCommand::BatchCancelOrders(cmd) => self.handle_batch_cancel_orders(client, cmd),
+            TradingCommand::QueryOrder(cmd) => self.handle_query_order(client, cmd),
+        }
     }
 
-    fn will_flip_position(&self, position: &Position, fill: OrderFilled) {
-        todo!();
+    fn handle_submit_order(&self, client: &dyn ExecutionClient, mut command: SubmitOrder) {
+        let client_order_id = command.order.client_order_id();
+        if !self.cache.borrow().order_exists(&client_order_id) {
+            if let Err(e) = self.cache.borrow_mut().add_order(
+                command.order.clone(),
+                command.position_id,
+                Some(command.client_id),
+                false,
+            ) {
+                error!("Cannot handle submit order: {e}");
+                return;
+            }
+            self.publish_order_snapshot(&command.order);
+        }
+
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&command.instrument_id)
+            .cloned()
+        else {
+            error!(
+                "Cannot handle submit order: no instrument found for {}, {:?}",
+                command.instrument_id, command
+            );
+            return;
+        };
+
+        // Check if converting quote quantity
+        if !instrument.is_inverse() && command.order.as_order().is_quote_quantity() {
+            let order = &mut command.order;
+            let Some(last_px) =
+                self.last_px_for_conversion(&order.instrument_id(), order.as_order().side())
+            else {
+                let reason = format!("no-price-to-convert-quote-qty {}", order.instrument_id());
+                self.deny_order(order, &reason);
+                return; // Denied
+            };
+            let base_qty = instrument.calculate_base_quantity(order.as_order().quantity(), last_px);
+            self.set_order_base_qty(order, base_qty);
+        }
+
+        // Send to execution client
+        if let Err(e) = client.submit_order(command) {
+            error!("Error submitting order to {}: {e}", client.client_id());
+        }
     }
 
-    fn flip_position(
-        &self,
-        instrument: InstrumentAny,
-        position: &Position,
-        fill: OrderFilled,
-        oms_type: OmsType,
-    ) {
-        todo!();
+    fn handle_submit_order_list(&self, client: &dyn ExecutionClient, mut command: SubmitOrderList) {
+        for order in &command.order_list.orders {
+            let client_order_id = order.client_order_id();
+            if self.cache.borrow().order_exists(&client_order_id) {
+                continue;
+            }
+            if let Err(e) = self.cache.borrow_mut().add_order(
+                order.clone(),
+                command.position_id,
+                Some(command.client_id),
+                false,
+            ) {
+                error!("Cannot handle submit order list: {e}");
+                return;
+            }
+            self.publish_order_snapshot(order);
+        }
+
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&command.instrument_id)
+            .cloned()
+        else {
+            error!(
+                "Cannot handle submit order list: no instrument found for {}, {:?}",
+                command.instrument_id, command
+            );
+            return;
+        };
+
+        // Check if converting quote quantity
+        let is_quote_quantity = command
+            .order_list
+            .orders
+            .first()
+            .is_some_and(|order| order.as_order().is_quote_quantity());
+        if !instrument.is_inverse() && is_quote_quantity {
+            let mut quote_qty: Option<Quantity> = None;
+            let mut last_px: Option<Price> = None;
+            for index in 0..command.order_list.orders.len() {
+                let order = &command.order_list.orders[index];
+                if !order.as_order().is_quote_quantity() {
+                    continue; // Base quantity already set
+                }
+                if Some(order.as_order().quantity()) != quote_qty {
+                    last_px = self
+                        .last_px_for_conversion(&order.instrument_id(), order.as_order().side());
+                    quote_qty = Some(order.as_order().quantity());
+                }
+                let (Some(px), Some(qty)) = (last_px, quote_qty) else {
+                    for order in &mut command.order_list.orders {
+                        let reason =
+                            format!("no-price-to-convert-quote-qty {}", order.instrument_id());
+                        self.deny_order(order, &reason);
+                    }
+                    return; // Denied
+                };
+                let base_qty = instrument.calculate_base_quantity(qty, px);
+                self.set_order_base_qty(&mut command.order_list.orders[index], base_qty);
+            }
+        }
+
+        // Send to execution client
+        if let Err(e) = client.submit_order_list(command) {
+            error!("Error submitting order list to {}: {e}", client.client_id());
+        }
     }
 
-    fn publish_order_snapshot(&self, order: &OrderAny) {
-        todo!();
+    fn handle_modify_order(&self, client: &dyn ExecutionClient, command: ModifyOrder) {
+        if let Err(e) = client.modify_order(command) {
+            error!("Error modifying order with {}: {e}", client.client_id());
+        }
     }
 
-    fn publish_position_snapshot(&self, position: &Position) {
-        todo!();
+    fn handle_cancel_order(&self, client: &dyn ExecutionClient, command: CancelOrder) {
+        if let Err(e) = client.cancel_order(command) {
+            error!("Error canceling order with {}: {e}", client.client_id());
+        }
     }
 
-    // -- INTERNAL ------------------------------------------------------------
+    fn handle_cancel_all_orders(&self, client: &dyn ExecutionClient, command: CancelAllOrders) {
+        if let Err(e) = client.cancel_all_orders(command) {
+            error!(
+                "Error canceling all orders with {}: {e}",
+                client.client_id()
+            );
+        }
+    }
+
+    fn handle_batch_cancel_orders(&self, client: &dyn ExecutionClient, command: BatchCancelOrders) {
+        if let Err(e) = client.batch_cancel_orders(command) {
+            error!(
+                "Error batch canceling orders with {}: {e}",
+                client.client_id()
+            );
+        }
+    }
 
-    fn set_position_id_counts(&self) {
-        todo!();
+    fn handle_query_order(&self, client: &dyn ExecutionClient, command: QueryOrder) {
+        if let Err(e) = client.query_order(command) {
+            error!("Error querying order with {}: {e}", client.client_id());
+        }
     }
 
-    fn last_px_for_conversion(&self, instrument_id: InstrumentId, side: OrderSide) {
-        todo!();
+    // -- EVENT HANDLERS ----------------------------------------------------
+
+    fn handle_event(&mut self, mut event: OrderEventAny) {
+        if self.config.debug {
+            debug!("<--[EVT] {:?}", event); // TODO: Log constants
+        }
+        self.event_count += 1;
+
+        // Fetch order from cache
+        let client_order_id = event.client_order_id();
+        let cached_order = self.cache.borrow().order(&client_order_id).cloned();
+        let mut order = match cached_order {
+            Some(order) => order,
+            None => {
+                warn!("Order with {client_order_id} not found in the cache to apply {event}");
+
+                let Some(venue_order_id) = event.venue_order_id() else {
+                    error!(
+                        "Cannot apply event to any order: {client_order_id} not found in the cache with no `VenueOrderId`"
+                    );
+                    return; // Cannot process event further
+                };
+
+                // Search cache for order matching the `VenueOrderId`
+                let order = {
+                    let cache = self.cache.borrow();
+                    cache
+                        .client_order_id(&venue_order_id)
+                        .and_then(|client_order_id| cache.order(client_order_id))
+                        .cloned()
+                };
+                let Some(order) = order else {
+                    error!(
+                        "Cannot apply event to any order: {client_order_id} and {venue_order_id} not found in the cache"
+                    );
+                    return; // Cannot process event further
+                };
+
+                // Set the correct `ClientOrderId` for the event
+                event.set_client_order_id(order.client_order_id());
+                info!(
+                    "Order with {} was found in the cache",
+                    order.client_order_id()
+                );
+                order
+            }
+        };
+
+        match event {
+            OrderEventAny::PartiallyFilled(mut fill) => {
+                let oms_type = self.determine_oms_type(&fill);
+                if self.determine_position_id(&mut fill, oms_type)
+                    && self.apply_event_to_order(&mut order, OrderEventAny::PartiallyFilled(fill))
+                {
+                    self.handle_order_fill(&order, fill, oms_type);
+                }
+            }
+            OrderEventAny::Filled(mut fill) => {
+                let oms_type = self.determine_oms_type(&fill);
+                if self.determine_position_id(&mut fill, oms_type)
+                    && self.apply_event_to_order(&mut order, OrderEventAny::Filled(fill))
+                {
+                    self.handle_order_fill(&order, fill, oms_type);
+                }
+            }
+            _ => {
+                self.apply_event_to_order(&mut order, event);
+            }
+        }
     }
 
-    fn set_order_base_qty(&self, order: &OrderAny, base_qty: Quantity) {
-        todo!();
+    fn determine_oms_type(&self, fill: &OrderFilled) -> OmsType {
+        // Check for strategy OMS override
+        if let Some(oms_type) = self.oms_overrides.get(&fill.strategy_id) {
+            if *oms_type != OmsType::Unspecified {
+                return *oms_type;
+            }
+        }
+
+        // Use native venue OMS
+        self.routing_map
+            .get(&fill.instrument_id.venue)
+            .and_then(|client_id| self.clients.get(client_id))
+            .or(self.default_client.as_ref())
+            .map_or(OmsType::Netting, |client| client.oms_type())
     }
 
-    fn deny_order(&self, order: &OrderAny, reason: &str) {
-        todo!();
+    /// Assigns the position ID for the `fill`, returning whether one could be determined.
+    fn determine_position_id(&mut self, fill: &mut OrderFilled, oms_type: OmsType) -> bool {
+        // Fetch ID from cache
+        let cached_position_id = self
+            .cache
+            .borrow()
+            .position_id(&fill.client_order_id)
+            .copied();
+        if self.config.debug {
+            debug!(
+                "Determining position ID for {}, position_id={:?}",
+                fill.client_order_id, cached_position_id
+            );
+        }
+
+        if let Some(position_id) = cached_position_id {
+            if fill.position_id.is_some_and(|id| id != position_id) {
+                error!(
+                    "Incorrect position ID assigned to fill: cached={position_id}, assigned={}, re-assigning from cache",
+                    fill.position_id.unwrap()
+                );
+            }
+            // Assign position ID to fill
+            fill.position_id = Some(position_id);
+            if self.config.debug {
+                debug!("Assigned {position_id} to {}", fill.client_order_id);
+            }
+            return true;
+        }
+
+        let position_id = match oms_type {
+            OmsType::Hedging => self.determine_hedging_position_id(fill),
+            OmsType::Netting => Some(self.determine_netting_position_id(fill)),
+            OmsType::Unspecified => {
+                error!("Cannot determine position ID: invalid `OmsType`, was {oms_type}");
+                None
+            }
+        };
+        let Some(position_id) = position_id else {
+            return false;
+        };
+        fill.position_id = Some(position_id);
+
+        // Check execution algorithm position ID
+        let cache = self.cache.borrow();
+        let Some(order) = cache.order(&fill.client_order_id) else {
+            return true;
+        };
+        if order.exec_algorithm_id().is_none() {
+            return true;
+        }
+        let Some(exec_spawn_id) = order.exec_spawn_id() else {
+            return true;
+        };
+        let primary = cache.order(&exec_spawn_id).cloned();
+        drop(cache);
+
+        if let Some(mut primary) = primary {
+            if primary.position_id().is_none() {
+                primary.set_position_id(Some(position_id));
+                let mut cache = self.cache.borrow_mut();
+                let result = cache
+                    .add_position_id(
+                        &position_id,
+                        &primary.instrument_id().venue,
+                        &primary.client_order_id(),
+                        &primary.strategy_id(),
+                    )
+                    .and_then(|()| cache.update_order(&primary));
+                match result {
+                    Ok(()) => debug!("Assigned primary order {position_id}"),
+                    Err(e) => error!("Error assigning primary order {position_id}: {e}"),
+                }
+            }
+        }
+        true
+    }
+
+    fn determine_hedging_position_id(&mut self, fill: &OrderFilled) -> Option<PositionId> {
+        if let Some(position_id) = fill.position_id {
+            if self.config.debug {
+                debug!("Already had a position ID of: {position_id}");
+            }
+            return Some(position_id);
+        }
+
+        let cache = self.cache.borrow();
+        let Some(order) = cache.order(&fill.client_order_id) else {
+            error!(
+                "Cannot determine position ID for {}: order not found in the cache",
+                fill.client_order_id
+            );
+            return None;
+        };
+
+        if order.exec_algorithm_id().is_some() {
+            if let Some(exec_spawn_id) = order.exec_spawn_id() {
+                // Use position ID from execution algorithm spawned order
+                let spawned_position_id = cache
+                    .orders_for_exec_spawn(&exec_spawn_id)
+                    .iter()
+                    .find_map(|spawned_order| spawned_order.position_id());
+                if spawned_position_id.is_some() {
+                    return spawned_position_id;
+                }
+            }
+        }
+        drop(cache);
+
+        // Assign new position ID
+        Some(self.pos_id_generator.generate(fill.strategy_id, false))
+    }
+
+    fn determine_netting_position_id(&self, fill: &OrderFilled) -> PositionId {
+        PositionId::new(format!("{}-{}", fill.instrument_id, fill.strategy_id).as_str()).unwrap()
+    }
+
+    /// Applies the `event` to the `order` and updates the cache, returning whether the
+    /// event was applied.
+    fn apply_event_to_order(&self, order: &mut OrderAny, event: OrderEventAny) -> bool {
+        if let Err(e) = order.apply(event.clone()) {
+            warn!("Error applying event: {e}, did not apply {event}");
+            return false;
+        }
+
+        if let Err(e) = self.cache.borrow_mut().update_order(order) {
+            error!("Error updating order in cache: {e}");
+        }
+
+        let topic = format!("events.order.{}", event.strategy_id());
+        self.msgbus.borrow().publish(&topic, &event);
+        self.publish_order_snapshot(order);
+        true
+    }
+
+    fn handle_order_fill(&mut self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
+        let Some(instrument) = self.cache.borrow().instrument(&fill.instrument_id).cloned() else {
+            error!(
+                "Cannot handle order fill: no instrument found for {}, {fill}",
+                fill.instrument_id
+            );
+            return;
+        };
+
+        let position_id = fill.position_id.expect("Position ID should be determined");
+        let cached_position = self.cache.borrow().position(&position_id).cloned();
+        let position = match cached_position {
+            Some(position) if position.is_open() && will_flip_position(&position, &fill) => {
+                self.flip_position(instrument, position, fill, oms_type)
+            }
+            Some(mut position) if position.is_open() => {
+                self.update_position(&mut position, fill);
+                Some(position)
+            }
+            position => self.open_position(instrument, position, fill, oms_type),
+        };
+
+        let Some(position) = position else {
+            return;
+        };
+        if order.as_order().contingency_type() != Some(ContingencyType::Oto) || !position.is_open()
+        {
+            return;
+        }
+
+        // Assign the position ID to any OTO contingent orders
+        let linked_order_ids = order
+            .as_order()
+            .linked_order_ids()
+            .map(<[_]>::to_vec)
+            .unwrap_or_default();
+        for client_order_id in linked_order_ids {
+            let contingent_order = self.cache.borrow().order(&client_order_id).cloned();
+            let Some(mut contingent_order) = contingent_order else {
+                continue;
+            };
+            if contingent_order.position_id().is_some() {
+                continue;
+            }
+            contingent_order.set_position_id(Some(position.id));
+            let mut cache = self.cache.borrow_mut();
+            let result = cache
+                .add_position_id(
+                    &position.id,
+                    &contingent_order.instrument_id().venue,
+                    &client_order_id,
+                    &contingent_order.strategy_id(),
+                )
+                .and_then(|()| cache.update_order(&contingent_order));
+            if let Err(e) = result {
+                error!("Error assigning {} to {client_order_id}: {e}", position.id);
+            }
+        }
+    }
+
+    fn open_position(
+        &self,
+        instrument: InstrumentAny,
+        position: Option<Position>,
+        fill: OrderFilled,
+        oms_type: OmsType,
+    ) -> Option<Position> {
+        let position = match position {
+            None => {
+                let position = match create_position(instrument, fill) {
+                    Ok(position) => position,
+                    Err(e) => {
+                        error!("Error opening position: {e}");
+                        return None;
+                    }
+                };
+                if let Err(e) = self
+                    .cache
+                    .borrow_mut()
+                    .add_position(position.clone(), oms_type)
+                {
+                    error!("Error adding {position} to cache: {e}");
+                    return None;
+                }
+                position
+            }
+            Some(mut position) => {
+                // Reopen the closed position
+                if !self.apply_fill_to_position(&mut position, &fill) {
+                    return None;
+                }
+                if let Err(e) = self.cache.borrow_mut().update_position(&position) {
+                    error!("Error updating {position} in cache: {e}");
+                }
+                position
+            }
+        };
+        self.publish_position_snapshot(&position);
+
+        let event = PositionOpened::create(&position, &fill, self.clock.get_time_ns());
+        self.publish_position_event(PositionEvent::PositionOpened(event));
+        Some(position)
+    }
+
+    fn update_position(&self, position: &mut Position, fill: OrderFilled) {
+        if !self.apply_fill_to_position(position, &fill) {
+            return;
+        }
+
+        if let Err(e) = self.cache.borrow_mut().update_position(position) {
+            error!("Error updating {position} in cache: {e}");
+        }
+        self.publish_position_snapshot(position);
+
+        let ts_init = self.clock.get_time_ns();
+        let event = if position.is_closed() {
+            PositionEvent::PositionClosed(PositionClosed::create(position, &fill, ts_init))
+        } else {
+            PositionEvent::PositionChanged(PositionChanged::create(position, &fill, ts_init))
+        };
+        self.publish_position_event(event);
+    }
+
+    /// Splits the `fill` into a fill closing the `position` and a fill opening a new
+    /// position on the opposite side, prorating any commission between the two.
+    fn flip_position(
+        &mut self,
+        instrument: InstrumentAny,
+        mut position: Position,
+        fill: OrderFilled,
+        oms_type: OmsType,
+    ) -> Option<Position> {
+        // Flipping guarantees the fill quantity exceeds the position quantity
+        let difference = fill.last_qty - position.quantity;
+
+        // Split commission between two positions
+        let fill_percent = position.quantity.as_f64() / fill.last_qty.as_f64();
+        let (commission1, commission2) = match fill.commission {
+            Some(commission) => {
+                let (commission1, commission2) = split_commission(commission, fill_percent);
+                (Some(commission1), Some(commission2))
+            }
+            None => (None, None),
+        };
+
+        // Split fill to close original position
+        let fill_split1 = OrderFilled {
+            position_id: Some(position.id),
+            last_qty: position.quantity, // Fill original position quantity remaining
+            commission: commission1,
+            ..fill
+        };
+        self.update_position(&mut position, fill_split1);
+
+        let position_id_flip = match oms_type {
+            // Generate new position ID for flipped position
+            OmsType::Hedging => self.pos_id_generator.generate(fill.strategy_id, true),
+            _ => position.id,
+        };
+
+        // Split fill to open flipped position
+        let fill_split2 = OrderFilled {
+            position_id: Some(position_id_flip),
+            last_qty: difference, // Fill difference from original as above
+            commission: commission2,
+            ..fill
+        };
+        if self.config.debug {
+            debug!("Flipping {} with {fill_split2}", position.id);
+        }
+        self.open_position(instrument, None, fill_split2, oms_type)
+    }
+
+    fn apply_fill_to_position(&self, position: &mut Position, fill: &OrderFilled) -> bool {
+        // Protect against duplicate fills (which would otherwise panic)
+        if position.trade_ids.contains(&fill.trade_id) {
+            error!(
+                "Error applying fill to {}: duplicate {} for {}",
+                position.id, fill.trade_id, fill.client_order_id
+            );
+            return false;
+        }
+        position.apply(fill);
+        true
+    }
+
+    fn publish_position_event(&self, event: PositionEvent) {
+        let topic = format!("events.position.{}", event.strategy_id());
+        self.msgbus.borrow().publish(&topic, &event);
+    }
+
+    fn publish_order_snapshot(&self, order: &OrderAny) {
+        let msgbus = self.msgbus.borrow();
+        if msgbus.has_backing && self.config.snapshot_orders {
+            let topic = format!("snapshots:orders:{}", order.client_order_id());
+            msgbus.publish(&topic, order);
+        }
+    }
+
+    fn publish_position_snapshot(&self, position: &Position) {
+        let msgbus = self.msgbus.borrow();
+        if msgbus.has_backing && self.config.snapshot_positions {
+            let topic = format!("snapshots:positions:{}", position.id);
+            msgbus.publish(&topic, position);
+        }
+    }
+
+    // -- INTERNAL ------------------------------------------------------------
+
+    fn set_position_id_counts(&mut self) {
+        // For the internal position ID generator
+        let mut counts: HashMap<StrategyId, usize> = HashMap::new();
+        for position in self.cache.borrow().positions(None, None, None, None) {
+            *counts.entry(position.strategy_id).or_default() += 1;
+        }
+
+        self.pos_id_generator.reset();
+
+        for (strategy_id, count) in counts {
+            self.pos_id_generator.set_count(count, strategy_id);
+            info!("Set PositionId count for {strategy_id} to {count}");
+        }
+    }
+
+    fn last_px_for_conversion(
+        &self,
+        instrument_id: &InstrumentId,
+        side: OrderSide,
+    ) -> Option<Price> {
+        let cache = self.cache.borrow();
+        if let Some(quote) = cache.quote_tick(instrument_id) {
+            return match side {
+                OrderSide::Buy => Some(quote.ask_price),
+                _ => Some(quote.bid_price),
+            };
+        }
+        cache.trade_tick(instrument_id).map(|trade| trade.price)
+    }
+
+    fn set_order_base_qty(&self, order: &mut OrderAny, base_qty: Quantity) {
+        let original_qty = order.as_order().quantity();
+        info!(
+            "Setting {} order quote quantity {original_qty} to base quantity {base_qty}",
+            order.instrument_id()
+        );
+        order.set_base_quantity(base_qty);
+        if let Err(e) = self.cache.borrow_mut().update_order(order) {
+            error!("Error updating order in cache: {e}");
+        }
+
+        if order.as_order().contingency_type() != Some(ContingencyType::Oto) {
+            return;
+        }
+
+        // Set base quantity for all OTO contingent orders
+        let linked_order_ids = order
+            .as_order()
+            .linked_order_ids()
+            .map(<[_]>::to_vec)
+            .unwrap_or_default();
+        for client_order_id in linked_order_ids {
+            let contingent_order = self.cache.borrow().order(&client_order_id).cloned();
+            let Some(mut contingent_order) = contingent_order else {
+                error!("Contingency order {client_order_id} not found");
+                continue;
+            };
+            if !contingent_order.as_order().is_quote_quantity() {
+                continue; // Already base quantity
+            }
+            if contingent_order.as_order().quantity() != original_qty {
+                warn!(
+                    "Contingent order quantity {} was not equal to the OTO parent original quantity {original_qty} when setting to base quantity of {base_qty}",
+                    contingent_order.as_order().quantity()
+                );
+            }
+            info!(
+                "Setting {} order quote quantity {} to base quantity {base_qty}",
+                contingent_order.instrument_id(),
+                contingent_order.as_order().quantity()
+            );
+            contingent_order.set_base_quantity(base_qty);
+            if let Err(e) = self.cache.borrow_mut().update_order(&contingent_order) {
+                error!("Error updating order in cache: {e}");
+            }
+        }
+    }
+
+    fn deny_order(&self, order: &mut OrderAny, reason: &str) {
+        let ts_now = self.clock.get_time_ns();
+        let denied = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )
+        .unwrap();
+        self.apply_event_to_order(order, OrderEventAny::Denied(denied));
+    }
+}
+
+fn will_flip_position(position: &Position, fill: &OrderFilled) -> bool {
+    position.is_opposite_side(fill.order_side) && fill.last_qty > position.quantity
+}
+
+/// Splits the `commission` by `fill_percent`, with the second part taking the remainder
+/// so that no commission is lost to rounding.
+fn split_commission(commission: Money, fill_percent: f64) -> (Money, Money) {
+    let commission1 = Money::new(commission * fill_percent, commission.currency)
+        .expect("Split commission should be within the valid range");
+    (commission1, commission - commission1)
+}
+
+/// Wraps the `fill` as a partial fill if it is for less than the `order` leaves quantity.
+fn fill_event(order: &OrderAny, fill: OrderFilled) -> OrderEventAny {
+    if fill.last_qty < order.as_order().leaves_qty() {
+        OrderEventAny::PartiallyFilled(fill)
+    } else {
+        OrderEventAny::Filled(fill)
+    }
+}
+
+/// Returns whether the `order` quantity or prices differ from the reported values.
+fn should_update(order: &OrderAny, report: &OrderStatusReport) -> bool {
+    if report.quantity != order.as_order().quantity() {
+        return true;
+    }
+
+    match order.as_order().order_type() {
+        OrderType::Limit => report.price != order.as_order().price(),
+        OrderType::StopMarket | OrderType::TrailingStopMarket => {
+            report.trigger_price != order.as_order().trigger_price()
+        }
+        OrderType::StopLimit | OrderType::TrailingStopLimit => {
+            report.trigger_price != order.as_order().trigger_price()
+                || report.price != order.as_order().price()
+        }
+        _ => false,
+    }
+}
+
+/// Checks the `report` has the prices required to initialize an order of its type.
+fn check_order_report_prices(report: &OrderStatusReport) -> anyhow::Result<()> {
+    let order_type = report.order_type;
+    let requires_price = matches!(
+        order_type,
+        OrderType::Limit
+            | OrderType::StopLimit
+            | OrderType::LimitIfTouched
+            | OrderType::TrailingStopLimit
+    );
+    let requires_trigger_price = matches!(
+        order_type,
+        OrderType::StopMarket
+            | OrderType::StopLimit
+            | OrderType::MarketIfTouched
+            | OrderType::LimitIfTouched
+            | OrderType::TrailingStopMarket
+            | OrderType::TrailingStopLimit
+    );
+
+    if requires_price && report.price.is_none() {
+        anyhow::bail!("`price` was `None` for {order_type} order");
+    }
+    if requires_trigger_price && report.trigger_price.is_none() {
+        anyhow::bail!("`trigger_price` was `None` for {order_type} order");
+    }
+    if matches!(
+        order_type,
+        OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+    ) && report.trailing_offset.is_none()
+    {
+        anyhow::bail!("`trailing_offset` was `None` for {order_type} order");
+    }
+    if order_type == OrderType::TrailingStopLimit && report.limit_offset.is_none() {
+        anyhow::bail!("`limit_offset` was `None` for {order_type} order");
+    }
+    Ok(())
+}
+
+fn signed_decimal_qty(position: &Position) -> Decimal {
+    match position.side {
+        PositionSide::Short => -position.quantity.as_decimal(),
+        _ => position.quantity.as_decimal(),
+    }
+}
+
+fn create_position(instrument: InstrumentAny, fill: OrderFilled) -> anyhow::Result<Position> {
+    match instrument {
+        InstrumentAny::CryptoFuture(inst) => Position::new(inst, fill),
+        InstrumentAny::CryptoPerpetual(inst) => Position::new(inst, fill),
+        InstrumentAny::CurrencyPair(inst) => Position::new(inst, fill),
+        InstrumentAny::Equity(inst) => Position::new(inst, fill),
+        InstrumentAny::FuturesContract(inst) => Position::new(inst, fill),
+        InstrumentAny::FuturesSpread(inst) => Position::new(inst, fill),
+        InstrumentAny::OptionsContract(inst) => Position::new(inst, fill),
+        InstrumentAny::OptionsSpread(inst) => Position::new(inst, fill),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, collections::HashSet, rc::Rc};
+
+    use nautilus_common::{cache::Cache, handlers::AnyMessageHandler, msgbus::MessageBus};
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+    use nautilus_model::{
+        enums::{
+            AccountType, LiquiditySide, OmsType, OrderSide, OrderStatus, OrderType, PositionSide,
+            TimeInForce,
+        },
+        events::{
+            order::{
+                accepted::OrderAccepted, event::OrderEventAny, filled::OrderFilled,
+                submitted::OrderSubmitted,
+            },
+            position::PositionEvent,
+        },
+        identifiers::{
+            account_id::AccountId,
+            client_id::ClientId,
+            client_order_id::ClientOrderId,
+            position_id::PositionId,
+            strategy_id::StrategyId,
+            stubs::{strategy_id_ema_cross, trader_id},
+            trade_id::TradeId,
+            venue::Venue,
+            venue_order_id::VenueOrderId,
+        },
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{any::OrderAny, stubs::TestOrderEventStubs, stubs::TestOrderStubs},
+        polymorphism::{GetClientOrderId, GetInstrumentId, GetStrategyId, GetTraderId},
+        types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{split_commission, ExecutionEngine, ExecutionEngineConfig, EXEC_ENGINE_EXECUTE};
+    use crate::{
+        client::{base::BaseExecutionClient, ExecutionClient},
+        messages::{
+            cancel::CancelOrder, cancel_all::CancelAllOrders, cancel_batch::BatchCancelOrders,
+            modify::ModifyOrder, query::QueryOrder, submit::SubmitOrder,
+            submit_list::SubmitOrderList, TradingCommand,
+        },
+        reports::{
+            fill::FillReport, mass_status::ExecutionMassStatus, order::OrderStatusReport,
+            position::PositionStatusReport,
+        },
+    };
+
+    type Events<T> = Rc<RefCell<Vec<T>>>;
+
+    struct TestContext {
+        engine: ExecutionEngine,
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        order_events: Events<OrderEventAny>,
+        position_events: Events<PositionEvent>,
+        commands: Events<TradingCommand>,
+    }
+
+    fn subscribe<T: Clone + 'static>(msgbus: &Rc<RefCell<MessageBus>>, topic: &str) -> Events<T> {
+        let events: Events<T> = Rc::new(RefCell::new(Vec::new()));
+        let events_clone = events.clone();
+        let handler = AnyMessageHandler::new(Ustr::from(topic), move |msg: &dyn Any| {
+            events_clone
+                .borrow_mut()
+                .push(msg.downcast_ref::<T>().unwrap().clone());
+        });
+        msgbus.borrow_mut().subscribe_any(topic, handler);
+        events
+    }
+
+    /// An execution client which records the commands it receives.
+    struct TestExecutionClient {
+        base: BaseExecutionClient,
+        commands: Events<TradingCommand>,
+    }
+
+    impl ExecutionClient for TestExecutionClient {
+        fn client_id(&self) -> ClientId {
+            self.base.client_id
+        }
+
+        fn venue(&self) -> Venue {
+            self.base.venue
+        }
+
+        fn oms_type(&self) -> OmsType {
+            self.base.oms_type
+        }
+
+        fn account_id(&self) -> AccountId {
+            self.base.account_id
+        }
+
+        fn is_connected(&self) -> bool {
+            self.base.is_connected
+        }
+
+        fn start(&mut self) -> anyhow::Result<()> {
+            self.base.is_connected = true;
+            Ok(())
+        }
+
+        fn stop(&mut self) -> anyhow::Result<()> {
+            self.base.is_connected = false;
+            Ok(())
+        }
+
+        fn submit_order(&self, command: SubmitOrder) -> anyhow::Result<()> {
+            self.base.generate_order_submitted(
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                command.ts_init,
+            )?;
+            self.record(TradingCommand::SubmitOrder(command))
+        }
+
+        fn submit_order_list(&self, command: SubmitOrderList) -> anyhow::Result<()> {
+            self.record(TradingCommand::SubmitOrderList(command))
+        }
+
+        fn modify_order(&self, command: ModifyOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::ModifyOrder(command))
+        }
+
+        fn cancel_order(&self, command: CancelOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::CancelOrder(command))
+        }
+
+        fn cancel_all_orders(&self, command: CancelAllOrders) -> anyhow::Result<()> {
+            self.record(TradingCommand::CancelAllOrders(command))
+        }
+
+        fn batch_cancel_orders(&self, command: BatchCancelOrders) -> anyhow::Result<()> {
+            self.record(TradingCommand::BatchCancelOrders(command))
+        }
+
+        fn query_order(&self, command: QueryOrder) -> anyhow::Result<()> {
+            self.record(TradingCommand::QueryOrder(command))
+        }
+    }
+
+    impl TestExecutionClient {
+        fn record(&self, command: TradingCommand) -> anyhow::Result<()> {
+            self.commands.borrow_mut().push(command);
+            Ok(())
+        }
+    }
+
+    fn get_client(
+        clock: &'static AtomicTime,
+        cache: &Rc<RefCell<Cache>>,
+        msgbus: &Rc<RefCell<MessageBus>>,
+        venue: &str,
+        oms_type: OmsType,
+    ) -> (Box<dyn ExecutionClient>, Events<TradingCommand>) {
+        let base = BaseExecutionClient::new(
+            ClientId::from(venue),
+            Venue::from(venue),
+            oms_type,
+            AccountId::from(format!("{venue}-001").as_str()),
+            AccountType::Margin,
+            None,
+            clock,
+            cache.clone(),
+            msgbus.clone(),
+        );
+        let commands: Events<TradingCommand> = Rc::new(RefCell::new(Vec::new()));
+        let client = TestExecutionClient {
+            base,
+            commands: commands.clone(),
+        };
+        (Box::new(client), commands)
+    }
+
+    #[fixture]
+    fn context(audusd_sim: CurrencyPair) -> TestContext {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap(),
+        ));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim))
+            .unwrap();
+
+        let order_events = subscribe(&msgbus, "events.order.*");
+        let position_events = subscribe(&msgbus, "events.position.*");
+
+        let (client, commands) = get_client(clock, &cache, &msgbus, "SIM", OmsType::Netting);
+        let mut engine = ExecutionEngine::new(
+            clock,
+            msgbus.clone(),
+            cache.clone(),
+            ExecutionEngineConfig::default(),
+        );
+        engine.register_client(client).unwrap();
+
+        TestContext {
+            engine,
+            clock,
+            cache,
+            msgbus,
+            order_events,
+            position_events,
+            commands,
+        }
+    }
+
+    fn submitted_order(
+        context: &mut TestContext,
+        side: OrderSide,
+        client_order_id: &str,
+    ) -> OrderAny {
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            audusd_sim().id,
+            side,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        ));
+        context
+            .cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+
+        let submitted = OrderSubmitted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            AccountId::from("SIM-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        context.engine.process(&OrderEventAny::Submitted(submitted));
+
+        context
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .cloned()
+            .unwrap()
+    }
+
+    fn fill(order: &OrderAny, last_qty: &str, position_id: Option<PositionId>) -> OrderFilled {
+        let OrderAny::Market(market_order) = order else {
+            panic!("Expected market order");
+        };
+        let mut fill = TestOrderEventStubs::order_filled(
+            market_order,
+            &audusd_sim(),
+            None,
+            None,
+            None,
+            Some(Price::from("1.00000")),
+            Some(Quantity::from(last_qty)),
+            None,
+            None,
+        );
+        fill.position_id = position_id;
+        fill
+    }
+
+    #[rstest]
+    fn test_register_client_when_already_registered_returns_error(mut context: TestContext) {
+        let (client, _) = get_client(
+            context.clock,
+            &context.cache,
+            &context.msgbus,
+            "SIM",
+            OmsType::Netting,
+        );
+
+        assert!(context.engine.register_client(client).is_err());
+    }
+
+    #[rstest]
+    fn test_register_venue_routing_when_client_not_registered_returns_error(
+        mut context: TestContext,
+    ) {
+        let result = context
+            .engine
+            .register_venue_routing(ClientId::from("OTHER"), Venue::from("XNAS"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_deregister_client(mut context: TestContext) {
+        context
+            .engine
+            .deregister_client(ClientId::from("SIM"))
+            .unwrap();
+
+        assert!(context
+            .engine
+            .deregister_client(ClientId::from("SIM"))
+            .is_err());
+        assert!(context.engine.check_disconnected());
+    }
+
+    #[rstest]
+    fn test_register_external_order_claims_when_already_claimed_returns_error(
+        mut context: TestContext,
+    ) {
+        let instrument_ids = HashSet::from([audusd_sim().id]);
+        context
+            .engine
+            .register_external_order_claims(strategy_id_ema_cross(), instrument_ids.clone())
+            .unwrap();
+
+        let result = context
+            .engine
+            .register_external_order_claims(strategy_id_ema_cross(), instrument_ids.clone());
+
+        assert!(result.is_err());
+        assert_eq!(
+            context.engine.get_external_order_claims_instruments(),
+            instrument_ids
+        );
+        assert_eq!(
+            context.engine.get_external_order_claim(&audusd_sim().id),
+            Some(strategy_id_ema_cross())
+        );
+    }
+
+    #[rstest]
+    fn test_execute_submit_order_when_no_client_does_not_cache_order(mut context: TestContext) {
+        context
+            .engine
+            .deregister_client(ClientId::from("SIM"))
+            .unwrap();
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        ));
+        let command = SubmitOrder::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            strategy_id_ema_cross(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        context.engine.execute(TradingCommand::SubmitOrder(command));
+
+        assert_eq!(context.engine.command_count, 1);
+        assert!(!context
+            .cache
+            .borrow()
+            .order_exists(&order.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_execute_submit_order_routes_to_client(context: TestContext) {
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        ));
+        let command = SubmitOrder::new(
+            trader_id(),
+            ClientId::from("OTHER"),
+            strategy_id_ema_cross(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::default(),
+            order.clone(),
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        let engine = Rc::new(RefCell::new(context.engine));
+        ExecutionEngine::register_endpoints(&engine);
+        context
+            .msgbus
+            .borrow()
+            .send(EXEC_ENGINE_EXECUTE, &TradingCommand::SubmitOrder(command));
+
+        let commands = context.commands.borrow();
+        assert_eq!(commands.len(), 1);
+        let TradingCommand::SubmitOrder(submitted) = &commands[0] else {
+            panic!("Unexpected command {:?}", commands[0]);
+        };
+        assert_eq!(submitted.client_order_id, order.client_order_id());
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .order(&order.client_order_id())
+                .unwrap()
+                .as_order()
+                .status(),
+            OrderStatus::Submitted
+        );
+        assert_eq!(engine.borrow().command_count, 1);
+        assert_eq!(engine.borrow().event_count, 1);
+        assert_eq!(context.order_events.borrow().len(), 1);
+    }
+
+    #[rstest]
+    fn test_process_event_for_unknown_order_does_nothing(mut context: TestContext) {
+        let order = OrderAny::Market(TestOrderStubs::market_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Quantity::from(100_000),
+            Some(ClientOrderId::from("O-1")),
+            None,
+        ));
+        let event = OrderEventAny::Filled(fill(&order, "100000", None));
+
+        context.engine.process(&event);
+
+        assert_eq!(context.engine.event_count, 1);
+        assert!(context.order_events.borrow().is_empty());
+        assert!(context.position_events.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_process_accepted_updates_cached_order_and_publishes(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let accepted = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("V-1"),
+            AccountId::from("SIM-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+        )
+        .unwrap();
+
+        context.engine.process(&OrderEventAny::Accepted(accepted));
+
+        let cache = context.cache.borrow();
+        let cached_order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(cached_order.as_order().status(), OrderStatus::Accepted);
+        assert!(cache.is_order_open(&order.client_order_id()));
+        assert_eq!(
+            cache.client_order_id(&VenueOrderId::from("V-1")),
+            Some(&order.client_order_id())
+        );
+        let order_events = context.order_events.borrow();
+        assert_eq!(order_events.len(), 2);
+        assert!(matches!(order_events[1], OrderEventAny::Accepted(_)));
+    }
+
+    #[rstest]
+    fn test_process_invalid_event_is_not_published(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let event = OrderEventAny::Filled(fill(&order, "100000", None));
+        context.engine.process(&event);
+
+        // The order is already filled, so cannot be accepted
+        let accepted = OrderAccepted::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            VenueOrderId::from("V-1"),
+            AccountId::from("SIM-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+        )
+        .unwrap();
+        context.engine.process(&OrderEventAny::Accepted(accepted));
+
+        assert_eq!(context.engine.event_count, 3);
+        assert_eq!(context.order_events.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_process_fill_netting_opens_position(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order, "100000", None)));
+
+        let expected_id = PositionId::from("AUD/USD.SIM-EMACross-001");
+        let cache = context.cache.borrow();
+        let position = cache.position(&expected_id).unwrap();
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.quantity, Quantity::from(100_000));
+        assert_eq!(
+            cache.position_id(&order.client_order_id()),
+            Some(&expected_id)
+        );
+        assert_eq!(
+            cache
+                .order(&order.client_order_id())
+                .unwrap()
+                .as_order()
+                .status(),
+            OrderStatus::Filled
+        );
+        match &context.order_events.borrow()[1] {
+            OrderEventAny::Filled(fill) => assert_eq!(fill.position_id, Some(expected_id)),
+            event => panic!("Unexpected event {event}"),
+        }
+        let position_events = context.position_events.borrow();
+        assert_eq!(position_events.len(), 1);
+        match &position_events[0] {
+            PositionEvent::PositionOpened(event) => {
+                assert_eq!(event.position_id, expected_id);
+                assert_eq!(event.side, PositionSide::Long);
+                assert_eq!(event.quantity, Quantity::from(100_000));
+            }
+            _ => panic!("Expected PositionOpened"),
+        }
+    }
+
+    #[rstest]
+    fn test_process_fills_netting_changes_then_closes_position(mut context: TestContext) {
+        let order1 = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let order2 = submitted_order(&mut context, OrderSide::Sell, "O-2");
+        let order3 = submitted_order(&mut context, OrderSide::Sell, "O-3");
+
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order1, "100000", None)));
+        context.engine.process(&OrderEventAny::PartiallyFilled(fill(
+            &order2, "40000", None,
+        )));
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order3, "60000", None)));
+
+        let position_events = context.position_events.borrow();
+        assert_eq!(position_events.len(), 3);
+        match &position_events[1] {
+            PositionEvent::PositionChanged(event) => {
+                assert_eq!(event.quantity, Quantity::from(60_000));
+                assert_eq!(event.last_qty, Quantity::from(40_000));
+            }
+            _ => panic!("Expected PositionChanged"),
+        }
+        match &position_events[2] {
+            PositionEvent::PositionClosed(event) => {
+                assert_eq!(event.side, PositionSide::Flat);
+                assert_eq!(event.closing_order_id, order3.client_order_id());
+            }
+            _ => panic!("Expected PositionClosed"),
+        }
+        let cache = context.cache.borrow();
+        let position_id = PositionId::from("AUD/USD.SIM-EMACross-001");
+        assert!(cache.is_position_closed(&position_id));
+        assert!(cache.position(&position_id).unwrap().is_closed());
+    }
+
+    #[rstest]
+    fn test_process_fill_netting_reopens_closed_position(mut context: TestContext) {
+        let order1 = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let order2 = submitted_order(&mut context, OrderSide::Sell, "O-2");
+        let order3 = submitted_order(&mut context, OrderSide::Sell, "O-3");
+
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order1, "100000", None)));
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order2, "100000", None)));
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order3, "100000", None)));
+
+        let position_events = context.position_events.borrow();
+        assert_eq!(position_events.len(), 3);
+        assert!(matches!(
+            position_events[2],
+            PositionEvent::PositionOpened(_)
+        ));
+        let cache = context.cache.borrow();
+        let position = cache
+            .position(&PositionId::from("AUD/USD.SIM-EMACross-001"))
+            .unwrap();
+        assert_eq!(position.side, PositionSide::Short);
+        assert!(cache.is_position_open(&position.id));
+    }
+
+    #[rstest]
+    fn test_process_fill_hedging_uses_fill_position_id(mut context: TestContext) {
+        context
+            .engine
+            .register_oms_type(strategy_id_ema_cross(), OmsType::Hedging);
+        let order1 = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let order2 = submitted_order(&mut context, OrderSide::Buy, "O-2");
+
+        let position_id1 = PositionId::from("SIM-1-001");
+        let position_id2 = PositionId::from("SIM-1-002");
+        context.engine.process(&OrderEventAny::Filled(fill(
+            &order1,
+            "100000",
+            Some(position_id1),
+        )));
+        context.engine.process(&OrderEventAny::Filled(fill(
+            &order2,
+            "100000",
+            Some(position_id2),
+        )));
+
+        let cache = context.cache.borrow();
+        assert_eq!(cache.positions_open_count(None, None, None, None), 2);
+        assert!(cache.is_position_open(&position_id1));
+        assert!(cache.is_position_open(&position_id2));
+    }
+
+    #[rstest]
+    fn test_process_fill_reassigns_cached_position_id(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let position_id = PositionId::from("P-001");
+        context
+            .cache
+            .borrow_mut()
+            .add_position_id(
+                &position_id,
+                &order.instrument_id().venue,
+                &order.client_order_id(),
+                &order.strategy_id(),
+            )
+            .unwrap();
+
+        context.engine.process(&OrderEventAny::Filled(fill(
+            &order,
+            "100000",
+            Some(PositionId::from("P-002")),
+        )));
+
+        let cache = context.cache.borrow();
+        assert!(cache.position_exists(&position_id));
+        assert!(!cache.position_exists(&PositionId::from("P-002")));
+    }
+
+    #[rstest]
+    fn test_process_fill_hedging_without_position_id_generates_position_id(
+        mut context: TestContext,
+    ) {
+        context
+            .engine
+            .register_oms_type(strategy_id_ema_cross(), OmsType::Hedging);
+        let order1 = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let order2 = submitted_order(&mut context, OrderSide::Buy, "O-2");
+
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order1, "100000", None)));
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order2, "100000", None)));
+
+        let position_id1 = PositionId::from("P-19700101-0000-001-001-1");
+        let position_id2 = PositionId::from("P-19700101-0000-001-001-2");
+        assert_eq!(context.engine.position_id_count(strategy_id_ema_cross()), 2);
+        let cache = context.cache.borrow();
+        assert_eq!(
+            cache.position_id(&order1.client_order_id()),
+            Some(&position_id1)
+        );
+        assert_eq!(
+            cache.position_id(&order2.client_order_id()),
+            Some(&position_id2)
+        );
+        assert!(cache.is_position_open(&position_id1));
+        assert!(cache.is_position_open(&position_id2));
+    }
+
+    #[rstest]
+    fn test_process_fill_netting_larger_than_position_flips_position(mut context: TestContext) {
+        let order1 = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let order2 = submitted_order(&mut context, OrderSide::Sell, "O-2");
+        context.engine.process(&OrderEventAny::PartiallyFilled(fill(
+            &order1, "25000", None,
+        )));
+
+        let mut fill2 = fill(&order2, "100000", None);
+        fill2.commission = Some(Money::from("2.00 USD"));
+        context.engine.process(&OrderEventAny::Filled(fill2));
+
+        let position_events = context.position_events.borrow();
+        assert_eq!(position_events.len(), 3);
+        let PositionEvent::PositionClosed(closed) = &position_events[1] else {
+            panic!("Expected position closed");
+        };
+        assert_eq!(closed.last_qty, Quantity::from(25_000));
+        assert!(matches!(
+            position_events[2],
+            PositionEvent::PositionOpened(_)
+        ));
+
+        let cache = context.cache.borrow();
+        let position = cache
+            .position(&PositionId::from("AUD/USD.SIM-EMACross-001"))
+            .unwrap();
+        assert_eq!(position.side, PositionSide::Short);
+        assert_eq!(position.quantity, Quantity::from(75_000));
+        assert_eq!(
+            position.commissions[&Currency::USD()],
+            Money::from("1.50 USD")
+        );
+        assert!(cache.is_position_open(&position.id));
+        assert!(!cache.is_position_closed(&position.id));
+    }
+
+    #[rstest]
+    fn test_process_fill_hedging_larger_than_position_flips_to_new_position(
+        mut context: TestContext,
+    ) {
+        context
+            .engine
+            .register_oms_type(strategy_id_ema_cross(), OmsType::Hedging);
+        let order1 = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let order2 = submitted_order(&mut context, OrderSide::Sell, "O-2");
+        let position_id = PositionId::from("SIM-1-001");
+        context.engine.process(&OrderEventAny::PartiallyFilled(fill(
+            &order1,
+            "50000",
+            Some(position_id),
+        )));
+
+        context.engine.process(&OrderEventAny::Filled(fill(
+            &order2,
+            "100000",
+            Some(position_id),
+        )));
+
+        let flipped_id = PositionId::from("P-19700101-0000-001-001-1F");
+        let cache = context.cache.borrow();
+        assert!(cache.is_position_closed(&position_id));
+        let flipped = cache.position(&flipped_id).unwrap();
+        assert_eq!(flipped.side, PositionSide::Short);
+        assert_eq!(flipped.quantity, Quantity::from(50_000));
+        assert!(cache.is_position_open(&flipped_id));
+    }
+
+    #[rstest]
+    #[case(0.25, "0.50 USD", "1.50 USD")]
+    #[case(1.0 / 3.0, "0.67 USD", "1.33 USD")]
+    fn test_split_commission(
+        #[case] fill_percent: f64,
+        #[case] expected1: &str,
+        #[case] expected2: &str,
+    ) {
+        let (commission1, commission2) = split_commission(Money::from("2.00 USD"), fill_percent);
+
+        assert_eq!(commission1, Money::from(expected1));
+        assert_eq!(commission2, Money::from(expected2));
+    }
+
+    #[rstest]
+    fn test_process_duplicate_fill_does_not_update_position(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let fill = fill(&order, "50000", None);
+        context
+            .engine
+            .process(&OrderEventAny::PartiallyFilled(fill));
+
+        context
+            .engine
+            .process(&OrderEventAny::PartiallyFilled(fill));
+
+        let cache = context.cache.borrow();
+        let position = cache
+            .position(&PositionId::from("AUD/USD.SIM-EMACross-001"))
+            .unwrap();
+        assert_eq!(position.quantity, Quantity::from(50_000));
+        assert_eq!(context.position_events.borrow().len(), 1);
+    }
+
+    fn order_report(
+        client_order_id: Option<&str>,
+        order_status: OrderStatus,
+        filled_qty: &str,
+    ) -> OrderStatusReport {
+        let mut report = OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            audusd_sim().id,
+            client_order_id.map(ClientOrderId::from),
+            VenueOrderId::from("V-1"),
+            OrderSide::Buy,
+            OrderType::Market,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from(100_000),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        report.avg_px = Some(1.0);
+        report
+    }
+
+    fn fill_report(trade_id: &str, last_qty: &str) -> FillReport {
+        FillReport::new(
+            AccountId::from("SIM-001"),
+            audusd_sim().id,
+            VenueOrderId::from("V-1"),
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from("1.00000"),
+            Some(Money::from("2 USD")),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn mass_status(
+        order_reports: Vec<OrderStatusReport>,
+        fill_reports: Vec<FillReport>,
+    ) -> ExecutionMassStatus {
+        let mut mass_status = ExecutionMassStatus::new(
+            ClientId::from("SIM"),
+            AccountId::from("SIM-001"),
+            Venue::from("SIM"),
+            UUID4::new(),
+            UnixNanos::default(),
+        );
+        mass_status.add_order_reports(order_reports);
+        mass_status.add_fill_reports(fill_reports);
+        mass_status
+    }
+
+    #[rstest]
+    fn test_reconcile_order_report_generates_external_order(mut context: TestContext) {
+        let reports = subscribe::<OrderStatusReport>(&context.msgbus, "reports.execution.*");
+
+        let result =
+            context
+                .engine
+                .reconcile_order_report(order_report(None, OrderStatus::Accepted, "0"));
+
+        assert!(result);
+        assert_eq!(context.engine.report_count, 1);
+        let cache = context.cache.borrow();
+        let client_order_id = *cache.client_order_id(&VenueOrderId::from("V-1")).unwrap();
+        let order = cache.order(&client_order_id).unwrap();
+        assert_eq!(order.strategy_id(), StrategyId::external());
+        assert_eq!(order.as_order().status(), OrderStatus::Accepted);
+        assert_eq!(reports.borrow()[0].client_order_id, Some(client_order_id));
+    }
+
+    #[rstest]
+    fn test_reconcile_order_report_with_claim_uses_claiming_strategy(mut context: TestContext) {
+        context
+            .engine
+            .register_external_order_claims(
+                strategy_id_ema_cross(),
+                HashSet::from([audusd_sim().id]),
+            )
+            .unwrap();
+
+        let result = context.engine.reconcile_order_report(order_report(
+            Some("O-EXT"),
+            OrderStatus::Accepted,
+            "0",
+        ));
+
+        assert!(result);
+        let order = context
+            .cache
+            .borrow()
+            .order(&ClientOrderId::from("O-EXT"))
+            .cloned()
+            .unwrap();
+        assert_eq!(order.strategy_id(), strategy_id_ema_cross());
+    }
+
+    #[rstest]
+    fn test_reconcile_order_report_filters_unclaimed_external_order(mut context: TestContext) {
+        context.engine.config.filter_unclaimed_external_orders = true;
+
+        let result =
+            context
+                .engine
+                .reconcile_order_report(order_report(None, OrderStatus::Accepted, "0"));
+
+        assert!(result);
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .orders_total_count(None, None, None, None),
+            0
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_order_report_for_external_limit_order_without_price_returns_false(
+        mut context: TestContext,
+    ) {
+        let mut report = order_report(None, OrderStatus::Accepted, "0");
+        report.order_type = OrderType::Limit;
+
+        assert!(!context.engine.reconcile_order_report(report));
+        assert_eq!(
+            context
+                .cache
+                .borrow()
+                .orders_total_count(None, None, None, None),
+            0
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_order_report_canceled_generates_accepted_then_canceled(
+        mut context: TestContext,
+    ) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+
+        let result = context.engine.reconcile_order_report(order_report(
+            Some("O-1"),
+            OrderStatus::Canceled,
+            "0",
+        ));
+
+        assert!(result);
+        let order_events = context.order_events.borrow();
+        assert_eq!(order_events.len(), 3);
+        assert!(matches!(order_events[1], OrderEventAny::Accepted(_)));
+        assert!(matches!(order_events[2], OrderEventAny::Canceled(_)));
+        let cache = context.cache.borrow();
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Canceled);
+    }
+
+    #[rstest]
+    fn test_reconcile_mass_status_applies_fill_reports(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        let mass_status = mass_status(
+            vec![order_report(Some("O-1"), OrderStatus::Filled, "100000")],
+            vec![fill_report("E-1", "40000"), fill_report("E-2", "60000")],
+        );
+
+        assert!(context.engine.reconcile_mass_status(mass_status));
+
+        let cache = context.cache.borrow();
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::Filled);
+        assert_eq!(
+            order.as_order().trade_ids(),
+            vec![&TradeId::from("E-1"), &TradeId::from("E-2")]
+        );
+        let position = cache
+            .position(&PositionId::from("AUD/USD.SIM-EMACross-001"))
+            .unwrap();
+        assert_eq!(position.quantity, Quantity::from(100_000));
+        assert_eq!(context.position_events.borrow().len(), 2);
+    }
+
+    #[rstest]
+    fn test_reconcile_order_report_with_missing_fills_generates_inferred_fill(
+        mut context: TestContext,
+    ) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+
+        let result = context.engine.reconcile_order_report(order_report(
+            Some("O-1"),
+            OrderStatus::PartiallyFilled,
+            "40000",
+        ));
+
+        assert!(result);
+        let cache = context.cache.borrow();
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.as_order().status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.as_order().filled_qty(), Quantity::from(40_000));
+        let order_events = context.order_events.borrow();
+        let OrderEventAny::PartiallyFilled(fill) = order_events.last().unwrap() else {
+            panic!("Expected inferred partial fill");
+        };
+        assert_eq!(fill.last_px, Price::from("1.00000"));
+        assert!(fill.reconciliation);
+        assert!(cache.is_position_open(&PositionId::from("AUD/USD.SIM-EMACross-001")));
+    }
+
+    #[rstest]
+    fn test_reconcile_fill_report_for_unknown_order_returns_false(mut context: TestContext) {
+        assert!(!context
+            .engine
+            .reconcile_fill_report(fill_report("E-1", "100000")));
+    }
+
+    #[rstest]
+    fn test_reconcile_fill_report_for_duplicate_trade_does_not_apply(mut context: TestContext) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        context.engine.reconcile_order_report(order_report(
+            Some("O-1"),
+            OrderStatus::Accepted,
+            "0",
+        ));
+
+        assert!(context
+            .engine
+            .reconcile_fill_report(fill_report("E-1", "40000")));
+        assert!(context
+            .engine
+            .reconcile_fill_report(fill_report("E-1", "40000")));
+
+        let cache = context.cache.borrow();
+        let order = cache.order(&order.client_order_id()).unwrap();
+        assert_eq!(order.as_order().filled_qty(), Quantity::from(40_000));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, "100000", true)]
+    #[case(PositionSide::Long, "50000", false)]
+    #[case(PositionSide::Short, "100000", false)]
+    fn test_reconcile_position_report_netting(
+        mut context: TestContext,
+        #[case] position_side: PositionSide,
+        #[case] quantity: &str,
+        #[case] expected: bool,
+    ) {
+        let order = submitted_order(&mut context, OrderSide::Buy, "O-1");
+        context
+            .engine
+            .process(&OrderEventAny::Filled(fill(&order, "100000", None)));
+        let report = PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            audusd_sim().id,
+            position_side,
+            Quantity::from(quantity),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert_eq!(context.engine.reconcile_position_report(report), expected);
+    }
+
+    #[rstest]
+    fn test_reconcile_position_report_for_unknown_venue_position_returns_false(
+        mut context: TestContext,
+    ) {
+        let report = PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            audusd_sim().id,
+            PositionSide::Long,
+            Quantity::from(100_000),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            Some(PositionId::from("SIM-1-001")),
+        );
+
+        assert!(!context.engine.reconcile_position_report(report));
     }
 }
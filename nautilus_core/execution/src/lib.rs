@@ -27,7 +27,11 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`
 //! - `python`: Enables Python bindings from `pyo3`
 
+pub mod algorithm;
 pub mod client;
+pub mod emulator;
 pub mod engine;
 pub mod matching_core;
 pub mod messages;
+pub mod reports;
+pub mod trailing;
@@ -43,6 +43,8 @@ pub struct OrderMatchingCore {
     pub ask: Option<Price>,
     /// The last price for the matching core.
     pub last: Option<Price>,
+    /// The mark price for the matching core.
+    pub mark: Option<Price>,
     orders_bid: Vec<PassiveOrderAny>,
     orders_ask: Vec<PassiveOrderAny>,
     trigger_stop_order: Option<fn(StopOrderAny)>,
@@ -65,6 +67,7 @@ impl OrderMatchingCore {
             bid: None,
             ask: None,
             last: None,
+            mark: None,
             orders_bid: Vec::new(),
             orders_ask: Vec::new(),
             trigger_stop_order,
@@ -90,6 +93,25 @@ impl OrderMatchingCore {
         self.orders_ask.as_slice()
     }
 
+    #[must_use]
+    pub fn get_order(&self, client_order_id: ClientOrderId) -> Option<&PassiveOrderAny> {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .find(|o| o.client_order_id() == client_order_id)
+    }
+
+    #[must_use]
+    pub fn get_order_mut(
+        &mut self,
+        client_order_id: ClientOrderId,
+    ) -> Option<&mut PassiveOrderAny> {
+        self.orders_bid
+            .iter_mut()
+            .chain(self.orders_ask.iter_mut())
+            .find(|o| o.client_order_id() == client_order_id)
+    }
+
     #[must_use]
     pub fn order_exists(&self, client_order_id: ClientOrderId) -> bool {
         self.orders_bid
@@ -107,6 +129,7 @@ impl OrderMatchingCore {
         self.bid = None;
         self.ask = None;
         self.last = None;
+        self.mark = None;
         self.orders_bid.clear();
         self.orders_ask.clear();
     }
@@ -193,17 +216,43 @@ impl OrderMatchingCore {
 
     #[must_use]
     pub fn is_limit_matched(&self, order: &LimitOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a <= order.limit_px()),
-            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b >= order.limit_px()),
-        }
+        self.is_limit_price_matched(order.order_side_specified(), order.limit_px())
     }
 
     #[must_use]
     pub fn is_stop_matched(&self, order: &StopOrderAny) -> bool {
-        match order.order_side_specified() {
-            OrderSideSpecified::Buy => self.ask.map_or(false, |a| a >= order.stop_px()),
-            OrderSideSpecified::Sell => self.bid.map_or(false, |b| b <= order.stop_px()),
+        match order {
+            StopOrderAny::LimitIfTouched(_) | StopOrderAny::MarketIfTouched(_) => {
+                self.is_touch_price_triggered(order.order_side_specified(), order.stop_px())
+            }
+            _ => self.is_stop_price_triggered(order.order_side_specified(), order.stop_px()),
+        }
+    }
+
+    /// Returns whether a limit order with the given `side` and `price` would match the market.
+    #[must_use]
+    pub fn is_limit_price_matched(&self, side: OrderSideSpecified, price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= price),
+        }
+    }
+
+    /// Returns whether a stop order with the given `side` and `trigger_price` would trigger.
+    #[must_use]
+    pub fn is_stop_price_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a >= trigger_price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b <= trigger_price),
+        }
+    }
+
+    /// Returns whether an if-touched order with the given `side` and `trigger_price` would trigger.
+    #[must_use]
+    pub fn is_touch_price_triggered(&self, side: OrderSideSpecified, trigger_price: Price) -> bool {
+        match side {
+            OrderSideSpecified::Buy => self.ask.is_some_and(|a| a <= trigger_price),
+            OrderSideSpecified::Sell => self.bid.is_some_and(|b| b >= trigger_price),
         }
     }
 }
@@ -577,4 +626,80 @@ mod tests {
         assert_eq!(filled_limits.len(), 1);
         assert_eq!(filled_limits[0], LimitOrderAny::Limit(order));
     }
+
+    #[rstest]
+    #[case(None, None, Price::from("100.00"), OrderSideSpecified::Buy, false)]
+    #[case(None, None, Price::from("100.00"), OrderSideSpecified::Sell, false)]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("100.00"),  // <-- Trigger below ask
+        OrderSideSpecified::Buy,
+        false
+    )]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("101.00"),  // <-- Trigger at ask
+        OrderSideSpecified::Buy,
+        true
+    )]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("99.00"),  // <-- Trigger below bid
+        OrderSideSpecified::Sell,
+        true
+    )]
+    #[case(
+        Some(Price::from("100.00")),
+        Some(Price::from("101.00")),
+        Price::from("100.50"),  // <-- Trigger above bid
+        OrderSideSpecified::Sell,
+        false
+    )]
+    fn test_is_touch_price_triggered(
+        #[case] bid: Option<Price>,
+        #[case] ask: Option<Price>,
+        #[case] trigger_price: Price,
+        #[case] side: OrderSideSpecified,
+        #[case] expected: bool,
+    ) {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+        matching_core.bid = bid;
+        matching_core.ask = ask;
+
+        assert_eq!(
+            matching_core.is_touch_price_triggered(side, trigger_price),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_get_order() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut matching_core = create_matching_core(instrument_id, Price::from("0.01"));
+
+        let order = TestOrderStubs::limit_order(
+            instrument_id,
+            OrderSide::Sell,
+            Price::from("100.00"),
+            Quantity::from("100"),
+            None,
+            None,
+        );
+        let client_order_id = order.client_order_id;
+
+        let passive_order = PassiveOrderAny::Limit(LimitOrderAny::Limit(order));
+        matching_core.add_order(passive_order.clone()).unwrap();
+
+        assert_eq!(
+            matching_core.get_order(client_order_id),
+            Some(&passive_order)
+        );
+        assert!(matching_core
+            .get_order(ClientOrderId::from("O-UNKNOWN"))
+            .is_none());
+    }
 }
@@ -31,6 +31,7 @@ pub mod query;
 pub mod submit;
 pub mod submit_list;
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Display)]
 pub enum TradingCommand {
     SubmitOrder(SubmitOrder),
@@ -15,17 +15,18 @@
 
 use std::fmt::Display;
 
-use derive_builder::Builder;
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
-use nautilus_model::identifiers::{
-    client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
-    instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
-    trader_id::TraderId, venue_order_id::VenueOrderId,
+use nautilus_model::{
+    identifiers::{
+        client_id::ClientId, client_order_id::ClientOrderId, exec_algorithm_id::ExecAlgorithmId,
+        instrument_id::InstrumentId, position_id::PositionId, strategy_id::StrategyId,
+        trader_id::TraderId, venue_order_id::VenueOrderId,
+    },
+    orders::any::OrderAny,
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize, Builder)]
-#[builder(default)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub struct SubmitOrder {
     pub trader_id: TraderId,
@@ -34,7 +35,7 @@ pub struct SubmitOrder {
     pub instrument_id: InstrumentId,
     pub client_order_id: ClientOrderId,
     pub venue_order_id: VenueOrderId,
-    // order: OrderAny,  // TODO: Implement Eq
+    pub order: OrderAny,
     pub exec_algorith_id: Option<ExecAlgorithmId>,
     pub position_id: Option<PositionId>,
     pub command_id: UUID4,
@@ -50,6 +51,7 @@ impl SubmitOrder {
         instrument_id: InstrumentId,
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
+        order: OrderAny,
         exec_algorith_id: Option<ExecAlgorithmId>,
         position_id: Option<PositionId>,
         command_id: UUID4,
@@ -62,6 +64,7 @@ impl SubmitOrder {
             instrument_id,
             client_order_id,
             venue_order_id,
+            order,
             exec_algorith_id,
             position_id,
             command_id,
@@ -74,8 +77,9 @@ impl Display for SubmitOrder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "SubmitOrder(instrument_id={}, order=TBD, position_id={})",
+            "SubmitOrder(instrument_id={}, order={}, position_id={})",
             self.instrument_id,
+            self.order,
             self.position_id
                 .map_or("None".to_string(), |position_id| format!("{position_id}")),
         )
@@ -0,0 +1,157 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{LiquiditySide, OrderSide},
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        position_id::PositionId, trade_id::TradeId, venue_order_id::VenueOrderId,
+    },
+    types::{money::Money, price::Price, quantity::Quantity},
+};
+
+/// Represents a report of a single order fill, as reported by the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FillReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub venue_order_id: VenueOrderId,
+    pub trade_id: TradeId,
+    pub order_side: OrderSide,
+    pub last_qty: Quantity,
+    pub last_px: Price,
+    pub commission: Option<Money>,
+    pub liquidity_side: LiquiditySide,
+    pub report_id: UUID4,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+    /// The reported client order ID (`None` for an order external to the system).
+    pub client_order_id: Option<ClientOrderId>,
+    /// The position ID assigned by the venue (if `None` then the execution engine OMS
+    /// will determine the position ID).
+    pub venue_position_id: Option<PositionId>,
+}
+
+impl FillReport {
+    /// Creates a new [`FillReport`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `last_qty` is not positive (> 0).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        venue_order_id: VenueOrderId,
+        trade_id: TradeId,
+        order_side: OrderSide,
+        last_qty: Quantity,
+        last_px: Price,
+        commission: Option<Money>,
+        liquidity_side: LiquiditySide,
+        report_id: UUID4,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+        client_order_id: Option<ClientOrderId>,
+        venue_position_id: Option<PositionId>,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(last_qty.is_positive(), "`last_qty` was not positive")?;
+
+        Ok(Self {
+            account_id,
+            instrument_id,
+            venue_order_id,
+            trade_id,
+            order_side,
+            last_qty,
+            last_px,
+            commission,
+            liquidity_side,
+            report_id,
+            ts_event,
+            ts_init,
+            client_order_id,
+            venue_position_id,
+        })
+    }
+}
+
+impl Display for FillReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FillReport(account_id={}, instrument_id={}, client_order_id={:?}, venue_order_id={}, venue_position_id={:?}, trade_id={}, order_side={}, last_qty={}, last_px={}, commission={:?}, liquidity_side={}, report_id={}, ts_event={}, ts_init={})",
+            self.account_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.venue_order_id,
+            self.venue_position_id,
+            self.trade_id,
+            self.order_side,
+            self.last_qty,
+            self.last_px,
+            self.commission,
+            self.liquidity_side,
+            self.report_id,
+            self.ts_event,
+            self.ts_init,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide},
+        identifiers::{
+            account_id::AccountId, instrument_id::InstrumentId, trade_id::TradeId,
+            venue_order_id::VenueOrderId,
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::FillReport;
+
+    #[rstest]
+    fn test_new_with_zero_last_qty_returns_error() {
+        let result = FillReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            VenueOrderId::from("V-1"),
+            TradeId::from("E-1"),
+            OrderSide::Buy,
+            Quantity::from("0"),
+            Price::from("0.80000"),
+            None,
+            LiquiditySide::Maker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,192 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use indexmap::IndexMap;
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::identifiers::{
+    account_id::AccountId, client_id::ClientId, instrument_id::InstrumentId, venue::Venue,
+    venue_order_id::VenueOrderId,
+};
+
+use super::{fill::FillReport, order::OrderStatusReport, position::PositionStatusReport};
+
+/// Represents an execution mass status report for an execution client, including the status
+/// of all orders, the fills for those orders and the open positions.
+///
+/// Reports are kept in insertion order so that reconciliation is deterministic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExecutionMassStatus {
+    pub client_id: ClientId,
+    pub account_id: AccountId,
+    pub venue: Venue,
+    pub report_id: UUID4,
+    pub ts_init: UnixNanos,
+    order_reports: IndexMap<VenueOrderId, OrderStatusReport>,
+    fill_reports: IndexMap<VenueOrderId, Vec<FillReport>>,
+    position_reports: IndexMap<InstrumentId, Vec<PositionStatusReport>>,
+}
+
+impl ExecutionMassStatus {
+    /// Creates a new [`ExecutionMassStatus`] instance.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        account_id: AccountId,
+        venue: Venue,
+        report_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            client_id,
+            account_id,
+            venue,
+            report_id,
+            ts_init,
+            order_reports: IndexMap::new(),
+            fill_reports: IndexMap::new(),
+            position_reports: IndexMap::new(),
+        }
+    }
+
+    /// Returns the order status reports keyed by venue order ID.
+    #[must_use]
+    pub fn order_reports(&self) -> &IndexMap<VenueOrderId, OrderStatusReport> {
+        &self.order_reports
+    }
+
+    /// Returns the fill reports keyed by venue order ID.
+    #[must_use]
+    pub fn fill_reports(&self) -> &IndexMap<VenueOrderId, Vec<FillReport>> {
+        &self.fill_reports
+    }
+
+    /// Returns the position status reports keyed by instrument ID.
+    #[must_use]
+    pub fn position_reports(&self) -> &IndexMap<InstrumentId, Vec<PositionStatusReport>> {
+        &self.position_reports
+    }
+
+    /// Adds the order `reports`, replacing any existing report for the same venue order ID.
+    pub fn add_order_reports(&mut self, reports: Vec<OrderStatusReport>) {
+        for report in reports {
+            self.order_reports.insert(report.venue_order_id, report);
+        }
+    }
+
+    /// Adds the fill `reports`.
+    pub fn add_fill_reports(&mut self, reports: Vec<FillReport>) {
+        for report in reports {
+            self.fill_reports
+                .entry(report.venue_order_id)
+                .or_default()
+                .push(report);
+        }
+    }
+
+    /// Adds the position status `reports`.
+    pub fn add_position_reports(&mut self, reports: Vec<PositionStatusReport>) {
+        for report in reports {
+            self.position_reports
+                .entry(report.instrument_id)
+                .or_default()
+                .push(report);
+        }
+    }
+}
+
+impl Display for ExecutionMassStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ExecutionMassStatus(client_id={}, account_id={}, venue={}, order_reports={}, fill_reports={}, position_reports={}, report_id={}, ts_init={})",
+            self.client_id,
+            self.account_id,
+            self.venue,
+            self.order_reports.len(),
+            self.fill_reports.values().map(Vec::len).sum::<usize>(),
+            self.position_reports.values().map(Vec::len).sum::<usize>(),
+            self.report_id,
+            self.ts_init,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide},
+        identifiers::{
+            account_id::AccountId, client_id::ClientId, instrument_id::InstrumentId,
+            trade_id::TradeId, venue::Venue, venue_order_id::VenueOrderId,
+        },
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::ExecutionMassStatus;
+    use crate::reports::fill::FillReport;
+
+    fn fill_report(venue_order_id: &str, trade_id: &str) -> FillReport {
+        FillReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            VenueOrderId::from(venue_order_id),
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            Quantity::from("100000"),
+            Price::from("0.80000"),
+            None,
+            LiquiditySide::Taker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_add_fill_reports_groups_by_venue_order_id() {
+        let mut mass_status = ExecutionMassStatus::new(
+            ClientId::from("SIM"),
+            AccountId::from("SIM-001"),
+            Venue::from("SIM"),
+            UUID4::new(),
+            UnixNanos::default(),
+        );
+
+        mass_status.add_fill_reports(vec![
+            fill_report("V-1", "E-1"),
+            fill_report("V-2", "E-2"),
+            fill_report("V-1", "E-3"),
+        ]);
+
+        let fill_reports = mass_status.fill_reports();
+        assert_eq!(fill_reports.len(), 2);
+        assert_eq!(fill_reports[&VenueOrderId::from("V-1")].len(), 2);
+        assert_eq!(
+            fill_reports[&VenueOrderId::from("V-1")][1].trade_id,
+            TradeId::from("E-3")
+        );
+        assert!(mass_status.order_reports().is_empty());
+    }
+}
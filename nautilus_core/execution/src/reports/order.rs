@@ -0,0 +1,225 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{correctness::check_predicate_true, nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{
+        ContingencyType, OrderSide, OrderStatus, OrderType, TimeInForce, TrailingOffsetType,
+        TriggerType,
+    },
+    identifiers::{
+        account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+        order_list_id::OrderListId, venue_order_id::VenueOrderId,
+    },
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents an order status at a point in time, as reported by the venue.
+///
+/// The optional order attributes default to `None` (or the equivalent "no value" enum
+/// variant) and can be set directly on the report after construction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    /// The reported client order ID (`None` for an order external to the system).
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub order_status: OrderStatus,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    pub report_id: UUID4,
+    pub ts_accepted: UnixNanos,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+    pub order_list_id: Option<OrderListId>,
+    pub contingency_type: ContingencyType,
+    pub expire_time: Option<UnixNanos>,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub trigger_type: TriggerType,
+    pub limit_offset: Option<Price>,
+    pub trailing_offset: Option<Price>,
+    pub trailing_offset_type: TrailingOffsetType,
+    pub avg_px: Option<f64>,
+    pub display_qty: Option<Quantity>,
+    pub post_only: bool,
+    pub reduce_only: bool,
+    pub cancel_reason: Option<String>,
+    pub ts_triggered: Option<UnixNanos>,
+}
+
+impl OrderStatusReport {
+    /// Creates a new [`OrderStatusReport`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `quantity` is not positive (> 0).
+    /// - If `filled_qty` is greater than `quantity`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        time_in_force: TimeInForce,
+        order_status: OrderStatus,
+        quantity: Quantity,
+        filled_qty: Quantity,
+        report_id: UUID4,
+        ts_accepted: UnixNanos,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(quantity.is_positive(), "`quantity` was not positive")?;
+        check_predicate_true(
+            filled_qty <= quantity,
+            "`filled_qty` was greater than `quantity`",
+        )?;
+
+        Ok(Self {
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_side,
+            order_type,
+            time_in_force,
+            order_status,
+            quantity,
+            filled_qty,
+            report_id,
+            ts_accepted,
+            ts_last,
+            ts_init,
+            order_list_id: None,
+            contingency_type: ContingencyType::NoContingency,
+            expire_time: None,
+            price: None,
+            trigger_price: None,
+            trigger_type: TriggerType::NoTrigger,
+            limit_offset: None,
+            trailing_offset: None,
+            trailing_offset_type: TrailingOffsetType::NoTrailingOffset,
+            avg_px: None,
+            display_qty: None,
+            post_only: false,
+            reduce_only: false,
+            cancel_reason: None,
+            ts_triggered: None,
+        })
+    }
+
+    /// Returns the reported quantity remaining to be filled.
+    #[must_use]
+    pub fn leaves_qty(&self) -> Quantity {
+        self.quantity - self.filled_qty
+    }
+
+    /// Returns whether the reported order was triggered.
+    #[must_use]
+    pub fn is_triggered(&self) -> bool {
+        self.ts_triggered.is_some_and(|ts| ts > 0)
+    }
+}
+
+impl Display for OrderStatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OrderStatusReport(account_id={}, instrument_id={}, client_order_id={:?}, venue_order_id={}, order_side={}, order_type={}, time_in_force={}, order_status={}, price={:?}, trigger_price={:?}, quantity={}, filled_qty={}, leaves_qty={}, avg_px={:?}, report_id={}, ts_accepted={}, ts_last={}, ts_init={})",
+            self.account_id,
+            self.instrument_id,
+            self.client_order_id,
+            self.venue_order_id,
+            self.order_side,
+            self.order_type,
+            self.time_in_force,
+            self.order_status,
+            self.price,
+            self.trigger_price,
+            self.quantity,
+            self.filled_qty,
+            self.leaves_qty(),
+            self.avg_px,
+            self.report_id,
+            self.ts_accepted,
+            self.ts_last,
+            self.ts_init,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+        identifiers::{
+            account_id::AccountId, instrument_id::InstrumentId, venue_order_id::VenueOrderId,
+        },
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+
+    use super::OrderStatusReport;
+
+    fn report(quantity: &str, filled_qty: &str) -> anyhow::Result<OrderStatusReport> {
+        OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            None,
+            VenueOrderId::from("V-1"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            OrderStatus::PartiallyFilled,
+            Quantity::from(quantity),
+            Quantity::from(filled_qty),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_new_with_defaults() {
+        let report = report("100000", "40000").unwrap();
+
+        assert_eq!(report.leaves_qty(), Quantity::from("60000"));
+        assert_eq!(report.price, None);
+        assert!(!report.post_only);
+        assert!(!report.is_triggered());
+    }
+
+    #[rstest]
+    #[case("0", "0")]
+    #[case("100000", "100001")]
+    fn test_new_with_invalid_quantities(#[case] quantity: &str, #[case] filled_qty: &str) {
+        assert!(report(quantity, filled_qty).is_err());
+    }
+}
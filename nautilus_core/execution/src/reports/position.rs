@@ -0,0 +1,130 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::PositionSide,
+    identifiers::{account_id::AccountId, instrument_id::InstrumentId, position_id::PositionId},
+    types::quantity::Quantity,
+};
+use rust_decimal::Decimal;
+
+/// Represents a position status at a point in time, as reported by the venue.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PositionStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub position_side: PositionSide,
+    pub quantity: Quantity,
+    /// The reported quantity, negative for a `SHORT` position.
+    pub signed_decimal_qty: Decimal,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+    /// The position ID assigned by the venue (if `None` then the execution engine OMS
+    /// will determine the position ID).
+    pub venue_position_id: Option<PositionId>,
+}
+
+impl PositionStatusReport {
+    /// Creates a new [`PositionStatusReport`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        position_side: PositionSide,
+        quantity: Quantity,
+        report_id: UUID4,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+        venue_position_id: Option<PositionId>,
+    ) -> Self {
+        let signed_decimal_qty = match position_side {
+            PositionSide::Short => -quantity.as_decimal(),
+            _ => quantity.as_decimal(),
+        };
+
+        Self {
+            account_id,
+            instrument_id,
+            position_side,
+            quantity,
+            signed_decimal_qty,
+            report_id,
+            ts_last,
+            ts_init,
+            venue_position_id,
+        }
+    }
+}
+
+impl Display for PositionStatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PositionStatusReport(account_id={}, instrument_id={}, venue_position_id={:?}, position_side={}, quantity={}, signed_decimal_qty={}, report_id={}, ts_last={}, ts_init={})",
+            self.account_id,
+            self.instrument_id,
+            self.venue_position_id,
+            self.position_side,
+            self.quantity,
+            self.signed_decimal_qty,
+            self.report_id,
+            self.ts_last,
+            self.ts_init,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::PositionSide,
+        identifiers::{account_id::AccountId, instrument_id::InstrumentId},
+        types::quantity::Quantity,
+    };
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::PositionStatusReport;
+
+    #[rstest]
+    #[case(PositionSide::Long, dec!(100))]
+    #[case(PositionSide::Short, dec!(-100))]
+    fn test_signed_decimal_qty(
+        #[case] position_side: PositionSide,
+        #[case] expected: rust_decimal::Decimal,
+    ) {
+        let report = PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            position_side,
+            Quantity::from("100"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            None,
+        );
+
+        assert_eq!(report.signed_decimal_qty, expected);
+    }
+}
@@ -0,0 +1,496 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides trailing stop price calculations for `TRAILING_STOP_MARKET` and
+//! `TRAILING_STOP_LIMIT` orders, for use by the `OrderEmulator` and `OrderMatchingEngine`.
+
+use nautilus_model::{
+    enums::{OrderSide, OrderType, TrailingOffsetType, TriggerType},
+    orders::any::OrderAny,
+    types::price::Price,
+};
+
+/// Calculates the new trigger price, and new limit price for a `TRAILING_STOP_LIMIT` order,
+/// of the trailing stop `order` from the current market.
+///
+/// The market price trailed is determined by the order's trigger type:
+/// - `DEFAULT` and `LAST_TRADE` trail the `last` price.
+/// - `MARK_PRICE` trails the `mark` price.
+/// - `BID_ASK` trails the `ask` for a `BUY` order and the `bid` for a `SELL` order.
+/// - `LAST_OR_BID_ASK` trails the `last` price, then the bid/ask price.
+///
+/// The `price_tiers` are only used for `PRICE_TIER` trailing offsets, see [`trailing_stop_price`].
+///
+/// Prices only ever move in the direction which tightens the stop, a price is `None` when it
+/// would not move from its current value.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `order` is not a trailing stop order.
+/// - If the order's trigger type or trailing offset type is not supported.
+/// - If a market price required by the order's trigger type is `None`.
+pub fn trailing_stop_calculate(
+    price_increment: Price,
+    price_tiers: &[(Price, Price)],
+    order: &OrderAny,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+    mark: Option<Price>,
+) -> anyhow::Result<(Option<Price>, Option<Price>)> {
+    let order = order.as_order();
+    let order_type = order.order_type();
+    if !matches!(
+        order_type,
+        OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+    ) {
+        anyhow::bail!("invalid `OrderType` for trailing stop calculation, was {order_type}");
+    }
+
+    let instrument_id = order.instrument_id();
+    let side = order.side();
+    let trigger_type = order.trigger_type().unwrap_or_default();
+    let trailing_offset_type = order
+        .trailing_offset_type()
+        .unwrap_or(TrailingOffsetType::NoTrailingOffset);
+    let trailing_offset = order
+        .trailing_offset()
+        .ok_or_else(|| anyhow::anyhow!("no `trailing_offset` for {instrument_id}"))?;
+    let limit_offset = order.limit_offset();
+
+    let last_price = || {
+        last.ok_or_else(|| {
+            anyhow::anyhow!(
+                "cannot process trailing stop, no LAST price for {instrument_id} (add trade ticks or use bars)"
+            )
+        })
+    };
+    let bid_ask_price = || {
+        match side {
+        OrderSide::Buy => ask.ok_or_else(|| {
+            anyhow::anyhow!(
+                "cannot process trailing stop, no ASK price for {instrument_id} (add quote ticks or use bars)"
+            )
+        }),
+        _ => bid.ok_or_else(|| {
+            anyhow::anyhow!(
+                "cannot process trailing stop, no BID price for {instrument_id} (add quote ticks or use bars)"
+            )
+        }),
+    }
+    };
+    let mark_price = || {
+        mark.ok_or_else(|| {
+            anyhow::anyhow!(
+                "cannot process trailing stop, no MARK price for {instrument_id} (add mark price updates)"
+            )
+        })
+    };
+
+    let market_prices = match trigger_type {
+        TriggerType::Default | TriggerType::LastTrade => vec![last_price()?],
+        TriggerType::MarkPrice => vec![mark_price()?],
+        TriggerType::BidAsk => vec![bid_ask_price()?],
+        TriggerType::LastOrBidAsk => vec![last_price()?, bid_ask_price()?],
+        _ => anyhow::bail!(
+            "cannot process trailing stop, `TriggerType` {trigger_type} not currently supported"
+        ),
+    };
+
+    let mut trigger_price = order.trigger_price();
+    let mut price = order.price();
+    let mut new_trigger_price = None;
+    let mut new_price = None;
+
+    for market_price in market_prices {
+        let temp_trigger_price = trailing_stop_price(
+            price_increment,
+            price_tiers,
+            trailing_offset_type,
+            side,
+            trailing_offset.as_f64(),
+            market_price,
+        )?;
+        if is_tighter(side, temp_trigger_price, trigger_price) {
+            trigger_price = Some(temp_trigger_price);
+            new_trigger_price = Some(temp_trigger_price);
+        }
+
+        if let Some(limit_offset) = limit_offset {
+            let temp_price = trailing_stop_price(
+                price_increment,
+                price_tiers,
+                trailing_offset_type,
+                side,
+                limit_offset.as_f64(),
+                market_price,
+            )?;
+            if is_tighter(side, temp_price, price) {
+                price = Some(temp_price);
+                new_price = Some(temp_price);
+            }
+        }
+    }
+
+    Ok((new_trigger_price, new_price))
+}
+
+/// Calculates a trailing stop price which is `offset` away from the `market_price`, above the
+/// market for a `BUY` order and below the market for a `SELL` order.
+///
+/// The `offset` is interpreted according to the `trailing_offset_type`:
+/// - `PRICE`: a price distance.
+/// - `BASIS_POINTS`: basis points of the `market_price`.
+/// - `TICKS`: a number of `price_increment` ticks.
+/// - `PRICE_TIER`: a number of tier offsets, the tier offset being that of the highest tier in
+///   `price_tiers` (pairs of tier start price and tier offset) starting at or below the
+///   `market_price`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the `trailing_offset_type` is `NO_TRAILING_OFFSET`.
+/// - If the `trailing_offset_type` is `PRICE_TIER` and no tier starts at or below the `market_price`.
+/// - If the `side` is `NO_ORDER_SIDE`.
+pub fn trailing_stop_price(
+    price_increment: Price,
+    price_tiers: &[(Price, Price)],
+    trailing_offset_type: TrailingOffsetType,
+    side: OrderSide,
+    offset: f64,
+    market_price: Price,
+) -> anyhow::Result<Price> {
+    let market_price_f64 = market_price.as_f64();
+    let offset = match trailing_offset_type {
+        TrailingOffsetType::Price => offset,
+        TrailingOffsetType::BasisPoints => market_price_f64 * (offset / 100.0) / 100.0,
+        TrailingOffsetType::Ticks => offset * price_increment.as_f64(),
+        TrailingOffsetType::PriceTier => offset * price_tier_offset(price_tiers, market_price)?.as_f64(),
+        TrailingOffsetType::NoTrailingOffset => anyhow::bail!(
+            "cannot process trailing stop, `TrailingOffsetType` {trailing_offset_type} not currently supported"
+        ),
+    };
+
+    match side {
+        OrderSide::Buy => Price::new(market_price_f64 + offset, price_increment.precision),
+        OrderSide::Sell => Price::new(market_price_f64 - offset, price_increment.precision),
+        OrderSide::NoOrderSide => anyhow::bail!("invalid `OrderSide`, was {side}"),
+    }
+}
+
+/// Returns the offset of the highest price tier starting at or below the `market_price`.
+fn price_tier_offset(price_tiers: &[(Price, Price)], market_price: Price) -> anyhow::Result<Price> {
+    price_tiers
+        .iter()
+        .filter(|(start, _)| *start <= market_price)
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, offset)| *offset)
+        .ok_or_else(|| anyhow::anyhow!("no price tier for market price {market_price}"))
+}
+
+/// Returns whether the `new` price tightens a stop on the given `side` from its `current` price.
+fn is_tighter(side: OrderSide, new: Price, current: Option<Price>) -> bool {
+    match (side, current) {
+        (_, None) => true,
+        (OrderSide::Buy, Some(current)) => new < current,
+        (_, Some(current)) => new > current,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        enums::{OrderSide, TrailingOffsetType, TriggerType},
+        identifiers::instrument_id::InstrumentId,
+        orders::{any::OrderAny, stubs::TestOrderStubs},
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::{trailing_stop_calculate, trailing_stop_price};
+
+    fn trailing_stop_market(
+        side: OrderSide,
+        trigger_price: &str,
+        trailing_offset: &str,
+        trailing_offset_type: TrailingOffsetType,
+        trigger_type: TriggerType,
+    ) -> OrderAny {
+        OrderAny::TrailingStopMarket(TestOrderStubs::trailing_stop_market_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            side,
+            Price::from(trigger_price),
+            Price::from(trailing_offset),
+            trailing_offset_type,
+            Quantity::from(100_000),
+            Some(trigger_type),
+            None,
+        ))
+    }
+
+    #[rstest]
+    #[case(TrailingOffsetType::Price, OrderSide::Buy, "0.00050", "1.00050")]
+    #[case(TrailingOffsetType::Price, OrderSide::Sell, "0.00050", "0.99950")]
+    #[case(TrailingOffsetType::BasisPoints, OrderSide::Buy, "10", "1.00100")]
+    #[case(TrailingOffsetType::BasisPoints, OrderSide::Sell, "10", "0.99900")]
+    #[case(TrailingOffsetType::Ticks, OrderSide::Buy, "5", "1.00005")]
+    #[case(TrailingOffsetType::Ticks, OrderSide::Sell, "5", "0.99995")]
+    #[case(TrailingOffsetType::PriceTier, OrderSide::Buy, "2", "1.00020")]
+    #[case(TrailingOffsetType::PriceTier, OrderSide::Sell, "2", "0.99980")]
+    fn test_trailing_stop_price(
+        #[case] trailing_offset_type: TrailingOffsetType,
+        #[case] side: OrderSide,
+        #[case] offset: f64,
+        #[case] expected: &str,
+    ) {
+        let price_tiers = [
+            (Price::from("0.10000"), Price::from("0.00001")),
+            (Price::from("1.00000"), Price::from("0.00010")),
+            (Price::from("10.00000"), Price::from("0.00100")),
+        ];
+        let price = trailing_stop_price(
+            Price::from("0.00001"),
+            &price_tiers,
+            trailing_offset_type,
+            side,
+            offset,
+            Price::from("1.00000"),
+        )
+        .unwrap();
+
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_price_with_no_trailing_offset_type_returns_error() {
+        let result = trailing_stop_price(
+            Price::from("0.00001"),
+            &[],
+            TrailingOffsetType::NoTrailingOffset,
+            OrderSide::Buy,
+            1.0,
+            Price::from("1.00000"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_trailing_stop_price_with_no_price_tier_returns_error() {
+        let price_tiers = [(Price::from("2.00000"), Price::from("0.00010"))];
+
+        let result = trailing_stop_price(
+            Price::from("0.00001"),
+            &price_tiers,
+            TrailingOffsetType::PriceTier,
+            OrderSide::Buy,
+            1.0,
+            Price::from("1.00000"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_calculate_mark_price_trigger_trails_mark_price() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "0.90000",
+            "0.00050",
+            TrailingOffsetType::Price,
+            TriggerType::MarkPrice,
+        );
+
+        let (new_trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            None,
+            None,
+            Some(Price::from("1.00000")),
+            Some(Price::from("0.99000")),
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, Some(Price::from("0.98950")));
+    }
+
+    #[rstest]
+    fn test_calculate_with_non_trailing_order_returns_error() {
+        let order = OrderAny::Limit(TestOrderStubs::limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+        ));
+
+        let result = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            Some(Price::from("1.00000")),
+            Some(Price::from("1.00000")),
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, "0.99000", Some("0.99950"))]
+    #[case(OrderSide::Sell, "0.99990", None)]
+    #[case(OrderSide::Buy, "1.01000", Some("1.00050"))]
+    #[case(OrderSide::Buy, "1.00010", None)]
+    fn test_calculate_trails_last_price_only_when_tighter(
+        #[case] side: OrderSide,
+        #[case] trigger_price: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let order = trailing_stop_market(
+            side,
+            trigger_price,
+            "0.00050",
+            TrailingOffsetType::Price,
+            TriggerType::LastTrade,
+        );
+
+        let (new_trigger_price, new_price) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            None,
+            None,
+            Some(Price::from("1.00000")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, expected.map(Price::from));
+        assert_eq!(new_price, None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "1.00030")]
+    #[case(OrderSide::Sell, "0.99970")]
+    fn test_calculate_trails_bid_ask_by_side(#[case] side: OrderSide, #[case] expected: &str) {
+        let order = trailing_stop_market(
+            side,
+            match side {
+                OrderSide::Buy => "1.10000",
+                _ => "0.90000",
+            },
+            "10",
+            TrailingOffsetType::Ticks,
+            TriggerType::BidAsk,
+        );
+
+        let (new_trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            Some(Price::from("0.99980")),
+            Some(Price::from("1.00020")),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    fn test_calculate_with_missing_market_price_returns_error() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "0.90000",
+            "0.00050",
+            TrailingOffsetType::Price,
+            TriggerType::LastOrBidAsk,
+        );
+
+        let result = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            Some(Price::from("0.99980")),
+            Some(Price::from("1.00020")),
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_calculate_last_or_bid_ask_uses_tightest_price() {
+        let order = trailing_stop_market(
+            OrderSide::Sell,
+            "0.90000",
+            "0.00050",
+            TrailingOffsetType::Price,
+            TriggerType::LastOrBidAsk,
+        );
+
+        let (new_trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            Some(Price::from("1.00010")),
+            Some(Price::from("1.00030")),
+            Some(Price::from("1.00000")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, Some(Price::from("0.99960")));
+    }
+
+    #[rstest]
+    fn test_calculate_trailing_stop_limit_moves_trigger_and_limit_prices() {
+        let order = OrderAny::TrailingStopLimit(TestOrderStubs::trailing_stop_limit_order(
+            InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Sell,
+            Price::from("0.98000"),
+            Price::from("0.98010"),
+            Price::from("0.00060"),
+            Price::from("0.00050"),
+            TrailingOffsetType::Price,
+            Quantity::from(100_000),
+            Some(TriggerType::BidAsk),
+            None,
+        ));
+
+        let (new_trigger_price, new_price) = trailing_stop_calculate(
+            Price::from("0.00001"),
+            &[],
+            &order,
+            Some(Price::from("1.00000")),
+            Some(Price::from("1.00020")),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(new_trigger_price, Some(Price::from("0.99950")));
+        assert_eq!(new_price, Some(Price::from("0.99940")));
+    }
+}
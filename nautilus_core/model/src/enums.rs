@@ -372,6 +372,55 @@ impl FromU8 for BookType {
     }
 }
 
+/// The policy for an order book update which would cross or lock the book.
+///
+/// The policy applies to deltas, depth snapshots and quote ticks applied to the book. Depth
+/// snapshots and quotes replace both sides at once, so there are no opposing levels to purge
+/// and `Purge` accepts them as is. Direct `add` and `update` calls are not checked.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum CrossedBookPolicy {
+    /// Accept the update, the crossed or locked state is flagged via `is_crossed`/`is_locked`.
+    #[default]
+    Accept = 1,
+    /// Reject the update, leaving the book unchanged.
+    Reject = 2,
+    /// Accept the update and purge the opposing levels it crosses (assumed stale).
+    Purge = 3,
+}
+
+impl FromU8 for CrossedBookPolicy {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Accept),
+            2 => Some(Self::Reject),
+            3 => Some(Self::Purge),
+            _ => None,
+        }
+    }
+}
+
 /// The order contigency type which specifies the behavior of linked orders.
 ///
 /// [FIX 5.0 SP2 : ContingencyType <1385> field](https://www.onixs.biz/fix-dictionary/5.0.sp2/tagnum_1385.html).
@@ -702,6 +751,7 @@ impl FromU8 for OrderSide {
 }
 
 /// The specified order side (BUY or SELL).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum OrderSideSpecified {
     /// The order is a BUY.
     Buy = 1,
@@ -1118,6 +1168,7 @@ enum_strum_serde!(InstrumentClass);
 enum_strum_serde!(BarAggregation);
 enum_strum_serde!(BookAction);
 enum_strum_serde!(BookType);
+enum_strum_serde!(CrossedBookPolicy);
 enum_strum_serde!(ContingencyType);
 enum_strum_serde!(CurrencyType);
 enum_strum_serde!(InstrumentCloseType);
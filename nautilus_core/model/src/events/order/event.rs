@@ -26,7 +26,9 @@ use crate::{
         rejected::OrderRejected, released::OrderReleased, submitted::OrderSubmitted,
         triggered::OrderTriggered, updated::OrderUpdated,
     },
-    identifiers::{client_order_id::ClientOrderId, strategy_id::StrategyId},
+    identifiers::{
+        client_order_id::ClientOrderId, strategy_id::StrategyId, venue_order_id::VenueOrderId,
+    },
 };
 
 #[derive(Clone, PartialEq, Eq, Display, Debug, Serialize, Deserialize)]
@@ -74,6 +76,52 @@ impl OrderEventAny {
         }
     }
 
+    /// Sets the client order ID for the event (when the order was matched on its venue order ID).
+    pub fn set_client_order_id(&mut self, client_order_id: ClientOrderId) {
+        match self {
+            Self::Initialized(event) => event.client_order_id = client_order_id,
+            Self::Denied(event) => event.client_order_id = client_order_id,
+            Self::Emulated(event) => event.client_order_id = client_order_id,
+            Self::Released(event) => event.client_order_id = client_order_id,
+            Self::Submitted(event) => event.client_order_id = client_order_id,
+            Self::Accepted(event) => event.client_order_id = client_order_id,
+            Self::Rejected(event) => event.client_order_id = client_order_id,
+            Self::Canceled(event) => event.client_order_id = client_order_id,
+            Self::Expired(event) => event.client_order_id = client_order_id,
+            Self::Triggered(event) => event.client_order_id = client_order_id,
+            Self::PendingUpdate(event) => event.client_order_id = client_order_id,
+            Self::PendingCancel(event) => event.client_order_id = client_order_id,
+            Self::ModifyRejected(event) => event.client_order_id = client_order_id,
+            Self::CancelRejected(event) => event.client_order_id = client_order_id,
+            Self::Updated(event) => event.client_order_id = client_order_id,
+            Self::PartiallyFilled(event) => event.client_order_id = client_order_id,
+            Self::Filled(event) => event.client_order_id = client_order_id,
+        }
+    }
+
+    #[must_use]
+    pub fn venue_order_id(&self) -> Option<VenueOrderId> {
+        match self {
+            Self::Initialized(_)
+            | Self::Denied(_)
+            | Self::Emulated(_)
+            | Self::Released(_)
+            | Self::Submitted(_)
+            | Self::Rejected(_) => None,
+            Self::Accepted(event) => Some(event.venue_order_id),
+            Self::Canceled(event) => event.venue_order_id,
+            Self::Expired(event) => event.venue_order_id,
+            Self::Triggered(event) => event.venue_order_id,
+            Self::PendingUpdate(event) => event.venue_order_id,
+            Self::PendingCancel(event) => event.venue_order_id,
+            Self::ModifyRejected(event) => event.venue_order_id,
+            Self::CancelRejected(event) => event.venue_order_id,
+            Self::Updated(event) => event.venue_order_id,
+            Self::PartiallyFilled(event) => Some(event.venue_order_id),
+            Self::Filled(event) => Some(event.venue_order_id),
+        }
+    }
+
     #[must_use]
     pub fn strategy_id(&self) -> StrategyId {
         match self {
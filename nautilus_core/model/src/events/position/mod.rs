@@ -13,8 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use crate::events::position::{
-    changed::PositionChanged, closed::PositionClosed, opened::PositionOpened,
+use crate::{
+    events::position::{changed::PositionChanged, closed::PositionClosed, opened::PositionOpened},
+    identifiers::{position_id::PositionId, strategy_id::StrategyId},
 };
 
 pub mod changed;
@@ -23,8 +24,29 @@ pub mod opened;
 
 pub mod state;
 
+#[derive(Clone, PartialEq, Debug)]
 pub enum PositionEvent {
     PositionOpened(PositionOpened),
     PositionChanged(PositionChanged),
     PositionClosed(PositionClosed),
 }
+
+impl PositionEvent {
+    #[must_use]
+    pub fn strategy_id(&self) -> StrategyId {
+        match self {
+            Self::PositionOpened(event) => event.strategy_id,
+            Self::PositionChanged(event) => event.strategy_id,
+            Self::PositionClosed(event) => event.strategy_id,
+        }
+    }
+
+    #[must_use]
+    pub fn position_id(&self) -> PositionId {
+        match self {
+            Self::PositionOpened(event) => event.position_id,
+            Self::PositionChanged(event) => event.position_id,
+            Self::PositionClosed(event) => event.position_id,
+        }
+    }
+}
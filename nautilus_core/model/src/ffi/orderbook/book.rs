@@ -152,18 +152,24 @@ pub extern "C" fn orderbook_clear_asks(book: &mut OrderBook_API, sequence: u64,
 
 #[no_mangle]
 pub extern "C" fn orderbook_apply_delta(book: &mut OrderBook_API, delta: OrderBookDelta) {
-    book.apply_delta(delta);
+    if let Err(e) = book.apply_delta(delta) {
+        log::error!("Error applying delta: {e}");
+    }
 }
 
 #[no_mangle]
 pub extern "C" fn orderbook_apply_deltas(book: &mut OrderBook_API, deltas: &OrderBookDeltas_API) {
     // Clone will actually copy the contents of the `deltas` vec
-    book.apply_deltas(deltas.deref().clone());
+    if let Err(e) = book.apply_deltas(deltas.deref().clone()) {
+        log::error!("Error applying deltas: {e}");
+    }
 }
 
 #[no_mangle]
 pub extern "C" fn orderbook_apply_depth(book: &mut OrderBook_API, depth: OrderBookDepth10) {
-    book.apply_depth(depth);
+    if let Err(e) = book.apply_depth(depth) {
+        log::error!("Error applying depth: {e}");
+    }
 }
 
 #[no_mangle]
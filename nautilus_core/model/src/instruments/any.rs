@@ -21,6 +21,7 @@ use super::{
     options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
 };
 use crate::{
+    enums::InstrumentClass,
     identifiers::instrument_id::InstrumentId,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
@@ -164,6 +165,104 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn instrument_class(&self) -> InstrumentClass {
+        match self {
+            Self::CryptoFuture(inst) => inst.instrument_class(),
+            Self::CryptoPerpetual(inst) => inst.instrument_class(),
+            Self::CurrencyPair(inst) => inst.instrument_class(),
+            Self::Equity(inst) => inst.instrument_class(),
+            Self::FuturesContract(inst) => inst.instrument_class(),
+            Self::FuturesSpread(inst) => inst.instrument_class(),
+            Self::OptionsContract(inst) => inst.instrument_class(),
+            Self::OptionsSpread(inst) => inst.instrument_class(),
+        }
+    }
+
+    #[must_use]
+    pub fn max_quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::CryptoFuture(inst) => inst.max_quantity(),
+            Self::CryptoPerpetual(inst) => inst.max_quantity(),
+            Self::CurrencyPair(inst) => inst.max_quantity(),
+            Self::Equity(inst) => inst.max_quantity(),
+            Self::FuturesContract(inst) => inst.max_quantity(),
+            Self::FuturesSpread(inst) => inst.max_quantity(),
+            Self::OptionsContract(inst) => inst.max_quantity(),
+            Self::OptionsSpread(inst) => inst.max_quantity(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::CryptoFuture(inst) => inst.min_quantity(),
+            Self::CryptoPerpetual(inst) => inst.min_quantity(),
+            Self::CurrencyPair(inst) => inst.min_quantity(),
+            Self::Equity(inst) => inst.min_quantity(),
+            Self::FuturesContract(inst) => inst.min_quantity(),
+            Self::FuturesSpread(inst) => inst.min_quantity(),
+            Self::OptionsContract(inst) => inst.min_quantity(),
+            Self::OptionsSpread(inst) => inst.min_quantity(),
+        }
+    }
+
+    #[must_use]
+    pub fn max_notional(&self) -> Option<Money> {
+        match self {
+            Self::CryptoFuture(inst) => inst.max_notional(),
+            Self::CryptoPerpetual(inst) => inst.max_notional(),
+            Self::CurrencyPair(inst) => inst.max_notional(),
+            Self::Equity(inst) => inst.max_notional(),
+            Self::FuturesContract(inst) => inst.max_notional(),
+            Self::FuturesSpread(inst) => inst.max_notional(),
+            Self::OptionsContract(inst) => inst.max_notional(),
+            Self::OptionsSpread(inst) => inst.max_notional(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_notional(&self) -> Option<Money> {
+        match self {
+            Self::CryptoFuture(inst) => inst.min_notional(),
+            Self::CryptoPerpetual(inst) => inst.min_notional(),
+            Self::CurrencyPair(inst) => inst.min_notional(),
+            Self::Equity(inst) => inst.min_notional(),
+            Self::FuturesContract(inst) => inst.min_notional(),
+            Self::FuturesSpread(inst) => inst.min_notional(),
+            Self::OptionsContract(inst) => inst.min_notional(),
+            Self::OptionsSpread(inst) => inst.min_notional(),
+        }
+    }
+
+    #[must_use]
+    pub fn max_price(&self) -> Option<Price> {
+        match self {
+            Self::CryptoFuture(inst) => inst.max_price(),
+            Self::CryptoPerpetual(inst) => inst.max_price(),
+            Self::CurrencyPair(inst) => inst.max_price(),
+            Self::Equity(inst) => inst.max_price(),
+            Self::FuturesContract(inst) => inst.max_price(),
+            Self::FuturesSpread(inst) => inst.max_price(),
+            Self::OptionsContract(inst) => inst.max_price(),
+            Self::OptionsSpread(inst) => inst.max_price(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_price(&self) -> Option<Price> {
+        match self {
+            Self::CryptoFuture(inst) => inst.min_price(),
+            Self::CryptoPerpetual(inst) => inst.min_price(),
+            Self::CurrencyPair(inst) => inst.min_price(),
+            Self::Equity(inst) => inst.min_price(),
+            Self::FuturesContract(inst) => inst.min_price(),
+            Self::FuturesSpread(inst) => inst.min_price(),
+            Self::OptionsContract(inst) => inst.min_price(),
+            Self::OptionsSpread(inst) => inst.min_price(),
+        }
+    }
+
     pub fn make_price(&self, value: f64) -> anyhow::Result<Price> {
         match self {
             Self::CryptoFuture(inst) => inst.make_price(value),
@@ -225,6 +324,20 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
+        match self {
+            Self::CryptoFuture(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::CurrencyPair(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Equity(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FuturesContract(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FuturesSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::OptionsContract(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::OptionsSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+        }
+    }
+
     // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
     #[must_use]
     pub fn maker_fee(&self) -> Decimal {
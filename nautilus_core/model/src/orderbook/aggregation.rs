@@ -18,7 +18,7 @@ use nautilus_core::nanos::UnixNanos;
 use super::{book::OrderBook, error::InvalidBookOperation};
 use crate::{
     data::{order::BookOrder, quote::QuoteTick, trade::TradeTick},
-    enums::{BookType, OrderSide, RecordFlag},
+    enums::{BookType, CrossedBookPolicy, OrderSide, RecordFlag},
 };
 
 pub(crate) fn pre_process_order(book_type: BookType, mut order: BookOrder, flags: u8) -> BookOrder {
@@ -45,6 +45,14 @@ pub(crate) fn update_book_with_quote_tick(
         return Err(InvalidBookOperation::Update(book.book_type));
     };
 
+    // Both sides are replaced together so there are no stale levels to purge
+    if book.crossed_policy == CrossedBookPolicy::Reject && quote.bid_price >= quote.ask_price {
+        return Err(InvalidBookOperation::Crossed(
+            quote.bid_price,
+            quote.ask_price,
+        ));
+    }
+
     let bid = BookOrder::new(
         OrderSide::Buy,
         quote.bid_price,
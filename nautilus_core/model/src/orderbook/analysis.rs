@@ -76,6 +76,117 @@ pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level
     }
 }
 
+/// Calculates the volume-weighted average price to fill the full `qty` from a set of
+/// order book levels, or `None` if the levels do not hold enough liquidity.
+#[must_use]
+pub fn get_vwap_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> Option<f64> {
+    if qty.raw == 0 {
+        return None;
+    }
+
+    let mut remaining_raw = qty.raw;
+    let mut cumulative_value = 0.0;
+
+    for (book_price, level) in levels {
+        let size_this_level = level.size_raw().min(remaining_raw);
+        remaining_raw -= size_this_level;
+        cumulative_value += book_price.value.as_f64() * size_this_level as f64;
+
+        if remaining_raw == 0 {
+            return Some(cumulative_value / qty.raw as f64);
+        }
+    }
+
+    None
+}
+
+/// Calculates the average fill price to trade the given `notional` (price * size) from a set
+/// of order book levels, or `None` if the levels do not hold enough liquidity.
+#[must_use]
+pub fn get_avg_px_for_notional(notional: f64, levels: &BTreeMap<BookPrice, Level>) -> Option<f64> {
+    if notional <= 0.0 {
+        return None;
+    }
+
+    let mut remaining = notional;
+    let mut cumulative_size = 0.0;
+
+    for (book_price, level) in levels {
+        let price = book_price.value.as_f64();
+        let notional_this_level = level.exposure().min(remaining);
+        remaining -= notional_this_level;
+        cumulative_size += notional_this_level / price;
+
+        if remaining <= 0.0 {
+            return Some(notional / cumulative_size);
+        }
+    }
+
+    None
+}
+
+/// Calculates the size-weighted midpoint across the top `depth` levels of each side.
+///
+/// Each side's volume-weighted price is weighted by the size on the opposite side, so with a
+/// `depth` of one this is the top-of-book microprice. Returns `None` if either side is empty.
+#[must_use]
+pub fn get_weighted_midpoint(
+    bids: &BTreeMap<BookPrice, Level>,
+    asks: &BTreeMap<BookPrice, Level>,
+    depth: usize,
+) -> Option<f64> {
+    let (bid_px, bid_size) = vwap_and_size(bids, depth)?;
+    let (ask_px, ask_size) = vwap_and_size(asks, depth)?;
+
+    Some((bid_px * ask_size + ask_px * bid_size) / (bid_size + ask_size))
+}
+
+/// Calculates the order book imbalance across the top `depth` levels of each side, in the
+/// range [-1, 1] where positive values indicate more bid size.
+///
+/// Level sizes are weighted by `1 / (n + 1)` for the nth level from the top, so liquidity
+/// closer to the touch dominates. Returns `None` if both sides are empty.
+#[must_use]
+pub fn get_depth_imbalance(
+    bids: &BTreeMap<BookPrice, Level>,
+    asks: &BTreeMap<BookPrice, Level>,
+    depth: usize,
+) -> Option<f64> {
+    let weighted_size = |levels: &BTreeMap<BookPrice, Level>| -> f64 {
+        levels
+            .values()
+            .take(depth)
+            .enumerate()
+            .map(|(i, level)| level.size() / (i + 1) as f64)
+            .sum()
+    };
+
+    let bid_size = weighted_size(bids);
+    let ask_size = weighted_size(asks);
+    let total = bid_size + ask_size;
+
+    if total == 0.0 {
+        return None;
+    }
+
+    Some((bid_size - ask_size) / total)
+}
+
+fn vwap_and_size(levels: &BTreeMap<BookPrice, Level>, depth: usize) -> Option<(f64, f64)> {
+    let (value, size) = levels
+        .values()
+        .take(depth)
+        .fold((0.0, 0.0), |(value, size), level| {
+            (value + level.exposure(), size + level.size())
+        });
+
+    if size == 0.0 {
+        return None;
+    }
+
+    Some((value / size, size))
+}
+
 pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError> {
     match book.book_type {
         BookType::L1_MBP => {
@@ -15,14 +15,26 @@
 
 use nautilus_core::nanos::UnixNanos;
 
-use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::Level};
+use super::{
+    aggregation::{pre_process_order, update_book_with_quote_tick, update_book_with_trade_tick},
+    analysis,
+    display::{pprint_book, pprint_grouped_levels},
+    group::{cumulative_depth, group_levels, CumulativeLevel},
+    level::Level,
+    own::OwnOrderBook,
+    snapshot::{book_diff_deltas, book_to_depth10, book_to_snapshot_deltas},
+};
 use crate::{
     data::{
         delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
+        quote::QuoteTick, trade::TradeTick,
     },
-    enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
+    enums::{BookAction, BookType, CrossedBookPolicy, OrderSide, OrderSideSpecified, RecordFlag},
     identifiers::instrument_id::InstrumentId,
-    orderbook::{error::BookIntegrityError, ladder::Ladder},
+    orderbook::{
+        error::{BookIntegrityError, InvalidBookOperation},
+        ladder::Ladder,
+    },
     types::{price::Price, quantity::Quantity},
 };
 
@@ -48,8 +60,15 @@ pub struct OrderBook {
     pub ts_last: UnixNanos,
     /// The current count of events applied to the order book.
     pub count: u64,
+    /// If delta sequence numbers are checked (stale deltas are rejected and gaps mark the book stale).
+    pub strict_sequence: bool,
+    /// If a sequence gap was detected and the book awaits a snapshot to resync.
+    pub is_stale: bool,
+    /// The policy for deltas and quotes which would cross or lock the book.
+    pub crossed_policy: CrossedBookPolicy,
     pub(crate) bids: Ladder,
     pub(crate) asks: Ladder,
+    pub(crate) own_orders: OwnOrderBook,
 }
 
 impl OrderBook {
@@ -61,17 +80,23 @@ impl OrderBook {
             sequence: 0,
             ts_last: UnixNanos::default(),
             count: 0,
+            strict_sequence: false,
+            is_stale: false,
+            crossed_policy: CrossedBookPolicy::default(),
             bids: Ladder::new(OrderSide::Buy),
             asks: Ladder::new(OrderSide::Sell),
+            own_orders: OwnOrderBook::new(instrument_id),
         }
     }
 
     pub fn reset(&mut self) {
         self.bids.clear();
         self.asks.clear();
+        self.own_orders.clear();
         self.sequence = 0;
         self.ts_last = UnixNanos::default();
         self.count = 0;
+        self.is_stale = false;
     }
 
     pub fn add(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
@@ -120,36 +145,128 @@ impl OrderBook {
         self.increment(sequence, ts_event);
     }
 
-    pub fn apply_delta(&mut self, delta: OrderBookDelta) {
-        let order = delta.order;
-        let flags = delta.flags;
-        let sequence = delta.sequence;
-        let ts_event = delta.ts_event;
-        match delta.action {
-            BookAction::Add => self.add(order, flags, sequence, ts_event),
-            BookAction::Update => self.update(order, flags, sequence, ts_event),
-            BookAction::Delete => self.delete(order, flags, sequence, ts_event),
-            BookAction::Clear => self.clear(sequence, ts_event),
+    /// Applies the given `delta` to the book.
+    ///
+    /// # Errors
+    ///
+    /// When `strict_sequence` is enabled, this function returns an error if:
+    /// - The book is stale and awaits a resync.
+    /// - The delta sequence is less than the last applied sequence.
+    /// - The delta sequence skips ahead of the next expected sequence (the book is then marked stale).
+    ///
+    /// When `crossed_policy` is `Reject`, this function returns an error if the delta would cross
+    /// or lock the book.
+    pub fn apply_delta(&mut self, delta: OrderBookDelta) -> Result<(), BookIntegrityError> {
+        if self.strict_sequence {
+            self.check_sequence(delta.sequence)?;
         }
+        self.check_crossed(&delta)?;
+
+        self.apply_delta_unchecked(delta);
+        Ok(())
     }
 
-    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) {
+    /// Applies the given `deltas` to the book.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the first error from [`OrderBook::apply_delta`].
+    ///
+    /// A delta rejected for crossing the book (when `crossed_policy` is `Reject`) is skipped,
+    /// and its sequence consumed, so the remaining deltas are still applied before the error
+    /// is returned. Any other error stops the batch, leaving the remaining deltas unapplied.
+    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) -> Result<(), BookIntegrityError> {
+        let mut first_error = None;
         for delta in deltas.deltas {
-            self.apply_delta(delta);
+            let sequence = delta.sequence;
+            match self.apply_delta(delta) {
+                Ok(()) => {}
+                Err(e @ BookIntegrityError::OrdersCrossed(_, _)) => {
+                    self.sequence = self.sequence.max(sequence);
+                    first_error.get_or_insert(e);
+                }
+                Err(e) => return Err(e),
+            }
         }
+        first_error.map_or(Ok(()), Err)
     }
 
-    pub fn apply_depth(&mut self, depth: OrderBookDepth10) {
-        self.bids.clear();
-        self.asks.clear();
+    /// Applies the given `depth` snapshot to the book, replacing all existing levels.
+    ///
+    /// # Errors
+    ///
+    /// When `strict_sequence` is enabled, this function returns an error if the book is stale
+    /// (use [`OrderBook::resync_depth`]) or the depth sequence is less than the last applied sequence.
+    ///
+    /// When `crossed_policy` is `Reject`, this function returns an error if the depth is crossed
+    /// or locked.
+    pub fn apply_depth(&mut self, depth: OrderBookDepth10) -> Result<(), BookIntegrityError> {
+        if self.strict_sequence {
+            if self.is_stale {
+                return Err(BookIntegrityError::BookStale);
+            }
+            if self.count > 0 && depth.sequence < self.sequence {
+                return Err(BookIntegrityError::StaleSequence(
+                    self.sequence,
+                    depth.sequence,
+                ));
+            }
+        }
+        if self.crossed_policy == CrossedBookPolicy::Reject {
+            check_depth_crossed(&depth)?;
+        }
 
-        for order in depth.bids {
-            self.add(order, depth.flags, depth.sequence, depth.ts_event);
+        self.apply_depth_unchecked(depth);
+        Ok(())
+    }
+
+    /// Rebuilds the book from the given snapshot `deltas` and clears the stale state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if no delta has the `F_SNAPSHOT` flag set.
+    pub fn resync(&mut self, deltas: OrderBookDeltas) -> Result<(), BookIntegrityError> {
+        if !deltas
+            .deltas
+            .iter()
+            .any(|delta| RecordFlag::F_SNAPSHOT.matches(delta.flags))
+        {
+            return Err(BookIntegrityError::NotSnapshot);
         }
 
-        for order in depth.asks {
-            self.add(order, depth.flags, depth.sequence, depth.ts_event);
+        self.bids.clear();
+        self.asks.clear();
+        for delta in deltas.deltas {
+            self.apply_delta_unchecked(delta);
         }
+        self.is_stale = false;
+        Ok(())
+    }
+
+    /// Rebuilds the book from the given `depth` snapshot and clears the stale state.
+    pub fn resync_depth(&mut self, depth: OrderBookDepth10) {
+        self.apply_depth_unchecked(depth);
+        self.is_stale = false;
+    }
+
+    /// Updates the top-of-book from the given `quote`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - The book type is not `L1_MBP`.
+    /// - The quote is crossed or locked and `crossed_policy` is `Reject`.
+    pub fn update_quote_tick(&mut self, quote: &QuoteTick) -> Result<(), InvalidBookOperation> {
+        update_book_with_quote_tick(self, quote)
+    }
+
+    /// Updates the top-of-book from the given `trade`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the book type is not `L1_MBP`.
+    pub fn update_trade_tick(&mut self, trade: &TradeTick) -> Result<(), InvalidBookOperation> {
+        update_book_with_trade_tick(self, trade)
     }
 
     pub fn bids(&self) -> impl Iterator<Item = &Level> {
@@ -160,6 +277,35 @@ impl OrderBook {
         self.asks.levels.values()
     }
 
+    /// Returns the overlay of our own resting orders for the book.
+    #[must_use]
+    pub fn own_orders(&self) -> &OwnOrderBook {
+        &self.own_orders
+    }
+
+    /// Returns a mutable overlay of our own resting orders for the book.
+    pub fn own_orders_mut(&mut self) -> &mut OwnOrderBook {
+        &mut self.own_orders
+    }
+
+    /// Returns the bid levels (price, size) with the size of our own orders excluded, for up to
+    /// `depth` levels (all levels if `None`).
+    ///
+    /// Levels consisting only of our own liquidity are omitted.
+    #[must_use]
+    pub fn bids_excluding_own(&self, depth: Option<usize>) -> Vec<(Price, Quantity)> {
+        self.levels_excluding_own(&self.bids, depth)
+    }
+
+    /// Returns the ask levels (price, size) with the size of our own orders excluded, for up to
+    /// `depth` levels (all levels if `None`).
+    ///
+    /// Levels consisting only of our own liquidity are omitted.
+    #[must_use]
+    pub fn asks_excluding_own(&self, depth: Option<usize>) -> Vec<(Price, Quantity)> {
+        self.levels_excluding_own(&self.asks, depth)
+    }
+
     #[must_use]
     pub fn has_bid(&self) -> bool {
         self.bids.top().map_or(false, |top| !top.orders.is_empty())
@@ -221,6 +367,66 @@ impl OrderBook {
         analysis::get_avg_px_for_quantity(qty, levels)
     }
 
+    /// Returns whether the best bid is above the best ask.
+    #[must_use]
+    pub fn is_crossed(&self) -> bool {
+        matches!((self.best_bid_price(), self.best_ask_price()), (Some(bid), Some(ask)) if bid > ask)
+    }
+
+    /// Returns whether the best bid is equal to the best ask.
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        matches!((self.best_bid_price(), self.best_ask_price()), (Some(bid), Some(ask)) if bid == ask)
+    }
+
+    /// Returns the top-of-book microprice, the midpoint weighted by the opposite side's size.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        self.weighted_midpoint(1)
+    }
+
+    /// Returns the size-weighted midpoint across the top `depth` levels of each side.
+    #[must_use]
+    pub fn weighted_midpoint(&self, depth: usize) -> Option<f64> {
+        analysis::get_weighted_midpoint(&self.bids.levels, &self.asks.levels, depth)
+    }
+
+    /// Returns the depth-weighted order book imbalance across the top `depth` levels of each
+    /// side, in the range [-1, 1] where positive values indicate more bid size.
+    #[must_use]
+    pub fn imbalance(&self, depth: usize) -> Option<f64> {
+        analysis::get_depth_imbalance(&self.bids.levels, &self.asks.levels, depth)
+    }
+
+    /// Returns the volume-weighted average price to fill the full `qty` for the given
+    /// `order_side`, or `None` if the book does not hold enough liquidity.
+    #[must_use]
+    pub fn get_vwap_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> Option<f64> {
+        let levels = match order_side {
+            OrderSide::Buy => &self.asks.levels,
+            OrderSide::Sell => &self.bids.levels,
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        };
+
+        analysis::get_vwap_for_quantity(qty, levels)
+    }
+
+    /// Returns the expected slippage (as a positive price distance from the best price) to
+    /// trade the given `notional` for the given `order_side`, or `None` if the book does not
+    /// hold enough liquidity.
+    #[must_use]
+    pub fn get_slippage_for_notional(&self, notional: f64, order_side: OrderSide) -> Option<f64> {
+        let (ladder, sign) = match order_side {
+            OrderSide::Buy => (&self.asks, 1.0),
+            OrderSide::Sell => (&self.bids, -1.0),
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        };
+
+        let best_px = ladder.top()?.price.value.as_f64();
+        let avg_px = analysis::get_avg_px_for_notional(notional, &ladder.levels)?;
+        Some(sign * (avg_px - best_px))
+    }
+
     #[must_use]
     pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
         let levels = match order_side {
@@ -244,7 +450,197 @@ impl OrderBook {
     /// Return a [`String`] representation of the order book in a human-readable table format.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
-        pprint_book(&self.bids, &self.asks, num_levels)
+        pprint_book(&self.bids, &self.asks, &self.own_orders, num_levels)
+    }
+
+    /// Return a [`String`] representation of the order book with levels aggregated into
+    /// buckets of `group_size`, showing `num_levels` buckets per side.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `group_size` is not positive.
+    pub fn pprint_grouped(&self, group_size: Price, num_levels: usize) -> anyhow::Result<String> {
+        let bids = self.grouped_bids(group_size, Some(num_levels))?;
+        let asks = self.grouped_asks(group_size, Some(num_levels))?;
+        Ok(pprint_grouped_levels(&bids, &asks))
+    }
+
+    /// Returns the bid levels aggregated into buckets of `group_size` (prices rounded down).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `group_size` is not positive.
+    pub fn grouped_bids(
+        &self,
+        group_size: Price,
+        depth: Option<usize>,
+    ) -> anyhow::Result<Vec<(Price, Quantity)>> {
+        group_levels(&self.bids, group_size, depth)
+    }
+
+    /// Returns the ask levels aggregated into buckets of `group_size` (prices rounded up).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `group_size` is not positive.
+    pub fn grouped_asks(
+        &self,
+        group_size: Price,
+        depth: Option<usize>,
+    ) -> anyhow::Result<Vec<(Price, Quantity)>> {
+        group_levels(&self.asks, group_size, depth)
+    }
+
+    /// Returns the cumulative depth curve (size and notional) for the given `order_side`.
+    #[must_use]
+    pub fn cumulative_depth(
+        &self,
+        order_side: OrderSide,
+        depth: Option<usize>,
+    ) -> Vec<CumulativeLevel> {
+        let ladder = match order_side.as_specified() {
+            OrderSideSpecified::Buy => &self.bids,
+            OrderSideSpecified::Sell => &self.asks,
+        };
+        cumulative_depth(ladder, depth)
+    }
+
+    /// Returns the top ten levels of each side as an `OrderBookDepth10`.
+    #[must_use]
+    pub fn to_depth10(&self, ts_init: UnixNanos) -> OrderBookDepth10 {
+        book_to_depth10(self, ts_init)
+    }
+
+    /// Returns a full snapshot of the book as `OrderBookDeltas` (a `Clear` followed by an
+    /// `Add` for every order).
+    #[must_use]
+    pub fn to_snapshot_deltas(&self, ts_init: UnixNanos) -> OrderBookDeltas {
+        book_to_snapshot_deltas(self, ts_init)
+    }
+
+    /// Returns the deltas which transform the `previous` book into this book.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the books are for different instruments.
+    pub fn diff_deltas(
+        &self,
+        previous: &Self,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Vec<OrderBookDelta>> {
+        book_diff_deltas(previous, self, ts_init)
+    }
+
+    fn levels_excluding_own(
+        &self,
+        ladder: &Ladder,
+        depth: Option<usize>,
+    ) -> Vec<(Price, Quantity)> {
+        ladder
+            .levels
+            .values()
+            .filter_map(|level| {
+                let precision = level.first()?.size.precision;
+                let own_size_raw = self.own_orders.size_raw_at(ladder.side, level.price.value);
+                let size_raw = level.size_raw().saturating_sub(own_size_raw);
+                (size_raw > 0).then(|| {
+                    (
+                        level.price.value,
+                        Quantity::from_raw(size_raw, precision).unwrap(),
+                    )
+                })
+            })
+            .take(depth.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    fn apply_delta_unchecked(&mut self, delta: OrderBookDelta) {
+        let order = delta.order;
+        let flags = delta.flags;
+        let sequence = delta.sequence;
+        let ts_event = delta.ts_event;
+        match delta.action {
+            BookAction::Add => self.add(order, flags, sequence, ts_event),
+            BookAction::Update => self.update(order, flags, sequence, ts_event),
+            BookAction::Delete => self.delete(order, flags, sequence, ts_event),
+            BookAction::Clear => self.clear(sequence, ts_event),
+        }
+    }
+
+    fn apply_depth_unchecked(&mut self, depth: OrderBookDepth10) {
+        self.bids.clear();
+        self.asks.clear();
+
+        // Skip empty levels (padding for sides with fewer than ten levels)
+        for order in depth.bids.into_iter().chain(depth.asks) {
+            if order.size.raw > 0 {
+                self.add(order, depth.flags, depth.sequence, depth.ts_event);
+            }
+        }
+    }
+
+    /// Multiple deltas may share a sequence (a single venue message can produce several
+    /// events), so only a decreasing sequence or a skip beyond the next sequence is invalid.
+    fn check_sequence(&mut self, sequence: u64) -> Result<(), BookIntegrityError> {
+        if self.is_stale {
+            return Err(BookIntegrityError::BookStale);
+        }
+        if self.count == 0 {
+            return Ok(()); // First event establishes the sequence
+        }
+        if sequence < self.sequence {
+            return Err(BookIntegrityError::StaleSequence(self.sequence, sequence));
+        }
+        // No sequence can skip ahead of `u64::MAX`
+        if let Some(expected) = self.sequence.checked_add(1) {
+            if sequence > expected {
+                self.is_stale = true;
+                return Err(BookIntegrityError::SequenceGap(expected, sequence));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_crossed(&mut self, delta: &OrderBookDelta) -> Result<(), BookIntegrityError> {
+        if self.crossed_policy == CrossedBookPolicy::Accept
+            || !matches!(delta.action, BookAction::Add | BookAction::Update)
+        {
+            return Ok(());
+        }
+
+        let order = delta.order;
+        let side = order.side.as_specified();
+        let opposing = match side {
+            OrderSideSpecified::Buy => &mut self.asks,
+            OrderSideSpecified::Sell => &mut self.bids,
+        };
+        let Some(top_price) = opposing.top().map(|level| level.price) else {
+            return Ok(());
+        };
+
+        let crosses = match side {
+            OrderSideSpecified::Buy => order.price >= top_price.value,
+            OrderSideSpecified::Sell => order.price <= top_price.value,
+        };
+        if !crosses {
+            return Ok(());
+        }
+
+        match self.crossed_policy {
+            CrossedBookPolicy::Reject => Err(match side {
+                OrderSideSpecified::Buy => {
+                    BookIntegrityError::OrdersCrossed(order.to_book_price(), top_price)
+                }
+                OrderSideSpecified::Sell => {
+                    BookIntegrityError::OrdersCrossed(top_price, order.to_book_price())
+                }
+            }),
+            CrossedBookPolicy::Purge => {
+                opposing.remove_levels_through(order.price);
+                Ok(())
+            }
+            CrossedBookPolicy::Accept => Ok(()),
+        }
     }
 
     fn increment(&mut self, sequence: u64, ts_event: UnixNanos) {
@@ -254,23 +650,56 @@ impl OrderBook {
     }
 }
 
+/// Both sides of a depth snapshot replace the book, so there are no stale levels to purge and
+/// only a crossed or locked snapshot itself is invalid.
+fn check_depth_crossed(depth: &OrderBookDepth10) -> Result<(), BookIntegrityError> {
+    // Skip empty levels (padding for sides with fewer than ten levels)
+    let best_bid = depth
+        .bids
+        .iter()
+        .filter(|order| order.size.raw > 0)
+        .max_by_key(|order| order.price);
+    let best_ask = depth
+        .asks
+        .iter()
+        .filter(|order| order.size.raw > 0)
+        .min_by_key(|order| order.price);
+
+    match (best_bid, best_ask) {
+        (Some(bid), Some(ask)) if bid.price >= ask.price => Err(BookIntegrityError::OrdersCrossed(
+            bid.to_book_price(),
+            ask.to_book_price(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use float_cmp::approx_eq;
     use rstest::rstest;
 
     use crate::{
         data::{
-            depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick,
+            delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+            order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick,
+        },
+        enums::{
+            AggressorSide, BookAction, BookType, CrossedBookPolicy, OrderSide, OrderSideSpecified,
+            RecordFlag,
+        },
+        identifiers::{
+            client_order_id::ClientOrderId, instrument_id::InstrumentId, trade_id::TradeId,
         },
-        enums::{AggressorSide, BookType, OrderSide},
-        identifiers::{instrument_id::InstrumentId, trade_id::TradeId},
         orderbook::{
             aggregation::{update_book_with_quote_tick, update_book_with_trade_tick},
             analysis::book_check_integrity,
             book::OrderBook,
+            error::{BookIntegrityError, InvalidBookOperation},
+            own::OwnBookOrder,
         },
         types::{price::Price, quantity::Quantity},
     };
@@ -507,13 +936,126 @@ mod tests {
         );
     }
 
+    fn analytics_book() -> OrderBook {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AAPL.XNAS"));
+        let orders = [
+            (OrderSide::Buy, "100.00", "10"),
+            (OrderSide::Buy, "99.00", "20"),
+            (OrderSide::Sell, "101.00", "5"),
+            (OrderSide::Sell, "102.00", "15"),
+        ];
+        for (i, (side, price, size)) in orders.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, i as u64, 0.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_analytics_when_empty() {
+        let book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AAPL.XNAS"));
+
+        assert_eq!(book.microprice(), None);
+        assert_eq!(book.weighted_midpoint(5), None);
+        assert_eq!(book.imbalance(5), None);
+        assert_eq!(
+            book.get_vwap_for_quantity(Quantity::from("1"), OrderSide::Buy),
+            None
+        );
+        assert_eq!(book.get_slippage_for_notional(100.0, OrderSide::Sell), None);
+    }
+
+    #[rstest]
+    fn test_microprice() {
+        let book = analytics_book();
+
+        let microprice = book.microprice().unwrap();
+
+        assert!(approx_eq!(f64, microprice, 1510.0 / 15.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_weighted_midpoint() {
+        let book = analytics_book();
+
+        let bid_vwap = 2980.0 / 30.0;
+        let ask_vwap = 2035.0 / 20.0;
+        let expected = (bid_vwap * 20.0 + ask_vwap * 30.0) / 50.0;
+
+        assert!(approx_eq!(
+            f64,
+            book.weighted_midpoint(2).unwrap(),
+            expected,
+            epsilon = 1e-9
+        ));
+        assert_eq!(book.weighted_midpoint(1), book.microprice());
+    }
+
+    #[rstest]
+    fn test_imbalance() {
+        let book = analytics_book();
+
+        assert!(approx_eq!(
+            f64,
+            book.imbalance(1).unwrap(),
+            5.0 / 15.0,
+            epsilon = 1e-9
+        ));
+        assert!(approx_eq!(
+            f64,
+            book.imbalance(2).unwrap(),
+            7.5 / 32.5,
+            epsilon = 1e-9
+        ));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "5", Some(101.0))]
+    #[case(OrderSide::Buy, "10", Some(101.5))]
+    #[case(OrderSide::Buy, "25", None)]
+    #[case(OrderSide::Sell, "10", Some(100.0))]
+    #[case(OrderSide::Sell, "20", Some(99.5))]
+    fn test_get_vwap_for_quantity(
+        #[case] side: OrderSide,
+        #[case] qty: &str,
+        #[case] expected: Option<f64>,
+    ) {
+        let book = analytics_book();
+
+        assert_eq!(
+            book.get_vwap_for_quantity(Quantity::from(qty), side),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 505.0, Some(0.0))]
+    #[case(OrderSide::Buy, 1015.0, Some(0.5))]
+    #[case(OrderSide::Buy, 5000.0, None)]
+    #[case(OrderSide::Sell, 500.0, Some(0.0))]
+    #[case(OrderSide::Sell, 1990.0, Some(0.5))]
+    fn test_get_slippage_for_notional(
+        #[case] side: OrderSide,
+        #[case] notional: f64,
+        #[case] expected: Option<f64>,
+    ) {
+        let book = analytics_book();
+
+        let slippage = book.get_slippage_for_notional(notional, side);
+
+        match expected {
+            Some(expected) => assert!(approx_eq!(f64, slippage.unwrap(), expected, epsilon = 1e-9)),
+            None => assert_eq!(slippage, None),
+        }
+    }
+
     #[rstest]
     fn test_apply_depth(stub_depth10: OrderBookDepth10) {
         let depth = stub_depth10;
         let instrument_id = InstrumentId::from("AAPL.XNAS");
         let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
 
-        book.apply_depth(depth);
+        book.apply_depth(depth).unwrap();
 
         assert_eq!(book.best_bid_price().unwrap().as_f64(), 99.00);
         assert_eq!(book.best_ask_price().unwrap().as_f64(), 100.00);
@@ -521,6 +1063,192 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap().as_f64(), 100.0);
     }
 
+    fn add_delta(side: OrderSide, price: &str, order_id: u64, sequence: u64) -> OrderBookDelta {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from("1.0"), order_id);
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            BookAction::Add,
+            order,
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_apply_delta_non_strict_ignores_sequence() {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("AAPL.XNAS"));
+
+        book.apply_delta(add_delta(OrderSide::Buy, "99.00", 1, 5))
+            .unwrap();
+        book.apply_delta(add_delta(OrderSide::Buy, "98.00", 2, 3))
+            .unwrap();
+        book.apply_delta(add_delta(OrderSide::Buy, "97.00", 3, 10))
+            .unwrap();
+
+        assert!(!book.is_stale);
+        assert_eq!(book.sequence, 10);
+        assert_eq!(book.count, 3);
+    }
+
+    #[rstest]
+    fn test_apply_delta_strict_accepts_repeated_and_next_sequence() {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("AAPL.XNAS"));
+        book.strict_sequence = true;
+
+        book.apply_delta(add_delta(OrderSide::Buy, "99.00", 1, 5))
+            .unwrap();
+        book.apply_delta(add_delta(OrderSide::Buy, "98.00", 2, 5))
+            .unwrap();
+        book.apply_delta(add_delta(OrderSide::Sell, "101.00", 3, 6))
+            .unwrap();
+
+        assert!(!book.is_stale);
+        assert_eq!(book.sequence, 6);
+        assert_eq!(book.count, 3);
+    }
+
+    #[rstest]
+    fn test_apply_delta_strict_rejects_stale_sequence() {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("AAPL.XNAS"));
+        book.strict_sequence = true;
+        book.apply_delta(add_delta(OrderSide::Buy, "99.00", 1, 5))
+            .unwrap();
+
+        let result = book.apply_delta(add_delta(OrderSide::Buy, "98.00", 2, 4));
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::StaleSequence(5, 4))
+        ));
+        assert!(!book.is_stale);
+        assert_eq!(book.sequence, 5);
+        assert_eq!(book.count, 1);
+        assert_eq!(book.bids().count(), 1);
+    }
+
+    #[rstest]
+    fn test_apply_delta_strict_gap_marks_book_stale() {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("AAPL.XNAS"));
+        book.strict_sequence = true;
+        book.apply_delta(add_delta(OrderSide::Buy, "99.00", 1, 5))
+            .unwrap();
+
+        let result = book.apply_delta(add_delta(OrderSide::Buy, "98.00", 2, 8));
+        assert!(matches!(result, Err(BookIntegrityError::SequenceGap(6, 8))));
+        assert!(book.is_stale);
+
+        // Subsequent deltas are rejected until a resync
+        let result = book.apply_delta(add_delta(OrderSide::Buy, "97.00", 3, 9));
+        assert!(matches!(result, Err(BookIntegrityError::BookStale)));
+        assert_eq!(book.bids().count(), 1);
+        assert_eq!(book.count, 1);
+    }
+
+    #[rstest]
+    fn test_apply_delta_strict_at_max_sequence() {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("AAPL.XNAS"));
+        book.strict_sequence = true;
+
+        book.apply_delta(add_delta(OrderSide::Buy, "99.00", 1, u64::MAX))
+            .unwrap();
+        book.apply_delta(add_delta(OrderSide::Buy, "98.00", 2, u64::MAX))
+            .unwrap();
+
+        assert!(!book.is_stale);
+        assert_eq!(book.sequence, u64::MAX);
+    }
+
+    #[rstest]
+    fn test_apply_deltas_strict_stops_at_first_error() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        book.strict_sequence = true;
+        let deltas = OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                add_delta(OrderSide::Buy, "99.00", 1, 1),
+                add_delta(OrderSide::Buy, "98.00", 2, 3),
+                add_delta(OrderSide::Buy, "97.00", 3, 4),
+            ],
+        );
+
+        let result = book.apply_deltas(deltas);
+
+        assert!(matches!(result, Err(BookIntegrityError::SequenceGap(2, 3))));
+        assert!(book.is_stale);
+        assert_eq!(book.bids().count(), 1);
+    }
+
+    #[rstest]
+    fn test_resync_requires_snapshot() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        let deltas = OrderBookDeltas::new(
+            instrument_id,
+            vec![add_delta(OrderSide::Buy, "99.00", 1, 1)],
+        );
+
+        let result = book.resync(deltas);
+
+        assert!(matches!(result, Err(BookIntegrityError::NotSnapshot)));
+        assert_eq!(book.count, 0);
+    }
+
+    #[rstest]
+    fn test_resync_from_snapshot_deltas_clears_stale() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(BookType::L3_MBO, instrument_id);
+        book.strict_sequence = true;
+        book.apply_delta(add_delta(OrderSide::Buy, "99.00", 1, 5))
+            .unwrap();
+        let _ = book.apply_delta(add_delta(OrderSide::Buy, "98.00", 2, 8));
+        assert!(book.is_stale);
+
+        let mut bid = add_delta(OrderSide::Buy, "100.00", 10, 20);
+        bid.flags = RecordFlag::F_SNAPSHOT as u8;
+        let mut ask = add_delta(OrderSide::Sell, "101.00", 11, 20);
+        ask.flags = RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8;
+        let snapshot = OrderBookDeltas::new(
+            instrument_id,
+            vec![
+                OrderBookDelta::clear(instrument_id, 20, 20.into(), 20.into()),
+                bid,
+                ask,
+            ],
+        );
+        book.resync(snapshot).unwrap();
+
+        assert!(!book.is_stale);
+        assert_eq!(book.sequence, 20);
+        assert_eq!(book.bids().count(), 1);
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("101.00")));
+
+        // Sequencing continues from the snapshot
+        book.apply_delta(add_delta(OrderSide::Buy, "99.50", 12, 21))
+            .unwrap();
+        assert_eq!(book.sequence, 21);
+    }
+
+    #[rstest]
+    fn test_apply_depth_strict_when_stale() {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AAPL.XNAS"));
+        book.strict_sequence = true;
+        book.is_stale = true;
+
+        let result = book.apply_depth(stub_depth10());
+        assert!(matches!(result, Err(BookIntegrityError::BookStale)));
+        assert!(!book.has_bid());
+
+        book.resync_depth(stub_depth10());
+
+        assert!(!book.is_stale);
+        assert_eq!(book.best_bid_price().unwrap().as_f64(), 99.00);
+        assert_eq!(book.best_ask_price().unwrap().as_f64(), 100.00);
+    }
+
     #[rstest]
     fn test_orderbook_creation() {
         let instrument_id = InstrumentId::from("AAPL.XNAS");
@@ -572,6 +1300,201 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap(), quote.ask_size);
     }
 
+    #[rstest]
+    fn test_update_quote_tick_l1_crossed_rejected() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L1_MBP, instrument_id);
+        book.crossed_policy = CrossedBookPolicy::Reject;
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("5100.000"),
+            Price::from("5100.000"),
+            Quantity::from("100.00000000"),
+            Quantity::from("99.00000000"),
+            0.into(),
+            0.into(),
+        )
+        .unwrap();
+
+        let result = book.update_quote_tick(&quote);
+
+        assert!(matches!(result, Err(InvalidBookOperation::Crossed(_, _))));
+        assert!(!book.has_bid());
+        assert!(!book.has_ask());
+    }
+
+    #[rstest]
+    #[case(CrossedBookPolicy::Accept)]
+    #[case(CrossedBookPolicy::Purge)]
+    fn test_update_quote_tick_l1_crossed_applied(#[case] policy: CrossedBookPolicy) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L1_MBP, instrument_id);
+        book.crossed_policy = policy;
+        let quote = QuoteTick::new(
+            instrument_id,
+            Price::from("5100.000"),
+            Price::from("5100.000"),
+            Quantity::from("100.00000000"),
+            Quantity::from("99.00000000"),
+            0.into(),
+            0.into(),
+        )
+        .unwrap();
+
+        book.update_quote_tick(&quote).unwrap();
+
+        assert!(book.is_locked());
+        assert_eq!(book.best_bid_price(), Some(quote.bid_price));
+        assert_eq!(book.best_ask_price(), Some(quote.ask_price));
+    }
+
+    #[rstest]
+    #[case(CrossedBookPolicy::Accept, false)]
+    #[case(CrossedBookPolicy::Reject, true)]
+    #[case(CrossedBookPolicy::Purge, false)]
+    fn test_apply_depth_crossed(#[case] policy: CrossedBookPolicy, #[case] expected_err: bool) {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AAPL.XNAS"));
+        book.crossed_policy = policy;
+        let mut depth = stub_depth10();
+        depth.bids[0].price = Price::from("100.50");
+
+        let result = book.apply_depth(depth);
+
+        assert_eq!(
+            matches!(result, Err(BookIntegrityError::OrdersCrossed(_, _))),
+            expected_err
+        );
+        assert_eq!(book.is_crossed(), !expected_err);
+        assert_eq!(book.has_bid(), !expected_err);
+    }
+
+    fn crossed_book(policy: CrossedBookPolicy) -> OrderBook {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AAPL.XNAS"));
+        book.crossed_policy = policy;
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("99.00"),
+                Quantity::from("10"),
+                0,
+            ),
+            0,
+            1,
+            1.into(),
+        );
+        for price in ["100.00", "101.00", "102.00"] {
+            let order =
+                BookOrder::new(OrderSide::Sell, Price::from(price), Quantity::from("10"), 0);
+            book.add(order, 0, 1, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_apply_delta_crossed_accept_flags_book() {
+        let mut book = crossed_book(CrossedBookPolicy::Accept);
+
+        book.apply_delta(add_delta(OrderSide::Buy, "100.00", 1, 2))
+            .unwrap();
+        assert!(book.is_locked());
+        assert!(!book.is_crossed());
+
+        book.apply_delta(add_delta(OrderSide::Buy, "100.50", 2, 3))
+            .unwrap();
+        assert!(book.is_crossed());
+        assert!(book_check_integrity(&book).is_err());
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100.00")]
+    #[case(OrderSide::Buy, "101.50")]
+    #[case(OrderSide::Sell, "99.00")]
+    #[case(OrderSide::Sell, "98.00")]
+    fn test_apply_delta_crossed_reject(#[case] side: OrderSide, #[case] price: &str) {
+        let mut book = crossed_book(CrossedBookPolicy::Reject);
+
+        let result = book.apply_delta(add_delta(side, price, 1, 2));
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::OrdersCrossed(_, _))
+        ));
+        assert_eq!(book.bids().count(), 1);
+        assert_eq!(book.asks().count(), 3);
+        assert_eq!(book.sequence, 1);
+    }
+
+    #[rstest]
+    fn test_apply_deltas_crossed_reject_applies_remaining_deltas() {
+        let mut book = crossed_book(CrossedBookPolicy::Reject);
+        book.strict_sequence = true;
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let delete = OrderBookDelta::new(
+            instrument_id,
+            BookAction::Delete,
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("102.00"),
+                Quantity::from("10"),
+                0,
+            ),
+            0,
+            3,
+            3.into(),
+            3.into(),
+        );
+        let deltas = OrderBookDeltas::new(
+            instrument_id,
+            vec![add_delta(OrderSide::Buy, "101.00", 1, 2), delete],
+        );
+
+        let result = book.apply_deltas(deltas);
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::OrdersCrossed(_, _))
+        ));
+        assert_eq!(book.best_bid_price(), Some(Price::from("99.00")));
+        assert_eq!(book.asks().count(), 2);
+        assert_eq!(book.sequence, 3);
+        assert!(!book.is_stale);
+    }
+
+    #[rstest]
+    fn test_apply_delta_not_crossed_with_reject_policy() {
+        let mut book = crossed_book(CrossedBookPolicy::Reject);
+
+        book.apply_delta(add_delta(OrderSide::Buy, "99.50", 1, 2))
+            .unwrap();
+        book.apply_delta(add_delta(OrderSide::Sell, "99.75", 2, 3))
+            .unwrap();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("99.50")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("99.75")));
+    }
+
+    #[rstest]
+    fn test_apply_delta_crossed_purge_removes_opposing_levels() {
+        let mut book = crossed_book(CrossedBookPolicy::Purge);
+
+        book.apply_delta(add_delta(OrderSide::Buy, "101.00", 1, 2))
+            .unwrap();
+
+        assert!(!book.is_crossed());
+        assert!(!book.is_locked());
+        assert_eq!(book.best_bid_price(), Some(Price::from("101.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("102.00")));
+        assert_eq!(book.asks().count(), 1);
+        assert!(book_check_integrity(&book).is_ok());
+
+        // The locking ask is now the fresh side, purging the bid
+        book.apply_delta(add_delta(OrderSide::Sell, "101.00", 0, 3))
+            .unwrap();
+        assert_eq!(book.best_ask_price(), Some(Price::from("101.00")));
+        assert_eq!(book.asks().count(), 2);
+        assert_eq!(book.best_bid_price(), Some(Price::from("99.00")));
+    }
+
     #[rstest]
     fn test_update_trade_tick_l1() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -685,4 +1608,117 @@ mod tests {
         println!("{pprint_output}");
         assert_eq!(pprint_output, expected_output);
     }
+
+    #[rstest]
+    fn test_levels_excluding_own_orders() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("3.0"),
+                0,
+            ),
+            0,
+            1,
+            100.into(),
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("0.900"),
+                Quantity::from("2.0"),
+                0,
+            ),
+            0,
+            2,
+            200.into(),
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("1.100"),
+                Quantity::from("1.0"),
+                0,
+            ),
+            0,
+            3,
+            300.into(),
+        );
+        book.own_orders_mut().add(OwnBookOrder::new(
+            ClientOrderId::from("O-1"),
+            OrderSideSpecified::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+        ));
+        book.own_orders_mut().add(OwnBookOrder::new(
+            ClientOrderId::from("O-2"),
+            OrderSideSpecified::Sell,
+            Price::from("1.100"),
+            Quantity::from("1.0"),
+        ));
+
+        assert_eq!(
+            book.bids_excluding_own(None),
+            vec![
+                (Price::from("1.000"), Quantity::from("2.0")),
+                (Price::from("0.900"), Quantity::from("2.0")),
+            ]
+        );
+        assert_eq!(
+            book.bids_excluding_own(Some(1)),
+            vec![(Price::from("1.000"), Quantity::from("2.0"))]
+        );
+        assert!(book.asks_excluding_own(None).is_empty());
+        assert!(book
+            .own_orders()
+            .would_self_trade(OrderSide::Buy, Price::from("1.100")));
+    }
+
+    #[rstest]
+    fn test_pprint_highlights_own_orders() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(BookType::L2_MBP, instrument_id);
+        book.add(
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.000"),
+                Quantity::from("3.0"),
+                0,
+            ),
+            0,
+            1,
+            100.into(),
+        );
+        book.add(
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("2.000"),
+                Quantity::from("4.0"),
+                0,
+            ),
+            0,
+            2,
+            200.into(),
+        );
+        book.own_orders_mut().add(OwnBookOrder::new(
+            ClientOrderId::from("O-1"),
+            OrderSideSpecified::Buy,
+            Price::from("1.000"),
+            Quantity::from("1.0"),
+        ));
+
+        let pprint_output = book.pprint(3);
+
+        let expected_output = "╭─────────────────┬───────┬───────╮\n\
+                               │ bids            │ price │ asks  │\n\
+                               ├─────────────────┼───────┼───────┤\n\
+                               │                 │ 2.000 │ [4.0] │\n\
+                               │ [3.0] (own 1.0) │ 1.000 │       │\n\
+                               ╰─────────────────┴───────┴───────╯";
+
+        println!("{pprint_output}");
+        assert_eq!(pprint_output, expected_output);
+    }
 }
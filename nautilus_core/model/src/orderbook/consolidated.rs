@@ -0,0 +1,558 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A consolidated order book which merges the books for an instrument across multiple venues.
+
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use indexmap::IndexMap;
+
+use super::{book::OrderBook, ladder::BookPrice};
+use crate::{
+    data::{delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10},
+    enums::OrderSide,
+    identifiers::{instrument_id::InstrumentId, venue::Venue},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents a consolidated price level with the size attributed to each venue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsolidatedLevel {
+    /// The level price.
+    pub price: Price,
+    /// The total size at the level across all venues.
+    pub size: Quantity,
+    /// The size at the level for each venue.
+    pub venue_sizes: IndexMap<Venue, Quantity>,
+}
+
+/// Represents the portion of a quantity to route to a venue.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VenueAllocation {
+    /// The venue to route to.
+    pub venue: Venue,
+    /// The quantity to route to the venue.
+    pub quantity: Quantity,
+    /// The expected average fill price at the venue.
+    pub avg_px: f64,
+}
+
+/// Provides a consolidated view of the order books for the same instrument across venues.
+///
+/// Each venue's `OrderBook` is maintained independently (one book per `Venue`), and the
+/// consolidated ladder is merged from the venue books on demand, so queries always reflect
+/// the latest state of every venue.
+#[derive(Clone, Debug, Default)]
+pub struct ConsolidatedOrderBook {
+    books: IndexMap<Venue, OrderBook>,
+}
+
+impl ConsolidatedOrderBook {
+    /// Creates a new empty [`ConsolidatedOrderBook`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of venue books.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.books.len()
+    }
+
+    /// Returns whether there are no venue books.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.books.is_empty()
+    }
+
+    /// Returns the venues with a book, in insertion order.
+    pub fn venues(&self) -> impl Iterator<Item = &Venue> {
+        self.books.keys()
+    }
+
+    /// Adds the given `book` for its instrument's venue, returning any book it replaces.
+    pub fn insert_book(&mut self, book: OrderBook) -> Option<OrderBook> {
+        self.books.insert(book.instrument_id.venue, book)
+    }
+
+    /// Removes and returns the book for the given `venue`.
+    pub fn remove_book(&mut self, venue: &Venue) -> Option<OrderBook> {
+        self.books.shift_remove(venue)
+    }
+
+    /// Returns the book for the given `venue`.
+    #[must_use]
+    pub fn book(&self, venue: &Venue) -> Option<&OrderBook> {
+        self.books.get(venue)
+    }
+
+    /// Returns a mutable reference to the book for the given `venue`.
+    pub fn book_mut(&mut self, venue: &Venue) -> Option<&mut OrderBook> {
+        self.books.get_mut(venue)
+    }
+
+    /// Applies the given `delta` to the book for its instrument's venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - There is no book for the venue, or the venue book is for another instrument.
+    /// - The venue book rejects the delta.
+    pub fn apply_delta(&mut self, delta: OrderBookDelta) -> anyhow::Result<()> {
+        self.venue_book_mut(&delta.instrument_id)?
+            .apply_delta(delta)
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Applies the given `deltas` to the book for their instrument's venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - There is no book for the venue, or the venue book is for another instrument.
+    /// - The venue book rejects the deltas.
+    pub fn apply_deltas(&mut self, deltas: OrderBookDeltas) -> anyhow::Result<()> {
+        self.venue_book_mut(&deltas.instrument_id)?
+            .apply_deltas(deltas)
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Applies the given `depth` to the book for its instrument's venue.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    /// - There is no book for the venue, or the venue book is for another instrument.
+    /// - The venue book rejects the depth.
+    pub fn apply_depth(&mut self, depth: OrderBookDepth10) -> anyhow::Result<()> {
+        self.venue_book_mut(&depth.instrument_id)?
+            .apply_depth(depth)
+            .map_err(anyhow::Error::from)
+    }
+
+    /// Returns the consolidated bid levels from the best price, limited to `depth` levels if given.
+    #[must_use]
+    pub fn bids(&self, depth: Option<usize>) -> Vec<ConsolidatedLevel> {
+        self.merge_levels(OrderSide::Buy, depth)
+    }
+
+    /// Returns the consolidated ask levels from the best price, limited to `depth` levels if given.
+    #[must_use]
+    pub fn asks(&self, depth: Option<usize>) -> Vec<ConsolidatedLevel> {
+        self.merge_levels(OrderSide::Sell, depth)
+    }
+
+    /// Returns the best consolidated bid level across all venues.
+    #[must_use]
+    pub fn best_bid(&self) -> Option<ConsolidatedLevel> {
+        self.bids(Some(1)).into_iter().next()
+    }
+
+    /// Returns the best consolidated ask level across all venues.
+    #[must_use]
+    pub fn best_ask(&self) -> Option<ConsolidatedLevel> {
+        self.asks(Some(1)).into_iter().next()
+    }
+
+    /// Returns the best bid price across all venues.
+    #[must_use]
+    pub fn best_bid_price(&self) -> Option<Price> {
+        self.books
+            .values()
+            .filter_map(OrderBook::best_bid_price)
+            .max()
+    }
+
+    /// Returns the best ask price across all venues.
+    #[must_use]
+    pub fn best_ask_price(&self) -> Option<Price> {
+        self.books
+            .values()
+            .filter_map(OrderBook::best_ask_price)
+            .min()
+    }
+
+    /// Returns the spread between the best ask and best bid across all venues.
+    #[must_use]
+    pub fn spread(&self) -> Option<f64> {
+        match (self.best_ask_price(), self.best_bid_price()) {
+            (Some(ask), Some(bid)) => Some(ask.as_f64() - bid.as_f64()),
+            _ => None,
+        }
+    }
+
+    /// Returns the midpoint between the best ask and best bid across all venues.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<f64> {
+        match (self.best_ask_price(), self.best_bid_price()) {
+            (Some(ask), Some(bid)) => Some((ask.as_f64() + bid.as_f64()) / 2.0),
+            _ => None,
+        }
+    }
+
+    /// Returns how to split `qty` for the given `order_side` across venues for the best
+    /// average price.
+    ///
+    /// The consolidated opposing ladder is walked from the best price. Within a level, the
+    /// venue with the most size is allocated first to minimize the number of child orders.
+    /// If the consolidated book does not hold enough liquidity, the allocations sum to less
+    /// than `qty`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `order_side` is `NoOrderSide`.
+    #[must_use]
+    pub fn route_quantity(&self, qty: Quantity, order_side: OrderSide) -> Vec<VenueAllocation> {
+        let levels = match order_side {
+            OrderSide::Buy => self.asks(None),
+            OrderSide::Sell => self.bids(None),
+            _ => panic!("Invalid `OrderSide` {order_side}"),
+        };
+
+        // Per venue: (size_raw, size_precision, cumulative value)
+        let mut allocations: IndexMap<Venue, (u64, u8, f64)> = IndexMap::new();
+        let mut remaining_raw = qty.raw;
+
+        for level in levels {
+            let mut venue_sizes: Vec<(&Venue, &Quantity)> = level.venue_sizes.iter().collect();
+            venue_sizes.sort_by_key(|(_, size)| Reverse(size.raw));
+
+            for (venue, size) in venue_sizes {
+                if remaining_raw == 0 {
+                    break;
+                }
+                let fill_raw = size.raw.min(remaining_raw);
+                remaining_raw -= fill_raw;
+
+                let allocation = allocations
+                    .entry(*venue)
+                    .or_insert((0, size.precision, 0.0));
+                allocation.0 += fill_raw;
+                allocation.2 += level.price.as_f64() * fill_raw as f64;
+            }
+
+            if remaining_raw == 0 {
+                break;
+            }
+        }
+
+        allocations
+            .into_iter()
+            .map(|(venue, (size_raw, precision, value))| VenueAllocation {
+                venue,
+                quantity: Quantity::from_raw(size_raw, precision.max(qty.precision)).unwrap(),
+                avg_px: value / size_raw as f64,
+            })
+            .collect()
+    }
+
+    /// Returns the expected average fill price to trade `qty` for the given `order_side` when
+    /// routed across venues, or `None` if the consolidated book does not hold enough liquidity.
+    #[must_use]
+    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> Option<f64> {
+        let allocations = self.route_quantity(qty, order_side);
+        let filled_raw: u64 = allocations.iter().map(|a| a.quantity.raw).sum();
+        if qty.raw == 0 || filled_raw < qty.raw {
+            return None;
+        }
+
+        let value: f64 = allocations
+            .iter()
+            .map(|a| a.avg_px * a.quantity.raw as f64)
+            .sum();
+        Some(value / filled_raw as f64)
+    }
+
+    fn venue_book_mut(&mut self, instrument_id: &InstrumentId) -> anyhow::Result<&mut OrderBook> {
+        let book = self
+            .books
+            .get_mut(&instrument_id.venue)
+            .ok_or_else(|| anyhow::anyhow!("No book for venue {}", instrument_id.venue))?;
+        anyhow::ensure!(
+            book.instrument_id == *instrument_id,
+            "Book for venue {} is for {}, was {instrument_id}",
+            instrument_id.venue,
+            book.instrument_id
+        );
+        Ok(book)
+    }
+
+    fn merge_levels(&self, side: OrderSide, depth: Option<usize>) -> Vec<ConsolidatedLevel> {
+        let depth = depth.unwrap_or(usize::MAX);
+        let mut levels: BTreeMap<BookPrice, ConsolidatedLevel> = BTreeMap::new();
+
+        for (venue, book) in &self.books {
+            let ladder = match side {
+                OrderSide::Buy => &book.bids,
+                _ => &book.asks,
+            };
+
+            // A level in the consolidated top `depth` must be within each venue's top `depth`
+            for level in ladder.levels.values().take(depth) {
+                let Some(first) = level.first() else {
+                    continue;
+                };
+                let size = Quantity::from_raw(level.size_raw(), first.size.precision).unwrap();
+                let price = level.price.value;
+
+                let consolidated = levels
+                    .entry(BookPrice::new(price, side))
+                    .or_insert_with(|| ConsolidatedLevel {
+                        price,
+                        size: Quantity::zero(size.precision),
+                        venue_sizes: IndexMap::new(),
+                    });
+                consolidated.size = Quantity::from_raw(
+                    consolidated.size.raw + size.raw,
+                    consolidated.size.precision.max(size.precision),
+                )
+                .unwrap();
+                consolidated.venue_sizes.insert(*venue, size);
+            }
+        }
+
+        levels.into_values().take(depth).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::{order::BookOrder, stubs::stub_depth10},
+        enums::{BookAction, BookType},
+    };
+
+    fn venue_book(instrument_id: &str, orders: &[(OrderSide, &str, &str)]) -> OrderBook {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from(instrument_id));
+        for (side, price, size) in orders {
+            let order = BookOrder::new(*side, Price::from(*price), Quantity::from(*size), 0);
+            book.add(order, 0, 1, 1.into());
+        }
+        book
+    }
+
+    fn consolidated_book() -> ConsolidatedOrderBook {
+        let mut book = ConsolidatedOrderBook::new();
+        book.insert_book(venue_book(
+            "BTCUSDT.BINANCE",
+            &[
+                (OrderSide::Buy, "100.00", "1.0"),
+                (OrderSide::Buy, "99.00", "2.0"),
+                (OrderSide::Sell, "101.00", "1.0"),
+                (OrderSide::Sell, "102.00", "3.0"),
+            ],
+        ));
+        book.insert_book(venue_book(
+            "BTC-USDT.OKX",
+            &[
+                (OrderSide::Buy, "100.50", "0.5"),
+                (OrderSide::Buy, "99.00", "1.5"),
+                (OrderSide::Sell, "101.00", "2.0"),
+                (OrderSide::Sell, "101.50", "1.0"),
+            ],
+        ));
+        book
+    }
+
+    #[rstest]
+    fn test_empty_book() {
+        let book = ConsolidatedOrderBook::new();
+
+        assert!(book.is_empty());
+        assert_eq!(book.best_bid(), None);
+        assert_eq!(book.best_ask_price(), None);
+        assert_eq!(book.midpoint(), None);
+        assert!(book
+            .route_quantity(Quantity::from("1.0"), OrderSide::Buy)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_insert_and_remove_books() {
+        let mut book = consolidated_book();
+        let binance = Venue::from("BINANCE");
+        let okx = Venue::from("OKX");
+
+        assert_eq!(book.len(), 2);
+        assert_eq!(
+            book.venues().copied().collect::<Vec<_>>(),
+            vec![binance, okx]
+        );
+        assert!(book.book(&okx).is_some());
+
+        let removed = book.remove_book(&okx).unwrap();
+
+        assert_eq!(removed.instrument_id, InstrumentId::from("BTC-USDT.OKX"));
+        assert_eq!(book.len(), 1);
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.00")));
+    }
+
+    #[rstest]
+    fn test_best_bid_and_ask_across_venues() {
+        let book = consolidated_book();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("100.50")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("101.00")));
+        assert_eq!(book.spread(), Some(0.5));
+        assert_eq!(book.midpoint(), Some(100.75));
+
+        let best_ask = book.best_ask().unwrap();
+        assert_eq!(best_ask.size, Quantity::from("3.0"));
+        assert_eq!(best_ask.venue_sizes.len(), 2);
+    }
+
+    #[rstest]
+    fn test_levels_retain_venue_attribution() {
+        let book = consolidated_book();
+
+        let bids = book.bids(None);
+
+        assert_eq!(
+            bids.iter().map(|level| level.price).collect::<Vec<_>>(),
+            vec![
+                Price::from("100.50"),
+                Price::from("100.00"),
+                Price::from("99.00")
+            ]
+        );
+        assert_eq!(bids[2].size, Quantity::from("3.5"));
+        assert_eq!(
+            bids[2].venue_sizes[&Venue::from("BINANCE")],
+            Quantity::from("2.0")
+        );
+        assert_eq!(
+            bids[2].venue_sizes[&Venue::from("OKX")],
+            Quantity::from("1.5")
+        );
+        assert_eq!(book.asks(Some(2)).len(), 2);
+    }
+
+    #[rstest]
+    fn test_apply_delta_routes_to_venue_book() {
+        let mut book = consolidated_book();
+        let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.75"),
+            Quantity::from("0.7"),
+            0,
+        );
+        let delta = OrderBookDelta::new(
+            instrument_id,
+            BookAction::Add,
+            order,
+            0,
+            2,
+            2.into(),
+            2.into(),
+        );
+
+        book.apply_delta(delta).unwrap();
+
+        let best_ask = book.best_ask().unwrap();
+        assert_eq!(best_ask.price, Price::from("100.75"));
+        assert_eq!(
+            best_ask.venue_sizes.keys().collect::<Vec<_>>(),
+            vec![&instrument_id.venue]
+        );
+    }
+
+    #[rstest]
+    fn test_apply_for_unknown_venue() {
+        let mut book = consolidated_book();
+
+        // The depth stub is for AAPL.XNAS
+        assert!(book.apply_depth(stub_depth10()).is_err());
+    }
+
+    #[rstest]
+    fn test_apply_for_other_instrument_on_venue() {
+        let mut book = consolidated_book();
+        let order = BookOrder::new(
+            OrderSide::Sell,
+            Price::from("100.75"),
+            Quantity::from("0.7"),
+            0,
+        );
+        let delta = OrderBookDelta::new(
+            InstrumentId::from("ETH-USDT.OKX"),
+            BookAction::Add,
+            order,
+            0,
+            2,
+            2.into(),
+            2.into(),
+        );
+
+        assert!(book.apply_delta(delta).is_err());
+        assert_eq!(book.best_ask().unwrap().price, Price::from("101.00"));
+    }
+
+    #[rstest]
+    fn test_route_quantity_splits_across_venues() {
+        let book = consolidated_book();
+
+        let allocations = book.route_quantity(Quantity::from("3.5"), OrderSide::Buy);
+
+        // 101.00: OKX 2.0 then BINANCE 1.0, then 101.50: OKX 0.5
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].venue, Venue::from("OKX"));
+        assert_eq!(allocations[0].quantity, Quantity::from("2.5"));
+        assert!((allocations[0].avg_px - (101.0 * 2.0 + 101.5 * 0.5) / 2.5).abs() < 1e-9);
+        assert_eq!(allocations[1].venue, Venue::from("BINANCE"));
+        assert_eq!(allocations[1].quantity, Quantity::from("1.0"));
+        assert_eq!(allocations[1].avg_px, 101.0);
+
+        let avg_px = book
+            .get_avg_px_for_quantity(Quantity::from("3.5"), OrderSide::Buy)
+            .unwrap();
+        assert!((avg_px - (101.0 * 3.0 + 101.5 * 0.5) / 3.5).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_route_quantity_sell() {
+        let book = consolidated_book();
+
+        let allocations = book.route_quantity(Quantity::from("1.0"), OrderSide::Sell);
+
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[0].venue, Venue::from("OKX"));
+        assert_eq!(allocations[0].quantity, Quantity::from("0.5"));
+        assert_eq!(allocations[1].venue, Venue::from("BINANCE"));
+        assert_eq!(allocations[1].quantity, Quantity::from("0.5"));
+        assert_eq!(allocations[1].avg_px, 100.0);
+    }
+
+    #[rstest]
+    fn test_route_quantity_with_insufficient_liquidity() {
+        let book = consolidated_book();
+
+        let allocations = book.route_quantity(Quantity::from("10.0"), OrderSide::Sell);
+        let routed: f64 = allocations.iter().map(|a| a.quantity.as_f64()).sum();
+
+        assert_eq!(routed, 5.0);
+        assert_eq!(
+            book.get_avg_px_for_quantity(Quantity::from("10.0"), OrderSide::Sell),
+            None
+        );
+    }
+}
@@ -15,8 +15,12 @@
 
 use tabled::{settings::Style, Table, Tabled};
 
-use super::{ladder::BookPrice, level::Level};
-use crate::orderbook::ladder::Ladder;
+use super::{ladder::BookPrice, level::Level, own::OwnOrderBook};
+use crate::{
+    enums::OrderSide,
+    orderbook::ladder::Ladder,
+    types::{price::Price, quantity::Quantity},
+};
 
 #[derive(Tabled)]
 struct OrderLevelDisplay {
@@ -26,8 +30,15 @@ struct OrderLevelDisplay {
 }
 
 /// Return a [`String`] representation of the order book in a human-readable table format.
+///
+/// The size of any of our own orders at a level is highlighted alongside the level sizes.
 #[must_use]
-pub fn pprint_book(bids: &Ladder, asks: &Ladder, num_levels: usize) -> String {
+pub fn pprint_book(
+    bids: &Ladder,
+    asks: &Ladder,
+    own_orders: &OwnOrderBook,
+    num_levels: usize,
+) -> String {
     let ask_levels: Vec<(&BookPrice, &Level)> = asks.levels.iter().take(num_levels).rev().collect();
     let bid_levels: Vec<(&BookPrice, &Level)> = bids.levels.iter().take(num_levels).collect();
     let levels: Vec<(&BookPrice, &Level)> = ask_levels.into_iter().chain(bid_levels).collect();
@@ -53,20 +64,50 @@ pub fn pprint_book(bids: &Ladder, asks: &Ladder, num_levels: usize) -> String {
                 .collect();
 
             OrderLevelDisplay {
-                bids: if bid_sizes.is_empty() {
-                    String::new()
-                } else {
-                    format!("[{}]", bid_sizes.join(", "))
-                },
+                bids: format_sizes(&bid_sizes, level, own_orders, OrderSide::Buy),
                 price: format!("{}", level.price),
-                asks: if ask_sizes.is_empty() {
-                    String::new()
-                } else {
-                    format!("[{}]", ask_sizes.join(", "))
-                },
+                asks: format_sizes(&ask_sizes, level, own_orders, OrderSide::Sell),
             }
         })
         .collect();
 
     Table::new(data).with(Style::rounded()).to_string()
 }
+
+/// Return a [`String`] representation of grouped order book levels in a human-readable table format.
+#[must_use]
+pub fn pprint_grouped_levels(bids: &[(Price, Quantity)], asks: &[(Price, Quantity)]) -> String {
+    let ask_rows = asks.iter().rev().map(|(price, size)| OrderLevelDisplay {
+        bids: String::new(),
+        price: format!("{price}"),
+        asks: format!("[{size}]"),
+    });
+    let bid_rows = bids.iter().map(|(price, size)| OrderLevelDisplay {
+        bids: format!("[{size}]"),
+        price: format!("{price}"),
+        asks: String::new(),
+    });
+    let data: Vec<OrderLevelDisplay> = ask_rows.chain(bid_rows).collect();
+
+    Table::new(data).with(Style::rounded()).to_string()
+}
+
+fn format_sizes(
+    sizes: &[String],
+    level: &Level,
+    own_orders: &OwnOrderBook,
+    side: OrderSide,
+) -> String {
+    if sizes.is_empty() {
+        return String::new();
+    }
+
+    let own_size_raw = own_orders.size_raw_at(side, level.price.value);
+    match level.first() {
+        Some(order) if own_size_raw > 0 => {
+            let own_size = Quantity::from_raw(own_size_raw, order.size.precision).unwrap();
+            format!("[{}] (own {own_size})", sizes.join(", "))
+        }
+        _ => format!("[{}]", sizes.join(", ")),
+    }
+}
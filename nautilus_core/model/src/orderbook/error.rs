@@ -16,7 +16,10 @@
 use nautilus_core::nanos::UnixNanos;
 
 use super::ladder::BookPrice;
-use crate::enums::{BookType, OrderSide};
+use crate::{
+    enums::{BookType, OrderSide},
+    types::price::Price,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum InvalidBookOperation {
@@ -26,6 +29,8 @@ pub enum InvalidBookOperation {
     Add(BookType),
     #[error("Invalid book operation: cannot update with tick for {0} book")]
     Update(BookType),
+    #[error("Invalid book operation: update would cross or lock the book [{0} {1}]")]
+    Crossed(Price, Price),
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -40,4 +45,12 @@ pub enum BookIntegrityError {
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: stale sequence, last={0}, was {1}")]
+    StaleSequence(u64, u64),
+    #[error("Integrity error: sequence gap, expected={0}, was {1}")]
+    SequenceGap(u64, u64),
+    #[error("Integrity error: book is stale, resync from a snapshot required")]
+    BookStale,
+    #[error("Integrity error: resync requires snapshot data (`F_SNAPSHOT` flag not set)")]
+    NotSnapshot,
 }
@@ -0,0 +1,268 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for aggregated views of an order book side (price buckets and cumulative depth).
+
+use super::ladder::Ladder;
+use crate::{
+    enums::OrderSide,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents a point on a cumulative depth curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CumulativeLevel {
+    /// The price of the level.
+    pub price: Price,
+    /// The cumulative size from the top of book through this level.
+    pub size: Quantity,
+    /// The cumulative notional (price * size) from the top of book through this level.
+    pub notional: f64,
+}
+
+/// Returns the levels of the `ladder` aggregated into buckets of `group_size`.
+///
+/// Bid prices are rounded down and ask prices are rounded up to the nearest multiple of
+/// `group_size`, so that a bucket price is never more aggressive than the levels within it.
+/// Buckets are returned from the top of book, limited to `depth` buckets if given.
+///
+/// # Errors
+///
+/// This function returns an error if `group_size` is not positive.
+pub fn group_levels(
+    ladder: &Ladder,
+    group_size: Price,
+    depth: Option<usize>,
+) -> anyhow::Result<Vec<(Price, Quantity)>> {
+    if group_size.raw <= 0 {
+        anyhow::bail!("Invalid `group_size`, should be positive and was {group_size}");
+    }
+
+    let group_raw = group_size.raw;
+    let depth = depth.unwrap_or(usize::MAX);
+    let mut groups: Vec<(i64, u64, u8, u8)> = Vec::new(); // (price_raw, size_raw, price_prec, size_prec)
+
+    for level in ladder.levels.values() {
+        let Some(first) = level.first() else {
+            continue;
+        };
+
+        let price = level.price.value;
+        let floor_raw = price.raw.div_euclid(group_raw) * group_raw;
+        let bucket_raw = match ladder.side {
+            OrderSide::Sell if floor_raw != price.raw => floor_raw + group_raw,
+            _ => floor_raw,
+        };
+
+        match groups.last_mut() {
+            Some(group) if group.0 == bucket_raw => group.1 += level.size_raw(),
+            _ => {
+                if groups.len() == depth {
+                    break;
+                }
+                groups.push((
+                    bucket_raw,
+                    level.size_raw(),
+                    price.precision.max(group_size.precision),
+                    first.size.precision,
+                ));
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(price_raw, size_raw, price_precision, size_precision)| {
+            Ok((
+                Price::from_raw(price_raw, price_precision)?,
+                Quantity::from_raw(size_raw, size_precision)?,
+            ))
+        })
+        .collect()
+}
+
+/// Returns the cumulative depth curve of the `ladder` from the top of book, limited to
+/// `depth` levels if given.
+#[must_use]
+pub fn cumulative_depth(ladder: &Ladder, depth: Option<usize>) -> Vec<CumulativeLevel> {
+    let mut size_raw = 0;
+    let mut notional = 0.0;
+
+    ladder
+        .levels
+        .values()
+        .take(depth.unwrap_or(usize::MAX))
+        .filter_map(|level| {
+            let precision = level.first()?.size.precision;
+            size_raw += level.size_raw();
+            notional += level.exposure();
+            Some(CumulativeLevel {
+                price: level.price.value,
+                size: Quantity::from_raw(size_raw, precision).unwrap(),
+                notional,
+            })
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::{
+        data::order::BookOrder,
+        enums::{BookType, OrderSide},
+        identifiers::instrument_id::InstrumentId,
+        orderbook::book::OrderBook,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        let levels = [
+            (OrderSide::Buy, "100.00", "1.0"),
+            (OrderSide::Buy, "99.95", "2.0"),
+            (OrderSide::Buy, "99.90", "3.0"),
+            (OrderSide::Buy, "99.80", "4.0"),
+            (OrderSide::Sell, "100.05", "1.5"),
+            (OrderSide::Sell, "100.10", "2.5"),
+            (OrderSide::Sell, "100.25", "3.5"),
+        ];
+        for (i, (side, price, size)) in levels.into_iter().enumerate() {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), i as u64);
+            book.add(order, 0, i as u64, 0.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_group_levels_invalid_group_size() {
+        let book = book();
+
+        assert!(book.grouped_bids(Price::from("0.00"), None).is_err());
+        assert!(book.grouped_asks(Price::from("-0.10"), None).is_err());
+    }
+
+    #[rstest]
+    fn test_group_bids_rounds_down() {
+        let book = book();
+
+        let groups = book.grouped_bids(Price::from("0.10"), None).unwrap();
+
+        assert_eq!(
+            groups,
+            vec![
+                (Price::from("100.00"), Quantity::from("1.0")),
+                (Price::from("99.90"), Quantity::from("5.0")),
+                (Price::from("99.80"), Quantity::from("4.0")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_group_asks_rounds_up() {
+        let book = book();
+
+        let groups = book.grouped_asks(Price::from("0.10"), None).unwrap();
+
+        assert_eq!(
+            groups,
+            vec![
+                (Price::from("100.10"), Quantity::from("4.0")),
+                (Price::from("100.30"), Quantity::from("3.5")),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_group_levels_with_depth() {
+        let book = book();
+
+        let groups = book.grouped_bids(Price::from("0.10"), Some(2)).unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1], (Price::from("99.90"), Quantity::from("5.0")));
+    }
+
+    #[rstest]
+    fn test_group_levels_with_coarse_group_size() {
+        let book = book();
+
+        let bids = book.grouped_bids(Price::from("1"), None).unwrap();
+        let asks = book.grouped_asks(Price::from("1"), None).unwrap();
+
+        assert_eq!(
+            bids,
+            vec![
+                (Price::from("100.00"), Quantity::from("1.0")),
+                (Price::from("99.00"), Quantity::from("9.0")),
+            ]
+        );
+        assert_eq!(asks, vec![(Price::from("101.00"), Quantity::from("7.5"))]);
+    }
+
+    #[rstest]
+    fn test_cumulative_depth() {
+        let book = book();
+
+        let curve = book.cumulative_depth(OrderSide::Sell, None);
+
+        assert_eq!(curve.len(), 3);
+        assert_eq!(curve[0].price, Price::from("100.05"));
+        assert_eq!(curve[0].size, Quantity::from("1.5"));
+        assert_eq!(curve[2].size, Quantity::from("7.5"));
+        let expected_notional = 100.05 * 1.5 + 100.10 * 2.5 + 100.25 * 3.5;
+        assert!((curve[2].notional - expected_notional).abs() < 1e-9);
+        assert!(curve.windows(2).all(|w| w[1].notional > w[0].notional));
+    }
+
+    #[rstest]
+    fn test_cumulative_depth_with_depth_limit() {
+        let book = book();
+
+        let curve = book.cumulative_depth(OrderSide::Buy, Some(2));
+
+        assert_eq!(curve.len(), 2);
+        assert_eq!(curve[1].price, Price::from("99.95"));
+        assert_eq!(curve[1].size, Quantity::from("3.0"));
+    }
+
+    #[rstest]
+    fn test_cumulative_depth_when_empty() {
+        let book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+
+        assert!(book.cumulative_depth(OrderSide::Buy, None).is_empty());
+    }
+
+    #[rstest]
+    fn test_pprint_grouped() {
+        let book = book();
+
+        let output = book.pprint_grouped(Price::from("0.10"), 2).unwrap();
+
+        let expected = r#"╭───────┬────────┬───────╮
+│ bids  │ price  │ asks  │
+├───────┼────────┼───────┤
+│       │ 100.30 │ [3.5] │
+│       │ 100.10 │ [4.0] │
+│ [1.0] │ 100.00 │       │
+│ [5.0] │ 99.90  │       │
+╰───────┴────────┴───────╯"#;
+        assert_eq!(output, expected);
+    }
+}
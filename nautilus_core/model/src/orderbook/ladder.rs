@@ -166,6 +166,23 @@ impl Ladder {
         }
     }
 
+    /// Removes all levels at or more aggressive than the given `price` (at or above for bids,
+    /// at or below for asks), returning the number of levels removed.
+    pub fn remove_levels_through(&mut self, price: Price) -> usize {
+        let limit = BookPrice::new(price, self.side);
+        let mut removed = 0;
+        while let Some(entry) = self.levels.first_entry() {
+            if *entry.key() > limit {
+                break;
+            }
+            for order_id in entry.remove().orders.keys() {
+                self.cache.remove(order_id);
+            }
+            removed += 1;
+        }
+        removed
+    }
+
     #[must_use]
     pub fn sizes(&self) -> f64 {
         self.levels.values().map(super::level::Level::size).sum()
@@ -18,7 +18,11 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod consolidated;
 pub mod display;
 pub mod error;
+pub mod group;
 pub mod ladder;
 pub mod level;
+pub mod own;
+pub mod snapshot;
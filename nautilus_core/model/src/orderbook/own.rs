@@ -0,0 +1,462 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeMap, HashMap};
+
+use indexmap::IndexMap;
+
+use super::ladder::BookPrice;
+use crate::{
+    enums::{OrderSide, OrderSideSpecified},
+    identifiers::{client_order_id::ClientOrderId, instrument_id::InstrumentId},
+    orders::any::OrderAny,
+    polymorphism::GetClientOrderId,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents one of our own resting orders in an order book.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OwnBookOrder {
+    /// The client order ID for the order.
+    pub client_order_id: ClientOrderId,
+    /// The order side.
+    pub side: OrderSideSpecified,
+    /// The order price.
+    pub price: Price,
+    /// The order size remaining in the book (the leaves quantity).
+    pub size: Quantity,
+}
+
+impl OwnBookOrder {
+    /// Creates a new [`OwnBookOrder`] instance.
+    #[must_use]
+    pub fn new(
+        client_order_id: ClientOrderId,
+        side: OrderSideSpecified,
+        price: Price,
+        size: Quantity,
+    ) -> Self {
+        Self {
+            client_order_id,
+            side,
+            price,
+            size,
+        }
+    }
+
+    #[must_use]
+    pub fn to_book_price(&self) -> BookPrice {
+        BookPrice::new(self.price, self.side.as_order_side())
+    }
+}
+
+/// Provides an overlay of our own resting orders for an order book, keyed by client order ID.
+///
+/// Orders are held per price level in the same priority as the book ladders, and in
+/// insertion order within each level.
+#[derive(Clone, Debug)]
+pub struct OwnOrderBook {
+    /// The instrument ID for the own order book.
+    pub instrument_id: InstrumentId,
+    bids: BTreeMap<BookPrice, IndexMap<ClientOrderId, OwnBookOrder>>,
+    asks: BTreeMap<BookPrice, IndexMap<ClientOrderId, OwnBookOrder>>,
+    index: HashMap<ClientOrderId, BookPrice>,
+}
+
+impl OwnOrderBook {
+    /// Creates a new [`OwnOrderBook`] instance.
+    #[must_use]
+    pub fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, client_order_id: &ClientOrderId) -> bool {
+        self.index.contains_key(client_order_id)
+    }
+
+    #[must_use]
+    pub fn get(&self, client_order_id: &ClientOrderId) -> Option<&OwnBookOrder> {
+        let book_price = self.index.get(client_order_id)?;
+        self.side_levels(book_price.side)
+            .get(book_price)
+            .and_then(|orders| orders.get(client_order_id))
+    }
+
+    /// Returns our own bid orders in book priority.
+    pub fn bids(&self) -> impl Iterator<Item = &OwnBookOrder> {
+        self.bids.values().flat_map(IndexMap::values)
+    }
+
+    /// Returns our own ask orders in book priority.
+    pub fn asks(&self) -> impl Iterator<Item = &OwnBookOrder> {
+        self.asks.values().flat_map(IndexMap::values)
+    }
+
+    /// Adds the `order`, replacing any existing order with the same client order ID.
+    ///
+    /// An order which remains at the same price keeps its position within the level.
+    pub fn add(&mut self, order: OwnBookOrder) {
+        let book_price = order.to_book_price();
+        let is_same_level = self
+            .index
+            .get(&order.client_order_id)
+            .is_some_and(|existing| {
+                existing.side == book_price.side && existing.value == book_price.value
+            });
+        if !is_same_level {
+            self.delete(&order.client_order_id);
+        }
+
+        self.index.insert(order.client_order_id, book_price);
+        self.side_levels_mut(book_price.side)
+            .entry(book_price)
+            .or_default()
+            .insert(order.client_order_id, order);
+    }
+
+    /// Deletes the order with the given `client_order_id`, returning the order if it existed.
+    pub fn delete(&mut self, client_order_id: &ClientOrderId) -> Option<OwnBookOrder> {
+        let book_price = self.index.remove(client_order_id)?;
+        let levels = self.side_levels_mut(book_price.side);
+        let orders = levels.get_mut(&book_price)?;
+        let order = orders.shift_remove(client_order_id);
+        if orders.is_empty() {
+            levels.remove(&book_price);
+        }
+        order
+    }
+
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.index.clear();
+    }
+
+    /// Updates the own order book from the current state of the `order`.
+    ///
+    /// Open orders with a price are added at their leaves quantity, all other orders are
+    /// deleted from the own order book.
+    pub fn update_order(&mut self, order: &OrderAny) {
+        let client_order_id = order.client_order_id();
+        let order = order.as_order();
+        let side = order.side();
+        let leaves_qty = order.leaves_qty();
+
+        match order.price() {
+            Some(price)
+                if order.is_open()
+                    && leaves_qty.is_positive()
+                    && side != OrderSide::NoOrderSide =>
+            {
+                self.add(OwnBookOrder::new(
+                    client_order_id,
+                    side.as_specified(),
+                    price,
+                    leaves_qty,
+                ));
+            }
+            _ => {
+                self.delete(&client_order_id);
+            }
+        }
+    }
+
+    /// Returns the total raw size of our own orders at the given `side` and `price`.
+    #[must_use]
+    pub fn size_raw_at(&self, side: OrderSide, price: Price) -> u64 {
+        if side == OrderSide::NoOrderSide {
+            return 0;
+        }
+
+        self.side_levels(side)
+            .get(&BookPrice::new(price, side))
+            .map_or(0, |orders| {
+                orders.values().map(|order| order.size.raw).sum()
+            })
+    }
+
+    /// Returns our own resting orders which an order on the given `side` at `price` would
+    /// trade against (in book priority).
+    #[must_use]
+    pub fn crossed_orders(&self, side: OrderSide, price: Price) -> Vec<&OwnBookOrder> {
+        let (levels, is_crossed): (_, fn(Price, Price) -> bool) = match side {
+            OrderSide::Buy => (&self.asks, |own, price| own <= price),
+            OrderSide::Sell => (&self.bids, |own, price| own >= price),
+            OrderSide::NoOrderSide => return Vec::new(),
+        };
+
+        levels
+            .iter()
+            .take_while(|(book_price, _)| is_crossed(book_price.value, price))
+            .flat_map(|(_, orders)| orders.values())
+            .collect()
+    }
+
+    /// Returns whether an order on the given `side` at `price` would trade against any of
+    /// our own resting orders.
+    #[must_use]
+    pub fn would_self_trade(&self, side: OrderSide, price: Price) -> bool {
+        !self.crossed_orders(side, price).is_empty()
+    }
+
+    fn side_levels(
+        &self,
+        side: OrderSide,
+    ) -> &BTreeMap<BookPrice, IndexMap<ClientOrderId, OwnBookOrder>> {
+        match side {
+            OrderSide::Buy => &self.bids,
+            _ => &self.asks,
+        }
+    }
+
+    fn side_levels_mut(
+        &mut self,
+        side: OrderSide,
+    ) -> &mut BTreeMap<BookPrice, IndexMap<ClientOrderId, OwnBookOrder>> {
+        match side {
+            OrderSide::Buy => &mut self.bids,
+            _ => &mut self.asks,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use rstest::rstest;
+
+    use super::{OwnBookOrder, OwnOrderBook};
+    use crate::{
+        enums::{OrderSide, OrderSideSpecified},
+        events::order::{
+            accepted::OrderAccepted, canceled::OrderCanceled, event::OrderEventAny,
+            submitted::OrderSubmitted,
+        },
+        identifiers::{
+            account_id::AccountId, client_order_id::ClientOrderId, instrument_id::InstrumentId,
+            venue_order_id::VenueOrderId,
+        },
+        orders::{any::OrderAny, stubs::TestOrderStubs},
+        polymorphism::ApplyOrderEventAny,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn own_order(
+        client_order_id: &str,
+        side: OrderSideSpecified,
+        price: &str,
+        size: &str,
+    ) -> OwnBookOrder {
+        OwnBookOrder::new(
+            ClientOrderId::from(client_order_id),
+            side,
+            Price::from(price),
+            Quantity::from(size),
+        )
+    }
+
+    fn accepted_limit_order(client_order_id: &str) -> OrderAny {
+        let mut order = OrderAny::Limit(TestOrderStubs::limit_order(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("10"),
+            Some(ClientOrderId::from(client_order_id)),
+            None,
+        ));
+        let order_ref = order.as_order();
+        let submitted = OrderSubmitted::new(
+            order_ref.trader_id(),
+            order_ref.strategy_id(),
+            order_ref.instrument_id(),
+            order_ref.client_order_id(),
+            AccountId::from("BINANCE-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        let accepted = OrderAccepted::new(
+            order_ref.trader_id(),
+            order_ref.strategy_id(),
+            order_ref.instrument_id(),
+            order_ref.client_order_id(),
+            VenueOrderId::from("V-1"),
+            AccountId::from("BINANCE-001"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order
+    }
+
+    #[rstest]
+    fn test_add_and_delete() {
+        let mut own_book = OwnOrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        own_book.add(own_order("O-1", OrderSideSpecified::Buy, "100.00", "10"));
+        own_book.add(own_order("O-2", OrderSideSpecified::Buy, "100.00", "5"));
+
+        assert_eq!(own_book.len(), 2);
+        assert_eq!(
+            own_book.size_raw_at(OrderSide::Buy, Price::from("100.00")),
+            Quantity::from("15").raw
+        );
+        assert_eq!(
+            own_book.size_raw_at(OrderSide::Sell, Price::from("100.00")),
+            0
+        );
+
+        let deleted = own_book.delete(&ClientOrderId::from("O-1"));
+
+        assert_eq!(deleted.map(|order| order.size), Some(Quantity::from("10")));
+        assert_eq!(own_book.len(), 1);
+        assert!(own_book.delete(&ClientOrderId::from("O-1")).is_none());
+    }
+
+    #[rstest]
+    fn test_add_existing_order_at_same_price_keeps_priority() {
+        let mut own_book = OwnOrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        own_book.add(own_order("O-1", OrderSideSpecified::Sell, "101.00", "10"));
+        own_book.add(own_order("O-2", OrderSideSpecified::Sell, "101.00", "5"));
+        own_book.add(own_order("O-1", OrderSideSpecified::Sell, "101.00", "4"));
+
+        let client_order_ids: Vec<&str> = own_book
+            .asks()
+            .map(|order| order.client_order_id.as_str())
+            .collect();
+
+        assert_eq!(client_order_ids, vec!["O-1", "O-2"]);
+        assert_eq!(
+            own_book.get(&ClientOrderId::from("O-1")).unwrap().size,
+            Quantity::from("4")
+        );
+    }
+
+    #[rstest]
+    fn test_add_existing_order_at_new_price_moves_level() {
+        let mut own_book = OwnOrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        own_book.add(own_order("O-1", OrderSideSpecified::Buy, "100.00", "10"));
+        own_book.add(own_order("O-1", OrderSideSpecified::Buy, "99.00", "10"));
+
+        assert_eq!(own_book.len(), 1);
+        assert_eq!(
+            own_book.size_raw_at(OrderSide::Buy, Price::from("100.00")),
+            0
+        );
+        assert_eq!(
+            own_book.size_raw_at(OrderSide::Buy, Price::from("99.00")),
+            Quantity::from("10").raw
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100.50", vec![])]
+    #[case(OrderSide::Buy, "101.00", vec!["O-3"])]
+    #[case(OrderSide::Buy, "102.00", vec!["O-3", "O-4"])]
+    #[case(OrderSide::Sell, "100.50", vec![])]
+    #[case(OrderSide::Sell, "98.00", vec!["O-1", "O-2"])]
+    fn test_crossed_orders(
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: Vec<&str>,
+    ) {
+        let mut own_book = OwnOrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        own_book.add(own_order("O-1", OrderSideSpecified::Buy, "100.00", "10"));
+        own_book.add(own_order("O-2", OrderSideSpecified::Buy, "99.00", "10"));
+        own_book.add(own_order("O-3", OrderSideSpecified::Sell, "101.00", "10"));
+        own_book.add(own_order("O-4", OrderSideSpecified::Sell, "102.00", "10"));
+
+        let crossed: Vec<&str> = own_book
+            .crossed_orders(side, Price::from(price))
+            .iter()
+            .map(|order| order.client_order_id.as_str())
+            .collect();
+
+        assert_eq!(crossed, expected);
+        assert_eq!(
+            own_book.would_self_trade(side, Price::from(price)),
+            !expected.is_empty()
+        );
+    }
+
+    #[rstest]
+    fn test_update_order_tracks_open_order_until_closed() {
+        let mut own_book = OwnOrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        let mut order = accepted_limit_order("O-1");
+
+        own_book.update_order(&order);
+
+        assert!(own_book.contains(&ClientOrderId::from("O-1")));
+
+        let order_ref = order.as_order();
+        let canceled = OrderCanceled::new(
+            order_ref.trader_id(),
+            order_ref.strategy_id(),
+            order_ref.instrument_id(),
+            order_ref.client_order_id(),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            false,
+            order_ref.venue_order_id(),
+            order_ref.account_id(),
+        )
+        .unwrap();
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+        own_book.update_order(&order);
+
+        assert!(own_book.is_empty());
+    }
+
+    #[rstest]
+    fn test_update_order_ignores_order_not_yet_open() {
+        let mut own_book = OwnOrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"));
+        let order = OrderAny::Limit(TestOrderStubs::limit_order(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            OrderSide::Buy,
+            Price::from("100.00"),
+            Quantity::from("10"),
+            None,
+            None,
+        ));
+
+        own_book.update_order(&order);
+
+        assert!(own_book.is_empty());
+    }
+}
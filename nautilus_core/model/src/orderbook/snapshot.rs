@@ -0,0 +1,344 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions for exporting an `OrderBook` as snapshot and incremental data.
+
+use indexmap::IndexMap;
+use nautilus_core::nanos::UnixNanos;
+
+use super::{book::OrderBook, ladder::Ladder};
+use crate::{
+    data::{
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::{OrderBookDepth10, DEPTH10_LEN},
+        order::{BookOrder, OrderId, NULL_ORDER},
+    },
+    enums::{BookAction, RecordFlag},
+    types::quantity::Quantity,
+};
+
+/// Returns the top levels of each side aggregated into an `OrderBookDepth10`.
+///
+/// Each level is represented by a single order with the total level size, and sides with fewer
+/// than ten levels are padded with null orders (zero size and count).
+#[must_use]
+pub fn book_to_depth10(book: &OrderBook, ts_init: UnixNanos) -> OrderBookDepth10 {
+    let (bids, bid_counts) = ladder_to_depth(&book.bids);
+    let (asks, ask_counts) = ladder_to_depth(&book.asks);
+
+    OrderBookDepth10::new(
+        book.instrument_id,
+        bids,
+        asks,
+        bid_counts,
+        ask_counts,
+        RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8,
+        book.sequence,
+        book.ts_last,
+        ts_init,
+    )
+}
+
+/// Returns a full snapshot of the book as a `Clear` delta followed by an `Add` delta for
+/// every order, all flagged `F_SNAPSHOT` with `F_LAST` set on the final delta.
+#[must_use]
+pub fn book_to_snapshot_deltas(book: &OrderBook, ts_init: UnixNanos) -> OrderBookDeltas {
+    let flags = RecordFlag::F_SNAPSHOT as u8;
+    let mut deltas = vec![OrderBookDelta::clear(
+        book.instrument_id,
+        book.sequence,
+        book.ts_last,
+        ts_init,
+    )];
+
+    for level in book.bids.levels.values().chain(book.asks.levels.values()) {
+        for order in level.get_orders() {
+            deltas.push(OrderBookDelta::new(
+                book.instrument_id,
+                BookAction::Add,
+                order,
+                flags,
+                book.sequence,
+                book.ts_last,
+                ts_init,
+            ));
+        }
+    }
+
+    // SAFETY: Always contains at least the `Clear` delta
+    deltas.last_mut().unwrap().flags |= RecordFlag::F_LAST as u8;
+
+    OrderBookDeltas::new(book.instrument_id, deltas)
+}
+
+/// Returns the deltas which transform the `previous` book into the `current` book.
+///
+/// Orders are matched by order ID (the price level for MBP books), with deletes emitted
+/// before updates and adds so that applying the deltas never transiently crosses the book.
+/// The deltas take the sequence and timestamp of `current`, with `F_LAST` set on the final
+/// delta. Returns an empty vector if the books are identical.
+///
+/// # Errors
+///
+/// This function returns an error if the books are for different instruments.
+pub fn book_diff_deltas(
+    previous: &OrderBook,
+    current: &OrderBook,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Vec<OrderBookDelta>> {
+    if previous.instrument_id != current.instrument_id {
+        anyhow::bail!(
+            "Cannot diff books for different instruments, {} and {}",
+            previous.instrument_id,
+            current.instrument_id
+        );
+    }
+
+    let mut deletes = Vec::new();
+    let mut updates = Vec::new();
+    let mut adds = Vec::new();
+
+    for (prev_ladder, curr_ladder) in [
+        (&previous.bids, &current.bids),
+        (&previous.asks, &current.asks),
+    ] {
+        let prev_orders = ladder_orders(prev_ladder);
+        let curr_orders = ladder_orders(curr_ladder);
+
+        for (order_id, order) in &prev_orders {
+            if !curr_orders.contains_key(order_id) {
+                deletes.push((BookAction::Delete, *order));
+            }
+        }
+
+        for (order_id, order) in &curr_orders {
+            match prev_orders.get(order_id) {
+                Some(prev) if prev.price == order.price && prev.size == order.size => {}
+                Some(_) => updates.push((BookAction::Update, *order)),
+                None => adds.push((BookAction::Add, *order)),
+            }
+        }
+    }
+
+    let mut deltas: Vec<OrderBookDelta> = deletes
+        .into_iter()
+        .chain(updates)
+        .chain(adds)
+        .map(|(action, order)| {
+            OrderBookDelta::new(
+                current.instrument_id,
+                action,
+                order,
+                0,
+                current.sequence,
+                current.ts_last,
+                ts_init,
+            )
+        })
+        .collect();
+
+    if let Some(last) = deltas.last_mut() {
+        last.flags |= RecordFlag::F_LAST as u8;
+    }
+
+    Ok(deltas)
+}
+
+fn ladder_to_depth(ladder: &Ladder) -> ([BookOrder; DEPTH10_LEN], [u32; DEPTH10_LEN]) {
+    let mut orders = [NULL_ORDER; DEPTH10_LEN];
+    let mut counts = [0; DEPTH10_LEN];
+
+    for (i, level) in ladder.levels.values().take(DEPTH10_LEN).enumerate() {
+        // SAFETY: Levels are never empty
+        let size_precision = level.first().unwrap().size.precision;
+        let price = level.price.value;
+        orders[i] = BookOrder::new(
+            ladder.side,
+            price,
+            Quantity::from_raw(level.size_raw(), size_precision).unwrap(),
+            price.raw as u64,
+        );
+        counts[i] = level.len() as u32;
+    }
+
+    (orders, counts)
+}
+
+fn ladder_orders(ladder: &Ladder) -> IndexMap<OrderId, BookOrder> {
+    ladder
+        .levels
+        .values()
+        .flat_map(|level| level.get_orders())
+        .map(|order| (order.order_id, order))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::stubs::stub_depth10,
+        enums::{BookType, OrderSide},
+        identifiers::instrument_id::InstrumentId,
+        types::price::Price,
+    };
+
+    fn order(side: OrderSide, price: &str, size: &str, order_id: u64) -> BookOrder {
+        BookOrder::new(side, Price::from(price), Quantity::from(size), order_id)
+    }
+
+    fn l3_book() -> OrderBook {
+        let mut book = OrderBook::new(BookType::L3_MBO, InstrumentId::from("AAPL.XNAS"));
+        book.add(order(OrderSide::Buy, "99.00", "10", 1), 0, 1, 1.into());
+        book.add(order(OrderSide::Buy, "99.00", "20", 2), 0, 2, 2.into());
+        book.add(order(OrderSide::Buy, "98.00", "30", 3), 0, 3, 3.into());
+        book.add(order(OrderSide::Sell, "100.00", "40", 4), 0, 4, 4.into());
+        book
+    }
+
+    #[rstest]
+    fn test_to_depth10_aggregates_levels_and_pads() {
+        let book = l3_book();
+
+        let depth = book_to_depth10(&book, 10.into());
+
+        assert_eq!(depth.instrument_id, book.instrument_id);
+        assert_eq!(depth.sequence, 4);
+        assert_eq!(depth.ts_event, 4);
+        assert_eq!(depth.ts_init, 10);
+        assert!(RecordFlag::F_SNAPSHOT.matches(depth.flags));
+        assert_eq!(depth.bids[0].price, Price::from("99.00"));
+        assert_eq!(depth.bids[0].size, Quantity::from("30"));
+        assert_eq!(depth.bids[1].price, Price::from("98.00"));
+        assert_eq!(depth.bid_counts[..3], [2, 1, 0]);
+        assert_eq!(depth.asks[0].size, Quantity::from("40"));
+        assert_eq!(depth.asks[1], NULL_ORDER);
+        assert_eq!(depth.ask_counts[..2], [1, 0]);
+    }
+
+    #[rstest]
+    fn test_to_depth10_round_trip() {
+        let depth = stub_depth10();
+        let mut book = OrderBook::new(BookType::L2_MBP, depth.instrument_id);
+        book.apply_depth(stub_depth10()).unwrap();
+
+        let result = book.to_depth10(depth.ts_init);
+
+        assert_eq!(
+            result.bids.map(|o| (o.price, o.size)),
+            depth.bids.map(|o| (o.price, o.size))
+        );
+        assert_eq!(
+            result.asks.map(|o| (o.price, o.size)),
+            depth.asks.map(|o| (o.price, o.size))
+        );
+        assert_eq!(result.bid_counts, depth.bid_counts);
+        assert_eq!(result.ask_counts, depth.ask_counts);
+    }
+
+    #[rstest]
+    fn test_apply_depth_skips_padding() {
+        let book = l3_book();
+        let mut other = OrderBook::new(BookType::L2_MBP, book.instrument_id);
+
+        other.apply_depth(book_to_depth10(&book, 0.into())).unwrap();
+
+        assert_eq!(other.bids().count(), 2);
+        assert_eq!(other.asks().count(), 1);
+        assert_eq!(other.best_bid_size(), Some(Quantity::from("30")));
+    }
+
+    #[rstest]
+    fn test_to_snapshot_deltas_when_empty() {
+        let book = OrderBook::new(BookType::L2_MBP, InstrumentId::from("AAPL.XNAS"));
+
+        let deltas = book_to_snapshot_deltas(&book, 0.into());
+
+        assert_eq!(deltas.deltas.len(), 1);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert!(RecordFlag::F_LAST.matches(deltas.flags));
+    }
+
+    #[rstest]
+    fn test_to_snapshot_deltas_rebuilds_book() {
+        let book = l3_book();
+
+        let deltas = book_to_snapshot_deltas(&book, 10.into());
+
+        assert_eq!(deltas.deltas.len(), 5);
+        assert_eq!(deltas.deltas[0].action, BookAction::Clear);
+        assert!(deltas.deltas[1..]
+            .iter()
+            .all(|d| d.action == BookAction::Add && RecordFlag::F_SNAPSHOT.matches(d.flags)));
+        assert!(!RecordFlag::F_LAST.matches(deltas.deltas[3].flags));
+        assert!(RecordFlag::F_LAST.matches(deltas.flags));
+        assert_eq!(deltas.sequence, book.sequence);
+
+        let mut rebuilt = OrderBook::new(BookType::L3_MBO, book.instrument_id);
+        rebuilt.resync(deltas).unwrap();
+
+        assert_eq!(rebuilt.pprint(5), book.pprint(5));
+        assert_eq!(rebuilt.sequence, book.sequence);
+    }
+
+    #[rstest]
+    fn test_diff_deltas_when_identical() {
+        let book = l3_book();
+
+        let deltas = book_diff_deltas(&book, &book.clone(), 0.into()).unwrap();
+
+        assert!(deltas.is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_deltas_different_instruments() {
+        let book = l3_book();
+        let other = OrderBook::new(BookType::L3_MBO, InstrumentId::from("MSFT.XNAS"));
+
+        assert!(book_diff_deltas(&book, &other, 0.into()).is_err());
+    }
+
+    #[rstest]
+    fn test_diff_deltas_applied_to_previous_yields_current() {
+        let previous = l3_book();
+        let mut current = previous.clone();
+        current.delete(order(OrderSide::Buy, "99.00", "10", 1), 0, 5, 5.into());
+        current.update(order(OrderSide::Buy, "98.50", "15", 3), 0, 6, 6.into());
+        current.add(order(OrderSide::Sell, "101.00", "5", 5), 0, 7, 7.into());
+
+        let deltas = current.diff_deltas(&previous, 10.into()).unwrap();
+
+        let actions: Vec<BookAction> = deltas.iter().map(|d| d.action).collect();
+        assert_eq!(
+            actions,
+            vec![BookAction::Delete, BookAction::Update, BookAction::Add]
+        );
+        assert!(deltas.iter().all(|d| d.sequence == 7));
+        assert!(RecordFlag::F_LAST.matches(deltas[2].flags));
+        assert!(!RecordFlag::F_LAST.matches(deltas[0].flags));
+
+        let mut book = previous.clone();
+        book.apply_deltas(OrderBookDeltas::new(book.instrument_id, deltas))
+            .unwrap();
+        assert_eq!(book.pprint(5), current.pprint(5));
+        assert_eq!(book.sequence, current.sequence);
+    }
+}
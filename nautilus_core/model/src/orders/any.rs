@@ -13,11 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::fmt::Display;
+
 use nautilus_core::nanos::UnixNanos;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    base::{Order, OrderError},
+    base::{Order, OrderCore, OrderError},
     limit::LimitOrder,
     limit_if_touched::LimitIfTouchedOrder,
     market::MarketOrder,
@@ -104,6 +106,66 @@ impl OrderAny {
         Self::StopMarket(order)
     }
 
+    /// Returns a reference to the underlying order as an [`Order`] trait object.
+    #[must_use]
+    pub fn as_order(&self) -> &dyn Order {
+        match self {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying order as an [`Order`] trait object.
+    #[must_use]
+    pub fn as_order_mut(&mut self) -> &mut dyn Order {
+        match self {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        }
+    }
+
+    /// Sets the orders quantity (and leaves quantity) to the given `base_qty`, converting
+    /// an order which was denominated in a quote quantity to a base quantity.
+    pub fn set_base_quantity(&mut self, base_qty: Quantity) {
+        let core = self.core_mut();
+        core.quantity = base_qty;
+        core.leaves_qty = base_qty;
+        core.is_quote_quantity = false;
+    }
+
+    /// Sets the position ID the order is associated with.
+    pub fn set_position_id(&mut self, position_id: Option<PositionId>) {
+        self.core_mut().position_id = position_id;
+    }
+
+    fn core_mut(&mut self) -> &mut OrderCore {
+        match self {
+            Self::Limit(order) => order,
+            Self::LimitIfTouched(order) => order,
+            Self::Market(order) => order,
+            Self::MarketIfTouched(order) => order,
+            Self::MarketToLimit(order) => order,
+            Self::StopLimit(order) => order,
+            Self::StopMarket(order) => order,
+            Self::TrailingStopLimit(order) => order,
+            Self::TrailingStopMarket(order) => order,
+        }
+    }
+
     pub fn from_events(events: Vec<OrderEventAny>) -> anyhow::Result<Self> {
         if events.is_empty() {
             anyhow::bail!("No events provided");
@@ -127,6 +189,22 @@ impl PartialEq for OrderAny {
     }
 }
 
+impl Display for OrderAny {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Limit(order) => order.fmt(f),
+            Self::LimitIfTouched(order) => order.fmt(f),
+            Self::Market(order) => order.fmt(f),
+            Self::MarketIfTouched(order) => order.fmt(f),
+            Self::MarketToLimit(order) => order.fmt(f),
+            Self::StopLimit(order) => order.fmt(f),
+            Self::StopMarket(order) => order.fmt(f),
+            Self::TrailingStopLimit(order) => order.fmt(f),
+            Self::TrailingStopMarket(order) => order.fmt(f),
+        }
+    }
+}
+
 impl GetTraderId for OrderAny {
     fn trader_id(&self) -> TraderId {
         match self {
@@ -455,6 +533,102 @@ impl PassiveOrderAny {
     }
 }
 
+impl TryFrom<OrderAny> for PassiveOrderAny {
+    type Error = anyhow::Error;
+
+    /// Converts the given `order` into a passive order.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `order` is a `MarketOrder` (which can never rest
+    /// passively in a book).
+    fn try_from(order: OrderAny) -> anyhow::Result<Self> {
+        Ok(match order {
+            OrderAny::Limit(order) => Self::Limit(LimitOrderAny::Limit(order)),
+            OrderAny::LimitIfTouched(order) => Self::Stop(StopOrderAny::LimitIfTouched(order)),
+            OrderAny::MarketIfTouched(order) => Self::Stop(StopOrderAny::MarketIfTouched(order)),
+            OrderAny::MarketToLimit(order) => Self::Limit(LimitOrderAny::MarketToLimit(order)),
+            OrderAny::StopLimit(order) => Self::Stop(StopOrderAny::StopLimit(order)),
+            OrderAny::StopMarket(order) => Self::Stop(StopOrderAny::StopMarket(order)),
+            OrderAny::TrailingStopLimit(order) => {
+                Self::Stop(StopOrderAny::TrailingStopLimit(order))
+            }
+            OrderAny::TrailingStopMarket(order) => {
+                Self::Stop(StopOrderAny::TrailingStopMarket(order))
+            }
+            OrderAny::Market(order) => {
+                anyhow::bail!("Cannot convert {order} to a passive order")
+            }
+        })
+    }
+}
+
+impl From<PassiveOrderAny> for OrderAny {
+    fn from(order: PassiveOrderAny) -> Self {
+        match order {
+            PassiveOrderAny::Limit(order) => order.into(),
+            PassiveOrderAny::Stop(order) => order.into(),
+        }
+    }
+}
+
+impl From<LimitOrderAny> for OrderAny {
+    fn from(order: LimitOrderAny) -> Self {
+        match order {
+            LimitOrderAny::Limit(order) => Self::Limit(order),
+            LimitOrderAny::MarketToLimit(order) => Self::MarketToLimit(order),
+            LimitOrderAny::StopLimit(order) => Self::StopLimit(order),
+            LimitOrderAny::TrailingStopLimit(order) => Self::TrailingStopLimit(order),
+        }
+    }
+}
+
+impl From<StopOrderAny> for OrderAny {
+    fn from(order: StopOrderAny) -> Self {
+        match order {
+            StopOrderAny::LimitIfTouched(order) => Self::LimitIfTouched(order),
+            StopOrderAny::MarketIfTouched(order) => Self::MarketIfTouched(order),
+            StopOrderAny::StopLimit(order) => Self::StopLimit(order),
+            StopOrderAny::StopMarket(order) => Self::StopMarket(order),
+            StopOrderAny::TrailingStopLimit(order) => Self::TrailingStopLimit(order),
+            StopOrderAny::TrailingStopMarket(order) => Self::TrailingStopMarket(order),
+        }
+    }
+}
+
+impl ApplyOrderEventAny for PassiveOrderAny {
+    fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        match self {
+            Self::Limit(order) => order.apply(event),
+            Self::Stop(order) => order.apply(event),
+        }
+    }
+}
+
+impl ApplyOrderEventAny for LimitOrderAny {
+    fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        match self {
+            Self::Limit(order) => order.apply(event),
+            Self::MarketToLimit(order) => order.apply(event),
+            Self::StopLimit(order) => order.apply(event),
+            Self::TrailingStopLimit(order) => order.apply(event),
+        }
+    }
+}
+
+impl ApplyOrderEventAny for StopOrderAny {
+    fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
+        match self {
+            Self::LimitIfTouched(order) => order.apply(event),
+            Self::MarketIfTouched(order) => order.apply(event),
+            Self::StopLimit(order) => order.apply(event),
+            Self::StopMarket(order) => order.apply(event),
+            Self::TrailingStopLimit(order) => order.apply(event),
+            Self::TrailingStopMarket(order) => order.apply(event),
+        }
+    }
+}
+
 impl PartialEq for PassiveOrderAny {
     fn eq(&self, rhs: &Self) -> bool {
         match self {
@@ -106,9 +106,11 @@ impl OrderStatus {
             (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
             (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
             (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
+            (Self::Initialized, OrderEventAny::Updated(_)) => Self::Initialized,  // Execution algo
             (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
             (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
             (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
+            (Self::Emulated, OrderEventAny::Updated(_)) => Self::Emulated,  // Emulated orders
             (Self::Released, OrderEventAny::Submitted(_)) => Self::Submitted,  // Emulated orders
             (Self::Released, OrderEventAny::Denied(_)) => Self::Denied,  // Emulated orders
             (Self::Released, OrderEventAny::Canceled(_)) => Self::Canceled,  // Execution algo
@@ -119,6 +121,7 @@ impl OrderStatus {
             (Self::Submitted, OrderEventAny::Accepted(_)) => Self::Accepted,
             (Self::Submitted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Submitted, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Submitted, OrderEventAny::Updated(_)) => Self::Submitted,
             (Self::Accepted, OrderEventAny::Rejected(_)) => Self::Rejected,  // StopLimit order
             (Self::Accepted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
             (Self::Accepted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
@@ -127,6 +130,7 @@ impl OrderStatus {
             (Self::Accepted, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::Accepted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Accepted, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Accepted, OrderEventAny::Updated(_)) => Self::Accepted,
             (Self::Canceled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
             (Self::Canceled, OrderEventAny::Filled(_)) => Self::Filled,  // Real world possibility
             (Self::PendingUpdate, OrderEventAny::Rejected(_)) => Self::Rejected,
@@ -152,12 +156,14 @@ impl OrderStatus {
             (Self::Triggered, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::Triggered, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::Triggered, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::Triggered, OrderEventAny::Updated(_)) => Self::Triggered,
             (Self::PartiallyFilled, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
             (Self::PartiallyFilled, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
             (Self::PartiallyFilled, OrderEventAny::Canceled(_)) => Self::Canceled,
             (Self::PartiallyFilled, OrderEventAny::Expired(_)) => Self::Expired,
             (Self::PartiallyFilled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
             (Self::PartiallyFilled, OrderEventAny::Filled(_)) => Self::Filled,
+            (Self::PartiallyFilled, OrderEventAny::Updated(_)) => Self::PartiallyFilled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
         Ok(new_state)
@@ -256,13 +262,11 @@ pub trait Order: 'static + Send {
     }
 
     fn is_primary(&self) -> bool {
-        // TODO: Guarantee `exec_spawn_id` is some if `exec_algorithm_id` is some
         self.exec_algorithm_id().is_some()
             && self.client_order_id() == self.exec_spawn_id().unwrap()
     }
 
     fn is_secondary(&self) -> bool {
-        // TODO: Guarantee `exec_spawn_id` is some if `exec_algorithm_id` is some
         self.exec_algorithm_id().is_some()
             && self.client_order_id() != self.exec_spawn_id().unwrap()
     }
@@ -454,7 +458,10 @@ impl OrderCore {
             parent_order_id: init.parent_order_id,
             exec_algorithm_id: init.exec_algorithm_id,
             exec_algorithm_params: init.exec_algorithm_params,
-            exec_spawn_id: init.exec_spawn_id,
+            // A primary order of an execution algorithm is its own execution spawn
+            exec_spawn_id: init
+                .exec_spawn_id
+                .or(init.exec_algorithm_id.map(|_| init.client_order_id)),
             tags: init.tags,
             filled_qty: Quantity::zero(init.quantity.precision),
             leaves_qty: init.quantity,
@@ -470,7 +477,13 @@ impl OrderCore {
         assert_eq!(self.client_order_id, event.client_order_id());
         assert_eq!(self.strategy_id, event.strategy_id());
 
-        let new_status = self.status.transition(&event)?;
+        let new_status = match (self.status, &event) {
+            // An update confirms a pending modify, so revert to the status prior to the request
+            (OrderStatus::PendingUpdate, OrderEventAny::Updated(_)) => {
+                self.previous_status.ok_or(OrderError::NoPreviousState)?
+            }
+            _ => self.status.transition(&event)?,
+        };
         self.previous_status = Some(self.status);
         self.status = new_status;
 
@@ -656,6 +669,14 @@ impl OrderCore {
     pub fn init_event(&self) -> Option<OrderEventAny> {
         self.events.first().cloned()
     }
+
+    /// Prepends the events of the `original` order ahead of this orders own events, for an
+    /// order which has been transformed from the `original`.
+    pub(crate) fn hydrate_initial_events(&mut self, original: &dyn Order) {
+        let mut events: Vec<OrderEventAny> = original.events().into_iter().cloned().collect();
+        events.append(&mut self.events);
+        self.events = events;
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -671,7 +692,8 @@ mod tests {
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
             accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            initialized::OrderInitializedBuilder, pending_update::OrderPendingUpdateBuilder,
+            submitted::OrderSubmittedBuilder, updated::OrderUpdatedBuilder,
         },
         orders::market::MarketOrder,
     };
@@ -785,4 +807,77 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    fn test_order_updated_when_accepted() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(50_000))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.quantity(), Quantity::from(50_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(50_000));
+    }
+
+    #[rstest]
+    fn test_order_updated_when_initialized() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(40_000))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.quantity(), Quantity::from(40_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(40_000));
+    }
+
+    #[rstest]
+    fn test_primary_order_exec_spawn_id_defaults_to_client_order_id() {
+        let init = OrderInitializedBuilder::default()
+            .exec_algorithm_id(Some(ExecAlgorithmId::from("TWAP")))
+            .build()
+            .unwrap();
+
+        let order: MarketOrder = init.clone().into();
+
+        assert_eq!(order.exec_spawn_id(), Some(init.client_order_id));
+        assert!(order.is_primary());
+        assert!(!order.is_spawned());
+    }
+
+    #[rstest]
+    fn test_order_updated_when_pending_update_reverts_status() {
+        let init = OrderInitializedBuilder::default().build().unwrap();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(50_000))
+            .build()
+            .unwrap();
+
+        let mut order: MarketOrder = init.into();
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order
+            .apply(OrderEventAny::PendingUpdate(pending_update))
+            .unwrap();
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.quantity(), Quantity::from(50_000));
+    }
 }
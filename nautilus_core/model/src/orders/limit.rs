@@ -146,6 +146,62 @@ impl LimitOrder {
             trigger_instrument_id,
         })
     }
+
+    /// Transforms the given `order` into a limit order (e.g. when an emulated order is released).
+    ///
+    /// The `price` overrides any existing price of the `order`. All existing events of the
+    /// `order` are retained ahead of the new `OrderInitialized` event, and the original
+    /// initialization timestamp is kept.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `price` is `None` and the `order` has no price.
+    /// - If the limit order cannot be initialized.
+    pub fn transform(
+        order: &OrderAny,
+        ts_init: UnixNanos,
+        price: Option<Price>,
+    ) -> anyhow::Result<Self> {
+        let order = order.as_order();
+        let Some(price) = price.or(order.price()) else {
+            anyhow::bail!("`order` {} has no price", order.client_order_id());
+        };
+        let mut transformed = Self::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.side(),
+            order.quantity(),
+            price,
+            order.time_in_force(),
+            order.expire_time(),
+            order.is_post_only(),
+            order.is_reduce_only(),
+            order.is_quote_quantity(),
+            order.display_qty(),
+            None,
+            None,
+            order.contingency_type(),
+            order.order_list_id(),
+            order.linked_order_ids().map(<[ClientOrderId]>::to_vec),
+            order.parent_order_id(),
+            order.exec_algorithm_id(),
+            order.exec_algorithm_params().cloned(),
+            order.exec_spawn_id(),
+            order.tags().map(<[Ustr]>::to_vec),
+            UUID4::new(),
+            ts_init,
+        )?;
+        transformed.liquidity_side = order.liquidity_side();
+
+        // Use original order initialization timestamp
+        transformed.ts_init = order.ts_init();
+        transformed.hydrate_initial_events(order);
+
+        Ok(transformed)
+    }
 }
 
 impl Deref for LimitOrder {
@@ -476,7 +532,7 @@ mod tests {
     use crate::{
         enums::{OrderSide, TimeInForce},
         instruments::{currency_pair::CurrencyPair, stubs::*},
-        orders::stubs::TestOrderStubs,
+        orders::{any::OrderAny, base::Order, limit::LimitOrder, stubs::TestOrderStubs},
         types::{price::Price, quantity::Quantity},
     };
 
@@ -524,4 +580,44 @@ mod tests {
             Some(TimeInForce::Gtd),
         );
     }
+
+    #[rstest]
+    fn test_transform_from_stop_market_order(audusd_sim: CurrencyPair) {
+        let order = OrderAny::StopMarket(TestOrderStubs::stop_market_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+            None,
+        ));
+
+        let transformed =
+            LimitOrder::transform(&order, 1.into(), Some(Price::from("1.00010"))).unwrap();
+
+        assert_eq!(
+            transformed.client_order_id(),
+            order.as_order().client_order_id()
+        );
+        assert_eq!(transformed.price, Price::from("1.00010"));
+        assert_eq!(transformed.trigger_instrument_id, None);
+        assert_eq!(transformed.ts_init(), order.as_order().ts_init());
+        assert_eq!(transformed.event_count(), 2);
+    }
+
+    #[rstest]
+    fn test_transform_without_price_returns_error(audusd_sim: CurrencyPair) {
+        let order = OrderAny::StopMarket(TestOrderStubs::stop_market_order(
+            audusd_sim.id,
+            OrderSide::Buy,
+            Price::from("1.00000"),
+            Quantity::from(100_000),
+            None,
+            None,
+            None,
+        ));
+
+        assert!(LimitOrder::transform(&order, 1.into(), None).is_err());
+    }
 }
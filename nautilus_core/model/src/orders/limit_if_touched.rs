@@ -15,6 +15,7 @@
 
 use std::{
     collections::HashMap,
+    fmt::Display,
     ops::{Deref, DerefMut},
 };
 
@@ -353,9 +354,18 @@ impl Order for LimitIfTouchedOrder {
             self.update(event);
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
+        let ts_triggered = match &event {
+            OrderEventAny::Triggered(event) => Some(event.ts_event),
+            _ => None,
+        };
 
         self.core.apply(event)?;
 
+        if ts_triggered.is_some() {
+            self.is_triggered = true;
+            self.ts_triggered = ts_triggered;
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
@@ -377,6 +387,28 @@ impl Order for LimitIfTouchedOrder {
     }
 }
 
+impl Display for LimitIfTouchedOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LimitIfTouchedOrder({} {} {} {} @ {}-TRIGGER[{}] {}-LIMIT {}, status={}, client_order_id={}, venue_order_id={}, position_id={}, tags={})",
+            self.side,
+            self.quantity.to_formatted_string(),
+            self.instrument_id,
+            self.order_type,
+            self.trigger_price,
+            self.trigger_type,
+            self.price,
+            self.time_in_force,
+            self.status,
+            self.client_order_id,
+            self.venue_order_id.map_or("None".to_string(), |venue_order_id| format!("{venue_order_id}")),
+            self.position_id.map_or("None".to_string(), |position_id| format!("{position_id}")),
+            self.tags.clone().map_or("None".to_string(), |tags| tags.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(", ")),
+        )
+    }
+}
+
 impl From<OrderInitialized> for LimitIfTouchedOrder {
     fn from(event: OrderInitialized) -> Self {
         Self::new(
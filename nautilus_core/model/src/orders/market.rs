@@ -123,6 +123,49 @@ impl MarketOrder {
             core: OrderCore::new(init_order).unwrap(),
         })
     }
+
+    /// Transforms the given `order` into a market order (e.g. when an emulated order is released).
+    ///
+    /// All existing events of the `order` are retained ahead of the new `OrderInitialized` event,
+    /// and the original initialization timestamp is kept. A `GTD` time in force becomes `GTC`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the market order cannot be initialized.
+    pub fn transform(order: &OrderAny, ts_init: UnixNanos) -> anyhow::Result<Self> {
+        let order = order.as_order();
+        let time_in_force = match order.time_in_force() {
+            TimeInForce::Gtd => TimeInForce::Gtc,
+            time_in_force => time_in_force,
+        };
+        let mut transformed = Self::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.side(),
+            order.quantity(),
+            time_in_force,
+            UUID4::new(),
+            ts_init,
+            order.is_reduce_only(),
+            order.is_quote_quantity(),
+            order.contingency_type(),
+            order.order_list_id(),
+            order.linked_order_ids().map(<[ClientOrderId]>::to_vec),
+            order.parent_order_id(),
+            order.exec_algorithm_id(),
+            order.exec_algorithm_params().cloned(),
+            order.exec_spawn_id(),
+            order.tags().map(<[Ustr]>::to_vec),
+        )?;
+
+        // Use original order initialization timestamp
+        transformed.ts_init = order.ts_init();
+        transformed.hydrate_initial_events(order);
+
+        Ok(transformed)
+    }
 }
 
 impl Deref for MarketOrder {
@@ -436,8 +479,8 @@ mod tests {
     use crate::{
         enums::{OrderSide, TimeInForce},
         instruments::{currency_pair::CurrencyPair, stubs::*},
-        orders::stubs::*,
-        types::quantity::Quantity,
+        orders::{any::OrderAny, base::Order, market::MarketOrder, stubs::*},
+        types::{price::Price, quantity::Quantity},
     };
 
     #[rstest]
@@ -463,4 +506,29 @@ mod tests {
             Some(TimeInForce::Gtd),
         );
     }
+
+    #[rstest]
+    fn test_transform_from_stop_market_order(audusd_sim: CurrencyPair) {
+        let order = OrderAny::StopMarket(TestOrderStubs::stop_market_order(
+            audusd_sim.id,
+            OrderSide::Sell,
+            Price::from("0.90000"),
+            Quantity::from(100_000),
+            None,
+            None,
+            None,
+        ));
+
+        let transformed = MarketOrder::transform(&order, 1.into()).unwrap();
+
+        assert_eq!(
+            transformed.client_order_id(),
+            order.as_order().client_order_id()
+        );
+        assert_eq!(transformed.side(), OrderSide::Sell);
+        assert_eq!(transformed.quantity(), Quantity::from(100_000));
+        assert_eq!(transformed.ts_init(), order.as_order().ts_init());
+        assert_eq!(transformed.event_count(), 2);
+        assert_eq!(transformed.events()[0], order.as_order().last_event());
+    }
 }
@@ -17,9 +17,12 @@ use std::str::FromStr;
 
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
 
-use super::{limit::LimitOrder, stop_market::StopMarketOrder};
+use super::{
+    limit::LimitOrder, stop_market::StopMarketOrder, trailing_stop_limit::TrailingStopLimitOrder,
+    trailing_stop_market::TrailingStopMarketOrder,
+};
 use crate::{
-    enums::{LiquiditySide, OrderSide, TimeInForce, TriggerType},
+    enums::{LiquiditySide, OrderSide, TimeInForce, TrailingOffsetType, TriggerType},
     events::order::filled::OrderFilled,
     identifiers::{
         client_order_id::ClientOrderId,
@@ -216,4 +219,103 @@ impl TestOrderStubs {
         )
         .unwrap()
     }
+
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_market_order(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        trigger_price: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        quantity: Quantity,
+        trigger_type: Option<TriggerType>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> TrailingStopMarketOrder {
+        let trader = trader_id();
+        let strategy = strategy_id_ema_cross();
+        let client_order_id = client_order_id.unwrap_or_default();
+        TrailingStopMarketOrder::new(
+            trader,
+            strategy,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::BidAsk),
+            trailing_offset,
+            trailing_offset_type,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn trailing_stop_limit_order(
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        price: Price,
+        trigger_price: Price,
+        limit_offset: Price,
+        trailing_offset: Price,
+        trailing_offset_type: TrailingOffsetType,
+        quantity: Quantity,
+        trigger_type: Option<TriggerType>,
+        client_order_id: Option<ClientOrderId>,
+    ) -> TrailingStopLimitOrder {
+        let trader = trader_id();
+        let strategy = strategy_id_ema_cross();
+        let client_order_id = client_order_id.unwrap_or_default();
+        TrailingStopLimitOrder::new(
+            trader,
+            strategy,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::BidAsk),
+            limit_offset,
+            trailing_offset,
+            trailing_offset_type,
+            TimeInForce::Gtc,
+            None,
+            false,
+            false,
+            false,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
 }
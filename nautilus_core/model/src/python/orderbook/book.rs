@@ -21,7 +21,7 @@ use crate::{
         delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
         quote::QuoteTick, trade::TradeTick,
     },
-    enums::{BookType, OrderSide},
+    enums::{BookType, CrossedBookPolicy, OrderSide},
     identifiers::instrument_id::InstrumentId,
     orderbook::{
         aggregation::{update_book_with_quote_tick, update_book_with_trade_tick},
@@ -90,6 +90,36 @@ impl OrderBook {
         self.count
     }
 
+    #[getter]
+    #[pyo3(name = "strict_sequence")]
+    fn py_strict_sequence(&self) -> bool {
+        self.strict_sequence
+    }
+
+    #[setter]
+    #[pyo3(name = "strict_sequence")]
+    fn py_set_strict_sequence(&mut self, value: bool) {
+        self.strict_sequence = value;
+    }
+
+    #[getter]
+    #[pyo3(name = "crossed_policy")]
+    fn py_crossed_policy(&self) -> CrossedBookPolicy {
+        self.crossed_policy
+    }
+
+    #[setter]
+    #[pyo3(name = "crossed_policy")]
+    fn py_set_crossed_policy(&mut self, value: CrossedBookPolicy) {
+        self.crossed_policy = value;
+    }
+
+    #[getter]
+    #[pyo3(name = "is_stale")]
+    fn py_is_stale(&self) -> bool {
+        self.is_stale
+    }
+
     #[pyo3(name = "reset")]
     fn py_reset(&mut self) {
         self.reset();
@@ -132,18 +162,44 @@ impl OrderBook {
     }
 
     #[pyo3(name = "apply_delta")]
-    fn py_apply_delta(&mut self, delta: OrderBookDelta) {
-        self.apply_delta(delta);
+    fn py_apply_delta(&mut self, delta: OrderBookDelta) -> PyResult<()> {
+        self.apply_delta(delta).map_err(to_pyruntime_err)
     }
 
     #[pyo3(name = "apply_deltas")]
-    fn py_apply_deltas(&mut self, deltas: OrderBookDeltas) {
-        self.apply_deltas(deltas);
+    fn py_apply_deltas(&mut self, deltas: OrderBookDeltas) -> PyResult<()> {
+        self.apply_deltas(deltas).map_err(to_pyruntime_err)
     }
 
     #[pyo3(name = "apply_depth")]
-    fn py_apply_depth(&mut self, depth: OrderBookDepth10) {
-        self.apply_depth(depth);
+    fn py_apply_depth(&mut self, depth: OrderBookDepth10) -> PyResult<()> {
+        self.apply_depth(depth).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "resync")]
+    fn py_resync(&mut self, deltas: OrderBookDeltas) -> PyResult<()> {
+        self.resync(deltas).map_err(to_pyruntime_err)
+    }
+
+    #[pyo3(name = "resync_depth")]
+    fn py_resync_depth(&mut self, depth: OrderBookDepth10) {
+        self.resync_depth(depth);
+    }
+
+    #[pyo3(name = "to_depth10")]
+    fn py_to_depth10(&self, ts_init: u64) -> OrderBookDepth10 {
+        self.to_depth10(ts_init.into())
+    }
+
+    #[pyo3(name = "to_snapshot_deltas")]
+    fn py_to_snapshot_deltas(&self, ts_init: u64) -> OrderBookDeltas {
+        self.to_snapshot_deltas(ts_init.into())
+    }
+
+    #[pyo3(name = "diff_deltas")]
+    fn py_diff_deltas(&self, previous: &Self, ts_init: u64) -> PyResult<Vec<OrderBookDelta>> {
+        self.diff_deltas(previous, ts_init.into())
+            .map_err(to_pyvalue_err)
     }
 
     #[pyo3(name = "check_integrity")]
@@ -193,6 +249,41 @@ impl OrderBook {
         self.midpoint()
     }
 
+    #[pyo3(name = "is_crossed")]
+    fn py_is_crossed(&self) -> bool {
+        self.is_crossed()
+    }
+
+    #[pyo3(name = "is_locked")]
+    fn py_is_locked(&self) -> bool {
+        self.is_locked()
+    }
+
+    #[pyo3(name = "microprice")]
+    fn py_microprice(&self) -> Option<f64> {
+        self.microprice()
+    }
+
+    #[pyo3(name = "weighted_midpoint")]
+    fn py_weighted_midpoint(&self, depth: usize) -> Option<f64> {
+        self.weighted_midpoint(depth)
+    }
+
+    #[pyo3(name = "imbalance")]
+    fn py_imbalance(&self, depth: usize) -> Option<f64> {
+        self.imbalance(depth)
+    }
+
+    #[pyo3(name = "get_vwap_for_quantity")]
+    fn py_get_vwap_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> Option<f64> {
+        self.get_vwap_for_quantity(qty, order_side)
+    }
+
+    #[pyo3(name = "get_slippage_for_notional")]
+    fn py_get_slippage_for_notional(&self, notional: f64, order_side: OrderSide) -> Option<f64> {
+        self.get_slippage_for_notional(notional, order_side)
+    }
+
     #[pyo3(name = "get_avg_px_for_quantity")]
     fn py_get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
         self.get_avg_px_for_quantity(qty, order_side)
@@ -29,8 +29,7 @@
 #![allow(warnings)] // non-local `impl` definition, temporary allow until pyo3 upgrade
 
 pub mod http;
-#[allow(dead_code)]
-mod ratelimiter;
+pub mod ratelimiter;
 pub mod socket;
 pub mod websocket;
 
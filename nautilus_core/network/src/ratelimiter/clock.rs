@@ -33,6 +33,8 @@ use std::{
     time::{Duration, Instant},
 };
 
+use nautilus_core::time::AtomicTime;
+
 use super::nanos::Nanos;
 
 /// A measurement from a clock.
@@ -164,6 +166,31 @@ impl Clock for MonotonicClock {
     }
 }
 
+/// A clock reading the time of an [`AtomicTime`].
+///
+/// Allows rate limits to follow the time of the owning component, which is the simulated
+/// time when the [`AtomicTime`] is in static mode (e.g. during a backtest).
+#[derive(Clone, Copy, Debug)]
+pub struct AtomicTimeClock {
+    time: &'static AtomicTime,
+}
+
+impl AtomicTimeClock {
+    /// Creates a new [`AtomicTimeClock`] instance reading the given `time`.
+    #[must_use]
+    pub const fn new(time: &'static AtomicTime) -> Self {
+        Self { time }
+    }
+}
+
+impl Clock for AtomicTimeClock {
+    type Instant = Nanos;
+
+    fn now(&self) -> Self::Instant {
+        self.time.get_time_ns().as_u64().into()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -141,8 +141,22 @@ where
     K: Eq + Hash,
 {
     pub fn new_with_quota(base_quota: Option<Quota>, keyed_quotas: Vec<(K, Quota)>) -> Self {
-        let clock = MonotonicClock {};
-        let start = MonotonicClock::now(&clock);
+        Self::new_with_quota_and_clock(MonotonicClock {}, base_quota, keyed_quotas)
+    }
+}
+
+impl<K, C> RateLimiter<K, C>
+where
+    K: Eq + Hash,
+    C: Clock,
+{
+    /// Creates a new [`RateLimiter`] measuring time with the given `clock`.
+    pub fn new_with_quota_and_clock(
+        clock: C,
+        base_quota: Option<Quota>,
+        keyed_quotas: Vec<(K, Quota)>,
+    ) -> Self {
+        let start = clock.now();
         let gcra = DashMap::from_iter(keyed_quotas.into_iter().map(|(k, q)| (k, Gcra::new(q))));
         Self {
             default_gcra: base_quota.map(Gcra::new),
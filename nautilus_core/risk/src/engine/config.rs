@@ -0,0 +1,50 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, num::NonZeroU32};
+
+use nautilus_model::identifiers::instrument_id::InstrumentId;
+use nautilus_network::ratelimiter::quota::Quota;
+use rust_decimal::Decimal;
+
+/// Configuration for `RiskEngine` instances.
+#[derive(Clone, Debug)]
+pub struct RiskEngineConfig {
+    /// If all pre-trade risk checks and rate limits are bypassed.
+    pub bypass: bool,
+    /// The maximum rate of submit order commands.
+    pub max_order_submit: Quota,
+    /// The maximum rate of modify order commands.
+    pub max_order_modify: Quota,
+    /// The maximum notional value of an order per instrument ID.
+    pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    /// If debug mode is active (will provide extra debug logging).
+    pub debug: bool,
+}
+
+impl Default for RiskEngineConfig {
+    /// Creates a new default [`RiskEngineConfig`] instance, limiting submit and modify
+    /// commands to 100 per second each.
+    fn default() -> Self {
+        let max_rate = Quota::per_second(NonZeroU32::new(100).unwrap());
+        Self {
+            bypass: false,
+            max_order_submit: max_rate,
+            max_order_modify: max_rate,
+            max_notional_per_order: HashMap::new(),
+            debug: false,
+        }
+    }
+}
@@ -0,0 +1,1484 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides a generic `RiskEngine` for backtesting and live environments.
+
+pub mod config;
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use log::{debug, error, info, warn};
+use nautilus_common::{
+    cache::Cache,
+    msgbus::{
+        endpoints::{EXEC_ENGINE_EXECUTE, EXEC_ENGINE_PROCESS},
+        MessageBus,
+    },
+};
+use nautilus_core::{correctness::check_predicate_true, time::AtomicTime, uuid::UUID4};
+use nautilus_execution::messages::{
+    modify::ModifyOrder, submit::SubmitOrder, submit_list::SubmitOrderList, TradingCommand,
+};
+use nautilus_model::{
+    enums::{InstrumentClass, OrderSide, OrderStatus, OrderType, PositionSide, TradingState},
+    events::order::{
+        denied::OrderDenied, event::OrderEventAny, modify_rejected::OrderModifyRejected,
+    },
+    identifiers::instrument_id::InstrumentId,
+    instruments::any::InstrumentAny,
+    orders::{any::OrderAny, list::OrderList},
+    polymorphism::{GetClientOrderId, GetInstrumentId, GetStrategyId, GetTraderId, IsClosed},
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use nautilus_network::ratelimiter::{clock::AtomicTimeClock, quota::Quota, RateLimiter};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use ustr::Ustr;
+
+use self::config::RiskEngineConfig;
+
+const ORDER_SUBMIT_THROTTLER: &str = "ORDER_SUBMIT_THROTTLER";
+const ORDER_MODIFY_THROTTLER: &str = "ORDER_MODIFY_THROTTLER";
+
+/// Provides a high-performance generic risk engine, which sits between strategies and the
+/// `ExecutionEngine`.
+///
+/// Submit and modify order commands are validated against the instrument constraints, the
+/// account free balance, the configured maximum notional per order and the current
+/// `TradingState`, and are throttled to the configured maximum rates. Orders which fail
+/// any check are denied, whereas modifications which fail are rejected.
+pub struct RiskEngine {
+    pub trading_state: TradingState,
+    pub command_count: u64,
+    pub event_count: u64,
+    clock: &'static AtomicTime,
+    msgbus: Rc<RefCell<MessageBus>>,
+    cache: Rc<RefCell<Cache>>,
+    throttler: RateLimiter<Ustr, AtomicTimeClock>,
+    max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    config: RiskEngineConfig,
+}
+
+impl RiskEngine {
+    /// Creates a new [`RiskEngine`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any configured max notional per order is not positive.
+    pub fn new(
+        clock: &'static AtomicTime,
+        msgbus: Rc<RefCell<MessageBus>>,
+        cache: Rc<RefCell<Cache>>,
+        config: RiskEngineConfig,
+    ) -> anyhow::Result<Self> {
+        let mut engine = Self {
+            trading_state: TradingState::Active, // Start active by default
+            command_count: 0,
+            event_count: 0,
+            clock,
+            msgbus,
+            cache,
+            throttler: create_throttler(clock, &config),
+            max_notional_per_order: HashMap::new(),
+            config,
+        };
+        engine.log_state();
+        info!(
+            "Set MAX_ORDER_SUBMIT_RATE: {:?}",
+            engine.config.max_order_submit
+        );
+        info!(
+            "Set MAX_ORDER_MODIFY_RATE: {:?}",
+            engine.config.max_order_modify
+        );
+
+        for (instrument_id, value) in engine.config.max_notional_per_order.clone() {
+            engine.set_max_notional_per_order(instrument_id, Some(value))?;
+        }
+        Ok(engine)
+    }
+
+    #[must_use]
+    pub fn is_bypassed(&self) -> bool {
+        self.config.bypass
+    }
+
+    /// Returns the maximum order submit rate setting.
+    #[must_use]
+    pub fn max_order_submit_rate(&self) -> Quota {
+        self.config.max_order_submit
+    }
+
+    /// Returns the maximum order modify rate setting.
+    #[must_use]
+    pub fn max_order_modify_rate(&self) -> Quota {
+        self.config.max_order_modify
+    }
+
+    /// Returns the maximum notionals per order settings.
+    #[must_use]
+    pub fn max_notionals_per_order(&self) -> &HashMap<InstrumentId, Decimal> {
+        &self.max_notional_per_order
+    }
+
+    /// Returns the maximum notional per order setting for the `instrument_id` (if any).
+    #[must_use]
+    pub fn max_notional_per_order(&self, instrument_id: &InstrumentId) -> Option<Decimal> {
+        self.max_notional_per_order.get(instrument_id).copied()
+    }
+
+    // -- COMMANDS --------------------------------------------------------------------------------
+
+    /// Sets the maximum notional value per order for the `instrument_id`.
+    ///
+    /// Passing a `new_value` of `None` will disable the max notional check for the instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `new_value` is not positive.
+    pub fn set_max_notional_per_order(
+        &mut self,
+        instrument_id: InstrumentId,
+        new_value: Option<Decimal>,
+    ) -> anyhow::Result<()> {
+        match new_value {
+            Some(value) => {
+                check_predicate_true(
+                    value.is_sign_positive() && !value.is_zero(),
+                    "`new_value` was not positive",
+                )?;
+                self.max_notional_per_order.insert(instrument_id, value);
+                info!("Set MAX_NOTIONAL_PER_ORDER: {instrument_id} {value}");
+            }
+            None => {
+                self.max_notional_per_order.remove(&instrument_id);
+                info!("Set MAX_NOTIONAL_PER_ORDER: {instrument_id} None");
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the trading state for the engine.
+    pub fn set_trading_state(&mut self, state: TradingState) {
+        if state == self.trading_state {
+            warn!("No change to trading state: already set to {state}");
+            return;
+        }
+
+        self.trading_state = state;
+        self.log_state();
+    }
+
+    /// Resets the engine counters and rate limits.
+    pub fn reset(&mut self) {
+        self.command_count = 0;
+        self.event_count = 0;
+        self.throttler = create_throttler(self.clock, &self.config);
+    }
+
+    pub fn execute(&mut self, command: TradingCommand) {
+        self.execute_command(command);
+    }
+
+    pub fn process(&mut self, event: &OrderEventAny) {
+        if self.config.debug {
+            debug!("<--[EVT] {event:?}");
+        }
+        self.event_count += 1;
+    }
+
+    // -- COMMAND HANDLERS ------------------------------------------------------------------------
+
+    fn execute_command(&mut self, command: TradingCommand) {
+        if self.config.debug {
+            debug!("<--[CMD] {command:?}");
+        }
+        self.command_count += 1;
+
+        match command {
+            TradingCommand::SubmitOrder(command) => self.handle_submit_order(command),
+            TradingCommand::SubmitOrderList(command) => self.handle_submit_order_list(command),
+            TradingCommand::ModifyOrder(command) => self.handle_modify_order(command),
+            _ => error!("Cannot handle command: {command:?}"),
+        }
+    }
+
+    fn handle_submit_order(&self, command: SubmitOrder) {
+        if self.is_bypassed() {
+            // Perform no further risk checks or throttling
+            self.send_to_execution(TradingCommand::SubmitOrder(command));
+            return;
+        }
+
+        let order = &command.order;
+
+        // Check reduce only
+        if let Some(position_id) = command.position_id {
+            if order.as_order().is_reduce_only() {
+                let would_reduce = self
+                    .cache
+                    .borrow()
+                    .position(&position_id)
+                    .is_some_and(|position| would_reduce_only(order, position));
+                if !would_reduce {
+                    self.deny_order(
+                        order,
+                        &format!("Reduce only order would increase position {position_id}"),
+                    );
+                    return; // Denied
+                }
+            }
+        }
+
+        // Get instrument for order
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id())
+            .cloned()
+        else {
+            self.deny_order(
+                order,
+                &format!("Instrument for {} not found", command.instrument_id),
+            );
+            return; // Denied
+        };
+
+        ////////////////////////////////////////////////////////////////////////////////
+        // PRE-TRADE ORDER(S) CHECKS
+        ////////////////////////////////////////////////////////////////////////////////
+        if !self.check_order(&instrument, order) {
+            return; // Denied
+        }
+
+        if !self.check_orders_risk(&instrument, std::slice::from_ref(order)) {
+            return; // Denied
+        }
+
+        self.execution_gateway(&instrument, TradingCommand::SubmitOrder(command));
+    }
+
+    fn handle_submit_order_list(&self, command: SubmitOrderList) {
+        if self.is_bypassed() {
+            // Perform no further risk checks or throttling
+            self.send_to_execution(TradingCommand::SubmitOrderList(command));
+            return;
+        }
+
+        // Get instrument for orders
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&command.instrument_id)
+            .cloned()
+        else {
+            self.deny_order_list(
+                &command.order_list,
+                &format!("no instrument found for {}", command.instrument_id),
+            );
+            return; // Denied
+        };
+
+        ////////////////////////////////////////////////////////////////////////////////
+        // PRE-TRADE ORDER(S) CHECKS
+        ////////////////////////////////////////////////////////////////////////////////
+        for order in &command.order_list.orders {
+            if !self.check_order(&instrument, order) {
+                return; // Denied
+            }
+        }
+
+        if !self.check_orders_risk(&instrument, &command.order_list.orders) {
+            // Deny all orders in list
+            self.deny_order_list(
+                &command.order_list,
+                &format!("OrderList {} DENIED", command.order_list.id),
+            );
+            return; // Denied
+        }
+
+        self.execution_gateway(&instrument, TradingCommand::SubmitOrderList(command));
+    }
+
+    fn handle_modify_order(&self, command: ModifyOrder) {
+        ////////////////////////////////////////////////////////////////////////////////
+        // VALIDATE COMMAND
+        ////////////////////////////////////////////////////////////////////////////////
+        let Some(order) = self.cache.borrow().order(&command.client_order_id).cloned() else {
+            error!(
+                "ModifyOrder DENIED: Order with {} not found",
+                command.client_order_id
+            );
+            return; // Denied
+        };
+
+        if order.is_closed() {
+            self.reject_modify_order(
+                &order,
+                &format!("Order with {} already closed", command.client_order_id),
+            );
+            return; // Denied
+        } else if order.as_order().is_pending_cancel() {
+            self.reject_modify_order(
+                &order,
+                &format!(
+                    "Order with {} already pending cancel",
+                    command.client_order_id
+                ),
+            );
+            return; // Denied
+        }
+
+        // Get instrument for orders
+        let Some(instrument) = self
+            .cache
+            .borrow()
+            .instrument(&command.instrument_id)
+            .cloned()
+        else {
+            self.reject_modify_order(
+                &order,
+                &format!("no instrument found for {}", command.instrument_id),
+            );
+            return; // Denied
+        };
+
+        // Check price
+        if let Some(reason) = check_price(&instrument, command.price) {
+            self.reject_modify_order(&order, &reason);
+            return; // Denied
+        }
+
+        // Check trigger
+        if let Some(reason) = check_price(&instrument, command.trigger_price) {
+            self.reject_modify_order(&order, &format!("trigger {reason}"));
+            return; // Denied
+        }
+
+        // Check quantity
+        if let Some(reason) = check_quantity(&instrument, command.quantity) {
+            self.reject_modify_order(&order, &reason);
+            return; // Denied
+        }
+
+        // Check TradingState
+        match self.trading_state {
+            TradingState::Halted => {
+                self.reject_modify_order(&order, "TradingState is HALTED");
+                return; // Denied
+            }
+            TradingState::Reducing => {
+                if command
+                    .quantity
+                    .is_some_and(|quantity| quantity > order.as_order().quantity())
+                    && self.would_increase_exposure(&order, &instrument.id())
+                {
+                    self.reject_modify_order(
+                        &order,
+                        "TradingState is REDUCING and update will increase exposure",
+                    );
+                    return; // Denied
+                }
+            }
+            TradingState::Active => {}
+        }
+
+        if self
+            .throttler
+            .check_key(&Ustr::from(ORDER_MODIFY_THROTTLER))
+            .is_err()
+        {
+            self.reject_modify_order(&order, "Exceeded MAX_ORDER_MODIFY_RATE");
+            return; // Denied
+        }
+
+        self.send_to_execution(TradingCommand::ModifyOrder(command));
+    }
+
+    // -- PRE-TRADE CHECKS ------------------------------------------------------------------------
+
+    fn check_order(&self, instrument: &InstrumentAny, order: &OrderAny) -> bool {
+        ////////////////////////////////////////////////////////////////////////////////
+        // VALIDATION CHECKS
+        ////////////////////////////////////////////////////////////////////////////////
+        if let Some(reason) = check_price(instrument, order.as_order().price()) {
+            self.deny_order(order, &reason);
+            return false; // Denied
+        }
+
+        if let Some(reason) = check_price(instrument, order.as_order().trigger_price()) {
+            self.deny_order(order, &format!("trigger {reason}"));
+            return false; // Denied
+        }
+
+        if let Some(reason) = check_quantity(instrument, Some(order.as_order().quantity())) {
+            self.deny_order(order, &reason);
+            return false; // Denied
+        }
+
+        true // Passed
+    }
+
+    fn check_orders_risk(&self, instrument: &InstrumentAny, orders: &[OrderAny]) -> bool {
+        ////////////////////////////////////////////////////////////////////////////////
+        // RISK CHECKS
+        ////////////////////////////////////////////////////////////////////////////////
+        let quote_currency = instrument.quote_currency();
+
+        // Determine max notional
+        let max_notional = self
+            .max_notional_per_order(&instrument.id())
+            .and_then(|value| Money::new(value.to_f64()?, quote_currency).ok());
+
+        // Get account balances for risk checks (only cash accounts are checked)
+        let balances = {
+            let cache = self.cache.borrow();
+            cache
+                .account_for_venue(&instrument.id().venue)
+                .filter(|account| account.is_cash_account())
+                .map(|account| AccountBalances {
+                    base_currency: account.base_currency(),
+                    free: account.balance_free(Some(quote_currency)),
+                    free_base: instrument
+                        .base_currency()
+                        .and_then(|currency| account.balance_free(Some(currency))),
+                })
+        };
+        if balances.is_none() {
+            debug!(
+                "No cash account for venue {} to check balances",
+                instrument.id().venue
+            );
+        }
+
+        let free = balances.as_ref().and_then(|balances| balances.free);
+        if self.config.debug {
+            debug!("Free: {free:?}");
+        }
+
+        let mut cum_notional_buy: Option<Money> = None;
+        let mut cum_notional_sell: Option<Money> = None;
+
+        for order in orders {
+            let Some(last_px) = self.last_px_for_order(instrument, order) else {
+                continue; // Cannot check order risk
+            };
+
+            let quantity = order.as_order().quantity();
+            let notional = instrument.calculate_notional_value(quantity, last_px, Some(true));
+            if self.config.debug {
+                debug!("Notional: {notional:?}");
+            }
+
+            // Check MAX notional per order limit
+            if let Some(max_notional) = max_notional {
+                if notional > max_notional {
+                    self.deny_order(
+                        order,
+                        &format!("NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional={max_notional}, notional={notional}"),
+                    );
+                    return false; // Denied
+                }
+            }
+
+            // Check MIN notional instrument limit
+            if let Some(min_notional) = instrument.min_notional() {
+                if min_notional.currency == notional.currency && notional < min_notional {
+                    self.deny_order(
+                        order,
+                        &format!("NOTIONAL_LESS_THAN_MIN_FOR_INSTRUMENT: min_notional={min_notional}, notional={notional}"),
+                    );
+                    return false; // Denied
+                }
+            }
+
+            // Check MAX notional instrument limit
+            if let Some(max_notional) = instrument.max_notional() {
+                if max_notional.currency == notional.currency && notional > max_notional {
+                    self.deny_order(
+                        order,
+                        &format!("NOTIONAL_GREATER_THAN_MAX_FOR_INSTRUMENT: max_notional={max_notional}, notional={notional}"),
+                    );
+                    return false; // Denied
+                }
+            }
+
+            let Some(balances) = &balances else {
+                continue; // No balances to check
+            };
+
+            let order_balance_impact = match order.as_order().side() {
+                OrderSide::Sell => notional,
+                _ => -notional,
+            };
+            if self.config.debug {
+                debug!("Balance impact: {order_balance_impact:?}");
+            }
+
+            if let Some(free) = free {
+                if free.raw + order_balance_impact.raw < 0 {
+                    self.deny_order(
+                        order,
+                        &format!("NOTIONAL_EXCEEDS_FREE_BALANCE: free={free}, notional={order_balance_impact}"),
+                    );
+                    return false; // Denied
+                }
+            }
+
+            if order.as_order().is_buy() {
+                let cum_notional = cum_notional_buy.map_or(notional, |cum| cum + notional);
+                cum_notional_buy = Some(cum_notional);
+                if self.config.debug {
+                    debug!("Cumulative notional BUY: {cum_notional:?}");
+                }
+
+                if let Some(free) = free {
+                    if cum_notional > free {
+                        self.deny_order(
+                            order,
+                            &format!("CUM_NOTIONAL_EXCEEDS_FREE_BALANCE: free={free}, cum_notional={cum_notional}"),
+                        );
+                        return false; // Denied
+                    }
+                }
+            } else if order.as_order().is_sell() {
+                // Selling requires the base currency for multi-currency cash accounts
+                let (value, free) = match (balances.base_currency, instrument.base_currency()) {
+                    (None, Some(base_currency)) => (
+                        Money::new(quantity.as_f64(), base_currency).ok(),
+                        balances.free_base,
+                    ),
+                    (Some(_), _) => (Some(notional), free),
+                    (None, None) => (None, None),
+                };
+                let Some(value) = value else {
+                    continue;
+                };
+
+                let cum_notional = cum_notional_sell.map_or(value, |cum| cum + value);
+                cum_notional_sell = Some(cum_notional);
+                if self.config.debug {
+                    debug!("Cumulative notional SELL: {cum_notional:?}");
+                }
+
+                if let Some(free) = free {
+                    if cum_notional > free {
+                        self.deny_order(
+                            order,
+                            &format!("CUM_NOTIONAL_EXCEEDS_FREE_BALANCE: free={free}, cum_notional={cum_notional}"),
+                        );
+                        return false; // Denied
+                    }
+                }
+            }
+        }
+
+        true // Passed
+    }
+
+    /// Returns the price at which the `order` is expected to execute for risk purposes.
+    fn last_px_for_order(&self, instrument: &InstrumentAny, order: &OrderAny) -> Option<Price> {
+        match order.as_order().order_type() {
+            OrderType::Market | OrderType::MarketToLimit => {
+                let cache = self.cache.borrow();
+                // Determine entry price
+                if let Some(quote) = cache.quote_tick(&instrument.id()) {
+                    return match order.as_order().side() {
+                        OrderSide::Buy => Some(quote.ask_price),
+                        _ => Some(quote.bid_price),
+                    };
+                }
+                if let Some(trade) = cache.trade_tick(&instrument.id()) {
+                    return Some(trade.price);
+                }
+                warn!(
+                    "Cannot check MARKET order risk: no prices for {}",
+                    instrument.id()
+                );
+                None
+            }
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit => {
+                let trigger_price = order.as_order().trigger_price();
+                if trigger_price.is_none() {
+                    warn!(
+                        "Cannot check {} order risk: no trigger price was set",
+                        order.as_order().order_type()
+                    );
+                }
+                trigger_price
+            }
+            OrderType::StopMarket | OrderType::MarketIfTouched => order.as_order().trigger_price(),
+            _ => order.as_order().price(),
+        }
+    }
+
+    // -- DENIALS ---------------------------------------------------------------------------------
+
+    fn deny_command(&self, command: &TradingCommand, reason: &str) {
+        match command {
+            TradingCommand::SubmitOrder(command) => self.deny_order(&command.order, reason),
+            TradingCommand::SubmitOrderList(command) => {
+                self.deny_order_list(&command.order_list, reason);
+            }
+            _ => error!("Cannot deny command {command:?}"),
+        }
+    }
+
+    fn deny_order(&self, order: &OrderAny, reason: &str) {
+        warn!(
+            "SubmitOrder for {} DENIED: {reason}",
+            order.client_order_id()
+        );
+
+        let cached_status = self
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .map(|cached_order| cached_order.as_order().status());
+        if cached_status.unwrap_or(order.as_order().status()) != OrderStatus::Initialized {
+            // Already denied or duplicated (INITIALIZED -> DENIED only valid state transition)
+            return;
+        }
+
+        if cached_status.is_none() {
+            if let Err(e) = self
+                .cache
+                .borrow_mut()
+                .add_order(order.clone(), None, None, false)
+            {
+                error!("Error adding order to cache: {e}");
+            }
+        }
+
+        let ts_now = self.clock.get_time_ns();
+        let denied = OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+        )
+        .unwrap();
+        self.msgbus
+            .borrow()
+            .send(EXEC_ENGINE_PROCESS, &OrderEventAny::Denied(denied));
+    }
+
+    fn deny_order_list(&self, order_list: &OrderList, reason: &str) {
+        for order in &order_list.orders {
+            if !order.is_closed() {
+                self.deny_order(order, reason);
+            }
+        }
+    }
+
+    fn reject_modify_order(&self, order: &OrderAny, reason: &str) {
+        let ts_now = self.clock.get_time_ns();
+        let rejected = OrderModifyRejected::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            Ustr::from(reason),
+            UUID4::new(),
+            ts_now,
+            ts_now,
+            false,
+            order.as_order().venue_order_id(),
+            order.as_order().account_id(),
+        )
+        .unwrap();
+        self.msgbus.borrow().send(
+            EXEC_ENGINE_PROCESS,
+            &OrderEventAny::ModifyRejected(rejected),
+        );
+    }
+
+    // -- EGRESS ----------------------------------------------------------------------------------
+
+    fn execution_gateway(&self, instrument: &InstrumentAny, command: TradingCommand) {
+        // Check TradingState
+        match self.trading_state {
+            TradingState::Halted => {
+                self.deny_command(&command, "TradingState is HALTED");
+                return; // Denied
+            }
+            TradingState::Reducing => {
+                let orders = match &command {
+                    TradingCommand::SubmitOrder(command) => std::slice::from_ref(&command.order),
+                    TradingCommand::SubmitOrderList(command) => &command.order_list.orders[..],
+                    _ => &[],
+                };
+                for order in orders {
+                    if self.would_increase_exposure(order, &instrument.id()) {
+                        let side = order.as_order().side();
+                        let position_side = match side {
+                            OrderSide::Buy => PositionSide::Long,
+                            _ => PositionSide::Short,
+                        };
+                        self.deny_command(
+                            &command,
+                            &format!(
+                                "{side} when TradingState is REDUCING and {position_side} {}",
+                                instrument.id()
+                            ),
+                        );
+                        return; // Denied
+                    }
+                }
+            }
+            TradingState::Active => {}
+        }
+
+        // All checks passed: check the order submit rate limit
+        if self
+            .throttler
+            .check_key(&Ustr::from(ORDER_SUBMIT_THROTTLER))
+            .is_err()
+        {
+            self.deny_command(&command, "Exceeded MAX_ORDER_SUBMIT_RATE");
+            return; // Denied
+        }
+
+        self.send_to_execution(command);
+    }
+
+    fn send_to_execution(&self, command: TradingCommand) {
+        self.msgbus.borrow().send(EXEC_ENGINE_EXECUTE, &command);
+    }
+
+    // -- INTERNAL --------------------------------------------------------------------------------
+
+    fn log_state(&self) {
+        info!("TradingState is {}", self.trading_state);
+
+        if self.is_bypassed() {
+            warn!("PRE-TRADE RISK CHECKS BYPASSED. This is not advisable for live trading");
+        }
+    }
+
+    /// Returns whether the `order` would increase the net exposure for the `instrument_id`,
+    /// i.e. a BUY when net long or a SELL when net short.
+    fn would_increase_exposure(&self, order: &OrderAny, instrument_id: &InstrumentId) -> bool {
+        let net_qty: f64 = self
+            .cache
+            .borrow()
+            .positions_open(None, Some(instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum();
+
+        match order.as_order().side() {
+            OrderSide::Buy => net_qty > 0.0,
+            OrderSide::Sell => net_qty < 0.0,
+            _ => false,
+        }
+    }
+}
+
+/// The free balances of a cash account relevant to the risk checks for an instrument.
+struct AccountBalances {
+    base_currency: Option<Currency>,
+    free: Option<Money>,
+    free_base: Option<Money>,
+}
+
+/// Creates the order command throttler, driven by the engine `clock` so that rates follow
+/// simulated time when backtesting.
+fn create_throttler(
+    clock: &'static AtomicTime,
+    config: &RiskEngineConfig,
+) -> RateLimiter<Ustr, AtomicTimeClock> {
+    RateLimiter::new_with_quota_and_clock(
+        AtomicTimeClock::new(clock),
+        None,
+        vec![
+            (Ustr::from(ORDER_SUBMIT_THROTTLER), config.max_order_submit),
+            (Ustr::from(ORDER_MODIFY_THROTTLER), config.max_order_modify),
+        ],
+    )
+}
+
+/// Returns a reason if the `price` is invalid for the `instrument`, otherwise `None`.
+fn check_price(instrument: &InstrumentAny, price: Option<Price>) -> Option<String> {
+    // Nothing to check
+    let price = price?;
+
+    if price.precision > instrument.price_precision() {
+        return Some(format!(
+            "price {price} invalid (precision {} > {})",
+            price.precision,
+            instrument.price_precision()
+        ));
+    }
+
+    if instrument.instrument_class() != InstrumentClass::Option && price.raw <= 0 {
+        return Some(format!("price {price} invalid (not positive)"));
+    }
+
+    let price_increment = instrument.price_increment();
+    if price_increment.raw > 0 && price.raw % price_increment.raw != 0 {
+        return Some(format!(
+            "price {price} invalid (not a multiple of price increment {price_increment})"
+        ));
+    }
+
+    if let Some(max_price) = instrument.max_price() {
+        if price > max_price {
+            return Some(format!(
+                "price {price} invalid (> maximum price of {max_price})"
+            ));
+        }
+    }
+
+    if let Some(min_price) = instrument.min_price() {
+        if price < min_price {
+            return Some(format!(
+                "price {price} invalid (< minimum price of {min_price})"
+            ));
+        }
+    }
+
+    None
+}
+
+/// Returns a reason if the `quantity` is invalid for the `instrument`, otherwise `None`.
+fn check_quantity(instrument: &InstrumentAny, quantity: Option<Quantity>) -> Option<String> {
+    // Nothing to check
+    let quantity = quantity?;
+
+    if quantity.precision > instrument.size_precision() {
+        return Some(format!(
+            "quantity {quantity} invalid (precision {} > {})",
+            quantity.precision,
+            instrument.size_precision()
+        ));
+    }
+
+    let size_increment = instrument.size_increment();
+    if size_increment.raw > 0 && quantity.raw % size_increment.raw != 0 {
+        return Some(format!(
+            "quantity {quantity} invalid (not a multiple of size increment {size_increment})"
+        ));
+    }
+
+    if let Some(max_quantity) = instrument.max_quantity() {
+        if quantity > max_quantity {
+            return Some(format!(
+                "quantity {quantity} invalid (> maximum trade size of {max_quantity})"
+            ));
+        }
+    }
+
+    if let Some(min_quantity) = instrument.min_quantity() {
+        if quantity < min_quantity {
+            return Some(format!(
+                "quantity {quantity} invalid (< minimum trade size of {min_quantity})"
+            ));
+        }
+    }
+
+    None
+}
+
+/// Returns whether the `order` would only reduce the `position`.
+fn would_reduce_only(order: &OrderAny, position: &Position) -> bool {
+    let leaves_qty = order.as_order().leaves_qty();
+    match (order.as_order().side(), position.side) {
+        (OrderSide::Buy, PositionSide::Short) | (OrderSide::Sell, PositionSide::Long) => {
+            leaves_qty <= position.quantity
+        }
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::RefCell, num::NonZeroU32, rc::Rc};
+
+    use nautilus_accounting::account::cash::CashAccount;
+    use nautilus_common::{cache::Cache, handlers::AnyMessageHandler, msgbus::MessageBus};
+    use nautilus_core::{nanos::UnixNanos, time::AtomicTime, uuid::UUID4};
+    use nautilus_execution::messages::{
+        modify::ModifyOrder, submit::SubmitOrder, submit_list::SubmitOrderList, TradingCommand,
+    };
+    use nautilus_model::{
+        enums::{OmsType, OrderSide, TradingState},
+        events::{account::stubs::cash_account_state_million_usd, order::event::OrderEventAny},
+        identifiers::{
+            client_id::ClientId,
+            client_order_id::ClientOrderId,
+            order_list_id::OrderListId,
+            position_id::PositionId,
+            stubs::{strategy_id_ema_cross, trader_id},
+            venue_order_id::VenueOrderId,
+        },
+        instruments::{any::InstrumentAny, stubs::audusd_sim},
+        orders::{
+            any::OrderAny,
+            list::OrderList,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+        polymorphism::{GetClientOrderId, GetInstrumentId},
+        position::Position,
+        types::{price::Price, quantity::Quantity},
+    };
+    use nautilus_network::ratelimiter::quota::Quota;
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+    use ustr::Ustr;
+
+    use super::{config::RiskEngineConfig, RiskEngine, EXEC_ENGINE_EXECUTE, EXEC_ENGINE_PROCESS};
+
+    type Messages<T> = Rc<RefCell<Vec<T>>>;
+
+    struct TestContext {
+        clock: &'static AtomicTime,
+        cache: Rc<RefCell<Cache>>,
+        msgbus: Rc<RefCell<MessageBus>>,
+        commands: Messages<TradingCommand>,
+        events: Messages<OrderEventAny>,
+    }
+
+    impl TestContext {
+        fn engine(&self, config: RiskEngineConfig) -> RiskEngine {
+            RiskEngine::new(self.clock, self.msgbus.clone(), self.cache.clone(), config).unwrap()
+        }
+
+        fn denied_reasons(&self) -> Vec<Ustr> {
+            self.events
+                .borrow()
+                .iter()
+                .filter_map(|event| match event {
+                    OrderEventAny::Denied(denied) => Some(denied.reason),
+                    _ => None,
+                })
+                .collect()
+        }
+    }
+
+    fn register<T: Clone + 'static>(
+        msgbus: &Rc<RefCell<MessageBus>>,
+        endpoint: &str,
+    ) -> Messages<T> {
+        let messages: Messages<T> = Rc::new(RefCell::new(Vec::new()));
+        let messages_clone = messages.clone();
+        let handler = AnyMessageHandler::new(Ustr::from(endpoint), move |msg: &dyn Any| {
+            messages_clone
+                .borrow_mut()
+                .push(msg.downcast_ref::<T>().unwrap().clone());
+        });
+        msgbus.borrow_mut().register_any(endpoint, handler);
+        messages
+    }
+
+    #[fixture]
+    fn context() -> TestContext {
+        let clock = Box::leak(Box::new(AtomicTime::new(false, UnixNanos::default())));
+        let msgbus = Rc::new(RefCell::new(
+            MessageBus::new(trader_id(), UUID4::new(), None, None).unwrap(),
+        ));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        cache
+            .borrow_mut()
+            .add_instrument(InstrumentAny::CurrencyPair(audusd_sim()))
+            .unwrap();
+
+        let commands = register(&msgbus, EXEC_ENGINE_EXECUTE);
+        let events = register(&msgbus, EXEC_ENGINE_PROCESS);
+
+        TestContext {
+            clock,
+            cache,
+            msgbus,
+            commands,
+            events,
+        }
+    }
+
+    fn limit_order(side: OrderSide, price: &str, quantity: &str, id: &str) -> OrderAny {
+        OrderAny::Limit(TestOrderStubs::limit_order(
+            audusd_sim().id,
+            side,
+            Price::from(price),
+            Quantity::from(quantity),
+            Some(ClientOrderId::from(id)),
+            None,
+        ))
+    }
+
+    fn submit(order: OrderAny, position_id: Option<PositionId>) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                trader_id(),
+                ClientId::from("SIM"),
+                strategy_id_ema_cross(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                order,
+                None,
+                position_id,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn modify(
+        order: &OrderAny,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+    ) -> TradingCommand {
+        TradingCommand::ModifyOrder(
+            ModifyOrder::new(
+                trader_id(),
+                ClientId::from("SIM"),
+                strategy_id_ema_cross(),
+                order.instrument_id(),
+                order.client_order_id(),
+                VenueOrderId::default(),
+                quantity,
+                price,
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn add_long_position(context: &TestContext, quantity: &str) -> PositionId {
+        let OrderAny::Market(order) = OrderAny::Market(TestOrderStubs::market_order(
+            audusd_sim().id,
+            OrderSide::Buy,
+            Quantity::from(quantity),
+            Some(ClientOrderId::from("O-POS")),
+            None,
+        )) else {
+            unreachable!()
+        };
+        let position_id = PositionId::from("P-001");
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &audusd_sim(),
+            None,
+            None,
+            Some(position_id),
+            Some(Price::from("0.80000")),
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(audusd_sim(), fill).unwrap();
+        context
+            .cache
+            .borrow_mut()
+            .add_position(position, OmsType::Netting)
+            .unwrap();
+        position_id
+    }
+
+    #[rstest]
+    fn test_new_with_non_positive_max_notional_returns_error(context: TestContext) {
+        let mut config = RiskEngineConfig::default();
+        config
+            .max_notional_per_order
+            .insert(audusd_sim().id, dec!(0));
+
+        let result = RiskEngine::new(context.clock, context.msgbus, context.cache, config);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_set_max_notional_per_order(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+        let instrument_id = audusd_sim().id;
+
+        engine
+            .set_max_notional_per_order(instrument_id, Some(dec!(1_000_000)))
+            .unwrap();
+        assert_eq!(
+            engine.max_notional_per_order(&instrument_id),
+            Some(dec!(1_000_000))
+        );
+
+        engine
+            .set_max_notional_per_order(instrument_id, None)
+            .unwrap();
+        assert!(engine.max_notionals_per_order().is_empty());
+    }
+
+    #[rstest]
+    fn test_submit_order_when_checks_pass_sends_to_execution(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-1"),
+            None,
+        ));
+
+        assert_eq!(engine.command_count, 1);
+        assert_eq!(context.commands.borrow().len(), 1);
+        assert!(context.events.borrow().is_empty());
+    }
+
+    #[rstest]
+    fn test_submit_order_when_bypassed_sends_invalid_order_to_execution(context: TestContext) {
+        let config = RiskEngineConfig {
+            bypass: true,
+            ..Default::default()
+        };
+        let mut engine = context.engine(config);
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.800001", "100000", "O-1"),
+            None,
+        ));
+
+        assert_eq!(context.commands.borrow().len(), 1);
+        assert!(context.events.borrow().is_empty());
+    }
+
+    #[rstest]
+    #[case("0.800001", "100000", "price 0.800001 invalid (precision 6 > 5)")]
+    #[case(
+        "0.80001",
+        "100000",
+        "price 0.80001 invalid (not a multiple of price increment 0.10000)"
+    )]
+    #[case("0.80000", "100000.5", "quantity 100000.5 invalid (precision 1 > 0)")]
+    #[case(
+        "0.80000",
+        "2000000",
+        "quantity 2000000 invalid (> maximum trade size of 1000000)"
+    )]
+    #[case("0.80000", "10", "quantity 10 invalid (< minimum trade size of 100)")]
+    fn test_submit_order_with_invalid_order_is_denied(
+        context: TestContext,
+        #[case] price: &str,
+        #[case] quantity: &str,
+        #[case] expected: &str,
+    ) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, price, quantity, "O-1"),
+            None,
+        ));
+
+        assert!(context.commands.borrow().is_empty());
+        assert_eq!(context.denied_reasons(), vec![Ustr::from(expected)]);
+        assert!(context
+            .cache
+            .borrow()
+            .order_exists(&ClientOrderId::from("O-1")));
+    }
+
+    #[rstest]
+    fn test_submit_reduce_only_order_without_position_is_denied(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+        let OrderAny::Limit(mut order) = limit_order(OrderSide::Sell, "0.80000", "100000", "O-1")
+        else {
+            unreachable!()
+        };
+        order.is_reduce_only = true;
+
+        engine.execute(submit(
+            OrderAny::Limit(order),
+            Some(PositionId::from("P-001")),
+        ));
+
+        assert!(context.commands.borrow().is_empty());
+        assert_eq!(
+            context.denied_reasons(),
+            vec![Ustr::from(
+                "Reduce only order would increase position P-001"
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_reduce_only_order_reducing_position_sends_to_execution(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+        let position_id = add_long_position(&context, "100000");
+        let OrderAny::Limit(mut order) = limit_order(OrderSide::Sell, "0.80000", "100000", "O-1")
+        else {
+            unreachable!()
+        };
+        order.is_reduce_only = true;
+
+        engine.execute(submit(OrderAny::Limit(order), Some(position_id)));
+
+        assert_eq!(context.commands.borrow().len(), 1);
+    }
+
+    #[rstest]
+    fn test_submit_order_exceeding_max_notional_per_order_is_denied(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+        engine
+            .set_max_notional_per_order(audusd_sim().id, Some(dec!(50_000)))
+            .unwrap();
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-1"),
+            None,
+        ));
+
+        assert!(context.commands.borrow().is_empty());
+        assert_eq!(
+            context.denied_reasons(),
+            vec![Ustr::from(
+                "NOTIONAL_EXCEEDS_MAX_PER_ORDER: max_notional=50000.00 USD, notional=80000.00 USD"
+            )]
+        );
+    }
+
+    #[rstest]
+    #[case("1000000", true)]
+    #[case("1000", false)]
+    fn test_submit_order_checks_free_balance(
+        context: TestContext,
+        #[case] quantity: &str,
+        #[case] expected_denied: bool,
+    ) {
+        let account = CashAccount::new(cash_account_state_million_usd(), true).unwrap();
+        context
+            .cache
+            .borrow_mut()
+            .add_account(Box::new(account))
+            .unwrap();
+        let mut engine = context.engine(RiskEngineConfig::default());
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "1.20000", quantity, "O-1"),
+            None,
+        ));
+
+        assert_eq!(context.commands.borrow().is_empty(), expected_denied);
+        assert_eq!(!context.denied_reasons().is_empty(), expected_denied);
+    }
+
+    #[rstest]
+    fn test_submit_order_list_with_cumulative_notional_exceeding_free_balance_denies_all(
+        context: TestContext,
+    ) {
+        let account = CashAccount::new(cash_account_state_million_usd(), true).unwrap();
+        context
+            .cache
+            .borrow_mut()
+            .add_account(Box::new(account))
+            .unwrap();
+        let mut engine = context.engine(RiskEngineConfig::default());
+        let order_list = OrderList::new(
+            OrderListId::from("OL-1"),
+            audusd_sim().id,
+            strategy_id_ema_cross(),
+            vec![
+                limit_order(OrderSide::Buy, "0.80000", "1000000", "O-1"),
+                limit_order(OrderSide::Buy, "0.80000", "1000000", "O-2"),
+            ],
+            UnixNanos::default(),
+        )
+        .unwrap();
+        let command = SubmitOrderList::new(
+            trader_id(),
+            ClientId::from("SIM"),
+            strategy_id_ema_cross(),
+            audusd_sim().id,
+            ClientOrderId::from("O-1"),
+            VenueOrderId::default(),
+            order_list,
+            None,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        engine.execute(TradingCommand::SubmitOrderList(command));
+
+        assert!(context.commands.borrow().is_empty());
+        let reasons = context.denied_reasons();
+        assert_eq!(reasons.len(), 3);
+        assert!(reasons[0].starts_with("CUM_NOTIONAL_EXCEEDS_FREE_BALANCE"));
+        assert_eq!(reasons[2], Ustr::from("OrderList OL-1 DENIED"));
+    }
+
+    #[rstest]
+    fn test_submit_order_when_halted_is_denied(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+        engine.set_trading_state(TradingState::Halted);
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-1"),
+            None,
+        ));
+
+        assert!(context.commands.borrow().is_empty());
+        assert_eq!(
+            context.denied_reasons(),
+            vec![Ustr::from("TradingState is HALTED")]
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, true)]
+    #[case(OrderSide::Sell, false)]
+    fn test_submit_order_when_reducing(
+        context: TestContext,
+        #[case] side: OrderSide,
+        #[case] expected_denied: bool,
+    ) {
+        add_long_position(&context, "100000");
+        let mut engine = context.engine(RiskEngineConfig::default());
+        engine.set_trading_state(TradingState::Reducing);
+
+        engine.execute(submit(limit_order(side, "0.80000", "100000", "O-1"), None));
+
+        assert_eq!(context.commands.borrow().is_empty(), expected_denied);
+        assert_eq!(!context.denied_reasons().is_empty(), expected_denied);
+    }
+
+    #[rstest]
+    fn test_submit_order_exceeding_max_submit_rate_is_denied(context: TestContext) {
+        let config = RiskEngineConfig {
+            max_order_submit: Quota::per_hour(NonZeroU32::new(1).unwrap()),
+            ..Default::default()
+        };
+        let mut engine = context.engine(config);
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-1"),
+            None,
+        ));
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-2"),
+            None,
+        ));
+
+        assert_eq!(context.commands.borrow().len(), 1);
+        assert_eq!(
+            context.denied_reasons(),
+            vec![Ustr::from("Exceeded MAX_ORDER_SUBMIT_RATE")]
+        );
+    }
+
+    #[rstest]
+    fn test_submit_order_rate_refills_with_clock_time(context: TestContext) {
+        let config = RiskEngineConfig {
+            max_order_submit: Quota::per_second(NonZeroU32::new(1).unwrap()),
+            ..Default::default()
+        };
+        let mut engine = context.engine(config);
+
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-1"),
+            None,
+        ));
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-2"),
+            None,
+        ));
+        context.clock.set_time(UnixNanos::from(1_000_000_000));
+        engine.execute(submit(
+            limit_order(OrderSide::Buy, "0.80000", "100000", "O-3"),
+            None,
+        ));
+
+        assert_eq!(context.commands.borrow().len(), 2);
+        assert_eq!(
+            context.denied_reasons(),
+            vec![Ustr::from("Exceeded MAX_ORDER_SUBMIT_RATE")]
+        );
+    }
+
+    #[rstest]
+    fn test_modify_order_for_unknown_order_does_nothing(context: TestContext) {
+        let mut engine = context.engine(RiskEngineConfig::default());
+        let order = limit_order(OrderSide::Buy, "0.80000", "100000", "O-1");
+
+        engine.execute(modify(&order, Some(Quantity::from(200_000)), None));
+
+        assert!(context.commands.borrow().is_empty());
+        assert!(context.events.borrow().is_empty());
+    }
+
+    #[rstest]
+    #[case(
+        TradingState::Active,
+        None,
+        Some("price 0.800001 invalid (precision 6 > 5)")
+    )]
+    #[case(TradingState::Halted, None, Some("TradingState is HALTED"))]
+    #[case(TradingState::Active, Some(1), Some("Exceeded MAX_ORDER_MODIFY_RATE"))]
+    #[case(TradingState::Active, None, None)]
+    fn test_modify_order(
+        context: TestContext,
+        #[case] trading_state: TradingState,
+        #[case] max_modify_rate: Option<u32>,
+        #[case] expected_rejection: Option<&str>,
+    ) {
+        let mut config = RiskEngineConfig::default();
+        if let Some(rate) = max_modify_rate {
+            config.max_order_modify = Quota::per_hour(NonZeroU32::new(rate).unwrap());
+        }
+        let mut engine = context.engine(config);
+        if trading_state != TradingState::Active {
+            engine.set_trading_state(trading_state);
+        }
+        let order = limit_order(OrderSide::Buy, "0.80000", "100000", "O-1");
+        context
+            .cache
+            .borrow_mut()
+            .add_order(order.clone(), None, None, false)
+            .unwrap();
+        if max_modify_rate.is_some() {
+            engine.execute(modify(&order, Some(Quantity::from(200_000)), None));
+            context.commands.borrow_mut().clear();
+        }
+
+        let price = match expected_rejection {
+            Some(reason) if reason.starts_with("price") => Price::from("0.800001"),
+            _ => Price::from("0.90000"),
+        };
+        engine.execute(modify(&order, None, Some(price)));
+
+        let events = context.events.borrow();
+        match expected_rejection {
+            Some(reason) => {
+                assert!(context.commands.borrow().is_empty());
+                let OrderEventAny::ModifyRejected(rejected) = &events[0] else {
+                    panic!("Expected modify rejected");
+                };
+                assert_eq!(rejected.reason, Ustr::from(reason));
+            }
+            None => {
+                assert_eq!(context.commands.borrow().len(), 1);
+                assert!(events.is_empty());
+            }
+        }
+    }
+}
@@ -673,6 +673,11 @@ class BookType(Enum):
     L2_MBP = "L2_MBP"
     L3_MBO = "L3_MBO"
 
+class CrossedBookPolicy(Enum):
+    ACCEPT = "ACCEPT"
+    REJECT = "REJECT"
+    PURGE = "PURGE"
+
 class ContingencyType(Enum):
     OCO = "OCO"
     OTO = "OTO"
@@ -2231,6 +2236,10 @@ class OrderBook:
     def ts_last(self) -> int: ...
     @property
     def count(self) -> int: ...
+    @property
+    def crossed_policy(self) -> CrossedBookPolicy: ...
+    @crossed_policy.setter
+    def crossed_policy(self, value: CrossedBookPolicy) -> None: ...
     def reset(self) -> None: ...
     def add(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...
     def update(self, order: BookOrder, flags: int, sequence: int, ts_event: int) -> None: ...